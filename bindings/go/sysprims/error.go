@@ -4,7 +4,10 @@ package sysprims
 #include "sysprims.h"
 */
 import "C"
-import "runtime"
+import (
+	"encoding/json"
+	"runtime"
+)
 
 // ErrorCode represents sysprims FFI error codes.
 //
@@ -78,6 +81,11 @@ type Error struct {
 	Code ErrorCode
 	// Message is a detailed error message from the library.
 	Message string
+	// Kind names the error variant (e.g. "permission_denied", "not_found").
+	Kind string
+	// Context holds the variant's typed fields (e.g. "pid"); empty when
+	// there are none.
+	Context map[string]interface{}
 }
 
 // Error implements the error interface.
@@ -92,8 +100,8 @@ func (e *Error) Error() string {
 //
 // Important: sysprims stores error details in thread-local storage (TLS). Go
 // goroutines can move between OS threads between cgo calls, so we lock the OS
-// thread to ensure `sysprims_last_error()` reads the error for the same thread
-// that performed the failing call.
+// thread to ensure `sysprims_last_error_json()` reads the error for the same
+// thread that performed the failing call.
 func callAndCheck(call func() C.SysprimsErrorCode) error {
 	runtime.LockOSThread()
 	defer runtime.UnlockOSThread()
@@ -103,11 +111,19 @@ func callAndCheck(call func() C.SysprimsErrorCode) error {
 		return nil
 	}
 
-	msgPtr := C.sysprims_last_error()
-	defer C.sysprims_free_string(msgPtr)
+	jsonPtr := C.sysprims_last_error_json()
+	defer C.sysprims_free_string(jsonPtr)
 
-	return &Error{
-		Code:    ErrorCode(code),
-		Message: C.GoString(msgPtr),
+	sErr := &Error{Code: ErrorCode(code)}
+	var detail struct {
+		Kind    string                 `json:"kind"`
+		Message string                 `json:"message"`
+		Context map[string]interface{} `json:"context"`
+	}
+	if err := json.Unmarshal([]byte(C.GoString(jsonPtr)), &detail); err == nil {
+		sErr.Kind = detail.Kind
+		sErr.Message = detail.Message
+		sErr.Context = detail.Context
 	}
+	return sErr
 }
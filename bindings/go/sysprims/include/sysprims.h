@@ -74,6 +74,11 @@ enum SysprimsGroupingMode {
      * Run in foreground. Only kills direct child on timeout.
      */
     SYSPRIMS_GROUPING_MODE_FOREGROUND = 1,
+    /**
+     * Create new process group but keep it in the terminal foreground.
+     * Kill entire tree on timeout. On Windows, same as GroupByDefault.
+     */
+    SYSPRIMS_GROUPING_MODE_NEW_GROUP_FOREGROUND = 2,
 };
 typedef int32_t SysprimsGroupingMode;
 
@@ -116,6 +121,12 @@ typedef struct SysprimsTimeoutConfig {
      * Whether to preserve the child's exit code.
      */
     bool preserve_status;
+    /**
+     * Expand `$VAR` / `${VAR}` in `command` and `args` against the inherited
+     * environment. Undefined variables expand to empty and are reported in
+     * the result's `warnings`.
+     */
+    bool expand_env;
 } SysprimsTimeoutConfig;
 
 
@@ -189,6 +200,52 @@ uint32_t sysprims_abi_version(void);
  */
 char *sysprims_get_platform(void);
 
+/**
+ * Returns the current platform's capabilities as a JSON C string.
+ *
+ * The JSON matches `capabilities.schema.json`, e.g.:
+ *
+ * ```json
+ * {
+ *   "schema_id": "https://schemas.3leaps.dev/sysprims/core/v1.1.0/capabilities.schema.json",
+ *   "platform": "linux",
+ *   "supports_killpg": true,
+ *   "supports_fd_enumeration": true,
+ *   "port_attribution": "full",
+ *   "supports_setsid": true
+ * }
+ * ```
+ *
+ * # Safety
+ *
+ * The returned pointer must be freed with `sysprims_free_string()`.
+ * Do not use `free()` or any other deallocator.
+ */
+char *sysprims_capabilities_json(void);
+
+/**
+ * Parse a human-friendly duration string into milliseconds.
+ *
+ * Accepts the same syntax as the CLI: `"500ms"`, `"5s"`, `"2m"`, `"1h"`, or
+ * a bare number of seconds (`"5"`, `"0.25"`). Negative values are rejected.
+ * Sub-millisecond precision is truncated; values beyond `u64::MAX` ms saturate.
+ *
+ * # Safety
+ *
+ * - `s` must be a valid null-terminated UTF-8 C string.
+ * - `ms_out` must be a valid pointer to a `u64`.
+ *
+ * # Example (C)
+ *
+ * ```c
+ * uint64_t ms = 0;
+ * if (sysprims_parse_duration_ms("1.5s", &ms) == SYSPRIMS_OK) {
+ *     // ms == 1500
+ * }
+ * ```
+ */
+SysprimsErrorCode sysprims_parse_duration_ms(const char *s, uint64_t *ms_out);
+
 /**
  * Frees a string allocated by sysprims functions.
  *
@@ -241,6 +298,38 @@ SysprimsErrorCode sysprims_last_error_code(void);
  */
 char *sysprims_last_error(void);
 
+/**
+ * Get the last error as a single JSON object.
+ *
+ * Returns an owned string (must be freed with `sysprims_free_string()`) of
+ * the form:
+ *
+ * ```json
+ * {"code": 4, "kind": "permission_denied",
+ *  "message": "Permission denied for 'signal' on PID 1",
+ *  "context": {"pid": 1, "operation": "signal", "needs_elevation": true}}
+ * ```
+ *
+ * `code` matches `sysprims_last_error_code()` and `message` matches
+ * `sysprims_last_error()`, so one call replaces both. `kind` names the error
+ * variant and `context` holds its typed fields (empty when there are none).
+ *
+ * After a successful operation (or after calling `sysprims_clear_error()`),
+ * this returns `{"code":0,"kind":"ok","message":"","context":{}}`. With
+ * `SYSPRIMS_VALIDATE_SCHEMA=1`, a successful call whose output did not match
+ * its schema lists the mismatches in `context.schema_mismatches`.
+ *
+ * # Safety
+ *
+ * The returned pointer must be freed with `sysprims_free_string()`.
+ * The caller owns the returned string.
+ *
+ * # Thread Safety
+ *
+ * Error state is thread-local. Each thread has its own error state.
+ */
+char *sysprims_last_error_json(void);
+
 /**
  * Clear the error state for the current thread.
  *
@@ -370,6 +459,39 @@ SysprimsErrorCode sysprims_proc_list_ex(const char *filter_json,
                                         const char *options_json,
                                         char **result_json_out);
 
+/**
+ * Resolve which PIDs a filter-selected kill would signal, without signaling.
+ *
+ * Applies `filter_json` (same format as [`sysprims_proc_list`]) and then the
+ * PID safety checks that kill workflows enforce (self, PID 1, parent).
+ * Bindings can show this as an authoritative preview before sending signals
+ * to `targets`.
+ *
+ * # Result JSON Format
+ *
+ * ```json
+ * {
+ *   "schema_id": "https://schemas.3leaps.dev/sysprims/signal/v1.1.0/kill-targets.schema.json",
+ *   "targets": [1234, 5678],
+ *   "skipped_safety_pids": [1]
+ * }
+ * ```
+ *
+ * # Returns
+ *
+ * * `SYSPRIMS_OK` on success (result written to `result_json_out`)
+ * * `SYSPRIMS_ERR_INVALID_ARGUMENT` if filter JSON is invalid
+ * * `SYSPRIMS_ERR_SYSTEM` on system error
+ *
+ * # Safety
+ *
+ * * `result_json_out` must be a valid pointer to a `char*`
+ * * `filter_json` must be NULL or a valid UTF-8 C string
+ * * The result string must be freed with `sysprims_free_string()`
+ */
+SysprimsErrorCode sysprims_proc_resolve_kill_targets(const char *filter_json,
+                                                     char **result_json_out);
+
 /**
  * Get information for a single process by PID.
  *
@@ -441,6 +563,18 @@ SysprimsErrorCode sysprims_proc_get_ex(uint32_t pid,
                                        const char *options_json,
                                        char **result_json_out);
 
+/**
+ * Get process info for the calling process.
+ *
+ * Equivalent to `sysprims_proc_get(getpid(), result_json_out)`.
+ *
+ * # Safety
+ *
+ * * `result_json_out` must be a valid pointer to a `char*`
+ * * The result string must be freed with `sysprims_free_string()`
+ */
+SysprimsErrorCode sysprims_proc_get_self(char **result_json_out);
+
 /**
  * Wait for a PID to exit, up to a timeout.
  *
@@ -518,6 +652,7 @@ SysprimsErrorCode sysprims_proc_descendants_ex(uint32_t root_pid,
  * Traverses the process tree from `root_pid`, collects descendant PIDs, and
  * sends the specified signal to each. Safety rules are enforced in this layer:
  * the root PID, self, PID 1, and parent are excluded from the kill list.
+ * Set `"include_root": true` in `filter_json` to signal the root as well.
  *
  * Returns a JSON object with `schema_id`, `signal_sent`, `succeeded`, `failed`,
  * and `skipped_safety` fields.
@@ -533,7 +668,7 @@ SysprimsErrorCode sysprims_proc_descendants_ex(uint32_t root_pid,
  * # Safety Rules (enforced here, not in bindings)
  *
  * The following PIDs are always excluded from the kill list:
- * - The root PID itself (descendants-only)
+ * - The root PID itself (descendants-only, unless `include_root` is set)
  * - The calling process (self)
  * - PID 1 (init/launchd)
  * - The calling process's parent
@@ -558,6 +693,12 @@ SysprimsErrorCode sysprims_proc_kill_descendants(uint32_t root_pid,
  * {"cpu_mode": "lifetime|monitor", "sample_duration_ms": 3000}
  * ```
  *
+ * `poll_duration_ms` (and optional `poll_interval_ms`, default 50) enable
+ * repeated sampling; descendants that exited during the window are never signaled.
+ *
+ * `include_root: true` adds `root_pid` to the kill list regardless of filters
+ * (still subject to the self/PID 1/parent exclusions).
+ *
  * # Safety
  *
  * * `result_json_out` must be a valid pointer to a `char*`
@@ -627,6 +768,36 @@ SysprimsErrorCode sysprims_self_getsid(unsigned int *sid_out);
  */
 SysprimsErrorCode sysprims_signal_send(uint32_t pid, int32_t signal);
 
+/**
+ * Send a signal to a process, resolving the signal by name.
+ *
+ * Accepts the same forms as the CLI: `"TERM"`, `"SIGTERM"`, `"term"`, or a
+ * number such as `"15"`. Glob patterns are not expanded; resolve them first
+ * with `sysprims_signal_resolve` if needed.
+ *
+ * # Arguments
+ *
+ * * `pid` - Process ID (must be > 0 and <= i32::MAX)
+ * * `name` - Null-terminated UTF-8 signal name or number
+ *
+ * # Returns
+ *
+ * * `SYSPRIMS_OK` on success
+ * * `SYSPRIMS_ERR_INVALID_ARGUMENT` if `name` is null or unknown, or pid is invalid
+ * * Other error codes as for `sysprims_signal_send`
+ *
+ * # Safety
+ *
+ * * `name` must be a valid null-terminated C string
+ *
+ * # Example (C)
+ *
+ * ```c
+ * SysprimsErrorCode err = sysprims_signal_send_by_name(pid, "TERM");
+ * ```
+ */
+SysprimsErrorCode sysprims_signal_send_by_name(uint32_t pid, const char *name);
+
 /**
  * Send a signal to a process group.
  *
@@ -693,6 +864,40 @@ SysprimsErrorCode sysprims_terminate(uint32_t pid);
  */
 SysprimsErrorCode sysprims_force_kill(uint32_t pid);
 
+/**
+ * Resolve a signal string to its number.
+ *
+ * Accepts numbers (`"15"`), names with or without the `SIG` prefix in any
+ * case (`"SIGTERM"`, `"TERM"`, `"term"`), and glob patterns that match exactly
+ * one signal (`"*usr1"`).
+ *
+ * # Arguments
+ *
+ * * `signal` - Null-terminated UTF-8 signal string
+ * * `signal_out` - Receives the resolved signal number
+ *
+ * # Returns
+ *
+ * * `SYSPRIMS_OK` on success
+ * * `SYSPRIMS_ERR_INVALID_ARGUMENT` for null pointers, unknown names, or
+ *   patterns matching zero or several signals
+ *
+ * # Safety
+ *
+ * * `signal` must be a valid null-terminated C string
+ * * `signal_out` must be a valid pointer to an `i32`
+ *
+ * # Example (C)
+ *
+ * ```c
+ * int32_t sig = 0;
+ * if (sysprims_signal_resolve("term", &sig) == SYSPRIMS_OK) {
+ *     sysprims_signal_send(pid, sig);
+ * }
+ * ```
+ */
+SysprimsErrorCode sysprims_signal_resolve(const char *signal, int32_t *signal_out);
+
 /**
  * Spawn a process in a new process group (Unix) or Job Object (Windows).
  *
@@ -768,6 +973,7 @@ SysprimsErrorCode sysprims_spawn_in_group(const char *config_json, char **result
  *     .signal = 15,  // SIGTERM
  *     .grouping = SYSPRIMS_GROUP_BY_DEFAULT,
  *     .preserve_status = false,
+ *     .expand_env = false,
  * };
  *
  * char* result = NULL;
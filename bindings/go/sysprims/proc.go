@@ -19,48 +19,137 @@ type ProcessInfo struct {
 	PPID uint32 `json:"ppid"`
 	// Name is the process name (executable name without path).
 	Name string `json:"name"`
+	// NameBytes is the raw bytes of a non-UTF-8 Name (Linux only, requires PreserveRawBytes).
+	NameBytes []byte `json:"name_bytes,omitempty"`
 	// User is the username running the process (may be nil if unavailable).
 	User *string `json:"user,omitempty"`
+	// UID is the owner user ID (Unix only).
+	UID *uint32 `json:"uid,omitempty"`
 	// CPUPercent is the CPU usage percentage (0-100).
 	CPUPercent float64 `json:"cpu_percent"`
+	// CPUUserNS is the total CPU time in user mode (nanoseconds), when available.
+	CPUUserNS *uint64 `json:"cpu_user_ns,omitempty"`
+	// CPUSystemNS is the total CPU time in kernel mode (nanoseconds), when available.
+	CPUSystemNS *uint64 `json:"cpu_system_ns,omitempty"`
 	// MemoryKB is the memory usage in kilobytes.
 	MemoryKB uint64 `json:"memory_kb"`
 	// ElapsedSeconds is the process runtime in seconds (may be nil if unavailable).
 	ElapsedSeconds *uint64 `json:"elapsed_seconds,omitempty"`
 	// StartTimeUnixMS is the process start time (Unix epoch ms), best-effort.
 	StartTimeUnixMS *uint64 `json:"start_time_unix_ms,omitempty"`
+	// StartTime is the process start time as RFC3339 (UTC), present with StartTimeUnixMS.
+	StartTime *string `json:"start_time,omitempty"`
 	// ExePath is the absolute executable path, best-effort.
 	ExePath *string `json:"exe_path,omitempty"`
+	// ExePathBytes is the raw bytes of a non-UTF-8 ExePath (Linux only, requires PreserveRawBytes).
+	ExePathBytes []byte `json:"exe_path_bytes,omitempty"`
+	// ExeInode is the inode of the running executable (Unix, requires IncludeExeStat).
+	ExeInode *uint64 `json:"exe_inode,omitempty"`
+	// ExeDevice is the device ID holding the executable (Unix, requires IncludeExeStat).
+	ExeDevice *uint64 `json:"exe_device,omitempty"`
+	// ExeDeleted reports a deleted or replaced executable (requires IncludeExeStat).
+	ExeDeleted *bool `json:"exe_deleted,omitempty"`
 	// State is the process state (may be nil if unavailable).
 	State *string `json:"state,omitempty"`
 	// Cmdline is the command line arguments (may be empty if unavailable).
 	Cmdline []string `json:"cmdline,omitempty"`
+	// CmdlineBytes is the raw bytes of every argument when any is non-UTF-8, cut to the
+	// same cmdline limits (Linux only, requires PreserveRawBytes).
+	CmdlineBytes [][]byte `json:"cmdline_bytes,omitempty"`
+	// CmdlineString is Cmdline joined into one shell-quoted string (requires IncludeCmdlineString).
+	CmdlineString *string `json:"cmdline_string,omitempty"`
 	// Env is process environment variables (same-user best-effort, may be nil).
 	Env map[string]string `json:"env,omitempty"`
 	// ThreadCount is the best-effort thread count for this process.
 	ThreadCount *uint32 `json:"thread_count,omitempty"`
+	// Cgroup is the cgroup path (Linux only, requires IncludeCgroup).
+	Cgroup *string `json:"cgroup,omitempty"`
+	// SocketCount is the number of open socket descriptors (requires IncludeSocketCount).
+	SocketCount *uint64 `json:"socket_count,omitempty"`
+	// NetRxBytes is bytes received in the process's network namespace, not by the
+	// process alone (Linux only, requires IncludeNet).
+	NetRxBytes *uint64 `json:"net_rx_bytes,omitempty"`
+	// NetTxBytes is bytes transmitted in the process's network namespace (Linux
+	// only, requires IncludeNet).
+	NetTxBytes *uint64 `json:"net_tx_bytes,omitempty"`
+	// FdLimitSoft is the soft open-file limit (Linux only, requires IncludeLimits).
+	// Unlimited is reported as the maximum uint64.
+	FdLimitSoft *uint64 `json:"fd_limit_soft,omitempty"`
+	// FdLimitHard is the hard open-file limit (Linux only, requires IncludeLimits).
+	FdLimitHard *uint64 `json:"fd_limit_hard,omitempty"`
+	// SigBlocked is the blocked-signal bitmask, bit n-1 for signal n (Linux
+	// only, requires IncludeSignalMasks).
+	SigBlocked *uint64 `json:"sig_blocked,omitempty"`
+	// SigIgnored is the ignored-signal bitmask (Linux only, requires IncludeSignalMasks).
+	SigIgnored *uint64 `json:"sig_ignored,omitempty"`
+	// SigCaught is the bitmask of signals with handlers (Linux only, requires IncludeSignalMasks).
+	SigCaught *uint64 `json:"sig_caught,omitempty"`
+	// SID is the session ID (Unix only).
+	SID *uint32 `json:"sid,omitempty"`
+	// Identity is a restart-stable key for the service (requires IncludeIdentity).
+	Identity *string `json:"identity,omitempty"`
 }
 
 // ProcessSnapshot represents a point-in-time listing of processes.
 type ProcessSnapshot struct {
 	// SchemaID identifies the JSON schema version.
 	SchemaID string `json:"schema_id"`
+	// SchemaVersion is the major version of SchemaID.
+	SchemaVersion uint32 `json:"schema_version"`
 	// Timestamp is the ISO 8601 timestamp when the snapshot was taken.
 	Timestamp string `json:"timestamp"`
 	// Processes is the list of process information.
 	Processes []ProcessInfo `json:"processes"`
+	// CPUPercentIsNormalized is false for sampled snapshots, whose per-core
+	// CPUPercent may exceed 100.
+	CPUPercentIsNormalized bool `json:"cpu_percent_is_normalized"`
+	// Filtered reports whether a filter was applied.
+	Filtered bool `json:"filtered"`
+	// TotalBeforeFilter is the number of processes enumerated before
+	// filtering. Set only when Filtered is true.
+	TotalBeforeFilter *int `json:"total_before_filter,omitempty"`
+	// Warnings lists non-fatal enumeration issues, such as PIDs skipped
+	// after a read timeout or truncated cmdlines. Omitted when empty.
+	Warnings []Warning `json:"warnings,omitempty"`
+}
+
+// WarningCode categorizes a non-fatal Warning.
+type WarningCode string
+
+const (
+	WarningPermissionDenied  WarningCode = "permission_denied"
+	WarningPartialVisibility WarningCode = "partial_visibility"
+	WarningTruncated         WarningCode = "truncated"
+	WarningPidReuseGuard     WarningCode = "pid_reuse_guard"
+	WarningDegraded          WarningCode = "degraded"
+	WarningProtected         WarningCode = "protected"
+	WarningConfig            WarningCode = "config"
+	WarningInfo              WarningCode = "info"
+	WarningOther             WarningCode = "other"
+)
+
+// Warning is a non-fatal condition with a stable code and a human-readable message.
+type Warning struct {
+	Code    WarningCode `json:"code"`
+	Message string      `json:"message"`
+}
+
+// String returns the message, so warnings print like plain strings.
+func (w Warning) String() string {
+	return w.Message
 }
 
 // WaitPidResult is the result of waiting for a PID to exit.
 type WaitPidResult struct {
-	SchemaID  string   `json:"schema_id"`
-	Timestamp string   `json:"timestamp"`
-	Platform  string   `json:"platform"`
-	PID       uint32   `json:"pid"`
-	Exited    bool     `json:"exited"`
-	TimedOut  bool     `json:"timed_out"`
-	ExitCode  *int32   `json:"exit_code,omitempty"`
-	Warnings  []string `json:"warnings"`
+	SchemaID      string    `json:"schema_id"`
+	SchemaVersion uint32    `json:"schema_version"`
+	Timestamp     string    `json:"timestamp"`
+	Platform      string    `json:"platform"`
+	PID           uint32    `json:"pid"`
+	Exited        bool      `json:"exited"`
+	TimedOut      bool      `json:"timed_out"`
+	ExitCode      *int32    `json:"exit_code,omitempty"`
+	Warnings      []Warning `json:"warnings"`
 }
 
 type Protocol string
@@ -90,17 +179,22 @@ type PortBinding struct {
 
 // PortBindingsSnapshot represents a point-in-time listing of listening ports.
 type PortBindingsSnapshot struct {
-	SchemaID  string        `json:"schema_id"`
-	Timestamp string        `json:"timestamp"`
-	Platform  string        `json:"platform"`
-	Bindings  []PortBinding `json:"bindings"`
-	Warnings  []string      `json:"warnings"`
+	SchemaID       string        `json:"schema_id"`
+	SchemaVersion  uint32        `json:"schema_version"`
+	Timestamp      string        `json:"timestamp"`
+	Platform       string        `json:"platform"`
+	Bindings       []PortBinding `json:"bindings"`
+	NeedsElevation bool          `json:"needs_elevation"`
+	Warnings       []Warning     `json:"warnings"`
 }
 
 // PortFilter specifies criteria for filtering port bindings.
 type PortFilter struct {
 	Protocol  *Protocol `json:"protocol,omitempty"`
 	LocalPort *uint16   `json:"local_port,omitempty"`
+	// ProcessNameContains filters by owning process name substring (case-insensitive).
+	// Bindings without a resolved process are excluded.
+	ProcessNameContains *string `json:"process_name_contains,omitempty"`
 }
 
 // ProcessFilter specifies criteria for filtering processes.
@@ -111,6 +205,8 @@ type ProcessFilter struct {
 	NameContains *string `json:"name_contains,omitempty"`
 	// NameEquals filters by exact process name match.
 	NameEquals *string `json:"name_equals,omitempty"`
+	// NameGlob filters by glob pattern (* and ?) on the process name (case-sensitive).
+	NameGlob *string `json:"name_glob,omitempty"`
 	// UserEquals filters by exact username match.
 	UserEquals *string `json:"user_equals,omitempty"`
 	// PIDIn filters to only these PIDs.
@@ -125,6 +221,15 @@ type ProcessFilter struct {
 	MemoryAboveKB *uint64 `json:"memory_above_kb,omitempty"`
 	// RunningForAtLeastSecs filters to processes running at least this many seconds.
 	RunningForAtLeastSecs *uint64 `json:"running_for_at_least_secs,omitempty"`
+	// AgeBucketIn filters by coarse age class: "just_started" (<1m), "recent" (<1h),
+	// "established" (<1d), or "long_running" (>=1d).
+	AgeBucketIn []string `json:"age_bucket_in,omitempty"`
+	// ExcludeKernelThreads drops Linux kernel threads (no-op on macOS/Windows).
+	ExcludeKernelThreads bool `json:"exclude_kernel_threads,omitempty"`
+	// CgroupContains filters by cgroup path substring, e.g. a container ID (Linux only).
+	CgroupContains *string `json:"cgroup_contains,omitempty"`
+	// ExeDeletedOnly keeps processes running from a deleted executable (Linux only; matches nothing elsewhere).
+	ExeDeletedOnly bool `json:"exe_deleted_only,omitempty"`
 }
 
 // ProcessOptions controls optional process detail collection.
@@ -135,6 +240,27 @@ type ProcessOptions struct {
 	IncludeEnv bool `json:"include_env,omitempty"`
 	// IncludeThreads requests collection of process thread count.
 	IncludeThreads bool `json:"include_threads,omitempty"`
+	// IncludeCgroup requests collection of cgroup membership (Linux only).
+	IncludeCgroup bool `json:"include_cgroup,omitempty"`
+	// IncludeSocketCount requests a count of open socket descriptors (Linux/macOS).
+	IncludeSocketCount bool `json:"include_socket_count,omitempty"`
+	// IncludeNet requests network namespace byte counters (Linux only).
+	IncludeNet bool `json:"include_net,omitempty"`
+	// IncludeLimits requests the soft and hard open-file limits (Linux only).
+	IncludeLimits bool `json:"include_limits,omitempty"`
+	// IncludeSignalMasks requests the blocked/ignored/caught signal masks (Linux only).
+	IncludeSignalMasks bool `json:"include_signal_masks,omitempty"`
+	// IncludeExeStat requests the executable inode, device, and deleted flag (Linux/macOS).
+	IncludeExeStat bool `json:"include_exe_stat,omitempty"`
+	// IncludeIdentity requests a restart-stable hash of the executable and arguments.
+	IncludeIdentity bool `json:"include_identity,omitempty"`
+	// IncludeCmdlineString requests Cmdline joined into one shell-quoted string.
+	IncludeCmdlineString bool `json:"include_cmdline_string,omitempty"`
+	// ResolveUsernames controls UID-to-username lookups (nil means true). Set to
+	// false to skip slow directory-service lookups and report only UID.
+	ResolveUsernames *bool `json:"resolve_usernames,omitempty"`
+	// PreserveRawBytes requests raw bytes of non-UTF-8 names, paths, and command lines (Linux only).
+	PreserveRawBytes bool `json:"preserve_raw_bytes,omitempty"`
 }
 
 // FdInfo describes an open file descriptor.
@@ -142,16 +268,21 @@ type FdInfo struct {
 	Fd   uint32  `json:"fd"`
 	Kind string  `json:"kind"`
 	Path *string `json:"path,omitempty"`
+	// PathBytes is the raw bytes of Path when it is not valid UTF-8 (Linux only).
+	PathBytes []byte `json:"path_bytes,omitempty"`
 }
 
 // FdSnapshot represents a point-in-time listing of open file descriptors.
 type FdSnapshot struct {
-	SchemaID  string   `json:"schema_id"`
-	Timestamp string   `json:"timestamp"`
-	Platform  string   `json:"platform"`
-	Pid       uint32   `json:"pid"`
-	Fds       []FdInfo `json:"fds"`
-	Warnings  []string `json:"warnings"`
+	SchemaID      string   `json:"schema_id"`
+	SchemaVersion uint32   `json:"schema_version"`
+	Timestamp     string   `json:"timestamp"`
+	Platform      string   `json:"platform"`
+	Pid           uint32   `json:"pid"`
+	Fds           []FdInfo `json:"fds"`
+	// KindCounts maps fd kind ("file", "socket", "pipe", "unknown") to its count in Fds.
+	KindCounts map[string]uint64 `json:"kind_counts,omitempty"`
+	Warnings   []Warning         `json:"warnings"`
 }
 
 // FdFilter specifies criteria for filtering file descriptors.
@@ -256,6 +387,43 @@ func ProcessListWithOptions(filter *ProcessFilter, opts *ProcessOptions) (*Proce
 	return &snapshot, nil
 }
 
+// ResolveKillTargets reports which PIDs a filter-selected kill would signal,
+// without sending any signal.
+//
+// The filter is applied first, then the same PID safety checks kill workflows
+// enforce (self, PID 1, parent). Dropped PIDs are listed in SkippedSafetyPIDs.
+//
+// # Errors
+//
+//   - [ErrInvalidArgument]: Invalid filter JSON
+//   - [ErrSystem]: System error reading process information
+func ResolveKillTargets(filter *ProcessFilter) (*KillTargets, error) {
+	var filterCStr *C.char
+	if filter != nil {
+		filterJSON, err := json.Marshal(filter)
+		if err != nil {
+			return nil, &Error{Code: ErrInvalidArgument, Message: "failed to marshal filter: " + err.Error()}
+		}
+		filterCStr = C.CString(string(filterJSON))
+		defer C.free(unsafe.Pointer(filterCStr))
+	}
+
+	var resultCStr *C.char
+	if err := callAndCheck(func() C.SysprimsErrorCode {
+		return C.sysprims_proc_resolve_kill_targets(filterCStr, &resultCStr)
+	}); err != nil {
+		return nil, err
+	}
+	defer C.sysprims_free_string(resultCStr)
+
+	var targets KillTargets
+	if err := json.Unmarshal([]byte(C.GoString(resultCStr)), &targets); err != nil {
+		return nil, &Error{Code: ErrInternal, Message: "failed to parse response: " + err.Error()}
+	}
+
+	return &targets, nil
+}
+
 // ProcessGet returns information for a single process by PID.
 //
 // # Errors
@@ -339,6 +507,7 @@ type DescendantsLevel struct {
 // DescendantsResult is the result of a descendants traversal.
 type DescendantsResult struct {
 	SchemaID        string             `json:"schema_id"`
+	SchemaVersion   uint32             `json:"schema_version"`
 	RootPID         uint32             `json:"root_pid"`
 	MaxLevels       uint32             `json:"max_levels"`
 	Levels          []DescendantsLevel `json:"levels"`
@@ -351,6 +520,7 @@ type DescendantsResult struct {
 // KillDescendantsResult is the result of a kill-descendants operation.
 type KillDescendantsResult struct {
 	SchemaID      string                `json:"schema_id"`
+	SchemaVersion uint32                `json:"schema_version"`
 	SignalSent    int                   `json:"signal_sent"`
 	RootPID       uint32                `json:"root_pid"`
 	Succeeded     []uint32              `json:"succeeded"`
@@ -364,6 +534,14 @@ type KillDescendantsFail struct {
 	Error string `json:"error"`
 }
 
+// KillTargets is the dry-run result of [ResolveKillTargets].
+type KillTargets struct {
+	SchemaID          string   `json:"schema_id"`
+	SchemaVersion     uint32   `json:"schema_version"`
+	Targets           []uint32 `json:"targets"`
+	SkippedSafetyPIDs []uint32 `json:"skipped_safety_pids"`
+}
+
 type DescendantsOptions struct {
 	// MaxLevels controls traversal depth. Nil means all levels.
 	MaxLevels *uint32
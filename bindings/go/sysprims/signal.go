@@ -2,10 +2,14 @@ package sysprims
 
 /*
 #include "sysprims.h"
+#include <stdlib.h>
 */
 import "C"
 
-import "math"
+import (
+	"math"
+	"unsafe"
+)
 
 const (
 	SIGINT  = 2  // Interrupt
@@ -36,6 +40,25 @@ func Kill(pid uint32, signal int) error {
 	})
 }
 
+// KillByName sends a signal to a process, resolving the signal by name.
+//
+// Accepts names with or without the SIG prefix in any case ("TERM",
+// "SIGTERM", "term") and numbers ("15"). Glob patterns are not expanded;
+// use [ResolveSignal] for those.
+//
+// # Errors
+//
+//   - [ErrInvalidArgument]: Unknown signal name, or pid is 0 or > math.MaxInt32
+//   - Other errors as for [Kill]
+func KillByName(pid uint32, name string) error {
+	cName := C.CString(name)
+	defer C.free(unsafe.Pointer(cName))
+
+	return callAndCheck(func() C.SysprimsErrorCode {
+		return C.sysprims_signal_send_by_name(C.uint32_t(pid), cName)
+	})
+}
+
 type BatchKillFailure struct {
 	PID   uint32
 	Error *Error
@@ -148,3 +171,25 @@ func KillGroup(pgid uint32, signal int) error {
 		return C.sysprims_signal_send_group(C.uint32_t(pgid), C.int32_t(signal))
 	})
 }
+
+// ResolveSignal resolves a signal string to its number.
+//
+// Accepts numbers ("15"), names with or without the SIG prefix in any case
+// ("SIGTERM", "TERM", "term"), and glob patterns matching exactly one signal
+// ("*usr1").
+//
+// # Errors
+//
+//   - [ErrInvalidArgument]: Unknown name, or a pattern matching zero or several signals
+func ResolveSignal(signal string) (int, error) {
+	cSignal := C.CString(signal)
+	defer C.free(unsafe.Pointer(cSignal))
+
+	var out C.int32_t
+	if err := callAndCheck(func() C.SysprimsErrorCode {
+		return C.sysprims_signal_resolve(cSignal, &out)
+	}); err != nil {
+		return 0, err
+	}
+	return int(out), nil
+}
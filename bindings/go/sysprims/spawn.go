@@ -13,16 +13,24 @@ import (
 // SpawnInGroupConfig spawns a process in a new process group (Unix) or Job Object (Windows).
 //
 // Env is treated as overrides/additions to the inherited environment.
+// ExpandEnv expands $VAR/${VAR} in Argv and Cwd against that effective
+// environment; undefined variables expand to empty and add a warning.
+// ClearEnv starts from an empty environment instead, keeping only the
+// parent variables named in EnvPassthrough before Env is applied.
 type SpawnInGroupConfig struct {
-	SchemaID string            `json:"schema_id"`
-	Argv     []string          `json:"argv"`
-	Cwd      *string           `json:"cwd,omitempty"`
-	Env      map[string]string `json:"env,omitempty"`
+	SchemaID       string            `json:"schema_id"`
+	Argv           []string          `json:"argv"`
+	Cwd            *string           `json:"cwd,omitempty"`
+	Env            map[string]string `json:"env,omitempty"`
+	ExpandEnv      bool              `json:"expand_env,omitempty"`
+	ClearEnv       bool              `json:"clear_env,omitempty"`
+	EnvPassthrough []string          `json:"env_passthrough,omitempty"`
 }
 
 // SpawnInGroupResult is the outcome of SpawnInGroup.
 type SpawnInGroupResult struct {
 	SchemaID            string   `json:"schema_id"`
+	SchemaVersion       uint32   `json:"schema_version"`
 	Timestamp           string   `json:"timestamp"`
 	Platform            string   `json:"platform"`
 	PID                 uint32   `json:"pid"`
@@ -33,7 +41,7 @@ type SpawnInGroupResult struct {
 
 func SpawnInGroup(config SpawnInGroupConfig) (*SpawnInGroupResult, error) {
 	if config.SchemaID == "" {
-		config.SchemaID = "https://schemas.3leaps.dev/sysprims/process/v1.0.0/spawn-in-group-config.schema.json"
+		config.SchemaID = "https://schemas.3leaps.dev/sysprims/process/v1.1.0/spawn-in-group-config.schema.json"
 	}
 
 	b, err := json.Marshal(config)
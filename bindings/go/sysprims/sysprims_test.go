@@ -5,7 +5,6 @@ import (
 	"net"
 	"os"
 	"runtime"
-	"strings"
 	"syscall"
 	"testing"
 	"time"
@@ -91,6 +90,22 @@ func TestKillNonexistentPID(t *testing.T) {
 	}
 }
 
+// TestKillByNameUnknownSignal verifies that KillByName rejects unknown signal names.
+func TestKillByNameUnknownSignal(t *testing.T) {
+	err := sysprims.KillByName(uint32(os.Getpid()), "NOTASIGNAL")
+	if err == nil {
+		t.Fatal("KillByName with unknown name should return error")
+	}
+
+	sErr, ok := err.(*sysprims.Error)
+	if !ok {
+		t.Fatalf("Expected *sysprims.Error, got %T", err)
+	}
+	if sErr.Code != sysprims.ErrInvalidArgument {
+		t.Errorf("Expected ErrInvalidArgument, got %d (%s)", sErr.Code, sErr.Code)
+	}
+}
+
 // TestProcessList verifies that ProcessList returns processes.
 func TestProcessList(t *testing.T) {
 	snapshot, err := sysprims.ProcessList(nil)
@@ -143,6 +158,22 @@ func TestProcessListWithOptions(t *testing.T) {
 	}
 }
 
+// TestResolveKillTargetsSkipsSelf verifies the dry run never targets the caller.
+func TestResolveKillTargetsSkipsSelf(t *testing.T) {
+	pid := uint32(os.Getpid())
+	result, err := sysprims.ResolveKillTargets(&sysprims.ProcessFilter{PIDIn: []uint32{pid}})
+	if err != nil {
+		t.Fatalf("ResolveKillTargets failed: %v", err)
+	}
+
+	if len(result.Targets) != 0 {
+		t.Errorf("ResolveKillTargets targeted self: %v", result.Targets)
+	}
+	if len(result.SkippedSafetyPIDs) != 1 || result.SkippedSafetyPIDs[0] != pid {
+		t.Errorf("Expected skipped_safety_pids [%d], got %v", pid, result.SkippedSafetyPIDs)
+	}
+}
+
 // TestProcessGetSelf verifies that ProcessGet works for the current process.
 func TestProcessGetSelf(t *testing.T) {
 	pid := uint32(os.Getpid())
@@ -245,6 +276,12 @@ func TestProcessGetNonexistent(t *testing.T) {
 	if sErr.Code != sysprims.ErrNotFound {
 		t.Errorf("Expected ErrNotFound, got %d (%s)", sErr.Code, sErr.Code)
 	}
+	if sErr.Kind != "not_found" {
+		t.Errorf("Expected kind not_found, got %q", sErr.Kind)
+	}
+	if pid, ok := sErr.Context["pid"].(float64); !ok || pid != 99999999 {
+		t.Errorf("Expected context pid 99999999, got %v", sErr.Context["pid"])
+	}
 }
 
 func TestSelfSessionIDs(t *testing.T) {
@@ -335,7 +372,7 @@ func TestListeningPortsSelfListener(t *testing.T) {
 		// CI runners typically don't have these privileges.
 		hasPermissionWarnings := false
 		for _, w := range snap.Warnings {
-			if strings.Contains(w, "permission") || strings.Contains(w, "Permission") {
+			if w.Code == sysprims.WarningPermissionDenied {
 				hasPermissionWarnings = true
 				break
 			}
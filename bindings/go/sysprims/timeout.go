@@ -21,6 +21,10 @@ const (
 	// Foreground runs without creating a new process group.
 	// Only the direct child is killed on timeout; grandchildren may survive.
 	Foreground GroupingMode = 1
+	// NewGroupForeground creates a new process group (so the whole tree is
+	// killed on timeout) but makes it the terminal's foreground group so the
+	// child keeps terminal access. On Windows this behaves like GroupByDefault.
+	NewGroupForeground GroupingMode = 2
 )
 
 // TimeoutConfig configures the behavior of [RunWithTimeout].
@@ -35,6 +39,10 @@ type TimeoutConfig struct {
 	// PreserveStatus causes the function to return the child's exit code
 	// when the command completes (instead of always returning 0 for success).
 	PreserveStatus bool
+	// ExpandEnv expands $VAR/${VAR} in the command and arguments against the
+	// inherited environment. Undefined variables expand to empty and are
+	// reported in [TimeoutResult.Warnings].
+	ExpandEnv bool
 }
 
 // DefaultTimeoutConfig returns sensible defaults for timeout execution.
@@ -57,7 +65,10 @@ func DefaultTimeoutConfig() TimeoutConfig {
 type TimeoutResult struct {
 	// SchemaID identifies the JSON schema version.
 	SchemaID string `json:"schema_id"`
-	// Status is either "completed" or "timed_out".
+	// SchemaVersion is the major version of SchemaID.
+	SchemaVersion uint32 `json:"schema_version"`
+	// Status is "completed", "timed_out", or "cancelled" (stopped by a
+	// cancellation request before the timeout).
 	Status string `json:"status"`
 	// ExitCode is the exit code if the command completed (nil if timed out).
 	ExitCode *int `json:"exit_code,omitempty"`
@@ -69,6 +80,12 @@ type TimeoutResult struct {
 	// Only present if the command timed out. "best_effort" indicates that on Windows,
 	// Job Object creation may have failed and some child processes might have escaped.
 	TreeKillReliability *string `json:"tree_kill_reliability,omitempty"`
+	// Survivors lists descendants still running after the kill. Only present if
+	// the command timed out or was cancelled; empty when the whole tree is gone.
+	Survivors []uint32 `json:"survivors,omitempty"`
+	// Warnings lists non-fatal issues, such as variables left undefined by
+	// ExpandEnv.
+	Warnings []Warning `json:"warnings"`
 }
 
 // -----------------------------------------------------------------------------
@@ -85,22 +102,33 @@ type TerminateTreeConfig struct {
 	KillTimeoutMS  *uint64 `json:"kill_timeout_ms,omitempty"`
 	Signal         *int32  `json:"signal,omitempty"`
 	KillSignal     *int32  `json:"kill_signal,omitempty"`
+
+	// RequireStartTimeMS and RequireExePath guard against PID reuse: a
+	// mismatch fails the call up front, or skips escalation if it appears
+	// during the grace period.
+	RequireStartTimeMS *uint64 `json:"require_start_time_ms,omitempty"`
+	RequireExePath     *string `json:"require_exe_path,omitempty"`
+
+	// SkipGrace sends KillSignal immediately, skipping Signal and the grace wait.
+	SkipGrace bool `json:"skip_grace,omitempty"`
 }
 
 // TerminateTreeResult is the outcome of a terminate-tree operation.
 type TerminateTreeResult struct {
-	SchemaID            string   `json:"schema_id"`
-	Timestamp           string   `json:"timestamp"`
-	Platform            string   `json:"platform"`
-	PID                 uint32   `json:"pid"`
-	PGID                *uint32  `json:"pgid,omitempty"`
-	SignalSent          int32    `json:"signal_sent"`
-	KillSignal          *int32   `json:"kill_signal,omitempty"`
-	Escalated           bool     `json:"escalated"`
-	Exited              bool     `json:"exited"`
-	TimedOut            bool     `json:"timed_out"`
-	TreeKillReliability string   `json:"tree_kill_reliability"`
-	Warnings            []string `json:"warnings"`
+	SchemaID            string    `json:"schema_id"`
+	SchemaVersion       uint32    `json:"schema_version"`
+	Timestamp           string    `json:"timestamp"`
+	Platform            string    `json:"platform"`
+	PID                 uint32    `json:"pid"`
+	PGID                *uint32   `json:"pgid,omitempty"`
+	SignalSent          int32     `json:"signal_sent"`
+	KillSignal          *int32    `json:"kill_signal,omitempty"`
+	Escalated           bool      `json:"escalated"`
+	Exited              bool      `json:"exited"`
+	TimedOut            bool      `json:"timed_out"`
+	ExitCode            *int      `json:"exit_code,omitempty"`
+	TreeKillReliability string    `json:"tree_kill_reliability"`
+	Warnings            []Warning `json:"warnings"`
 }
 
 // Completed returns true if the command completed without timing out.
@@ -113,6 +141,11 @@ func (r *TimeoutResult) TimedOut() bool {
 	return r.Status == "timed_out"
 }
 
+// Cancelled returns true if the command was terminated by a cancellation request.
+func (r *TimeoutResult) Cancelled() bool {
+	return r.Status == "cancelled"
+}
+
 // RunWithTimeout executes a command with a timeout.
 //
 // If the command doesn't complete within the timeout, it is killed.
@@ -191,6 +224,7 @@ func RunWithTimeout(command string, args []string, timeout time.Duration, config
 		signal:          C.int32_t(config.Signal),
 		grouping:        C.SysprimsGroupingMode(config.Grouping),
 		preserve_status: C.bool(config.PreserveStatus),
+		expand_env:      C.bool(config.ExpandEnv),
 	}
 
 	var resultCStr *C.char
@@ -220,7 +254,7 @@ func RunWithTimeout(command string, args []string, timeout time.Duration, config
 // group kill for better coverage.
 func TerminateTree(pid uint32, config TerminateTreeConfig) (*TerminateTreeResult, error) {
 	if config.SchemaID == "" {
-		config.SchemaID = "https://schemas.3leaps.dev/sysprims/process/v1.0.0/terminate-tree-config.schema.json"
+		config.SchemaID = "https://schemas.3leaps.dev/sysprims/process/v1.1.0/terminate-tree-config.schema.json"
 	}
 
 	configJSON, err := json.Marshal(config)
@@ -1,13 +1,21 @@
 use std::time::Duration;
 
+use napi::bindgen_prelude::AsyncTask;
+use napi::{Env, Task};
 use napi_derive::napi;
-use sysprims_core::schema::{SPAWN_IN_GROUP_CONFIG_V1, TERMINATE_TREE_CONFIG_V1};
+use sysprims_core::schema::{
+    accepts_schema_id, SPAWN_IN_GROUP_CONFIG_V1, TERMINATE_TREE_CONFIG_V1, TIMEOUT_RESULT_V1,
+    TIMEOUT_RUN_CONFIG_V1,
+};
 use sysprims_core::SysprimsError;
 use sysprims_proc::{
-    descendants_with_config_and_options, CpuMode, DescendantsConfig, FdFilter, PortFilter,
-    ProcessFilter, ProcessOptions,
+    descendants_with_config_and_options, CpuMode, DescendantsConfig, FdFilter, PollConfig,
+    PortFilter, ProcessFilter, ProcessOptions,
+};
+use sysprims_timeout::{
+    run_with_timeout, spawn_in_group, terminate_tree, GroupingMode, SpawnInGroupConfig,
+    TerminateTreeConfig, TimeoutConfig, TimeoutOutcome, TreeKillReliability,
 };
-use sysprims_timeout::{spawn_in_group, terminate_tree, SpawnInGroupConfig, TerminateTreeConfig};
 
 #[repr(i32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -121,6 +129,16 @@ pub fn sysprims_abi_version() -> u32 {
 struct ProcessOptionsWire {
     include_env: bool,
     include_threads: bool,
+    include_cgroup: bool,
+    include_socket_count: bool,
+    include_net: bool,
+    include_limits: bool,
+    include_signal_masks: bool,
+    include_exe_stat: bool,
+    include_identity: bool,
+    include_cmdline_string: bool,
+    resolve_usernames: Option<bool>,
+    preserve_raw_bytes: bool,
 }
 
 fn parse_process_options(options_json: &str) -> Result<ProcessOptions, SysprimsError> {
@@ -134,6 +152,17 @@ fn parse_process_options(options_json: &str) -> Result<ProcessOptions, SysprimsE
     Ok(ProcessOptions {
         include_env: wire.include_env,
         include_threads: wire.include_threads,
+        include_cgroup: wire.include_cgroup,
+        include_socket_count: wire.include_socket_count,
+        include_net: wire.include_net,
+        include_limits: wire.include_limits,
+        include_signal_masks: wire.include_signal_masks,
+        include_exe_stat: wire.include_exe_stat,
+        include_identity: wire.include_identity,
+        include_cmdline_string: wire.include_cmdline_string,
+        resolve_usernames: wire.resolve_usernames.unwrap_or(true),
+        preserve_raw_bytes: wire.preserve_raw_bytes,
+        ..ProcessOptions::default()
     })
 }
 
@@ -152,6 +181,10 @@ struct DescendantsConfigWire {
     filter: ProcessFilter,
     cpu_mode: CpuModeWire,
     sample_duration_ms: Option<u64>,
+    poll_interval_ms: Option<u64>,
+    poll_duration_ms: Option<u64>,
+    deadline_ms: Option<u64>,
+    include_root: bool,
 }
 
 #[derive(Debug, Default)]
@@ -159,11 +192,15 @@ struct ParsedDescendantsConfig {
     filter: Option<ProcessFilter>,
     cpu_mode: CpuMode,
     sample_duration: Option<Duration>,
+    poll: Option<PollConfig>,
+    deadline: Option<Duration>,
+    include_root: bool,
 }
 
 fn process_filter_has_criteria(filter: &ProcessFilter) -> bool {
     filter.name_contains.is_some()
         || filter.name_equals.is_some()
+        || filter.name_glob.is_some()
         || filter.user_equals.is_some()
         || filter.pid_in.is_some()
         || filter.ppid.is_some()
@@ -171,6 +208,9 @@ fn process_filter_has_criteria(filter: &ProcessFilter) -> bool {
         || filter.cpu_above.is_some()
         || filter.memory_above_kb.is_some()
         || filter.running_for_at_least_secs.is_some()
+        || filter.exclude_kernel_threads
+        || filter.cgroup_contains.is_some()
+        || filter.exe_deleted_only
 }
 
 fn wire_cpu_mode_to_proc(mode: CpuModeWire) -> CpuMode {
@@ -194,10 +234,26 @@ fn parse_descendants_config(config_json: &str) -> Result<ParsedDescendantsConfig
         None
     };
 
+    let poll = match (wire.poll_interval_ms, wire.poll_duration_ms) {
+        (None, None) => None,
+        (interval_ms, Some(duration_ms)) => Some(PollConfig {
+            interval: Duration::from_millis(interval_ms.unwrap_or(50)),
+            duration: Duration::from_millis(duration_ms),
+        }),
+        (Some(_), None) => {
+            return Err(SysprimsError::invalid_argument(
+                "poll_interval_ms requires poll_duration_ms",
+            ))
+        }
+    };
+
     Ok(ParsedDescendantsConfig {
         filter,
         cpu_mode: wire_cpu_mode_to_proc(wire.cpu_mode),
         sample_duration: wire.sample_duration_ms.map(Duration::from_millis),
+        poll,
+        deadline: wire.deadline_ms.map(Duration::from_millis),
+        include_root: wire.include_root,
     })
 }
 
@@ -267,6 +323,66 @@ pub fn sysprims_proc_list_ex(filter_json: String, options_json: String) -> Syspr
     }
 }
 
+#[napi]
+pub fn sysprims_proc_resolve_kill_targets(filter_json: String) -> SysprimsCallJsonResult {
+    let filter = if filter_json.is_empty() || filter_json == "{}" {
+        ProcessFilter::default()
+    } else {
+        match serde_json::from_str::<ProcessFilter>(&filter_json) {
+            Ok(f) => f,
+            Err(e) => {
+                return err_json(SysprimsError::invalid_argument(format!(
+                    "invalid filter JSON: {}",
+                    e
+                )))
+            }
+        }
+    };
+
+    if let Err(e) = filter.validate() {
+        return err_json(e);
+    }
+
+    let snapshot = match sysprims_proc::snapshot_filtered(&filter) {
+        Ok(s) => s,
+        Err(e) => return err_json(e),
+    };
+
+    let mut targets: Vec<u32> = snapshot.processes.iter().map(|p| p.pid).collect();
+    targets.sort_unstable();
+    targets.dedup();
+
+    // Safety: same exclusions as kill-descendants (self, PID 1, parent).
+    let protected = sysprims_proc::protected_pids();
+    let (skipped_safety_pids, targets): (Vec<u32>, Vec<u32>) =
+        targets.into_iter().partition(|pid| protected.contains(pid));
+
+    let result = KillTargetsWire {
+        schema_id: sysprims_core::schema::KILL_TARGETS_V1.to_string(),
+        targets,
+        skipped_safety_pids,
+    };
+
+    match serde_json::to_string(&result) {
+        Ok(json) => ok_json(json),
+        Err(e) => err_json(SysprimsError::internal(format!(
+            "failed to serialize kill targets: {}",
+            e
+        ))),
+    }
+}
+
+#[derive(serde::Serialize)]
+struct KillTargetsWire {
+    #[serde(
+        flatten,
+        serialize_with = "sysprims_core::schema::serialize_with_version"
+    )]
+    schema_id: String,
+    targets: Vec<u32>,
+    skipped_safety_pids: Vec<u32>,
+}
+
 #[napi]
 pub fn sysprims_proc_listening_ports(filter_json: String) -> SysprimsCallJsonResult {
     let filter = if filter_json.is_empty() || filter_json == "{}" {
@@ -360,12 +476,20 @@ pub fn sysprims_proc_descendants(
         Err(e) => return err_json(e),
     };
 
+    if parsed.include_root {
+        return err_json(SysprimsError::invalid_argument(
+            "include_root only applies to kill_descendants",
+        ));
+    }
+
     let config = DescendantsConfig {
         root_pid,
         max_levels: Some(max_levels),
         filter: parsed.filter,
         cpu_mode: parsed.cpu_mode,
         sample_duration: parsed.sample_duration,
+        poll: parsed.poll,
+        deadline: parsed.deadline,
     };
 
     match descendants_with_config_and_options(config, ProcessOptions::default()) {
@@ -391,6 +515,7 @@ pub fn sysprims_proc_kill_descendants(
         Ok(c) => c,
         Err(e) => return err_json(e),
     };
+    let include_root = parsed.include_root;
 
     let config = DescendantsConfig {
         root_pid,
@@ -398,6 +523,8 @@ pub fn sysprims_proc_kill_descendants(
         filter: parsed.filter,
         cpu_mode: parsed.cpu_mode,
         sample_duration: parsed.sample_duration,
+        poll: parsed.poll,
+        deadline: parsed.deadline,
     };
 
     // Traverse descendants before sending any signal.
@@ -415,19 +542,23 @@ pub fn sysprims_proc_kill_descendants(
     target_pids.sort_unstable();
     target_pids.dedup();
 
-    // Safety: exclude root PID (descendants-only)
-    target_pids.retain(|&pid| pid != root_pid);
-
-    // Safety: exclude self, PID 1, parent
-    let self_pid = std::process::id();
-    let parent_pid = sysprims_proc::get_process(self_pid).ok().map(|p| p.ppid);
+    // Safety: never signal PIDs only seen while polling; they have exited and
+    // the PID may already belong to an unrelated process.
+    if let Some(transient) = desc_result.transient_pids.as_ref() {
+        target_pids.retain(|pid| !transient.contains(pid));
+    }
 
-    let before = target_pids.len();
-    target_pids.retain(|&pid| pid != self_pid && pid != 1);
-    if let Some(ppid) = parent_pid {
-        target_pids.retain(|&pid| pid != ppid);
+    // Safety: exclude root PID unless explicitly requested
+    if include_root {
+        if !target_pids.contains(&root_pid) {
+            target_pids.push(root_pid);
+        }
+    } else {
+        target_pids.retain(|&pid| pid != root_pid);
     }
-    let skipped_safety = before.saturating_sub(target_pids.len());
+
+    // Safety: exclude self, PID 1, parent
+    let skipped_safety = sysprims_proc::filter_protected(&mut target_pids, false);
 
     // Build result
     let (succeeded, failed) = if target_pids.is_empty() {
@@ -475,6 +606,10 @@ struct KillDescendantsFailureWire {
 
 #[derive(serde::Serialize)]
 struct KillDescendantsResultWire {
+    #[serde(
+        flatten,
+        serialize_with = "sysprims_core::schema::serialize_with_version"
+    )]
     schema_id: String,
     signal_sent: i32,
     root_pid: u32,
@@ -531,6 +666,14 @@ pub fn sysprims_signal_send(pid: u32, signal: i32) -> SysprimsCallVoidResult {
     }
 }
 
+#[napi]
+pub fn sysprims_signal_send_by_name(pid: u32, name: String) -> SysprimsCallVoidResult {
+    match sysprims_signal::resolve_signal(&name).and_then(|sig| sysprims_signal::kill(pid, sig)) {
+        Ok(()) => ok_void(),
+        Err(e) => err_void(e),
+    }
+}
+
 #[napi]
 pub fn sysprims_signal_send_group(pgid: u32, signal: i32) -> SysprimsCallVoidResult {
     match sysprims_signal::killpg(pgid, signal) {
@@ -539,6 +682,14 @@ pub fn sysprims_signal_send_group(pgid: u32, signal: i32) -> SysprimsCallVoidRes
     }
 }
 
+#[napi]
+pub fn sysprims_signal_resolve(signal: String) -> SysprimsCallU32Result {
+    match sysprims_signal::resolve_signal_or_pattern(&signal) {
+        Ok(target) => ok_u32(target.number() as u32),
+        Err(e) => err_u32(e),
+    }
+}
+
 #[napi]
 pub fn sysprims_terminate(pid: u32) -> SysprimsCallVoidResult {
     match sysprims_signal::terminate(pid) {
@@ -573,6 +724,12 @@ struct WireTerminateTreeConfig {
     signal: Option<i32>,
     #[serde(default)]
     kill_signal: Option<i32>,
+    #[serde(default)]
+    require_start_time_ms: Option<u64>,
+    #[serde(default)]
+    require_exe_path: Option<String>,
+    #[serde(default)]
+    skip_grace: bool,
 }
 
 fn default_terminate_tree_schema_id() -> String {
@@ -594,6 +751,9 @@ impl From<WireTerminateTreeConfig> for TerminateTreeConfig {
         if let Some(v) = value.kill_signal {
             cfg.kill_signal = v;
         }
+        cfg.require_start_time_ms = value.require_start_time_ms;
+        cfg.require_exe_path = value.require_exe_path;
+        cfg.skip_grace = value.skip_grace;
         cfg
     }
 }
@@ -613,7 +773,7 @@ pub fn sysprims_terminate_tree(pid: u32, config_json: String) -> SysprimsCallJso
             }
         };
 
-        if wire.schema_id != TERMINATE_TREE_CONFIG_V1 {
+        if !accepts_schema_id(TERMINATE_TREE_CONFIG_V1, &wire.schema_id) {
             return err_json(SysprimsError::invalid_argument(format!(
                 "invalid schema_id (expected {})",
                 TERMINATE_TREE_CONFIG_V1
@@ -648,6 +808,12 @@ struct WireSpawnInGroupConfig {
     cwd: Option<String>,
     #[serde(default)]
     env: Option<std::collections::BTreeMap<String, String>>,
+    #[serde(default)]
+    expand_env: bool,
+    #[serde(default)]
+    clear_env: bool,
+    #[serde(default)]
+    env_passthrough: Vec<String>,
 }
 
 #[napi]
@@ -668,7 +834,7 @@ pub fn sysprims_spawn_in_group(config_json: String) -> SysprimsCallJsonResult {
         }
     };
 
-    if wire.schema_id != SPAWN_IN_GROUP_CONFIG_V1 {
+    if !accepts_schema_id(SPAWN_IN_GROUP_CONFIG_V1, &wire.schema_id) {
         return err_json(SysprimsError::invalid_argument(format!(
             "invalid schema_id (expected {})",
             SPAWN_IN_GROUP_CONFIG_V1
@@ -679,6 +845,9 @@ pub fn sysprims_spawn_in_group(config_json: String) -> SysprimsCallJsonResult {
         argv: wire.argv,
         cwd: wire.cwd,
         env: wire.env,
+        expand_env: wire.expand_env,
+        clear_env: wire.clear_env,
+        env_passthrough: wire.env_passthrough,
     };
 
     match spawn_in_group(cfg) {
@@ -692,3 +861,192 @@ pub fn sysprims_spawn_in_group(config_json: String) -> SysprimsCallJsonResult {
         Err(e) => err_json(e),
     }
 }
+
+// -----------------------------------------------------------------------------
+// Timeout Run
+// -----------------------------------------------------------------------------
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct WireTimeoutRunConfig {
+    schema_id: String,
+    argv: Vec<String>,
+    timeout_ms: u64,
+    #[serde(default)]
+    kill_after_ms: Option<u64>,
+    #[serde(default)]
+    signal: Option<i32>,
+    #[serde(default)]
+    grouping: Option<GroupingMode>,
+    #[serde(default)]
+    preserve_status: Option<bool>,
+    #[serde(default)]
+    expand_env: Option<bool>,
+    #[serde(default)]
+    clear_env: Option<bool>,
+    #[serde(default)]
+    env_passthrough: Option<Vec<String>>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct WireTimeoutResult {
+    #[serde(
+        flatten,
+        serialize_with = "sysprims_core::schema::serialize_with_version"
+    )]
+    schema_id: &'static str,
+    status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exit_code: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signal_sent: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    escalated: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tree_kill_reliability: Option<TreeKillReliability>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    survivors: Option<Vec<u32>>,
+    warnings: Vec<sysprims_core::Warning>,
+}
+
+impl From<TimeoutOutcome> for WireTimeoutResult {
+    fn from(outcome: TimeoutOutcome) -> Self {
+        match outcome {
+            TimeoutOutcome::Completed {
+                exit_status,
+                warnings,
+            } => WireTimeoutResult {
+                schema_id: TIMEOUT_RESULT_V1,
+                status: "completed",
+                exit_code: exit_status.code(),
+                signal_sent: None,
+                escalated: None,
+                tree_kill_reliability: None,
+                survivors: None,
+                warnings,
+            },
+            TimeoutOutcome::TimedOut {
+                signal_sent,
+                escalated,
+                tree_kill_reliability,
+                survivors,
+                warnings,
+            } => WireTimeoutResult {
+                schema_id: TIMEOUT_RESULT_V1,
+                status: "timed_out",
+                exit_code: None,
+                signal_sent: Some(signal_sent),
+                escalated: Some(escalated),
+                tree_kill_reliability: Some(tree_kill_reliability),
+                survivors: Some(survivors),
+                warnings,
+            },
+            TimeoutOutcome::Cancelled {
+                signal_sent,
+                escalated,
+                tree_kill_reliability,
+                survivors,
+                warnings,
+            } => WireTimeoutResult {
+                schema_id: TIMEOUT_RESULT_V1,
+                status: "cancelled",
+                exit_code: None,
+                signal_sent: Some(signal_sent),
+                escalated: Some(escalated),
+                tree_kill_reliability: Some(tree_kill_reliability),
+                survivors: Some(survivors),
+                warnings,
+            },
+        }
+    }
+}
+
+fn timeout_run_json(config_json: &str) -> SysprimsCallJsonResult {
+    if config_json.is_empty() {
+        return err_json(SysprimsError::invalid_argument(
+            "config_json cannot be empty",
+        ));
+    }
+
+    let wire = match serde_json::from_str::<WireTimeoutRunConfig>(config_json) {
+        Ok(v) => v,
+        Err(e) => {
+            return err_json(SysprimsError::invalid_argument(format!(
+                "invalid config JSON: {}",
+                e
+            )))
+        }
+    };
+
+    if wire.schema_id != TIMEOUT_RUN_CONFIG_V1 {
+        return err_json(SysprimsError::invalid_argument(format!(
+            "invalid schema_id (expected {})",
+            TIMEOUT_RUN_CONFIG_V1
+        )));
+    }
+
+    let Some((command, args)) = wire.argv.split_first() else {
+        return err_json(SysprimsError::invalid_argument("argv cannot be empty"));
+    };
+    if wire.timeout_ms == 0 {
+        return err_json(SysprimsError::invalid_argument("timeout_ms must be > 0"));
+    }
+
+    let mut cfg = TimeoutConfig::default();
+    if let Some(v) = wire.kill_after_ms {
+        cfg.kill_after = Duration::from_millis(v);
+    }
+    if let Some(v) = wire.signal {
+        cfg.signal = v;
+    }
+    if let Some(v) = wire.grouping {
+        cfg.grouping = v;
+    }
+    if let Some(v) = wire.preserve_status {
+        cfg.preserve_status = v;
+    }
+    if let Some(v) = wire.expand_env {
+        cfg.expand_env = v;
+    }
+    if let Some(v) = wire.clear_env {
+        cfg.clear_env = v;
+    }
+    if let Some(v) = wire.env_passthrough {
+        cfg.env_passthrough = v;
+    }
+
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    match run_with_timeout(command, &args, Duration::from_millis(wire.timeout_ms), cfg) {
+        Ok(outcome) => match serde_json::to_string(&WireTimeoutResult::from(outcome)) {
+            Ok(json) => ok_json(json),
+            Err(e) => err_json(SysprimsError::internal(format!(
+                "failed to serialize timeout result: {}",
+                e
+            ))),
+        },
+        Err(e) => err_json(e),
+    }
+}
+
+/// Runs `run_with_timeout` on the libuv threadpool so the event loop stays free.
+pub struct TimeoutRunTask {
+    config_json: String,
+}
+
+impl Task for TimeoutRunTask {
+    type Output = SysprimsCallJsonResult;
+    type JsValue = SysprimsCallJsonResult;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        Ok(timeout_run_json(&self.config_json))
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+#[napi]
+pub fn sysprims_timeout_run(config_json: String) -> AsyncTask<TimeoutRunTask> {
+    AsyncTask::new(TimeoutRunTask { config_json })
+}
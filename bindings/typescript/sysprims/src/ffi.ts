@@ -40,6 +40,7 @@ export type SysprimsLib = {
   sysprimsProcGetEx: (pid: number, optionsJson: string) => SysprimsCallJsonResult;
   sysprimsProcList: (filterJson: string) => SysprimsCallJsonResult;
   sysprimsProcListEx: (filterJson: string, optionsJson: string) => SysprimsCallJsonResult;
+  sysprimsProcResolveKillTargets: (filterJson: string) => SysprimsCallJsonResult;
   sysprimsProcListeningPorts: (filterJson: string) => SysprimsCallJsonResult;
   sysprimsProcWaitPid: (pid: number, timeoutMs: number) => SysprimsCallJsonResult;
   sysprimsProcListFds: (pid: number, filterJson: string) => SysprimsCallJsonResult;
@@ -63,7 +64,9 @@ export type SysprimsLib = {
 
   // Signals
   sysprimsSignalSend: (pid: number, signal: number) => SysprimsCallVoidResult;
+  sysprimsSignalSendByName: (pid: number, name: string) => SysprimsCallVoidResult;
   sysprimsSignalSendGroup: (pgid: number, signal: number) => SysprimsCallVoidResult;
+  sysprimsSignalResolve: (signal: string) => SysprimsCallU32Result;
   sysprimsTerminate: (pid: number) => SysprimsCallVoidResult;
   sysprimsForceKill: (pid: number) => SysprimsCallVoidResult;
 
@@ -72,6 +75,9 @@ export type SysprimsLib = {
 
   // Spawn in group
   sysprimsSpawnInGroup: (configJson: string) => SysprimsCallJsonResult;
+
+  // Timeout run (runs off the event loop)
+  sysprimsTimeoutRun: (configJson: string) => Promise<SysprimsCallJsonResult>;
 };
 
 function raiseSysprimsError(code: number, message?: string): never {
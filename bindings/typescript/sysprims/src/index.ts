@@ -10,6 +10,7 @@ import type {
   FdSnapshot,
   KillDescendantsOptions,
   KillDescendantsResult,
+  KillTargets,
   PortBindingsSnapshot,
   PortFilter,
   ProcessFilter,
@@ -20,11 +21,14 @@ import type {
   SpawnInGroupResult,
   TerminateTreeConfig,
   TerminateTreeResult,
+  TimeoutRunConfig,
+  TimeoutRunResult,
   WaitPidResult,
 } from "./types";
 
 export { SysprimsError, SysprimsErrorCode };
 export type {
+  AgeBucket,
   BatchKillFailure,
   BatchKillResult,
   CpuMode,
@@ -33,9 +37,11 @@ export type {
   DescendantsResult,
   FdFilter,
   FdSnapshot,
+  GroupingMode,
   KillDescendantsFailure,
   KillDescendantsOptions,
   KillDescendantsResult,
+  KillTargets,
   PortBinding,
   PortBindingsSnapshot,
   PortFilter,
@@ -49,7 +55,11 @@ export type {
   SpawnInGroupResult,
   TerminateTreeConfig,
   TerminateTreeResult,
+  TimeoutRunConfig,
+  TimeoutRunResult,
   WaitPidResult,
+  Warning,
+  WarningCode,
 } from "./types";
 
 // -----------------------------------------------------------------------------
@@ -61,15 +71,71 @@ function serializeProcessOptions(options?: ProcessOptions): string {
     return "";
   }
 
-  const wire: { include_env?: boolean; include_threads?: boolean } = {};
+  const wire: {
+    include_env?: boolean;
+    include_threads?: boolean;
+    include_cgroup?: boolean;
+    include_socket_count?: boolean;
+    include_net?: boolean;
+    include_limits?: boolean;
+    include_signal_masks?: boolean;
+    include_exe_stat?: boolean;
+    include_identity?: boolean;
+    include_cmdline_string?: boolean;
+    resolve_usernames?: boolean;
+    preserve_raw_bytes?: boolean;
+  } = {};
   if (options.includeEnv === true) {
     wire.include_env = true;
   }
   if (options.includeThreads === true) {
     wire.include_threads = true;
   }
+  if (options.includeCgroup === true) {
+    wire.include_cgroup = true;
+  }
+  if (options.includeSocketCount === true) {
+    wire.include_socket_count = true;
+  }
+  if (options.includeNet === true) {
+    wire.include_net = true;
+  }
+  if (options.includeLimits === true) {
+    wire.include_limits = true;
+  }
+  if (options.includeSignalMasks === true) {
+    wire.include_signal_masks = true;
+  }
+  if (options.includeExeStat === true) {
+    wire.include_exe_stat = true;
+  }
+  if (options.includeIdentity === true) {
+    wire.include_identity = true;
+  }
+  if (options.includeCmdlineString === true) {
+    wire.include_cmdline_string = true;
+  }
+  if (options.resolveUsernames === false) {
+    wire.resolve_usernames = false;
+  }
+  if (options.preserveRawBytes === true) {
+    wire.preserve_raw_bytes = true;
+  }
 
-  if (!wire.include_env && !wire.include_threads) {
+  if (
+    !wire.include_env &&
+    !wire.include_threads &&
+    !wire.include_cgroup &&
+    !wire.include_socket_count &&
+    !wire.include_net &&
+    !wire.include_limits &&
+    !wire.include_signal_masks &&
+    !wire.include_exe_stat &&
+    !wire.include_identity &&
+    !wire.include_cmdline_string &&
+    !wire.preserve_raw_bytes &&
+    wire.resolve_usernames === undefined
+  ) {
     return "";
   }
 
@@ -169,6 +235,26 @@ export function processList(filter?: ProcessFilter, options?: ProcessOptions): P
   return result as ProcessSnapshot;
 }
 
+/**
+ * Resolve which PIDs a filter-selected kill would signal, without signaling.
+ *
+ * Applies the filter, then the PID safety checks kill workflows enforce
+ * (self, PID 1, parent). Use this to preview a batch kill before sending it.
+ *
+ * @param filter - Optional filter criteria (same as {@link processList})
+ * @returns Target PIDs plus the PIDs dropped by safety checks
+ *
+ * @example
+ * const preview = resolveKillTargets({ name_equals: "worker" });
+ * killMany(preview.targets, "TERM");
+ */
+export function resolveKillTargets(filter?: ProcessFilter): KillTargets {
+  const lib = loadSysprims();
+  const filterJson = filter ? JSON.stringify(filter) : "";
+  const result = callJsonReturn(() => lib.sysprimsProcResolveKillTargets(filterJson));
+  return result as KillTargets;
+}
+
 /**
  * List listening network ports with optional filtering.
  *
@@ -347,6 +433,30 @@ export function selfSID(): number {
 // Signal Operations
 // -----------------------------------------------------------------------------
 
+/**
+ * Resolve a signal name, number, or glob to its signal number.
+ *
+ * Accepts numbers (`15`, `"15"`), names with or without the `SIG` prefix in
+ * any case (`"SIGTERM"`, `"TERM"`, `"term"`), and glob patterns matching
+ * exactly one signal (`"*usr1"`).
+ *
+ * @param signal - Signal number or string
+ * @throws {SysprimsError} InvalidArgument for unknown names or ambiguous patterns
+ *
+ * @example
+ * resolveSignal("term"); // 15
+ */
+export function resolveSignal(signal: number | string): number {
+  if (typeof signal === "number") {
+    if (!Number.isInteger(signal)) {
+      throw new SysprimsError(SysprimsErrorCode.InvalidArgument, "signal must be an integer");
+    }
+    return signal | 0;
+  }
+  const lib = loadSysprims();
+  return callU32Out(() => lib.sysprimsSignalResolve(signal));
+}
+
 /**
  * Send a signal to a process.
  *
@@ -355,7 +465,7 @@ export function selfSID(): number {
  * meaningful; other signals may return NotSupported.
  *
  * @param pid - Target process ID
- * @param signal - Signal number (Unix semantics)
+ * @param signal - Signal number (Unix semantics) or name such as `"TERM"` (see {@link resolveSignal})
  * @throws {SysprimsError} NotFound if process does not exist
  * @throws {SysprimsError} PermissionDenied if access is denied
  * @throws {SysprimsError} NotSupported if signal is not supported on this platform
@@ -367,10 +477,16 @@ export function selfSID(): number {
  * @example
  * // Send SIGTERM
  * signalSend(1234, 15);
+ * signalSend(1234, "TERM");
  */
-export function signalSend(pid: number, signal: number): void {
+export function signalSend(pid: number, signal: number | string): void {
   const lib = loadSysprims();
-  callVoid(() => lib.sysprimsSignalSend(pid >>> 0, signal | 0));
+  if (typeof signal === "string") {
+    callVoid(() => lib.sysprimsSignalSendByName(pid >>> 0, signal));
+    return;
+  }
+  const signum = resolveSignal(signal);
+  callVoid(() => lib.sysprimsSignalSend(pid >>> 0, signum));
 }
 
 /**
@@ -380,14 +496,15 @@ export function signalSend(pid: number, signal: number): void {
  * On Windows: NotSupported (no process group concept).
  *
  * @param pgid - Target process group ID
- * @param signal - Signal number (Unix semantics)
+ * @param signal - Signal number (Unix semantics) or name such as `"TERM"`
  * @throws {SysprimsError} NotFound if process group does not exist
  * @throws {SysprimsError} PermissionDenied if access is denied
  * @throws {SysprimsError} NotSupported on Windows
  */
-export function signalSendGroup(pgid: number, signal: number): void {
+export function signalSendGroup(pgid: number, signal: number | string): void {
+  const signum = resolveSignal(signal);
   const lib = loadSysprims();
-  callVoid(() => lib.sysprimsSignalSendGroup(pgid >>> 0, signal | 0));
+  callVoid(() => lib.sysprimsSignalSendGroup(pgid >>> 0, signum));
 }
 
 /**
@@ -442,26 +559,20 @@ function validatePidList(pids: number[]): void {
   }
 }
 
-function validateSignal(signal: number): void {
-  if (!Number.isInteger(signal)) {
-    throw new SysprimsError(SysprimsErrorCode.InvalidArgument, "signal must be an integer");
-  }
-}
-
 /**
  * Send a signal to multiple processes.
  *
  * PID validation happens for the entire slice before any signals are sent.
  * Individual failures are collected and returned.
  */
-export function killMany(pids: number[], signal: number): BatchKillResult {
+export function killMany(pids: number[], signal: number | string): BatchKillResult {
   validatePidList(pids);
-  validateSignal(signal);
+  const signum = resolveSignal(signal);
 
   const result: BatchKillResult = { succeeded: [], failed: [] };
   for (const pid of pids) {
     try {
-      signalSend(pid, signal);
+      signalSend(pid, signum);
       result.succeeded.push(pid >>> 0);
     } catch (e) {
       const msg = e instanceof Error ? e.message : String(e);
@@ -537,7 +648,7 @@ export function terminateTree(pid: number, config?: TerminateTreeConfig): Termin
   const cfg: TerminateTreeConfig = {
     schema_id:
       config.schema_id ||
-      "https://schemas.3leaps.dev/sysprims/process/v1.0.0/terminate-tree-config.schema.json",
+      "https://schemas.3leaps.dev/sysprims/process/v1.1.0/terminate-tree-config.schema.json",
     ...config,
   };
 
@@ -555,8 +666,39 @@ export function spawnInGroup(config: SpawnInGroupConfig): SpawnInGroupResult {
   const cfg: SpawnInGroupConfig = {
     schema_id:
       config.schema_id ||
-      "https://schemas.3leaps.dev/sysprims/process/v1.0.0/spawn-in-group-config.schema.json",
+      "https://schemas.3leaps.dev/sysprims/process/v1.1.0/spawn-in-group-config.schema.json",
     ...config,
   };
   return callJsonReturn(() => lib.sysprimsSpawnInGroup(JSON.stringify(cfg))) as SpawnInGroupResult;
 }
+
+// -----------------------------------------------------------------------------
+// Timeout Run
+// -----------------------------------------------------------------------------
+
+/**
+ * Run a command with a timeout, killing its process tree if it overruns.
+ *
+ * The command runs on the libuv threadpool, so the event loop is not blocked
+ * while waiting.
+ *
+ * @param config - Command argv, timeout, and optional signal/grouping settings
+ * @returns Promise resolving to the completed or timed-out outcome
+ * @throws {SysprimsError} InvalidArgument if the config is invalid
+ * @throws {SysprimsError} NotFound or SpawnFailed if the command cannot be started
+ *
+ * @example
+ * const result = await timeoutRun({ argv: ["make", "build"], timeout_ms: 300_000 });
+ * if (result.status === "timed_out") console.error("build timed out");
+ */
+export async function timeoutRun(config: TimeoutRunConfig): Promise<TimeoutRunResult> {
+  const lib = loadSysprims();
+  const cfg: TimeoutRunConfig = {
+    schema_id:
+      config.schema_id ||
+      "https://schemas.3leaps.dev/sysprims/timeout/v1.0.0/timeout-run-config.schema.json",
+    ...config,
+  };
+  const r = await lib.sysprimsTimeoutRun(JSON.stringify(cfg));
+  return callJsonReturn(() => r) as TimeoutRunResult;
+}
@@ -2,7 +2,11 @@ export type JsonObject = Record<string, unknown>;
 
 // Process types
 
-export type ProcessState = "running" | "sleeping" | "stopped" | "zombie" | "unknown";
+export type ProcessState = "running" | "sleeping" | "disk_sleep" | "stopped" | "zombie" | "unknown";
+
+/** Coarse process age: <1m, <1h, <1d, >=1d. */
+export type AgeBucket = "just_started" | "recent" | "established" | "long_running";
+
 export type CpuMode = "lifetime" | "monitor";
 
 /**
@@ -13,16 +17,62 @@ export interface ProcessInfo {
   pid: number;
   ppid: number;
   name: string;
+  /** Raw bytes of a non-UTF-8 `name`, base64 (Linux only, requires `preserveRawBytes`). */
+  name_bytes?: string | null;
   user?: string | null;
+  /** Owner user ID (Unix only). */
+  uid?: number | null;
   cpu_percent: number;
+  /** Total CPU time in user mode (nanoseconds), when available. */
+  cpu_user_ns?: number | null;
+  /** Total CPU time in kernel mode (nanoseconds), when available. */
+  cpu_system_ns?: number | null;
   memory_kb: number;
   elapsed_seconds: number;
   start_time_unix_ms?: number | null;
+  start_time?: string | null;
   exe_path?: string | null;
+  /** Raw bytes of a non-UTF-8 `exe_path`, base64 (Linux only, requires `preserveRawBytes`). */
+  exe_path_bytes?: string | null;
+  /** Executable inode (Unix, requires `includeExeStat`). */
+  exe_inode?: number | null;
+  /** Device ID holding the executable (Unix, requires `includeExeStat`). */
+  exe_device?: number | null;
+  /** Executable deleted or replaced since start (requires `includeExeStat`). */
+  exe_deleted?: boolean | null;
   state: ProcessState;
   cmdline: string[];
+  /** Raw bytes of every argument, base64, when any is non-UTF-8, cut to the cmdline limits (Linux only, requires `preserveRawBytes`). */
+  cmdline_bytes?: string[] | null;
+  /** Shell-quoted `cmdline` joined into one string (requires `includeCmdlineString`). */
+  cmdline_string?: string | null;
   env?: Record<string, string> | null;
   thread_count?: number | null;
+  /** Cgroup path (Linux only, requires `includeCgroup`). */
+  cgroup?: string | null;
+  /** Open socket descriptors (requires `includeSocketCount`; absent on Windows). */
+  socket_count?: number | null;
+  /** Bytes received in the process's network namespace (Linux only, requires `includeNet`). */
+  net_rx_bytes?: number | null;
+  /** Bytes transmitted in the process's network namespace (Linux only, requires `includeNet`). */
+  net_tx_bytes?: number | null;
+  /** Soft open-file limit (Linux only, requires `includeLimits`). */
+  fd_limit_soft?: number | null;
+  /** Hard open-file limit (Linux only, requires `includeLimits`). */
+  fd_limit_hard?: number | null;
+  /**
+   * Blocked-signal bitmask, bit n-1 for signal n (Linux only, requires `includeSignalMasks`).
+   * Values can exceed 2^53; bits above 53 may be lost.
+   */
+  sig_blocked?: number | null;
+  /** Ignored-signal bitmask (Linux only, requires `includeSignalMasks`). */
+  sig_ignored?: number | null;
+  /** Bitmask of signals with handlers (Linux only, requires `includeSignalMasks`). */
+  sig_caught?: number | null;
+  /** Session ID (Unix only). */
+  sid?: number | null;
+  /** Restart-stable hash of executable and arguments (requires `includeIdentity`). */
+  identity?: string | null;
 }
 
 /**
@@ -32,6 +82,8 @@ export interface ProcessInfo {
 export interface ProcessFilter {
   name_contains?: string;
   name_equals?: string;
+  /** Glob pattern (`*`, `?`) matched case-sensitively against the process name. */
+  name_glob?: string;
   user_equals?: string;
   pid_in?: number[];
   ppid?: number;
@@ -39,6 +91,13 @@ export interface ProcessFilter {
   cpu_above?: number;
   memory_above_kb?: number;
   running_for_at_least_secs?: number;
+  age_bucket_in?: AgeBucket[];
+  /** Drop Linux kernel threads (no-op on macOS/Windows). */
+  exclude_kernel_threads?: boolean;
+  /** Cgroup path substring, e.g. a container ID (Linux only). */
+  cgroup_contains?: string;
+  /** Keep processes running from a deleted executable (Linux only; matches nothing elsewhere). */
+  exe_deleted_only?: boolean;
 }
 
 /**
@@ -49,6 +108,26 @@ export interface ProcessFilter {
 export interface ProcessOptions {
   includeEnv?: boolean;
   includeThreads?: boolean;
+  /** Collect cgroup membership (Linux only). */
+  includeCgroup?: boolean;
+  /** Count open socket descriptors (Linux/macOS). */
+  includeSocketCount?: boolean;
+  /** Collect network namespace byte counters (Linux only). */
+  includeNet?: boolean;
+  /** Collect the open-file limit (Linux only). */
+  includeLimits?: boolean;
+  /** Collect blocked/ignored/caught signal masks (Linux only). */
+  includeSignalMasks?: boolean;
+  /** Stat the executable for inode/device and deleted detection (Linux/macOS). */
+  includeExeStat?: boolean;
+  /** Compute a restart-stable identity key. */
+  includeIdentity?: boolean;
+  /** Join `cmdline` into a shell-quoted string. */
+  includeCmdlineString?: boolean;
+  /** Resolve `uid` to `user` (default: true); false skips slow directory lookups. */
+  resolveUsernames?: boolean;
+  /** Keep raw bytes of non-UTF-8 names, paths, and command lines (Linux only). */
+  preserveRawBytes?: boolean;
 }
 
 /**
@@ -57,8 +136,38 @@ export interface ProcessOptions {
  */
 export interface ProcessSnapshot {
   schema_id: string;
+  /** Major version of `schema_id`, for numeric compatibility checks. */
+  schema_version: number;
   timestamp: string;
   processes: ProcessInfo[];
+  /** False for sampled snapshots, whose per-core `cpu_percent` may exceed 100. */
+  cpu_percent_is_normalized: boolean;
+  /** Whether a filter was applied. */
+  filtered: boolean;
+  /** Processes enumerated before filtering; present only when `filtered`. */
+  total_before_filter?: number;
+  /** Non-fatal enumeration warnings (e.g. PIDs skipped after a read timeout); omitted when empty. */
+  warnings?: Warning[];
+}
+
+// Warnings
+
+/** Category of a non-fatal warning. */
+export type WarningCode =
+  | "permission_denied"
+  | "partial_visibility"
+  | "truncated"
+  | "pid_reuse_guard"
+  | "degraded"
+  | "protected"
+  | "config"
+  | "info"
+  | "other";
+
+/** A non-fatal warning with a stable code and a human-readable message. */
+export interface Warning {
+  code: WarningCode;
+  message: string;
 }
 
 // Wait PID
@@ -69,13 +178,15 @@ export interface ProcessSnapshot {
  */
 export interface WaitPidResult {
   schema_id: string;
+  /** Major version of `schema_id`, for numeric compatibility checks. */
+  schema_version: number;
   timestamp: string;
   platform: string;
   pid: number;
   exited: boolean;
   timed_out: boolean;
   exit_code?: number | null;
-  warnings: string[];
+  warnings: Warning[];
 }
 
 // Terminate tree
@@ -86,10 +197,18 @@ export interface TerminateTreeConfig {
   kill_timeout_ms?: number | null;
   signal?: number | null;
   kill_signal?: number | null;
+  /** Refuse unless the PID's start time (Unix epoch ms) matches; re-checked before escalation. */
+  require_start_time_ms?: number | null;
+  /** Refuse unless the PID's executable path matches; re-checked before escalation. */
+  require_exe_path?: string | null;
+  /** Send `kill_signal` immediately, skipping the grace signal and wait. */
+  skip_grace?: boolean;
 }
 
 export interface TerminateTreeResult {
   schema_id: string;
+  /** Major version of `schema_id`, for numeric compatibility checks. */
+  schema_version: number;
   timestamp: string;
   platform: string;
   pid: number;
@@ -99,8 +218,9 @@ export interface TerminateTreeResult {
   escalated: boolean;
   exited: boolean;
   timed_out: boolean;
+  exit_code?: number | null;
   tree_kill_reliability: "guaranteed" | "best_effort";
-  warnings: string[];
+  warnings: Warning[];
 }
 
 // Spawn in group
@@ -110,10 +230,18 @@ export interface SpawnInGroupConfig {
   argv: string[];
   cwd?: string | null;
   env?: Record<string, string> | null;
+  /** Expand `$VAR`/`${VAR}` in argv and cwd against env overlaid on the inherited environment. */
+  expand_env?: boolean;
+  /** Start from an empty environment instead of inheriting the parent's. */
+  clear_env?: boolean;
+  /** Parent variables (e.g. `PATH`, `HOME`) kept when `clear_env` is set. */
+  env_passthrough?: string[];
 }
 
 export interface SpawnInGroupResult {
   schema_id: string;
+  /** Major version of `schema_id`, for numeric compatibility checks. */
+  schema_version: number;
   timestamp: string;
   platform: string;
   pid: number;
@@ -122,6 +250,42 @@ export interface SpawnInGroupResult {
   warnings: string[];
 }
 
+// Timeout run
+
+export type GroupingMode = "group_by_default" | "foreground" | "new_group_foreground";
+
+export interface TimeoutRunConfig {
+  schema_id?: string;
+  argv: string[];
+  timeout_ms: number;
+  kill_after_ms?: number | null;
+  signal?: number | null;
+  grouping?: GroupingMode | null;
+  preserve_status?: boolean | null;
+  /** Expand `$VAR`/`${VAR}` in argv against the inherited environment. */
+  expand_env?: boolean | null;
+  /** Start from an empty environment, keeping only `env_passthrough` variables. */
+  clear_env?: boolean | null;
+  /** Parent variables kept when `clear_env` is set (e.g. `PATH`, `HOME`). */
+  env_passthrough?: string[] | null;
+}
+
+export interface TimeoutRunResult {
+  schema_id: string;
+  /** Major version of `schema_id`, for numeric compatibility checks. */
+  schema_version: number;
+  /** `"cancelled"` when the run was stopped by a cancellation request before the timeout. */
+  status: "completed" | "timed_out" | "cancelled";
+  exit_code?: number | null;
+  signal_sent?: number;
+  escalated?: boolean;
+  tree_kill_reliability?: "guaranteed" | "best_effort";
+  /** Descendants still running after the kill (present only when timed out or cancelled). */
+  survivors?: number[];
+  /** Non-fatal issues, such as variables left undefined by `expand_env`. */
+  warnings: Warning[];
+}
+
 // Port types
 
 export type Protocol = "tcp" | "udp";
@@ -146,6 +310,8 @@ export interface PortBinding {
 export interface PortFilter {
   protocol?: Protocol;
   local_port?: number;
+  /** Case-insensitive substring of the owning process name; unresolved bindings are excluded. */
+  process_name_contains?: string;
 }
 
 /**
@@ -154,10 +320,14 @@ export interface PortFilter {
  */
 export interface PortBindingsSnapshot {
   schema_id: string;
+  /** Major version of `schema_id`, for numeric compatibility checks. */
+  schema_version: number;
   timestamp: string;
   platform: string;
   bindings: PortBinding[];
-  warnings: string[];
+  /** True when rerunning elevated would attribute bindings lacking a `pid`. */
+  needs_elevation: boolean;
+  warnings: Warning[];
 }
 
 // File descriptors
@@ -168,6 +338,8 @@ export interface FdInfo {
   fd: number;
   kind: FdKind;
   path?: string | null;
+  /** Raw bytes of `path`, base64, when it is not valid UTF-8 (Linux only). */
+  path_bytes?: string | null;
 }
 
 export interface FdFilter {
@@ -176,11 +348,15 @@ export interface FdFilter {
 
 export interface FdSnapshot {
   schema_id: string;
+  /** Major version of `schema_id`, for numeric compatibility checks. */
+  schema_version: number;
   timestamp: string;
   platform: string;
   pid: number;
   fds: FdInfo[];
-  warnings: string[];
+  /** Count of `fds` entries per kind; kinds with no descriptors are omitted. */
+  kind_counts?: Partial<Record<FdKind, number>>;
+  warnings: Warning[];
 }
 
 // Signal operations
@@ -211,6 +387,8 @@ export interface DescendantsLevel {
  */
 export interface DescendantsResult {
   schema_id: string;
+  /** Major version of `schema_id`, for numeric compatibility checks. */
+  schema_version: number;
   root_pid: number;
   max_levels: number;
   levels: DescendantsLevel[];
@@ -218,6 +396,10 @@ export interface DescendantsResult {
   matched_by_filter: number;
   timestamp: string;
   platform: string;
+  /** True when the traversal deadline expired; omitted when false. */
+  truncated?: boolean;
+  /** Non-fatal issues encountered during the traversal; omitted when empty. */
+  warnings?: Warning[];
 }
 
 /**
@@ -248,6 +430,19 @@ export interface KillDescendantsOptions {
   sampleDurationMs?: number;
 }
 
+/**
+ * Dry-run result of {@link resolveKillTargets}.
+ */
+export interface KillTargets {
+  schema_id: string;
+  /** Major version of `schema_id`, for numeric compatibility checks. */
+  schema_version: number;
+  /** PIDs a kill with the same filter would signal, ascending. */
+  targets: number[];
+  /** Matching PIDs dropped by PID safety checks (self, PID 1, parent), ascending. */
+  skipped_safety_pids: number[];
+}
+
 /**
  * A single failure in a kill-descendants operation.
  */
@@ -261,6 +456,8 @@ export interface KillDescendantsFailure {
  */
 export interface KillDescendantsResult {
   schema_id: string;
+  /** Major version of `schema_id`, for numeric compatibility checks. */
+  schema_version: number;
   signal_sent: number;
   root_pid: number;
   succeeded: number[];
@@ -4,18 +4,24 @@ import { once } from "node:events";
 import test from "node:test";
 
 import {
+  descendants,
   forceKill,
+  killDescendants,
   listeningPorts,
   listFds,
   processList,
   procGet,
+  resolveKillTargets,
+  resolveSignal,
   SysprimsError,
   SysprimsErrorCode,
   selfPGID,
   selfSID,
+  signalSend,
   spawnInGroup,
   terminate,
   terminateTree,
+  timeoutRun,
   waitPID,
 } from "../src/index";
 
@@ -91,6 +97,13 @@ test("processList({ name_contains }) filters correctly", () => {
   assert.ok(found, "current process should match its own name filter");
 });
 
+test("resolveKillTargets() never targets the current process", () => {
+  const preview = resolveKillTargets({ pid_in: [process.pid] });
+
+  assert.deepEqual(preview.targets, []);
+  assert.deepEqual(preview.skipped_safety_pids, [process.pid]);
+});
+
 test("listFds(process.pid) returns a snapshot", () => {
   if (process.platform === "win32") {
     assert.throws(
@@ -127,6 +140,31 @@ test("listeningPorts({ protocol: 'tcp' }) filters correctly", () => {
   }
 });
 
+test("descendants(process.pid) finds a spawned child", async () => {
+  const child = spawnLongRunningChild();
+  const pid = child.pid;
+  if (pid === undefined) {
+    throw new Error("Failed to spawn child process");
+  }
+
+  await new Promise((r) => setTimeout(r, 50));
+
+  const result = descendants(process.pid, { maxLevels: 1 });
+  assert.equal(result.root_pid, process.pid);
+  const pids = result.levels.flatMap((l) => l.processes.map((p) => p.pid));
+  assert.ok(pids.includes(pid), "spawned child should be a descendant");
+
+  forceKill(pid);
+  await waitForExit(child, 5000);
+});
+
+test("killDescendants rejects pid 0", () => {
+  assert.throws(
+    () => killDescendants(0),
+    (e: unknown) => e instanceof SysprimsError && e.code === SysprimsErrorCode.InvalidArgument,
+  );
+});
+
 // -----------------------------------------------------------------------------
 // Self Introspection Tests
 // -----------------------------------------------------------------------------
@@ -152,6 +190,26 @@ test("selfPGID/selfSID are > 0 on Unix or NotSupported on Windows", () => {
 // Signal Tests (error-path only, using spawn-and-exit for safe PIDs)
 // -----------------------------------------------------------------------------
 
+test("resolveSignal accepts names, short ids, and numbers", () => {
+  assert.equal(resolveSignal("TERM"), 15);
+  assert.equal(resolveSignal("term"), 15);
+  assert.equal(resolveSignal("SIGKILL"), 9);
+  assert.equal(resolveSignal(9), 9);
+  assert.throws(
+    () => resolveSignal("NOPE"),
+    (e: unknown) => e instanceof SysprimsError && e.code === SysprimsErrorCode.InvalidArgument,
+  );
+});
+
+test("signalSend accepts signal names and rejects unknown ones", () => {
+  // Signal 0 only checks existence.
+  signalSend(process.pid, "0");
+  assert.throws(
+    () => signalSend(process.pid, "NOTASIGNAL"),
+    (e: unknown) => e instanceof SysprimsError && e.code === SysprimsErrorCode.InvalidArgument,
+  );
+});
+
 test("terminate rejects pid 0", () => {
   assert.throws(
     () => terminate(0),
@@ -226,3 +284,28 @@ test("spawnInGroup returns a pid", () => {
   assert.ok(r.pid > 0);
   terminateTree(r.pid, { grace_timeout_ms: 100, kill_timeout_ms: 1000 });
 });
+
+test("timeoutRun resolves for a completed command", async () => {
+  const result = await timeoutRun({
+    argv: [process.execPath, "-e", "process.exit(0)"],
+    timeout_ms: 10_000,
+  });
+  assert.equal(result.status, "completed");
+  assert.equal(result.exit_code, 0);
+});
+
+test("timeoutRun kills a command that overruns", async () => {
+  const result = await timeoutRun({
+    argv: [process.execPath, "-e", "setInterval(() => {}, 1000)"],
+    timeout_ms: 200,
+    kill_after_ms: 1000,
+  });
+  assert.equal(result.status, "timed_out");
+});
+
+test("timeoutRun rejects a wrong schema_id", async () => {
+  await assert.rejects(
+    timeoutRun({ schema_id: "nope", argv: ["true"], timeout_ms: 1000 }),
+    (e: unknown) => e instanceof SysprimsError && e.code === SysprimsErrorCode.InvalidArgument,
+  );
+});
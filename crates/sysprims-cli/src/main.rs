@@ -1,31 +1,55 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use clap::{Parser, Subcommand};
 use sysprims_core::SysprimsError;
 use sysprims_core::{
-    get_platform,
-    schema::{BATCH_KILL_RESULT_V1, PROCESS_INFO_SAMPLED_V1},
+    capabilities, get_platform, parse_duration,
+    schema::{embedded_schema, BATCH_KILL_RESULT_V1, EMBEDDED_SCHEMAS, SIGNAL_CATALOG_V1},
+    schema_check, self_pid, WarningCode,
+};
+use sysprims_proc::render::{
+    cpu_indicator, format_elapsed, format_memory, render_table, truncate, RenderOptions,
 };
 use sysprims_proc::{
-    cpu_total_time_ns, descendants_with_config, get_process, list_fds, listening_ports, snapshot,
-    snapshot_filtered, CpuMode as ProcCpuMode, DescendantsConfig, FdFilter, FdKind, PortFilter,
-    ProcessFilter, Protocol,
+    cpu_total_time_ns, descendants_with_config, fd_growth, filter_protected, filter_snapshot,
+    get_process, get_process_with_options, list_fds, listening_ports, listening_ports_for_pid,
+    processes_by_port, processes_using_path_filtered, protected_pids, snapshot, snapshot_filtered,
+    snapshot_filtered_with_options, snapshot_with_config, snapshot_with_options,
+    wait_pid_with_config, AgeBucket, CpuMode as ProcCpuMode, DescendantsConfig, FdFilter, FdKind,
+    OnExec, PollConfig, PortFilter, ProcessFilter, ProcessOptions, ProcessSnapshot, Protocol,
+    SnapshotConfig, UserProtections, WaitPidConfig,
+};
+use sysprims_signal::{
+    default_disposition, filter_own_group, kill_many_with_options, own_group_warning,
+    resolve_signal, resolve_signal_or_pattern, BatchKillOptions, BatchKillResult, DefaultAction,
+    SignalTarget,
 };
-use sysprims_signal::match_signal_names;
 use sysprims_timeout::{run_with_timeout, GroupingMode, TimeoutConfig, TimeoutOutcome};
-use tracing::info;
+use tracing::{info, warn};
 use tracing_subscriber::{filter::EnvFilter, fmt, prelude::*};
 
-const PSTAT_AFTER_HELP: &str = r#"Examples:
+const PSTAT_AFTER_HELP: &str = r#"Exit status:
+  0  success (no threshold breached with --alert-if-any)
+  1  error
+  2  usage error
+  3  --alert-if-any: at least one process breached a threshold
+
+Examples:
   sysprims pstat --table
   sysprims pstat --cpu-mode monitor --sample 3s --cpu-above 80 --table
   sysprims pstat --pid 1234 --json
+  sysprims pstat --fields pid,name,cpu_percent --top 10
   SYSPRIMS_NO_HINTS=1 sysprims pstat --cpu-above 70 --table
+  sysprims pstat --name app --cpu-above 95 --alert-if-any --table || notify
 "#;
 
 const DESCENDANTS_AFTER_HELP: &str = r#"Examples:
   sysprims descendants 14796 --tree
+  sysprims descendants 14796 --max-levels all --tree --compact
   sysprims descendants 14796 --max-levels all --table
+  sysprims descendants 14796 --max-levels all --json-tree
   sysprims descendants 14796 --cpu-mode monitor --sample 3s --cpu-above 80 --tree
   SYSPRIMS_NO_HINTS=1 sysprims descendants 14796 --cpu-above 80 --table
 "#;
@@ -35,6 +59,22 @@ const KILL_DESCENDANTS_AFTER_HELP: &str = r#"Examples:
   sysprims kill-descendants 14796 --cpu-mode monitor --sample 3s --cpu-above 80 --dry-run
   sysprims kill-descendants 14796 --cpu-mode monitor --sample 3s --cpu-above 80 --signal KILL --yes
   sysprims kill-descendants 14796 --name worker --signal TERM --yes
+  sysprims kill-descendants 14796 --include-root --signal TERM --yes
+"#;
+
+const KILL_PORT_AFTER_HELP: &str = r#"Examples:
+  sysprims kill-port 8080 --dry-run
+  sysprims kill-port 8080 --yes
+  sysprims kill-port 5353 --protocol udp --signal KILL --yes
+"#;
+
+const PORT_CHECK_AFTER_HELP: &str = r#"Exit status:
+  0  port is free (no visible listener)
+  1  port is in use
+
+Examples:
+  sysprims port-check 8080
+  sysprims port-check 5353 --protocol udp --json
 "#;
 
 const HELP_AFTER_HELP: &str = r#"Topics:
@@ -93,6 +133,12 @@ enum Command {
     /// On Unix this uses process groups when possible; on Windows it uses Job Objects.
     TerminateTree(TerminateTreeArgs),
 
+    /// Wait for a process to exit.
+    ///
+    /// Blocks until the PID exits or --timeout elapses. Exits 0 when the
+    /// process exited and 124 on timeout.
+    Wait(WaitArgs),
+
     /// List descendants of a process.
     ///
     /// Traverses the process tree from a root PID, showing direct children
@@ -105,14 +151,44 @@ enum Command {
     /// matching descendants. Defaults to preview mode unless --yes is provided.
     KillDescendants(KillDescendantsArgs),
 
+    /// Signal the process listening on a port.
+    ///
+    /// Resolves the owning process of a local port and sends it a signal.
+    /// Defaults to preview mode unless --yes is provided.
+    KillPort(KillPortArgs),
+
     /// List open file descriptors for a process.
     Fds(FdsArgs),
 
     /// List listening port bindings.
     Ports(PortsArgs),
 
+    /// Check whether a local port is free, and who holds it if not.
+    ///
+    /// Prints `free` (exit 0) or the owning process (exit 1). Best-effort:
+    /// listeners owned by other users may be invisible without privileges.
+    PortCheck(PortCheckArgs),
+
+    /// List processes holding a path (or anything under it) open.
+    ///
+    /// Scans every visible process's file descriptors, so it is slow and
+    /// best-effort. Use --user to narrow the scan.
+    Lsof(LsofArgs),
+
     /// Explain concepts and safety guidance.
     Help(HelpArgs),
+
+    /// Print the embedded JSON Schema for a sysprims output.
+    ///
+    /// Schemas are compiled into the binary, so the output always matches
+    /// what this build emits. Use --list to see available names.
+    Schema(SchemaArgs),
+
+    /// Report which features this platform supports.
+    ///
+    /// Output is JSON by default so tools can disable unsupported features
+    /// up front, or a table for human consumption.
+    Capabilities(CapabilitiesArgs),
 }
 
 #[derive(Parser, Debug)]
@@ -136,6 +212,7 @@ struct KillArgs {
     ///
     /// Without argument: list all signals in table format.
     /// With argument: print the signal number for the given name.
+    /// With --json: print `{name, number, id, default_disposition}` entries as a JSON array.
     #[arg(short = 'l', long = "list", value_name = "SIGNAL", num_args = 0..=1)]
     list: Option<Option<String>>,
 
@@ -146,8 +223,8 @@ struct KillArgs {
     #[arg(short = 'g', long = "group", conflicts_with = "list")]
     group: bool,
 
-    /// Output JSON batch result.
-    #[arg(long, conflicts_with = "list")]
+    /// Output JSON batch result (with --list: the signal catalog as JSON).
+    #[arg(long)]
     json: bool,
 
     /// Filter by parent PID.
@@ -158,6 +235,12 @@ struct KillArgs {
     #[arg(long, value_name = "NAME", conflicts_with = "list")]
     name: Option<String>,
 
+    /// Filter by process name glob (`*`, `?`; case-sensitive).
+    ///
+    /// Matches the process name only, not the full command line.
+    #[arg(long, value_name = "PATTERN", conflicts_with = "list")]
+    name_glob: Option<String>,
+
     /// Filter by username.
     #[arg(long, value_name = "USER", conflicts_with = "list")]
     user: Option<String>,
@@ -190,6 +273,23 @@ struct KillArgs {
     /// Proceed even if CLI safety checks would normally refuse.
     #[arg(long, conflicts_with = "list")]
     force: bool,
+
+    /// Never signal filter-selected processes with this exact name (repeatable).
+    ///
+    /// Adds to SYSPRIMS_PROTECTED_NAMES / SYSPRIMS_PROTECTED_PIDS; --force overrides.
+    #[arg(long, value_name = "NAME", conflicts_with = "list")]
+    protect_name: Vec<String>,
+
+    /// Allow filter-selected targets in sysprims' own process group (Unix).
+    ///
+    /// By default, filter-based selection skips processes sharing the
+    /// caller's process group, such as the invoking shell session.
+    #[arg(long, conflicts_with_all = ["list", "group"])]
+    include_own_group: bool,
+
+    /// Warn when a target has the signal blocked or ignored (Linux).
+    #[arg(long, conflicts_with_all = ["list", "group"])]
+    check_disposition: bool,
 }
 
 #[derive(Parser, Debug)]
@@ -241,26 +341,54 @@ struct TimeoutArgs {
 #[derive(Parser, Debug)]
 #[command(after_help = PSTAT_AFTER_HELP)]
 struct PstatArgs {
-    /// Output as JSON (default for automation).
+    /// Output as JSON (deprecated alias for `--format json`).
     #[arg(long)]
     json: bool,
 
-    /// Output as human-readable table.
+    /// Output as human-readable table (deprecated alias for `--format table`).
     #[arg(long, conflicts_with = "json")]
     table: bool,
 
+    /// Output format: json, yaml, or table (default: json).
+    #[arg(long, value_enum, value_name = "FORMAT", conflicts_with_all = ["json", "table"])]
+    format: Option<OutputFormat>,
+
     /// Show only a specific process by PID.
     #[arg(long, value_name = "PID")]
     pid: Option<u32>,
 
+    /// Show only the sysprims process itself.
+    #[arg(long = "self", conflicts_with = "pid")]
+    self_only: bool,
+
     /// Filter by process name (substring match, case-insensitive).
     #[arg(long, value_name = "NAME")]
     name: Option<String>,
 
+    /// Filter by process name glob (`*`, `?`; case-sensitive).
+    ///
+    /// Matches the process name only, not the full command line.
+    #[arg(long, value_name = "PATTERN")]
+    name_glob: Option<String>,
+
     /// Filter by username.
     #[arg(long, value_name = "USER")]
     user: Option<String>,
 
+    /// Skip username lookups; report only the numeric `uid`.
+    ///
+    /// Speeds up snapshots where user resolution goes to a slow directory
+    /// service (LDAP/AD).
+    #[arg(long, conflicts_with = "user")]
+    no_username: bool,
+
+    /// Only enumerate processes owned by the current user (Unix only).
+    ///
+    /// Other users' processes are skipped before they are read, which is much
+    /// cheaper for unprivileged monitoring than filtering afterwards.
+    #[arg(long, conflicts_with_all = ["pid", "self_only"])]
+    mine: bool,
+
     /// Filter by parent PID.
     #[arg(long, value_name = "PID")]
     ppid: Option<u32>,
@@ -299,11 +427,98 @@ struct PstatArgs {
     #[arg(long, value_name = "DURATION")]
     running_for: Option<String>,
 
+    /// Filter by coarse age class (comma-separated, e.g. "just-started,recent").
+    ///
+    /// just-started < 1m <= recent < 1h <= established < 1d <= long-running.
+    #[arg(long, value_enum, value_name = "BUCKET", value_delimiter = ',')]
+    age: Option<Vec<AgeBucketArg>>,
+
+    /// Exclude Linux kernel threads (children of kthreadd with no cmdline).
+    ///
+    /// No effect on macOS or Windows.
+    #[arg(long)]
+    no_kernel_threads: bool,
+
+    /// Only show processes running from a deleted or replaced executable.
+    ///
+    /// Linux only (`/proc/[pid]/exe` ends in "(deleted)"); matches nothing elsewhere.
+    #[arg(long)]
+    deleted_exe: bool,
+
     /// Sort by field (pid, name, cpu, memory).
     #[arg(long, value_name = "FIELD", default_value = "pid")]
     sort: String,
+
+    /// Project JSON output to these process fields (comma-separated, e.g. pid,name,cpu_percent).
+    ///
+    /// The snapshot envelope (schema_id, timestamp) is kept; each process object
+    /// only contains the requested keys.
+    #[arg(
+        long,
+        value_name = "FIELDS",
+        value_delimiter = ',',
+        conflicts_with = "table"
+    )]
+    fields: Option<Vec<String>>,
+
+    /// Also write the JSON snapshot to this file (atomically, via temp + rename).
+    ///
+    /// Honors `--fields`. Normal output still goes to stdout.
+    #[arg(long, value_name = "PATH")]
+    output: Option<PathBuf>,
+
+    /// Exit with code 3 if any process breaches `--cpu-above`/`--memory-above`.
+    ///
+    /// The matching processes are still printed. Requires at least one threshold.
+    #[arg(long, conflicts_with_all = ["pid", "self_only"])]
+    alert_if_any: bool,
 }
 
+/// Exit code for `pstat --alert-if-any` when a threshold was breached.
+///
+/// Distinct from errors (1) and clap's usage errors (2), so scripts can tell
+/// an alert from a failed invocation.
+const PSTAT_ALERT_EXIT_CODE: i32 = 3;
+
+/// `ProcessInfo` JSON keys accepted by `pstat --fields`.
+const PROCESS_INFO_FIELDS: &[&str] = &[
+    "pid",
+    "ppid",
+    "name",
+    "name_bytes",
+    "user",
+    "uid",
+    "cpu_percent",
+    "cpu_user_ns",
+    "cpu_system_ns",
+    "memory_kb",
+    "elapsed_seconds",
+    "start_time_unix_ms",
+    "start_time",
+    "exe_path",
+    "exe_path_bytes",
+    "exe_inode",
+    "exe_device",
+    "exe_deleted",
+    "state",
+    "cmdline",
+    "cmdline_bytes",
+    "cmdline_string",
+    "env",
+    "thread_count",
+    "cgroup",
+    "socket_count",
+    "net_rx_bytes",
+    "net_tx_bytes",
+    "fd_limit_soft",
+    "fd_limit_hard",
+    "sig_blocked",
+    "sig_ignored",
+    "sig_caught",
+    "sid",
+    "identity",
+];
+
 #[derive(Parser, Debug)]
 #[command(after_help = DESCENDANTS_AFTER_HELP)]
 struct DescendantsArgs {
@@ -315,22 +530,41 @@ struct DescendantsArgs {
     #[arg(long, value_name = "N", default_value = "1")]
     max_levels: String,
 
-    /// Output as JSON (default).
+    /// Output as JSON (deprecated alias for `--format json`).
     #[arg(long, conflicts_with_all = ["table", "tree"])]
     json: bool,
 
-    /// Output as human-readable table (flat, grouped by level).
+    /// Output as human-readable table, flat and grouped by level (deprecated alias for
+    /// `--format table`).
     #[arg(long, conflicts_with_all = ["json", "tree"])]
     table: bool,
 
+    /// Output format: json, yaml, or table (default: json).
+    #[arg(long, value_enum, value_name = "FORMAT", conflicts_with_all = ["json", "table", "tree"])]
+    format: Option<OutputFormat>,
+
     /// Output as ASCII art tree with hierarchy visualization.
     #[arg(long, conflicts_with_all = ["json", "table"])]
     tree: bool,
 
+    /// With --tree, render each node as just `PID NAME` (no metrics).
+    #[arg(long, requires = "tree")]
+    compact: bool,
+
+    /// Output as nested JSON (`{pid, info, children}`), children sorted by PID.
+    #[arg(long, conflicts_with_all = ["json", "table", "format", "tree"])]
+    json_tree: bool,
+
     /// Filter by process name (substring match, case-insensitive).
     #[arg(long, value_name = "NAME")]
     name: Option<String>,
 
+    /// Filter by process name glob (`*`, `?`; case-sensitive).
+    ///
+    /// Matches the process name only, not the full command line.
+    #[arg(long, value_name = "PATTERN")]
+    name_glob: Option<String>,
+
     /// Filter by username.
     #[arg(long, value_name = "USER")]
     user: Option<String>,
@@ -362,6 +596,24 @@ struct DescendantsArgs {
     /// Filter by minimum process age (e.g., "5s", "1m", "2h").
     #[arg(long, value_name = "DURATION")]
     running_for: Option<String>,
+
+    /// Poll for short-lived descendants over a window (e.g., "2s").
+    ///
+    /// Snapshots are merged over the window; descendants that exited before
+    /// the final snapshot are listed in `transient_pids`.
+    #[arg(long, value_name = "DURATION")]
+    poll: Option<String>,
+
+    /// Interval between snapshots when using --poll (default: 50ms).
+    #[arg(long, value_name = "DURATION", requires = "poll")]
+    poll_interval: Option<String>,
+
+    /// Bound the whole traversal, including polling and sampling (e.g., "2s").
+    ///
+    /// When it expires, the descendants gathered so far are reported with
+    /// `truncated: true` and a warning.
+    #[arg(long, value_name = "DURATION")]
+    deadline: Option<String>,
 }
 
 #[derive(Parser, Debug)]
@@ -388,6 +640,12 @@ struct KillDescendantsArgs {
     #[arg(long, value_name = "NAME")]
     name: Option<String>,
 
+    /// Filter by process name glob (`*`, `?`; case-sensitive).
+    ///
+    /// Matches the process name only, not the full command line.
+    #[arg(long, value_name = "PATTERN")]
+    name_glob: Option<String>,
+
     /// Filter by username.
     #[arg(long, value_name = "USER")]
     user: Option<String>,
@@ -432,6 +690,59 @@ struct KillDescendantsArgs {
     #[arg(long)]
     force: bool,
 
+    /// Never signal a process with this exact name (repeatable).
+    ///
+    /// Adds to SYSPRIMS_PROTECTED_NAMES / SYSPRIMS_PROTECTED_PIDS; --force overrides.
+    #[arg(long, value_name = "NAME")]
+    protect_name: Vec<String>,
+
+    /// Also signal the root PID itself (still subject to self/PID1/parent safety).
+    #[arg(long)]
+    include_root: bool,
+
+    /// Output as JSON.
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Parser, Debug)]
+#[command(after_help = KILL_PORT_AFTER_HELP)]
+struct KillPortArgs {
+    /// Local port whose owning process should be signaled.
+    #[arg(value_name = "PORT")]
+    port: u16,
+
+    /// Protocol of the listening socket.
+    #[arg(long, value_enum, value_name = "PROTO", default_value = "tcp")]
+    protocol: ProtocolArg,
+
+    /// Signal name or number (default: TERM).
+    #[arg(
+        short = 's',
+        long = "signal",
+        value_name = "SIGNAL",
+        default_value = "TERM"
+    )]
+    signal: String,
+
+    /// Print the owning process but do not send signals.
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Proceed with kill (default is preview mode).
+    #[arg(long)]
+    yes: bool,
+
+    /// Proceed even if CLI safety checks would normally refuse.
+    #[arg(long)]
+    force: bool,
+
+    /// Never signal a process with this exact name (repeatable).
+    ///
+    /// Adds to SYSPRIMS_PROTECTED_NAMES / SYSPRIMS_PROTECTED_PIDS; --force overrides.
+    #[arg(long, value_name = "NAME")]
+    protect_name: Vec<String>,
+
     /// Output as JSON.
     #[arg(long)]
     json: bool,
@@ -463,6 +774,28 @@ struct HelpArgs {
     topic: HelpTopic,
 }
 
+#[derive(Parser, Debug)]
+struct SchemaArgs {
+    /// Schema name (e.g. process-info, port-bindings) or full schema ID.
+    #[arg(value_name = "NAME", required_unless_present = "list")]
+    name: Option<String>,
+
+    /// List available schema names and IDs.
+    #[arg(long, conflicts_with = "name")]
+    list: bool,
+}
+
+#[derive(Parser, Debug)]
+struct CapabilitiesArgs {
+    /// Output as JSON.
+    #[arg(long)]
+    json: bool,
+
+    /// Output as human-readable table.
+    #[arg(long, conflicts_with = "json")]
+    table: bool,
+}
+
 #[derive(Parser, Debug)]
 struct TerminateTreeArgs {
     /// Target process ID.
@@ -486,13 +819,23 @@ struct TerminateTreeArgs {
     kill_signal: String,
 
     /// Refuse to terminate if the PID's start time does not match.
+    ///
+    /// Re-checked before escalation; a mismatch then skips the kill signal.
     #[arg(long, value_name = "UNIX_MS")]
     require_start_time_ms: Option<u64>,
 
     /// Refuse to terminate if the PID's executable path does not match.
+    ///
+    /// Re-checked before escalation, like --require-start-time-ms.
     #[arg(long, value_name = "PATH")]
     require_exe_path: Option<String>,
 
+    /// Send the kill signal immediately, skipping the grace signal and wait.
+    ///
+    /// For processes already known to be unresponsive.
+    #[arg(long, conflicts_with_all = ["grace", "signal"])]
+    skip_grace: bool,
+
     /// Proceed even if identity checks fail.
     #[arg(long)]
     force: bool,
@@ -500,6 +843,38 @@ struct TerminateTreeArgs {
     /// Output as JSON.
     #[arg(long)]
     json: bool,
+
+    /// Also write the JSON result to this file (atomically, via temp + rename).
+    #[arg(long, value_name = "PATH")]
+    output: Option<PathBuf>,
+}
+
+#[derive(Parser, Debug)]
+struct WaitArgs {
+    /// Target process ID.
+    #[arg(value_name = "PID")]
+    pid: u32,
+
+    /// Give up after this duration (default: wait indefinitely).
+    #[arg(long, value_name = "DURATION")]
+    timeout: Option<String>,
+
+    /// Fixed interval between liveness checks (default: adaptive backoff).
+    ///
+    /// Ignored on Windows, which waits on the process handle.
+    #[arg(long, value_name = "DURATION")]
+    interval: Option<String>,
+
+    /// Stop waiting if the process execs into a different program.
+    ///
+    /// Useful for wrappers that re-exec into the real workload. Also stops
+    /// when the PID is reused by another process.
+    #[arg(long)]
+    exit_on_exec: bool,
+
+    /// Output as JSON.
+    #[arg(long)]
+    json: bool,
 }
 
 #[derive(Parser, Debug)]
@@ -508,29 +883,77 @@ struct FdsArgs {
     #[arg(long, value_name = "PID")]
     pid: u32,
 
-    /// Output as JSON.
+    /// Output as JSON (deprecated alias for `--format json`).
     #[arg(long)]
     json: bool,
 
-    /// Output as human-readable table.
+    /// Output as human-readable table (deprecated alias for `--format table`).
     #[arg(long, conflicts_with = "json")]
     table: bool,
 
+    /// Output format: json, yaml, or table (default: json).
+    #[arg(long, value_enum, value_name = "FORMAT", conflicts_with_all = ["json", "table"])]
+    format: Option<OutputFormat>,
+
     /// Filter by fd kind.
-    #[arg(long, value_enum, value_name = "KIND")]
+    #[arg(long, value_enum, value_name = "KIND", conflicts_with = "watch")]
     kind: Option<FdKindArg>,
+
+    /// Sample the fd count over time and report growth (leak detection).
+    #[arg(long)]
+    watch: bool,
+
+    /// Number of samples to take with --watch (default: 5).
+    #[arg(long, value_name = "N", default_value_t = 5, requires = "watch")]
+    samples: u32,
+
+    /// Interval between samples with --watch (default: 1s).
+    #[arg(
+        long,
+        value_name = "DURATION",
+        default_value = "1s",
+        requires = "watch"
+    )]
+    interval: String,
+}
+
+#[derive(Parser, Debug)]
+struct LsofArgs {
+    /// File or directory to look up.
+    #[arg(value_name = "PATH")]
+    path: String,
+
+    /// Only scan processes owned by this user.
+    #[arg(long)]
+    user: Option<String>,
+
+    /// Output as JSON (deprecated alias for `--format json`).
+    #[arg(long)]
+    json: bool,
+
+    /// Output as human-readable table (deprecated alias for `--format table`).
+    #[arg(long, conflicts_with = "json")]
+    table: bool,
+
+    /// Output format: json, yaml, or table (default: json).
+    #[arg(long, value_enum, value_name = "FORMAT", conflicts_with_all = ["json", "table"])]
+    format: Option<OutputFormat>,
 }
 
 #[derive(Parser, Debug)]
 struct PortsArgs {
-    /// Output as JSON.
+    /// Output as JSON (deprecated alias for `--format json`).
     #[arg(long)]
     json: bool,
 
-    /// Output as human-readable table.
+    /// Output as human-readable table (deprecated alias for `--format table`).
     #[arg(long, conflicts_with = "json")]
     table: bool,
 
+    /// Output format: json, yaml, or table (default: json).
+    #[arg(long, value_enum, value_name = "FORMAT", conflicts_with_all = ["json", "table"])]
+    format: Option<OutputFormat>,
+
     /// Filter by protocol.
     #[arg(long, value_enum, value_name = "PROTO")]
     protocol: Option<ProtocolArg>,
@@ -538,6 +961,57 @@ struct PortsArgs {
     /// Filter by local port.
     #[arg(long, value_name = "PORT")]
     local_port: Option<u16>,
+
+    /// Filter by owning process name (substring match, case-insensitive).
+    ///
+    /// Bindings whose owning process could not be resolved are excluded.
+    #[arg(long, value_name = "NAME")]
+    name: Option<String>,
+
+    /// Only list sockets owned by this PID (inspects just that process).
+    #[arg(long, value_name = "PID")]
+    pid: Option<u32>,
+
+    /// Also write the JSON snapshot to this file (atomically, via temp + rename).
+    #[arg(long, value_name = "PATH")]
+    output: Option<PathBuf>,
+}
+
+#[derive(Parser, Debug)]
+#[command(after_help = PORT_CHECK_AFTER_HELP)]
+struct PortCheckArgs {
+    /// Local port to check.
+    #[arg(value_name = "PORT")]
+    port: u16,
+
+    /// Protocol of the listening socket.
+    #[arg(long, value_enum, value_name = "PROTO", default_value = "tcp")]
+    protocol: ProtocolArg,
+
+    /// Output the matching port bindings snapshot as JSON.
+    #[arg(long)]
+    json: bool,
+}
+
+/// Output format for read commands (`--format`).
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum OutputFormat {
+    Json,
+    Yaml,
+    Table,
+}
+
+impl OutputFormat {
+    /// Resolve `--format` together with the deprecated `--table` flag.
+    ///
+    /// `--json` needs no handling: JSON is already the default.
+    fn resolve(format: Option<OutputFormat>, table: bool) -> OutputFormat {
+        format.unwrap_or(if table {
+            OutputFormat::Table
+        } else {
+            OutputFormat::Json
+        })
+    }
 }
 
 #[derive(clap::ValueEnum, Clone, Debug, PartialEq, Eq)]
@@ -555,6 +1029,25 @@ impl From<ProtocolArg> for Protocol {
     }
 }
 
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum AgeBucketArg {
+    JustStarted,
+    Recent,
+    Established,
+    LongRunning,
+}
+
+impl From<AgeBucketArg> for AgeBucket {
+    fn from(value: AgeBucketArg) -> Self {
+        match value {
+            AgeBucketArg::JustStarted => AgeBucket::JustStarted,
+            AgeBucketArg::Recent => AgeBucket::Recent,
+            AgeBucketArg::Established => AgeBucket::Established,
+            AgeBucketArg::LongRunning => AgeBucket::LongRunning,
+        }
+    }
+}
+
 #[derive(clap::ValueEnum, Clone, Debug, PartialEq, Eq)]
 enum FdKindArg {
     File,
@@ -612,6 +1105,9 @@ fn main() {
             }
             Err(err) => {
                 eprintln!("Error: {err}");
+                if err.needs_elevation() {
+                    eprintln!("hint: {}", elevation_hint());
+                }
                 std::process::exit(1);
             }
         }
@@ -621,6 +1117,15 @@ fn main() {
     info!("Main logic finished.");
 }
 
+/// Next step for a permission error that elevation could resolve.
+fn elevation_hint() -> &'static str {
+    if cfg!(windows) {
+        "retry from an elevated (Administrator) prompt"
+    } else {
+        "retry with sudo"
+    }
+}
+
 fn help_topic_text(topic: HelpTopic) -> &'static str {
     match topic {
         HelpTopic::CpuMode => {
@@ -630,7 +1135,7 @@ fn help_topic_text(topic: HelpTopic) -> &'static str {
             "Signal guidance\n\nList signals:\n  sysprims kill -l\n\nResolve one signal:\n  sysprims kill -l TERM\n\nSend a signal:\n  sysprims kill --signal TERM 1234\n\nPlatform notes:\n- Unix: process and process-group signaling are supported.\n- Windows: only supported termination semantics are available for some signal values."
         }
         HelpTopic::Safety => {
-            "PID safety guidance (ADR-0011)\n\nNever target these values in signal/kill workflows:\n- PID 0: signals the caller's process group\n- PID 1: init/launchd\n- u32::MAX or values > i32::MAX: can map to broadcast semantics\n\nSafe testing targets:\n- std::process::id()\n- spawned child processes\n- high nonexistent PID like 99999\n\nFor kill-descendants, preview first:\n  sysprims kill-descendants <pid> --dry-run\n\nUse --force carefully: it bypasses some CLI safety checks (self/PID1/parent filtering)."
+            "PID safety guidance (ADR-0011)\n\nNever target these values in signal/kill workflows:\n- PID 0: signals the caller's process group\n- PID 1: init/launchd\n- u32::MAX or values > i32::MAX: can map to broadcast semantics\n\nSafe testing targets:\n- std::process::id()\n- spawned child processes\n- high nonexistent PID like 99999\n\nFor kill-descendants, preview first:\n  sysprims kill-descendants <pid> --dry-run\n\nProtect more targets for filter-based kills with SYSPRIMS_PROTECTED_PIDS / SYSPRIMS_PROTECTED_NAMES (comma-separated) or --protect-name.\n\nUse --force carefully: it bypasses some CLI safety checks (self/PID1/parent and protected-name filtering)."
         }
     }
 }
@@ -649,44 +1154,64 @@ fn run_command(command: Command) -> Result<i32, SysprimsError> {
             run_terminate_tree(args)?;
             Ok(0)
         }
+        Command::Wait(args) => run_wait(args),
         Command::Descendants(args) => run_descendants(args),
         Command::KillDescendants(args) => run_kill_descendants(args),
+        Command::KillPort(args) => run_kill_port(args),
         Command::Fds(args) => run_fds(args),
         Command::Ports(args) => run_ports(args),
+        Command::PortCheck(args) => run_port_check(args),
+        Command::Lsof(args) => run_lsof(args),
         Command::Help(args) => Ok(run_help(args)),
+        Command::Schema(args) => run_schema(args),
+        Command::Capabilities(args) => run_capabilities(args),
     }
 }
 
-enum SignalTarget {
-    Number(i32),
-    Name(String),
-}
+fn run_capabilities(args: CapabilitiesArgs) -> Result<i32, SysprimsError> {
+    let caps = capabilities();
 
-fn parse_signal_arg(signal_arg: &str) -> Result<SignalTarget, SysprimsError> {
-    let trimmed = signal_arg.trim();
-    if trimmed.is_empty() {
-        return Err(SysprimsError::invalid_argument("signal cannot be empty"));
+    if args.table {
+        let port_attribution = serde_json::to_value(caps.port_attribution).unwrap();
+        println!("{:<24} {}", "platform", caps.platform);
+        println!("{:<24} {}", "supports_killpg", caps.supports_killpg);
+        println!(
+            "{:<24} {}",
+            "supports_fd_enumeration", caps.supports_fd_enumeration
+        );
+        println!(
+            "{:<24} {}",
+            "port_attribution",
+            port_attribution.as_str().unwrap_or("-")
+        );
+        println!("{:<24} {}", "supports_setsid", caps.supports_setsid);
+        return Ok(0);
     }
 
-    if trimmed.contains('*') || trimmed.contains('?') {
-        let matches = match_signal_names(trimmed);
-        return match matches.len() {
-            0 => Err(SysprimsError::invalid_argument(format!(
-                "signal pattern '{trimmed}' matched no signals"
-            ))),
-            1 => Ok(SignalTarget::Name(matches[0].to_string())),
-            _ => Err(SysprimsError::invalid_argument(format!(
-                "signal pattern '{trimmed}' matched multiple signals: {}",
-                matches.join(", ")
-            ))),
-        };
-    }
+    // Default to JSON
+    println!("{}", to_json_pretty(&caps)?);
+    Ok(0)
+}
 
-    if let Ok(number) = trimmed.parse::<i32>() {
-        return Ok(SignalTarget::Number(number));
+fn run_schema(args: SchemaArgs) -> Result<i32, SysprimsError> {
+    if args.list {
+        for schema in EMBEDDED_SCHEMAS {
+            println!("{:<28} {}", schema.name, schema.schema_id);
+        }
+        return Ok(0);
     }
 
-    Ok(SignalTarget::Name(trimmed.to_string()))
+    let name = args.name.unwrap_or_default();
+    let schema = embedded_schema(&name).ok_or_else(|| {
+        SysprimsError::invalid_argument(format!(
+            "unknown schema '{name}' (use --list to see available schemas)"
+        ))
+    })?;
+    print!("{}", schema.json);
+    if !schema.json.ends_with('\n') {
+        println!();
+    }
+    Ok(0)
 }
 
 #[derive(serde::Serialize)]
@@ -697,16 +1222,22 @@ struct BatchKillFailureJson {
 
 #[derive(serde::Serialize)]
 struct BatchKillResultJson {
+    #[serde(
+        flatten,
+        serialize_with = "sysprims_core::schema::serialize_with_version"
+    )]
     schema_id: &'static str,
     signal_sent: i32,
     succeeded: Vec<u32>,
     failed: Vec<BatchKillFailureJson>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    warnings: Vec<String>,
 }
 
 fn run_kill(args: KillArgs) -> Result<i32, SysprimsError> {
     // Handle --list flag
     if let Some(list_arg) = args.list {
-        return run_kill_list(list_arg);
+        return run_kill_list(list_arg, args.json);
     }
 
     if args.group && args.pids.len() != 1 {
@@ -718,6 +1249,7 @@ fn run_kill(args: KillArgs) -> Result<i32, SysprimsError> {
     if args.group
         && (args.ppid.is_some()
             || args.name.is_some()
+            || args.name_glob.is_some()
             || args.user.is_some()
             || args.cpu_above.is_some()
             || args.memory_above.is_some()
@@ -731,19 +1263,19 @@ fn run_kill(args: KillArgs) -> Result<i32, SysprimsError> {
     maybe_emit_cpu_above_hint(args.cpu_above, args.json, CpuMode::Lifetime, false);
 
     // Parse signal
-    let signal = parse_signal_arg(&args.signal)?;
-    let signal_num = match signal {
-        SignalTarget::Number(n) => n,
-        SignalTarget::Name(ref name) => sysprims_signal::get_signal_number(name)
-            .or_else(|| sysprims_signal::get_signal_number(&name.to_ascii_uppercase()))
-            .or_else(|| {
-                sysprims_signal::get_signal_number(&format!("SIG{}", name.to_ascii_uppercase()))
-            })
-            .ok_or_else(|| SysprimsError::invalid_argument(format!("unknown signal '{}'", name)))?,
-    };
+    let signal = resolve_signal_or_pattern(&args.signal)?;
+    let signal_num = signal.number();
 
     let schema_id = BATCH_KILL_RESULT_V1;
 
+    let mut warnings: Vec<String> = Vec::new();
+    if let Some(warning) = ignored_by_default_warning(&signal) {
+        eprintln!("Warning: {warning}");
+        if args.json {
+            warnings.push(warning);
+        }
+    }
+
     // Send signal to process or process group
     if args.group {
         let pgid = args.pids[0];
@@ -764,11 +1296,9 @@ fn run_kill(args: KillArgs) -> Result<i32, SysprimsError> {
                 signal_sent: signal_num,
                 succeeded,
                 failed,
+                warnings,
             };
-            println!(
-                "{}",
-                serde_json::to_string_pretty(&out).expect("serialize json")
-            );
+            println!("{}", to_json_pretty(&out)?);
             return Ok(if out.failed.is_empty() { 0 } else { 1 });
         }
 
@@ -791,6 +1321,7 @@ fn run_kill(args: KillArgs) -> Result<i32, SysprimsError> {
 
     let filter_used = args.ppid.is_some()
         || args.name.is_some()
+        || args.name_glob.is_some()
         || args.user.is_some()
         || args.cpu_above.is_some()
         || args.memory_above.is_some()
@@ -807,6 +1338,7 @@ fn run_kill(args: KillArgs) -> Result<i32, SysprimsError> {
     let (targets, filter_snapshot) = if filter_used {
         let filter = ProcessFilter {
             name_contains: args.name.clone(),
+            name_glob: args.name_glob.clone(),
             user_equals: args.user.clone(),
             cpu_above: args.cpu_above,
             memory_above_kb: args.memory_above,
@@ -836,11 +1368,9 @@ fn run_kill(args: KillArgs) -> Result<i32, SysprimsError> {
                 signal_sent: signal_num,
                 succeeded: vec![],
                 failed: vec![],
+                warnings,
             };
-            println!(
-                "{}",
-                serde_json::to_string_pretty(&out).expect("serialize json")
-            );
+            println!("{}", to_json_pretty(&out)?);
         }
         return Ok(0);
     }
@@ -848,37 +1378,37 @@ fn run_kill(args: KillArgs) -> Result<i32, SysprimsError> {
     // Apply additional CLI safety checks only when targets were selected via filters.
     // (Explicit PIDs preserve the existing "do what I said" behavior.)
     let mut safe_targets = targets;
-    if filter_used && !args.force {
-        let self_pid = std::process::id();
-        let parent_pid = get_process(self_pid).ok().map(|p| p.ppid);
-
-        let before = safe_targets.len();
-        safe_targets.retain(|&pid| pid != self_pid);
-        safe_targets.retain(|&pid| pid != 1);
-        if let Some(ppid) = parent_pid {
-            safe_targets.retain(|&pid| pid != ppid);
-        }
-        let removed = before.saturating_sub(safe_targets.len());
+    if filter_used {
+        let removed = filter_protected(&mut safe_targets, args.force);
         if removed > 0 {
             eprintln!(
                 "Skipped {removed} unsafe targets (self/PID1/parent); use --force to override"
             );
         }
+        for warning in filter_user_protected(&mut safe_targets, &args.protect_name, args.force)? {
+            eprintln!("{warning}; use --force to override");
+            if args.json {
+                warnings.push(warning);
+            }
+        }
     }
+    // Own-group PIDs are dropped by the batch kill itself; previews apply the
+    // same guard here so they list exactly what would be signaled.
+    let guard_own_group = filter_used && !args.include_own_group;
 
     // Default to a preview when selecting by filters unless --yes is provided.
     if args.dry_run || (filter_used && args.pids.is_empty() && !args.yes) {
+        if guard_own_group {
+            let skipped = filter_own_group(&mut safe_targets);
+            if let Some(warning) = own_group_warning(&skipped) {
+                eprintln!("{warning}; use --include-own-group to override");
+            }
+        }
         if args.json {
             if let Some(snap) = filter_snapshot {
-                println!(
-                    "{}",
-                    serde_json::to_string_pretty(&snap).expect("serialize json")
-                );
+                println!("{}", to_json_pretty(&snap)?);
             } else {
-                println!(
-                    "{}",
-                    serde_json::to_string_pretty(&safe_targets).expect("serialize json")
-                );
+                println!("{}", to_json_pretty(&safe_targets)?);
             }
         } else {
             for pid in &safe_targets {
@@ -893,7 +1423,26 @@ fn run_kill(args: KillArgs) -> Result<i32, SysprimsError> {
     }
 
     // Non-group: multi-PID supported.
-    let batch = sysprims_signal::kill_many(&safe_targets, signal_num)?;
+    let batch = if safe_targets.is_empty() {
+        // Every target was dropped by the safety filters above.
+        BatchKillResult::default()
+    } else {
+        let options = BatchKillOptions {
+            guard_own_group,
+            check_disposition: args.check_disposition,
+        };
+        kill_many_with_options(&safe_targets, signal_num, &options)?
+    };
+    for warning in batch.warnings {
+        if warning.code == WarningCode::Protected {
+            eprintln!("{warning}; use --include-own-group to override");
+            if args.json {
+                warnings.push(warning.message);
+            }
+        } else {
+            warnings.push(warning.message);
+        }
+    }
     let failed: Vec<BatchKillFailureJson> = batch
         .failed
         .into_iter()
@@ -909,14 +1458,16 @@ fn run_kill(args: KillArgs) -> Result<i32, SysprimsError> {
             signal_sent: signal_num,
             succeeded: batch.succeeded,
             failed,
+            warnings,
         };
-        println!(
-            "{}",
-            serde_json::to_string_pretty(&out).expect("serialize json")
-        );
+        println!("{}", to_json_pretty(&out)?);
         return Ok(if out.failed.is_empty() { 0 } else { 1 });
     }
 
+    for warning in &warnings {
+        eprintln!("Warning: {warning}");
+    }
+
     if !failed.is_empty() {
         for f in failed {
             eprintln!("PID {}: {}", f.pid, f.error);
@@ -927,8 +1478,81 @@ fn run_kill(args: KillArgs) -> Result<i32, SysprimsError> {
     Ok(0)
 }
 
+/// Warn when the signal's default action is to ignore it (e.g. `SIGCHLD`).
+fn ignored_by_default_warning(signal: &SignalTarget) -> Option<String> {
+    if default_disposition(signal.number()) != Some(DefaultAction::Ignore) {
+        return None;
+    }
+    let label = match signal {
+        SignalTarget::Named { name, .. } => name.clone(),
+        SignalTarget::Number(number) => format!("signal {number}"),
+    };
+    Some(format!(
+        "{label} is ignored by default; this likely won't stop the process"
+    ))
+}
+
+/// `kill --list --json` output.
+#[derive(serde::Serialize)]
+struct SignalCatalogJson {
+    #[serde(
+        flatten,
+        serialize_with = "sysprims_core::schema::serialize_with_version"
+    )]
+    schema_id: &'static str,
+    platform: &'static str,
+    signals: Vec<SignalInfoJson>,
+}
+
+/// `kill --list --json` entry.
+#[derive(serde::Serialize)]
+struct SignalInfoJson {
+    name: String,
+    number: i32,
+    id: String,
+    default_disposition: Option<&'static str>,
+}
+
+impl From<sysprims_signal::SignalInfo> for SignalInfoJson {
+    fn from(info: sysprims_signal::SignalInfo) -> Self {
+        Self {
+            name: info.name,
+            number: info.number,
+            id: info.id,
+            default_disposition: info.default_disposition.map(|action| match action {
+                DefaultAction::Terminate => "terminate",
+                DefaultAction::CoreDump => "core_dump",
+                DefaultAction::Ignore => "ignore",
+                DefaultAction::Stop => "stop",
+                DefaultAction::Continue => "continue",
+            }),
+        }
+    }
+}
+
 /// Handle `kill --list` command.
-fn run_kill_list(signal_name: Option<String>) -> Result<i32, SysprimsError> {
+fn run_kill_list(signal_name: Option<String>, json: bool) -> Result<i32, SysprimsError> {
+    if json {
+        let catalog = sysprims_signal::signal_catalog().into_iter();
+        let signals: Vec<SignalInfoJson> = match signal_name {
+            Some(name) => {
+                let number = resolve_signal(&name)?;
+                catalog
+                    .filter(|info| info.number == number)
+                    .map(Into::into)
+                    .collect()
+            }
+            None => catalog.map(Into::into).collect(),
+        };
+        let out = SignalCatalogJson {
+            schema_id: SIGNAL_CATALOG_V1,
+            platform: get_platform(),
+            signals,
+        };
+        println!("{}", to_json_pretty(&out)?);
+        return Ok(0);
+    }
+
     if let Some(name) = signal_name {
         // Print signal number for a specific signal name
         let num = sysprims_signal::get_signal_number(&name)
@@ -979,7 +1603,7 @@ fn print_signal_table() {
 /// - 127: Command not found
 /// - 137: Command killed by SIGKILL (128 + 9)
 mod exit_codes {
-    pub const TIMEOUT: i32 = 124;
+    pub const TIMEOUT: i32 = sysprims_timeout::EXIT_TIMED_OUT;
     pub const INTERNAL_ERROR: i32 = 125;
     pub const CANNOT_INVOKE: i32 = 126;
     pub const NOT_FOUND: i32 = 127;
@@ -1008,6 +1632,10 @@ fn run_timeout(args: TimeoutArgs) -> Result<i32, SysprimsError> {
             GroupingMode::GroupByDefault
         },
         preserve_status: args.preserve_status,
+        expand_env: false,
+        clear_env: false,
+        env_passthrough: Vec::new(),
+        cancel: None,
     };
 
     // Convert args to &str slice
@@ -1022,37 +1650,41 @@ fn run_timeout(args: TimeoutArgs) -> Result<i32, SysprimsError> {
     );
 
     match run_with_timeout(&args.command, &arg_refs, timeout, config) {
-        Ok(TimeoutOutcome::Completed { exit_status }) => {
-            // Command completed within timeout
-            if args.preserve_status {
-                Ok(exit_status.code().unwrap_or(0))
-            } else {
-                Ok(0)
+        Ok(
+            ref outcome @ (TimeoutOutcome::TimedOut {
+                signal_sent,
+                escalated,
+                tree_kill_reliability,
+                ref survivors,
+                ..
             }
-        }
-        Ok(TimeoutOutcome::TimedOut {
-            signal_sent,
-            escalated,
-            tree_kill_reliability,
-        }) => {
+            | TimeoutOutcome::Cancelled {
+                signal_sent,
+                escalated,
+                tree_kill_reliability,
+                ref survivors,
+                ..
+            }),
+        ) => {
             info!(
                 signal_sent = signal_sent,
                 escalated = escalated,
                 reliability = ?tree_kill_reliability,
+                survivors = ?survivors,
                 "Command timed out"
             );
-
-            if args.preserve_status {
-                let exit_signal = if escalated {
-                    sysprims_signal::SIGKILL
-                } else {
-                    signal_sent
-                };
-                Ok(128 + exit_signal)
-            } else {
-                Ok(exit_codes::TIMEOUT)
+            if !survivors.is_empty() {
+                warn!(
+                    "{} descendants survived the timeout kill: {:?}",
+                    survivors.len(),
+                    survivors
+                );
             }
+
+            Ok(outcome.exit_code(args.preserve_status))
         }
+        // Command completed within timeout
+        Ok(outcome) => Ok(outcome.exit_code(args.preserve_status)),
         Err(SysprimsError::NotFoundCommand { .. }) => Ok(exit_codes::NOT_FOUND),
         Err(SysprimsError::PermissionDeniedCommand { .. }) => Ok(exit_codes::CANNOT_INVOKE),
         Err(e) => {
@@ -1070,7 +1702,7 @@ fn run_terminate_tree(args: TerminateTreeArgs) -> Result<(), SysprimsError> {
     // Hard safety checks for interactive CLI usage.
     // The library allows terminating any pid > 0 (subject to OS permission), but the CLI
     // defaults to refusing the most footgun targets unless the user explicitly opts in.
-    let self_pid = std::process::id();
+    let self_pid = self_pid();
     if args.pid == self_pid && !args.force {
         return Err(SysprimsError::invalid_argument(
             "refusing to terminate the sysprims process itself (use --force to override)",
@@ -1092,37 +1724,6 @@ fn run_terminate_tree(args: TerminateTreeArgs) -> Result<(), SysprimsError> {
         }
     }
 
-    // Optional PID identity checks to prevent PID reuse mistakes.
-    if args.require_start_time_ms.is_some() || args.require_exe_path.is_some() {
-        let info = get_process(args.pid)?;
-
-        if let Some(expected) = args.require_start_time_ms {
-            let actual = info.start_time_unix_ms.ok_or_else(|| {
-                SysprimsError::invalid_argument(
-                    "start_time_unix_ms unavailable; cannot enforce --require-start-time-ms",
-                )
-            })?;
-            if actual != expected && !args.force {
-                return Err(SysprimsError::invalid_argument(format!(
-                    "PID identity mismatch: start_time_unix_ms expected {expected}, got {actual} (use --force to override)"
-                )));
-            }
-        }
-
-        if let Some(ref expected) = args.require_exe_path {
-            let actual = info.exe_path.clone().ok_or_else(|| {
-                SysprimsError::invalid_argument(
-                    "exe_path unavailable; cannot enforce --require-exe-path",
-                )
-            })?;
-            if &actual != expected && !args.force {
-                return Err(SysprimsError::invalid_argument(format!(
-                    "PID identity mismatch: exe_path expected '{expected}', got '{actual}' (use --force to override)"
-                )));
-            }
-        }
-    }
-
     let grace = parse_duration(&args.grace)?;
     let kill_after = parse_duration(&args.kill_after)?;
     let signal = resolve_signal(&args.signal)?;
@@ -1142,12 +1743,25 @@ fn run_terminate_tree(args: TerminateTreeArgs) -> Result<(), SysprimsError> {
         kill_timeout_ms: kill_after.as_millis() as u64,
         signal,
         kill_signal,
+        // Identity checks guard against PID reuse; --force skips them.
+        require_start_time_ms: args.require_start_time_ms.filter(|_| !args.force),
+        require_exe_path: args.require_exe_path.filter(|_| !args.force),
+        skip_grace: args.skip_grace,
     };
 
-    let result = sysprims_timeout::terminate_tree(args.pid, cfg)?;
+    let result = sysprims_timeout::terminate_tree(args.pid, cfg).map_err(|e| match e {
+        SysprimsError::IdentityMismatch { pid, reason } => {
+            SysprimsError::identity_mismatch(pid, format!("{reason} (use --force to override)"))
+        }
+        e => e,
+    })?;
+
+    if let Some(path) = args.output.as_deref() {
+        write_json_output(path, &result)?;
+    }
 
     if args.json {
-        println!("{}", serde_json::to_string_pretty(&result).unwrap());
+        println!("{}", to_json_pretty(&result)?);
     } else {
         // Human summary
         println!(
@@ -1156,6 +1770,9 @@ fn run_terminate_tree(args: TerminateTreeArgs) -> Result<(), SysprimsError> {
             result.tree_kill_reliability,
             result.warnings.len()
         );
+        if let Some(code) = result.exit_code {
+            println!("exit_code: {code}");
+        }
         for w in result.warnings {
             println!("warning: {w}");
         }
@@ -1164,65 +1781,55 @@ fn run_terminate_tree(args: TerminateTreeArgs) -> Result<(), SysprimsError> {
     Ok(())
 }
 
-/// Parse a duration string like "5s", "100ms", "2m", "1h", or just "5" (seconds).
-fn parse_duration(s: &str) -> Result<Duration, SysprimsError> {
-    let s = s.trim();
+// ============================================================================
+// Wait command
+// ============================================================================
 
-    // Try to parse as plain number (seconds)
-    if let Ok(secs) = s.parse::<f64>() {
-        if secs < 0.0 {
-            return Err(SysprimsError::invalid_argument(
-                "duration cannot be negative",
-            ));
+fn run_wait(args: WaitArgs) -> Result<i32, SysprimsError> {
+    let timeout = match args.timeout.as_deref() {
+        Some(d) => parse_duration(d)?,
+        None => Duration::MAX,
+    };
+    let poll_interval = match args.interval.as_deref() {
+        Some(d) => {
+            let interval = parse_duration(d)?;
+            if interval.is_zero() {
+                return Err(SysprimsError::invalid_argument("--interval must be > 0"));
+            }
+            Some(interval)
         }
-        return Ok(Duration::from_secs_f64(secs));
-    }
-
-    // Try to parse with suffix
-    let (num_str, multiplier) = if let Some(n) = s.strip_suffix("ms") {
-        (n, 0.001)
-    } else if let Some(n) = s.strip_suffix('s') {
-        (n, 1.0)
-    } else if let Some(n) = s.strip_suffix('m') {
-        (n, 60.0)
-    } else if let Some(n) = s.strip_suffix('h') {
-        (n, 3600.0)
-    } else {
-        return Err(SysprimsError::invalid_argument(format!(
-            "invalid duration '{}': expected number or number with suffix (ms, s, m, h)",
-            s
-        )));
+        None => None,
     };
 
-    let num: f64 = num_str.trim().parse().map_err(|_| {
-        SysprimsError::invalid_argument(format!("invalid duration '{}': not a valid number", s))
-    })?;
-
-    if num < 0.0 {
-        return Err(SysprimsError::invalid_argument(
-            "duration cannot be negative",
-        ));
-    }
-
-    Ok(Duration::from_secs_f64(num * multiplier))
-}
-
-/// Resolve signal name or number to signal number.
-fn resolve_signal(s: &str) -> Result<i32, SysprimsError> {
-    let trimmed = s.trim();
+    let config = WaitPidConfig {
+        poll_interval,
+        on_exec: if args.exit_on_exec {
+            OnExec::TreatAsExit
+        } else {
+            OnExec::Ignore
+        },
+        ..WaitPidConfig::default()
+    };
+    let result = wait_pid_with_config(args.pid, timeout, config)?;
 
-    // Try as number first
-    if let Ok(num) = trimmed.parse::<i32>() {
-        return Ok(num);
+    if args.json {
+        println!("{}", to_json_pretty(&result)?);
+    } else {
+        let status = if result.exited { "exited" } else { "timed out" };
+        match result.exit_code {
+            Some(code) => println!("wait: pid={} {status} exit_code={code}", result.pid),
+            None => println!("wait: pid={} {status}", result.pid),
+        }
+        for w in &result.warnings {
+            println!("warning: {w}");
+        }
     }
 
-    // Try as signal name (supports "TERM", "SIGTERM", "term", etc.)
-    sysprims_signal::get_signal_number(trimmed)
-        .or_else(|| sysprims_signal::get_signal_number(&trimmed.to_ascii_uppercase()))
-        .or_else(|| {
-            sysprims_signal::get_signal_number(&format!("SIG{}", trimmed.to_ascii_uppercase()))
-        })
-        .ok_or_else(|| SysprimsError::invalid_argument(format!("unknown signal '{}'", trimmed)))
+    Ok(if result.timed_out {
+        exit_codes::TIMEOUT
+    } else {
+        0
+    })
 }
 
 // ============================================================================
@@ -1245,6 +1852,7 @@ fn parse_max_levels(s: &str) -> Result<u32, SysprimsError> {
 /// Build a ProcessFilter from descendants/kill-descendants shared args.
 fn build_descendants_filter(
     name: &Option<String>,
+    name_glob: &Option<String>,
     user: &Option<String>,
     cpu_above: Option<f64>,
     memory_above: Option<u64>,
@@ -1257,6 +1865,7 @@ fn build_descendants_filter(
         .map(|d| d.as_secs());
 
     let has_filter = name.is_some()
+        || name_glob.is_some()
         || user.is_some()
         || cpu_above.is_some()
         || memory_above.is_some()
@@ -1268,6 +1877,7 @@ fn build_descendants_filter(
 
     Ok(Some(ProcessFilter {
         name_contains: name.clone(),
+        name_glob: name_glob.clone(),
         user_equals: user.clone(),
         cpu_above,
         memory_above_kb: memory_above,
@@ -1326,9 +1936,12 @@ fn maybe_emit_cpu_above_hint(
 }
 
 fn run_descendants(args: DescendantsArgs) -> Result<i32, SysprimsError> {
+    let format = OutputFormat::resolve(args.format, args.table);
     maybe_emit_cpu_above_hint(
         args.cpu_above,
-        args.json,
+        args.json
+            || args.json_tree
+            || matches!(args.format, Some(OutputFormat::Json | OutputFormat::Yaml)),
         args.cpu_mode.clone(),
         cpu_mode_flag_explicit_from_argv(),
     );
@@ -1337,39 +1950,61 @@ fn run_descendants(args: DescendantsArgs) -> Result<i32, SysprimsError> {
 
     let filter = build_descendants_filter(
         &args.name,
+        &args.name_glob,
         &args.user,
         args.cpu_above,
         args.memory_above,
         &args.running_for,
     )?;
     let sample_duration = args.sample.as_deref().map(parse_duration).transpose()?;
+    let poll = match args.poll.as_deref() {
+        Some(window) => Some(PollConfig {
+            interval: match args.poll_interval.as_deref() {
+                Some(interval) => parse_duration(interval)?,
+                None => Duration::from_millis(50),
+            },
+            duration: parse_duration(window)?,
+        }),
+        None => None,
+    };
     let config = DescendantsConfig {
         root_pid: args.pid,
         max_levels: Some(max_levels),
         filter,
         cpu_mode: to_proc_cpu_mode(args.cpu_mode),
         sample_duration,
+        poll,
+        deadline: args.deadline.as_deref().map(parse_duration).transpose()?,
     };
 
     let result = descendants_with_config(config)?;
+    if format == OutputFormat::Table || args.tree {
+        for w in &result.warnings {
+            eprintln!("Warning: {w}");
+        }
+    }
 
     if args.tree {
         let root_info = get_process(args.pid).ok();
-        print_descendants_tree(&result, root_info.as_ref());
-    } else if args.table {
+        print_descendants_tree(&result, root_info.as_ref(), args.compact);
+    } else if args.json_tree {
+        let mut tree = result.to_tree_result();
+        tree.root.info = get_process(args.pid).ok();
+        println!("{}", to_json_pretty(&tree)?);
+    } else if format == OutputFormat::Table {
         for level in &result.levels {
             println!("--- Level {} ---", level.level);
-            print_process_table(&level.processes);
+            print!(
+                "{}",
+                render_table(&level.processes, &RenderOptions::default())
+            );
         }
         println!(
             "\nTotal: {} descendants found, {} matched filter",
             result.total_found, result.matched_by_filter
         );
     } else {
-        println!(
-            "{}",
-            serde_json::to_string_pretty(&result).expect("serialize json")
-        );
+        print_structured(&result, format)?;
     }
 
     Ok(0)
@@ -1379,59 +2014,6 @@ fn run_descendants(args: DescendantsArgs) -> Result<i32, SysprimsError> {
 // ASCII Tree Rendering (C2)
 // ============================================================================
 
-/// Format elapsed seconds as a human-readable duration string.
-fn format_elapsed(secs: u64) -> String {
-    if secs < 60 {
-        format!("{secs}s")
-    } else if secs < 3600 {
-        let m = secs / 60;
-        let s = secs % 60;
-        if s == 0 {
-            format!("{m}m")
-        } else {
-            format!("{m}m{s}s")
-        }
-    } else if secs < 86400 {
-        let h = secs / 3600;
-        let m = (secs % 3600) / 60;
-        if m == 0 {
-            format!("{h}h")
-        } else {
-            format!("{h}h{m}m")
-        }
-    } else {
-        let d = secs / 86400;
-        let h = (secs % 86400) / 3600;
-        if h == 0 {
-            format!("{d}d")
-        } else {
-            format!("{d}d{h}h")
-        }
-    }
-}
-
-/// Format memory in KB as a human-readable string.
-fn format_memory(kb: u64) -> String {
-    if kb < 1024 {
-        format!("{kb}K")
-    } else if kb < 1024 * 1024 {
-        format!("{}M", kb / 1024)
-    } else {
-        format!("{:.1}G", kb as f64 / (1024.0 * 1024.0))
-    }
-}
-
-/// CPU threshold indicator.
-fn cpu_indicator(cpu: f64) -> &'static str {
-    if cpu > 90.0 {
-        " ★ HIGH"
-    } else if cpu > 50.0 {
-        " ⚠ WARN"
-    } else {
-        ""
-    }
-}
-
 /// Extract a useful cmdline hint beyond the process name.
 ///
 /// Returns a short snippet when the cmdline contains information not
@@ -1480,14 +2062,28 @@ fn format_tree_node(proc: &sysprims_proc::ProcessInfo) -> String {
     )
 }
 
+/// Format a tree node as just `PID NAME`, for `--tree --compact`.
+fn format_compact_tree_node(proc: &sysprims_proc::ProcessInfo) -> String {
+    format!("{} {}", proc.pid, proc.name)
+}
+
 /// Print an ASCII art tree from a DescendantsResult.
+///
+/// `compact` drops the metrics bracket and the CPU legend.
 fn print_descendants_tree(
     result: &sysprims_proc::DescendantsResult,
     root_info: Option<&sysprims_proc::ProcessInfo>,
+    compact: bool,
 ) {
+    let format_node = if compact {
+        format_compact_tree_node
+    } else {
+        format_tree_node
+    };
+
     // Print root node.
     if let Some(root) = root_info {
-        println!("{}", format_tree_node(root));
+        println!("{}", format_node(root));
     } else {
         println!("{} (root)", result.root_pid);
     }
@@ -1513,13 +2109,14 @@ fn print_descendants_tree(
         pid: u32,
         prefix: &str,
         children_map: &std::collections::HashMap<u32, Vec<&sysprims_proc::ProcessInfo>>,
+        format_node: fn(&sysprims_proc::ProcessInfo) -> String,
     ) {
         if let Some(children) = children_map.get(&pid) {
             let count = children.len();
             for (i, child) in children.iter().enumerate() {
                 let is_last = i == count - 1;
                 let connector = if is_last { "└── " } else { "├── " };
-                let node_line = format_tree_node(child);
+                let node_line = format_node(child);
                 println!("{prefix}{connector}{node_line}");
 
                 let child_prefix = if is_last {
@@ -1527,12 +2124,12 @@ fn print_descendants_tree(
                 } else {
                     format!("{prefix}│   ")
                 };
-                print_subtree(child.pid, &child_prefix, children_map);
+                print_subtree(child.pid, &child_prefix, children_map, format_node);
             }
         }
     }
 
-    print_subtree(result.root_pid, "", &children_map);
+    print_subtree(result.root_pid, "", &children_map, format_node);
 
     // Summary footer.
     let high_cpu = result
@@ -1553,7 +2150,7 @@ fn print_descendants_tree(
         "Total: {} processes in subtree, {} matched filter",
         result.total_found, result.matched_by_filter
     );
-    if high_cpu > 0 || warn_cpu > 0 {
+    if !compact && (high_cpu > 0 || warn_cpu > 0) {
         println!("★ = CPU > 90%, ⚠ = CPU > 50%");
     }
 }
@@ -1570,6 +2167,7 @@ fn run_kill_descendants(args: KillDescendantsArgs) -> Result<i32, SysprimsError>
 
     let filter = build_descendants_filter(
         &args.name,
+        &args.name_glob,
         &args.user,
         args.cpu_above,
         args.memory_above,
@@ -1582,6 +2180,8 @@ fn run_kill_descendants(args: KillDescendantsArgs) -> Result<i32, SysprimsError>
         filter,
         cpu_mode: to_proc_cpu_mode(args.cpu_mode),
         sample_duration,
+        poll: None,
+        deadline: None,
     };
 
     let result = descendants_with_config(config)?;
@@ -1595,8 +2195,14 @@ fn run_kill_descendants(args: KillDescendantsArgs) -> Result<i32, SysprimsError>
     target_pids.sort_unstable();
     target_pids.dedup();
 
-    // Never kill the root PID itself — descendants-only.
-    target_pids.retain(|&pid| pid != args.pid);
+    // Never kill the root PID itself unless --include-root — descendants-only by default.
+    if args.include_root {
+        if !target_pids.contains(&args.pid) {
+            target_pids.push(args.pid);
+        }
+    } else {
+        target_pids.retain(|&pid| pid != args.pid);
+    }
 
     if target_pids.is_empty() {
         if args.json {
@@ -1606,40 +2212,27 @@ fn run_kill_descendants(args: KillDescendantsArgs) -> Result<i32, SysprimsError>
                 signal_sent: 0,
                 succeeded: vec![],
                 failed: vec![],
+                warnings: vec![],
             };
-            println!(
-                "{}",
-                serde_json::to_string_pretty(&out).expect("serialize json")
-            );
+            println!("{}", to_json_pretty(&out)?);
         }
         return Ok(0);
     }
 
     // Safety: drop self, PID 1, parent unless --force.
-    if !args.force {
-        let self_pid = std::process::id();
-        let parent_pid = get_process(self_pid).ok().map(|p| p.ppid);
-
-        let before = target_pids.len();
-        target_pids.retain(|&pid| pid != self_pid && pid != 1);
-        if let Some(ppid) = parent_pid {
-            target_pids.retain(|&pid| pid != ppid);
-        }
-        let removed = before.saturating_sub(target_pids.len());
-        if removed > 0 {
-            eprintln!(
-                "Skipped {removed} unsafe targets (self/PID1/parent); use --force to override"
-            );
-        }
+    let removed = filter_protected(&mut target_pids, args.force);
+    if removed > 0 {
+        eprintln!("Skipped {removed} unsafe targets (self/PID1/parent); use --force to override");
+    }
+    let warnings = filter_user_protected(&mut target_pids, &args.protect_name, args.force)?;
+    for warning in &warnings {
+        eprintln!("{warning}; use --force to override");
     }
 
     // Default to preview unless --yes.
     if args.dry_run || !args.yes {
         if args.json {
-            println!(
-                "{}",
-                serde_json::to_string_pretty(&result).expect("serialize json")
-            );
+            println!("{}", to_json_pretty(&result)?);
         } else {
             for pid in &target_pids {
                 println!("{pid}");
@@ -1671,11 +2264,129 @@ fn run_kill_descendants(args: KillDescendantsArgs) -> Result<i32, SysprimsError>
             signal_sent: signal_num,
             succeeded: batch.succeeded,
             failed,
+            warnings,
         };
-        println!(
-            "{}",
-            serde_json::to_string_pretty(&out).expect("serialize json")
+        println!("{}", to_json_pretty(&out)?);
+        return Ok(if out.failed.is_empty() { 0 } else { 1 });
+    }
+
+    if !failed.is_empty() {
+        for f in &failed {
+            eprintln!("PID {}: {}", f.pid, f.error);
+        }
+        return Ok(1);
+    }
+
+    Ok(0)
+}
+
+/// Drop targets protected via SYSPRIMS_PROTECTED_PIDS / SYSPRIMS_PROTECTED_NAMES
+/// and `--protect-name`, returning one message per skipped target.
+fn filter_user_protected(
+    pids: &mut Vec<u32>,
+    protect_names: &[String],
+    force: bool,
+) -> Result<Vec<String>, SysprimsError> {
+    let mut protections = UserProtections::from_env()?;
+    protections.names.extend(protect_names.iter().cloned());
+    Ok(protections
+        .filter(pids, force)
+        .into_iter()
+        .map(|target| {
+            format!(
+                "Skipped protected target {} (PID {})",
+                target.name.as_deref().unwrap_or("<unknown>"),
+                target.pid
+            )
+        })
+        .collect())
+}
+
+fn run_kill_port(args: KillPortArgs) -> Result<i32, SysprimsError> {
+    let signal_num = resolve_signal(&args.signal)?;
+    let protocol_name = match args.protocol {
+        ProtocolArg::Tcp => "tcp",
+        ProtocolArg::Udp => "udp",
+    };
+
+    let owners = processes_by_port(args.port, args.protocol.into())?;
+    if owners.is_empty() {
+        eprintln!(
+            "No process found listening on {protocol_name} port {} (ports owned by other users may require elevated privileges)",
+            args.port
+        );
+        return Ok(1);
+    }
+
+    // Safety: skip self, PID 1, parent, and user-protected owners unless
+    // --force, reporting each one and continuing with the rest.
+    let mut warnings = Vec::new();
+    let mut target_pids: Vec<u32> = owners.iter().map(|p| p.pid).collect();
+    if !args.force {
+        let protected = protected_pids();
+        for owner in owners.iter().filter(|p| protected.contains(&p.pid)) {
+            let warning = format!(
+                "Skipped PID {} ({}) owning {protocol_name} port {} (self/PID1/parent)",
+                owner.pid, owner.name, args.port
+            );
+            eprintln!("{warning}; use --force to override");
+            warnings.push(warning);
+        }
+        target_pids.retain(|pid| !protected.contains(pid));
+    }
+    for warning in filter_user_protected(&mut target_pids, &args.protect_name, args.force)? {
+        eprintln!(
+            "{warning} owning {protocol_name} port {}; use --force to override",
+            args.port
+        );
+        warnings.push(warning);
+    }
+    if target_pids.is_empty() {
+        eprintln!(
+            "Refusing to signal: every owner of {protocol_name} port {} is protected",
+            args.port
         );
+        return Ok(1);
+    }
+
+    // Default to preview unless --yes.
+    if args.dry_run || !args.yes {
+        let targets: Vec<&sysprims_proc::ProcessInfo> = owners
+            .iter()
+            .filter(|p| target_pids.contains(&p.pid))
+            .collect();
+        if args.json {
+            println!("{}", to_json_pretty(&targets)?);
+        } else {
+            for target in &targets {
+                println!("{}", target.pid);
+            }
+            if !args.yes && !args.dry_run {
+                eprintln!("Refusing to send signals without --yes (use --dry-run to preview)");
+            }
+        }
+        return Ok(0);
+    }
+
+    let batch = sysprims_signal::kill_many(&target_pids, signal_num)?;
+    let failed: Vec<BatchKillFailureJson> = batch
+        .failed
+        .into_iter()
+        .map(|f| BatchKillFailureJson {
+            pid: f.pid,
+            error: f.error.to_string(),
+        })
+        .collect();
+
+    if args.json {
+        let out = BatchKillResultJson {
+            schema_id: BATCH_KILL_RESULT_V1,
+            signal_sent: signal_num,
+            succeeded: batch.succeeded,
+            failed,
+            warnings,
+        };
+        println!("{}", to_json_pretty(&out)?);
         return Ok(if out.failed.is_empty() { 0 } else { 1 });
     }
 
@@ -1694,6 +2405,26 @@ fn run_kill_descendants(args: KillDescendantsArgs) -> Result<i32, SysprimsError>
 // ============================================================================
 
 fn run_pstat(args: PstatArgs) -> Result<i32, SysprimsError> {
+    let format = OutputFormat::resolve(args.format, args.table);
+    let fields = args.fields.as_deref().map(parse_pstat_fields).transpose()?;
+    if fields.is_some() && format == OutputFormat::Table {
+        return Err(SysprimsError::invalid_argument(
+            "--fields applies only to json/yaml output",
+        ));
+    }
+
+    if args.alert_if_any && args.cpu_above.is_none() && args.memory_above.is_none() {
+        return Err(SysprimsError::invalid_argument(
+            "--alert-if-any requires --cpu-above or --memory-above",
+        ));
+    }
+
+    let options = if args.no_username {
+        ProcessOptions::default().without_usernames()
+    } else {
+        ProcessOptions::default()
+    };
+
     let monitor_mode = args.cpu_mode == CpuMode::Monitor;
     let sampling = args.sample.is_some() || monitor_mode;
     let sample_duration = if sampling {
@@ -1707,10 +2438,15 @@ fn run_pstat(args: PstatArgs) -> Result<i32, SysprimsError> {
     };
 
     // If specific PID requested, route through snapshot envelope for schema compliance.
-    if let Some(pid) = args.pid {
+    let pid = if args.self_only {
+        Some(self_pid())
+    } else {
+        args.pid
+    };
+    if let Some(pid) = pid {
         // Preserve `get_process(pid)` error semantics (NotFound vs PermissionDenied),
         // while still returning a schema-compliant snapshot envelope for JSON output.
-        let mut proc_opt = match get_process(pid) {
+        let mut proc_opt = match get_process_with_options(pid, options) {
             Ok(p) => Some(p),
             Err(SysprimsError::NotFound { .. }) => None,
             Err(e) => return Err(e),
@@ -1730,7 +2466,7 @@ fn run_pstat(args: PstatArgs) -> Result<i32, SysprimsError> {
                 let cpu0 = cpu_total_time_ns(proc0.pid)?;
                 std::thread::sleep(sample);
 
-                match get_process(pid) {
+                match get_process_with_options(pid, options) {
                     Ok(mut proc1) => {
                         // PID reuse guard: only compute if start time matches.
                         if start0.is_none()
@@ -1758,23 +2494,33 @@ fn run_pstat(args: PstatArgs) -> Result<i32, SysprimsError> {
             }
         }
 
-        if args.table {
+        // JSON always uses the snapshot envelope shape.
+        // We reuse `snapshot()` as the source of timestamp + schema_id.
+        let snap = if format == OutputFormat::Table && args.output.is_none() {
+            None
+        } else {
+            let mut snap = snapshot()?;
+            snap.processes = proc_opt.iter().cloned().collect();
+            if sampled {
+                snap.mark_sampled();
+            }
+            Some(snap)
+        };
+
+        if let (Some(path), Some(snap)) = (args.output.as_deref(), snap.as_ref()) {
+            write_json_output(path, &snapshot_output(snap, fields.as_deref()))?;
+        }
+
+        if format == OutputFormat::Table {
             if let Some(p) = proc_opt {
-                print_process_table(&[p]);
+                print!("{}", render_table(&[p], &RenderOptions::default()));
                 return Ok(0);
             }
             return Err(SysprimsError::not_found(pid));
         }
 
-        // Default to JSON: always emit the snapshot envelope shape.
-        // We reuse `snapshot()` as the source of timestamp + schema_id.
-        let mut snap = snapshot()?;
-        snap.processes = proc_opt.into_iter().collect();
-        if sampled {
-            snap.schema_id = PROCESS_INFO_SAMPLED_V1;
-        }
-
-        println!("{}", serde_json::to_string_pretty(&snap).unwrap());
+        let snap = snap.expect("snapshot is built for structured output");
+        print_structured(&snapshot_output(&snap, fields.as_deref()), format)?;
         return Ok(if snap.processes.is_empty() { 1 } else { 0 });
     }
 
@@ -1791,27 +2537,61 @@ fn run_pstat(args: PstatArgs) -> Result<i32, SysprimsError> {
     // processes due to lifetime-average CPU values.
     let base_filter = ProcessFilter {
         name_contains: args.name.clone(),
+        name_glob: args.name_glob.clone(),
         user_equals: args.user.clone(),
         cpu_above: if sampling { None } else { args.cpu_above },
         memory_above_kb: args.memory_above,
         ppid: args.ppid,
         running_for_at_least_secs: running_for_secs,
+        age_bucket_in: args
+            .age
+            .as_ref()
+            .map(|buckets| buckets.iter().map(|&b| b.into()).collect()),
+        exclude_kernel_threads: args.no_kernel_threads,
+        exe_deleted_only: args.deleted_exe,
         ..Default::default()
     };
 
     let has_filter = base_filter.name_contains.is_some()
+        || base_filter.name_glob.is_some()
         || base_filter.user_equals.is_some()
         || base_filter.cpu_above.is_some()
         || base_filter.memory_above_kb.is_some()
         || base_filter.ppid.is_some()
-        || base_filter.running_for_at_least_secs.is_some();
-
-    let mut snap = if has_filter {
-        snapshot_filtered(&base_filter)?
+        || base_filter.running_for_at_least_secs.is_some()
+        || base_filter.age_bucket_in.is_some()
+        || base_filter.exclude_kernel_threads
+        || base_filter.exe_deleted_only;
+
+    // --mine scopes enumeration to the uid this CLI runs as.
+    let mine_uid = if args.mine {
+        let uid = get_process(self_pid())?.uid;
+        Some(uid.ok_or_else(|| SysprimsError::not_supported("--mine", "windows"))?)
     } else {
-        snapshot()?
+        None
+    };
+    let take_snapshot = || -> Result<ProcessSnapshot, SysprimsError> {
+        match mine_uid {
+            Some(uid) => {
+                base_filter.validate()?;
+                let snap = snapshot_with_config(SnapshotConfig {
+                    uid: Some(uid),
+                    options,
+                    ..Default::default()
+                })?;
+                Ok(if has_filter {
+                    filter_snapshot(&snap, &base_filter)
+                } else {
+                    snap
+                })
+            }
+            None if has_filter => snapshot_filtered_with_options(&base_filter, options),
+            None => snapshot_with_options(options),
+        }
     };
 
+    let mut snap = take_snapshot()?;
+
     if sampling {
         let sample = sample_duration;
         if sample.is_zero() {
@@ -1831,11 +2611,7 @@ fn run_pstat(args: PstatArgs) -> Result<i32, SysprimsError> {
         std::thread::sleep(sample);
 
         // Refresh snapshot (same base filter) for current fields.
-        let mut snap1 = if has_filter {
-            snapshot_filtered(&base_filter)?
-        } else {
-            snapshot()?
-        };
+        let mut snap1 = take_snapshot()?;
 
         let dt_ns = sample.as_nanos() as f64;
         if dt_ns > 0.0 {
@@ -1858,11 +2634,14 @@ fn run_pstat(args: PstatArgs) -> Result<i32, SysprimsError> {
 
         // Apply cpu_above after sampling.
         if let Some(threshold) = args.cpu_above {
+            snap1.total_before_filter =
+                Some(snap1.total_before_filter.unwrap_or(snap1.processes.len()));
+            snap1.filtered = true;
             snap1.processes.retain(|p| p.cpu_percent >= threshold);
         }
 
         // Sampling changes CPU semantics (can exceed 100 for multi-core).
-        snap1.schema_id = PROCESS_INFO_SAMPLED_V1;
+        snap1.mark_sampled();
 
         snap = snap1;
     }
@@ -1882,16 +2661,157 @@ fn run_pstat(args: PstatArgs) -> Result<i32, SysprimsError> {
     }
 
     // Output
-    if args.table {
-        print_process_table(&snap.processes);
+    if let Some(path) = args.output.as_deref() {
+        write_json_output(path, &snapshot_output(&snap, fields.as_deref()))?;
+    }
+
+    if format == OutputFormat::Table {
+        print!(
+            "{}",
+            render_table(&snap.processes, &RenderOptions::default())
+        );
     } else {
-        // Default to JSON
-        println!("{}", serde_json::to_string_pretty(&snap).unwrap());
+        print_structured(&snapshot_output(&snap, fields.as_deref()), format)?;
     }
 
+    if args.alert_if_any && !snap.processes.is_empty() {
+        return Ok(PSTAT_ALERT_EXIT_CODE);
+    }
     Ok(0)
 }
 
+/// Validate `--fields` names against [`PROCESS_INFO_FIELDS`].
+fn parse_pstat_fields(raw: &[String]) -> Result<Vec<String>, SysprimsError> {
+    let mut fields = Vec::new();
+    for field in raw.iter().map(|f| f.trim()).filter(|f| !f.is_empty()) {
+        if !PROCESS_INFO_FIELDS.contains(&field) {
+            return Err(SysprimsError::invalid_argument(format!(
+                "unknown field '{field}' (valid: {})",
+                PROCESS_INFO_FIELDS.join(", ")
+            )));
+        }
+        if !fields.iter().any(|f| f == field) {
+            fields.push(field.to_string());
+        }
+    }
+    if fields.is_empty() {
+        return Err(SysprimsError::invalid_argument("--fields cannot be empty"));
+    }
+    Ok(fields)
+}
+
+/// Retain only `fields` in each process object of a serialized snapshot.
+fn project_snapshot_fields(
+    snap: &sysprims_proc::ProcessSnapshot,
+    fields: &[String],
+) -> serde_json::Value {
+    let mut value = serde_json::to_value(snap).expect("serialize json");
+    if let Some(processes) = value
+        .get_mut("processes")
+        .and_then(serde_json::Value::as_array_mut)
+    {
+        for process in processes.iter_mut() {
+            if let Some(obj) = process.as_object_mut() {
+                obj.retain(|key, _| fields.iter().any(|f| f == key));
+            }
+        }
+    }
+    value
+}
+
+/// A `pstat` snapshot ready for serialization.
+///
+/// Unprojected snapshots serialize straight from the struct so field order
+/// matches the schema; only `--fields` goes through a `serde_json::Value`.
+#[derive(serde::Serialize)]
+#[serde(untagged)]
+enum SnapshotOutput<'a> {
+    Full(&'a sysprims_proc::ProcessSnapshot),
+    Projected(serde_json::Value),
+}
+
+fn snapshot_output<'a>(
+    snap: &'a sysprims_proc::ProcessSnapshot,
+    fields: Option<&[String]>,
+) -> SnapshotOutput<'a> {
+    match fields {
+        Some(fields) => SnapshotOutput::Projected(project_snapshot_fields(snap, fields)),
+        None => SnapshotOutput::Full(snap),
+    }
+}
+
+/// Serialize `value` as pretty JSON.
+///
+/// With `SYSPRIMS_VALIDATE_SCHEMA` set, the value is first checked against its
+/// embedded schema: mismatches are logged, or an error when set to `strict`.
+fn to_json_pretty<T: serde::Serialize + ?Sized>(value: &T) -> Result<String, SysprimsError> {
+    check_output_schema(value)?;
+    Ok(serde_json::to_string_pretty(value).expect("serialize json"))
+}
+
+fn check_output_schema<T: serde::Serialize + ?Sized>(value: &T) -> Result<(), SysprimsError> {
+    for mismatch in schema_check::check_output(value)? {
+        warn!("{mismatch}");
+    }
+    Ok(())
+}
+
+/// Print `value` as pretty JSON, or as YAML for `--format yaml`.
+fn print_structured<T: serde::Serialize + ?Sized>(
+    value: &T,
+    format: OutputFormat,
+) -> Result<(), SysprimsError> {
+    match format {
+        OutputFormat::Yaml => {
+            check_output_schema(value)?;
+            print!(
+                "{}",
+                serde_norway::to_string(value).expect("serialize yaml")
+            )
+        }
+        OutputFormat::Json | OutputFormat::Table => println!("{}", to_json_pretty(value)?),
+    }
+    Ok(())
+}
+
+/// Write `value` as pretty JSON to `path` for `--output`.
+///
+/// The JSON goes to a temp file in the same directory which is then renamed
+/// over `path`, so readers never observe a partially written result.
+fn write_json_output<T: serde::Serialize + ?Sized>(
+    path: &Path,
+    value: &T,
+) -> Result<(), SysprimsError> {
+    let file_name = path.file_name().ok_or_else(|| {
+        SysprimsError::invalid_argument(format!(
+            "--output path '{}' has no file name",
+            path.display()
+        ))
+    })?;
+    let tmp = path.with_file_name(format!(
+        ".{}.tmp-{}",
+        file_name.to_string_lossy(),
+        std::process::id()
+    ));
+
+    let json = to_json_pretty(value)?;
+    let write = || -> std::io::Result<()> {
+        let mut file = std::fs::File::create(&tmp)?;
+        file.write_all(json.as_bytes())?;
+        file.write_all(b"\n")?;
+        file.sync_all()?;
+        std::fs::rename(&tmp, path)
+    };
+
+    write().map_err(|e| {
+        let _ = std::fs::remove_file(&tmp);
+        SysprimsError::system(
+            format!("failed to write {}: {}", path.display(), e),
+            e.raw_os_error().unwrap_or(0),
+        )
+    })
+}
+
 // ============================================================================
 // Fds command
 // ============================================================================
@@ -1906,6 +2826,10 @@ fn fd_kind_str(kind: FdKind) -> &'static str {
 }
 
 fn run_fds(args: FdsArgs) -> Result<i32, SysprimsError> {
+    if args.watch {
+        return run_fds_watch(args);
+    }
+
     let filter = args.kind.map(|k| FdFilter {
         kind: Some(k.into()),
     });
@@ -1915,7 +2839,8 @@ fn run_fds(args: FdsArgs) -> Result<i32, SysprimsError> {
         None => list_fds(args.pid, None)?,
     };
 
-    if args.table {
+    let format = OutputFormat::resolve(args.format, args.table);
+    if format == OutputFormat::Table {
         print_fd_table(&snapshot.fds);
         for w in snapshot.warnings {
             eprintln!("Warning: {w}");
@@ -1923,8 +2848,32 @@ fn run_fds(args: FdsArgs) -> Result<i32, SysprimsError> {
         return Ok(0);
     }
 
-    // Default to JSON
-    println!("{}", serde_json::to_string_pretty(&snapshot).unwrap());
+    print_structured(&snapshot, format)?;
+    Ok(0)
+}
+
+fn run_fds_watch(args: FdsArgs) -> Result<i32, SysprimsError> {
+    let interval = parse_duration(&args.interval)?;
+    let report = fd_growth(args.pid, args.samples, interval)?;
+
+    let format = OutputFormat::resolve(args.format, args.table);
+    if format == OutputFormat::Table {
+        println!("{:>6} {:>8}", "SAMPLE", "FDS");
+        println!("{:-<15}", "");
+        for (i, count) in report.counts.iter().enumerate() {
+            println!("{:>6} {:>8}", i + 1, count);
+        }
+        println!(
+            "\nmin={} max={} slope={:.2}/s likely_leaking={}",
+            report.min, report.max, report.slope_per_sec, report.likely_leaking
+        );
+        for w in report.warnings {
+            eprintln!("Warning: {w}");
+        }
+        return Ok(0);
+    }
+
+    print_structured(&report, format)?;
     Ok(0)
 }
 
@@ -1948,6 +2897,75 @@ fn print_fd_table(fds: &[sysprims_proc::FdInfo]) {
     }
 }
 
+// ============================================================================
+// Lsof command
+// ============================================================================
+
+#[derive(serde::Serialize)]
+struct PathUserJson<'a> {
+    pid: u32,
+    name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<&'a str>,
+    fds: &'a [sysprims_proc::FdInfo],
+}
+
+#[derive(serde::Serialize)]
+struct PathUsersJson<'a> {
+    path: &'a str,
+    processes: Vec<PathUserJson<'a>>,
+}
+
+fn run_lsof(args: LsofArgs) -> Result<i32, SysprimsError> {
+    let filter = ProcessFilter {
+        user_equals: args.user.clone(),
+        ..Default::default()
+    };
+    if args.user.is_none() {
+        eprintln!(
+            "Warning: scanning the fds of every visible process; results are best-effort. Use --user to narrow the scan."
+        );
+    }
+
+    let users = processes_using_path_filtered(&args.path, &filter)?;
+
+    let format = OutputFormat::resolve(args.format, args.table);
+    if format == OutputFormat::Table {
+        println!("{:>7} {:<20} {:>5} TARGET", "PID", "NAME", "FD");
+        println!("{:-<80}", "");
+        if users.is_empty() {
+            println!("(no visible process holds this path open)");
+        }
+        for (proc, fds) in &users {
+            for fd in fds {
+                println!(
+                    "{:>7} {:<20} {:>5} {}",
+                    proc.pid,
+                    truncate(&proc.name, 20),
+                    fd.fd,
+                    truncate(fd.path.as_deref().unwrap_or("-"), 45)
+                );
+            }
+        }
+        return Ok(0);
+    }
+
+    let out = PathUsersJson {
+        path: &args.path,
+        processes: users
+            .iter()
+            .map(|(proc, fds)| PathUserJson {
+                pid: proc.pid,
+                name: &proc.name,
+                user: proc.user.as_deref(),
+                fds,
+            })
+            .collect(),
+    };
+    print_structured(&out, format)?;
+    Ok(0)
+}
+
 // ============================================================================
 // Ports command
 // ============================================================================
@@ -1971,27 +2989,81 @@ fn run_ports(args: PortsArgs) -> Result<i32, SysprimsError> {
     let filter = PortFilter {
         protocol: args.protocol.map(Into::into),
         local_port: args.local_port,
+        process_name_contains: args.name,
     };
 
-    let snapshot = if filter.protocol.is_some() || filter.local_port.is_some() {
+    let snapshot = if let Some(pid) = args.pid {
+        listening_ports_for_pid(pid, Some(&filter))?
+    } else if filter.protocol.is_some()
+        || filter.local_port.is_some()
+        || filter.process_name_contains.is_some()
+    {
         listening_ports(Some(&filter))?
     } else {
         listening_ports(None)?
     };
 
-    if args.table {
+    if let Some(path) = args.output.as_deref() {
+        write_json_output(path, &snapshot)?;
+    }
+
+    let format = OutputFormat::resolve(args.format, args.table);
+    if format == OutputFormat::Table {
         print_ports_table(&snapshot.bindings);
         for w in snapshot.warnings {
             eprintln!("Warning: {w}");
         }
+        if snapshot.needs_elevation {
+            eprintln!("hint: {} to attribute all bindings", elevation_hint());
+        }
         return Ok(0);
     }
 
-    // Default to JSON
-    println!("{}", serde_json::to_string_pretty(&snapshot).unwrap());
+    print_structured(&snapshot, format)?;
     Ok(0)
 }
 
+fn run_port_check(args: PortCheckArgs) -> Result<i32, SysprimsError> {
+    // A filtered listening_ports() reports "no match" as NotSupported, so
+    // list everything and filter here to tell a free port from no support.
+    let protocol: Protocol = args.protocol.into();
+    let mut snapshot = listening_ports(None)?;
+    snapshot
+        .bindings
+        .retain(|b| b.protocol == protocol && b.local_port == args.port);
+    let in_use = !snapshot.bindings.is_empty();
+
+    if args.json {
+        println!("{}", to_json_pretty(&snapshot)?);
+    } else {
+        if in_use {
+            // One line per owner; dual-stack listeners often appear twice.
+            let mut owners: Vec<String> = snapshot
+                .bindings
+                .iter()
+                .map(|b| match (b.pid, b.process.as_ref()) {
+                    (Some(pid), Some(p)) => format!("in use by PID {pid} ({})", p.name),
+                    (Some(pid), None) => format!("in use by PID {pid}"),
+                    (None, _) => {
+                        "in use (owner not visible; may require elevated privileges)".to_string()
+                    }
+                })
+                .collect();
+            owners.dedup();
+            for owner in owners {
+                println!("{owner}");
+            }
+        } else {
+            println!("free");
+        }
+        for w in &snapshot.warnings {
+            eprintln!("Warning: {w}");
+        }
+    }
+
+    Ok(if in_use { 1 } else { 0 })
+}
+
 fn print_ports_table(bindings: &[sysprims_proc::PortBinding]) {
     println!(
         "{:>5} {:<22} {:<8} {:>7} NAME",
@@ -2028,63 +3100,17 @@ fn print_ports_table(bindings: &[sysprims_proc::PortBinding]) {
 fn sort_processes(processes: &mut [sysprims_proc::ProcessInfo], field: &str) {
     match field.to_lowercase().as_str() {
         "pid" => processes.sort_by_key(|p| p.pid),
-        "name" => processes.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase())),
+        "name" => processes.sort_by_key(|p| p.name.to_lowercase()),
         "cpu" => processes.sort_by(|a, b| {
             b.cpu_percent
                 .partial_cmp(&a.cpu_percent)
                 .unwrap_or(std::cmp::Ordering::Equal)
         }),
-        "memory" | "mem" => processes.sort_by(|a, b| b.memory_kb.cmp(&a.memory_kb)),
+        "memory" | "mem" => processes.sort_by_key(|p| std::cmp::Reverse(p.memory_kb)),
         _ => processes.sort_by_key(|p| p.pid),
     }
 }
 
-/// Print processes in table format.
-fn print_process_table(processes: &[sysprims_proc::ProcessInfo]) {
-    // Header
-    println!(
-        "{:>7} {:>7} {:>6} {:>10} {:>8} {:<16} NAME",
-        "PID", "PPID", "CPU%", "MEM(KB)", "STATE", "USER"
-    );
-    println!("{:-<80}", "");
-
-    if processes.is_empty() {
-        println!("(no matching processes)");
-        return;
-    }
-
-    for p in processes {
-        let user = p.user.as_deref().unwrap_or("-");
-        let state = match p.state {
-            sysprims_proc::ProcessState::Running => "R",
-            sysprims_proc::ProcessState::Sleeping => "S",
-            sysprims_proc::ProcessState::Stopped => "T",
-            sysprims_proc::ProcessState::Zombie => "Z",
-            sysprims_proc::ProcessState::Unknown => "?",
-        };
-        println!(
-            "{:>7} {:>7} {:>6.1} {:>10} {:>8} {:<16} {}",
-            p.pid,
-            p.ppid,
-            p.cpu_percent,
-            p.memory_kb,
-            state,
-            truncate(user, 16),
-            truncate(&p.name, 32)
-        );
-    }
-}
-
-/// Truncate string to max characters (not bytes).
-///
-/// Safe for UTF-8 strings with multi-byte characters.
-fn truncate(s: &str, max_chars: usize) -> &str {
-    match s.char_indices().nth(max_chars) {
-        Some((byte_idx, _)) => &s[..byte_idx],
-        None => s, // String has fewer than max_chars characters
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2118,6 +3144,43 @@ mod tests {
         assert_eq!(args.cpu_above, Some(80.0));
     }
 
+    #[test]
+    fn kill_parses_name_glob_filter() {
+        let cli = Cli::try_parse_from(["sysprims", "kill", "--name-glob", "worker-?"]).unwrap();
+        let Command::Kill(args) = cli.command.unwrap() else {
+            panic!("expected kill command");
+        };
+        assert_eq!(args.name_glob.as_deref(), Some("worker-?"));
+    }
+
+    #[test]
+    fn kill_port_defaults_to_tcp_and_term() {
+        let cli = Cli::try_parse_from(["sysprims", "kill-port", "8080"]).unwrap();
+        let Command::KillPort(args) = cli.command.unwrap() else {
+            panic!("expected kill-port command");
+        };
+        assert_eq!(args.port, 8080);
+        assert_eq!(args.protocol, ProtocolArg::Tcp);
+        assert_eq!(args.signal, "TERM");
+        assert!(!args.yes);
+    }
+
+    #[test]
+    fn port_check_defaults_to_tcp() {
+        let cli = Cli::try_parse_from(["sysprims", "port-check", "8080"]).unwrap();
+        let Command::PortCheck(args) = cli.command.unwrap() else {
+            panic!("expected port-check command");
+        };
+        assert_eq!(args.port, 8080);
+        assert_eq!(args.protocol, ProtocolArg::Tcp);
+        assert!(!args.json);
+    }
+
+    #[test]
+    fn kill_port_rejects_out_of_range_port() {
+        assert!(Cli::try_parse_from(["sysprims", "kill-port", "70000"]).is_err());
+    }
+
     #[test]
     fn kill_list_parses_without_pid() {
         let cli = Cli::try_parse_from(["sysprims", "kill", "-l"]).unwrap();
@@ -2147,6 +3210,44 @@ mod tests {
         assert_eq!(args.topic, HelpTopic::CpuMode);
     }
 
+    #[test]
+    fn schema_parses_name_and_list() {
+        let cli = Cli::try_parse_from(["sysprims", "schema", "port-bindings"]).unwrap();
+        let Command::Schema(args) = cli.command.unwrap() else {
+            panic!("expected schema command");
+        };
+        assert_eq!(args.name.as_deref(), Some("port-bindings"));
+        assert!(!args.list);
+
+        let cli = Cli::try_parse_from(["sysprims", "schema", "--list"]).unwrap();
+        let Command::Schema(args) = cli.command.unwrap() else {
+            panic!("expected schema command");
+        };
+        assert!(args.list);
+
+        assert!(Cli::try_parse_from(["sysprims", "schema"]).is_err());
+    }
+
+    #[test]
+    fn capabilities_parses_table_flag() {
+        let cli = Cli::try_parse_from(["sysprims", "capabilities", "--table"]).unwrap();
+        let Command::Capabilities(args) = cli.command.unwrap() else {
+            panic!("expected capabilities command");
+        };
+        assert!(args.table);
+        assert!(!args.json);
+    }
+
+    #[test]
+    fn schema_rejects_unknown_name() {
+        let err = run_schema(SchemaArgs {
+            name: Some("no-such-schema".to_string()),
+            list: false,
+        })
+        .unwrap_err();
+        assert!(matches!(err, SysprimsError::InvalidArgument { .. }));
+    }
+
     #[test]
     fn help_topic_text_mentions_expected_guidance() {
         let cpu = help_topic_text(HelpTopic::CpuMode);
@@ -2216,6 +3317,40 @@ mod tests {
         assert_eq!(args.local_port, Some(8080));
     }
 
+    #[test]
+    fn format_flag_supersedes_json_and_table() {
+        let cli = Cli::try_parse_from(["sysprims", "ports", "--format", "yaml"]).unwrap();
+        let Command::Ports(args) = cli.command.unwrap() else {
+            panic!("expected ports command");
+        };
+        assert_eq!(
+            OutputFormat::resolve(args.format, args.table),
+            OutputFormat::Yaml
+        );
+
+        // Deprecated aliases still map onto the new option.
+        let cli = Cli::try_parse_from(["sysprims", "fds", "--pid", "1", "--table"]).unwrap();
+        let Command::Fds(args) = cli.command.unwrap() else {
+            panic!("expected fds command");
+        };
+        assert_eq!(
+            OutputFormat::resolve(args.format, args.table),
+            OutputFormat::Table
+        );
+        assert_eq!(OutputFormat::resolve(None, false), OutputFormat::Json);
+
+        assert!(Cli::try_parse_from(["sysprims", "pstat", "--format", "yaml", "--json"]).is_err());
+        assert!(Cli::try_parse_from([
+            "sysprims",
+            "descendants",
+            "1",
+            "--format",
+            "json",
+            "--tree"
+        ])
+        .is_err());
+    }
+
     #[test]
     fn descendants_parses_with_filters() {
         let cli = Cli::try_parse_from([
@@ -2264,6 +3399,20 @@ mod tests {
         assert!(
             Cli::try_parse_from(["sysprims", "descendants", "1234", "--tree", "--table",]).is_err()
         );
+
+        // --compact only applies to --tree
+        assert!(Cli::try_parse_from(["sysprims", "descendants", "1234", "--compact"]).is_err());
+    }
+
+    #[test]
+    fn compact_tree_node_is_pid_and_name() {
+        let mut proc = sysprims_proc::get_process(std::process::id()).unwrap();
+        proc.name = "worker".to_string();
+        proc.cpu_percent = 95.0;
+        assert_eq!(
+            format_compact_tree_node(&proc),
+            format!("{} worker", proc.pid)
+        );
     }
 
     #[test]
@@ -2298,6 +3447,16 @@ mod tests {
         assert!(args.yes);
     }
 
+    #[test]
+    fn kill_descendants_parses_include_root() {
+        let cli = Cli::try_parse_from(["sysprims", "kill-descendants", "7825", "--include-root"])
+            .unwrap();
+        let Command::KillDescendants(args) = cli.command.unwrap() else {
+            panic!("expected kill-descendants command");
+        };
+        assert!(args.include_root);
+    }
+
     #[test]
     fn pstat_parses_running_for() {
         let cli = Cli::try_parse_from([
@@ -2316,6 +3475,48 @@ mod tests {
         assert_eq!(args.running_for.as_deref(), Some("5s"));
     }
 
+    #[test]
+    fn pstat_parses_age_buckets() {
+        let cli =
+            Cli::try_parse_from(["sysprims", "pstat", "--age", "just-started,recent"]).unwrap();
+        let Command::Pstat(args) = cli.command.unwrap() else {
+            panic!("expected pstat command");
+        };
+        assert_eq!(
+            args.age,
+            Some(vec![AgeBucketArg::JustStarted, AgeBucketArg::Recent])
+        );
+    }
+
+    #[test]
+    fn pstat_parses_and_validates_fields() {
+        let cli =
+            Cli::try_parse_from(["sysprims", "pstat", "--fields", "pid,name,cpu_percent"]).unwrap();
+        let Command::Pstat(args) = cli.command.unwrap() else {
+            panic!("expected pstat command");
+        };
+        let fields = parse_pstat_fields(args.fields.as_deref().unwrap()).unwrap();
+        assert_eq!(fields, vec!["pid", "name", "cpu_percent"]);
+
+        let err = parse_pstat_fields(&["pid".to_string(), "bogus".to_string()]).unwrap_err();
+        assert!(matches!(err, SysprimsError::InvalidArgument { .. }));
+
+        assert!(Cli::try_parse_from(["sysprims", "pstat", "--fields", "pid", "--table"]).is_err());
+    }
+
+    #[test]
+    fn project_snapshot_fields_keeps_only_requested_keys() {
+        let mut snap = snapshot().unwrap();
+        snap.processes.truncate(1);
+        let value = project_snapshot_fields(&snap, &["pid".to_string(), "name".to_string()]);
+
+        assert!(value.get("schema_id").is_some());
+        let process = value["processes"][0].as_object().unwrap();
+        let mut keys: Vec<&str> = process.keys().map(String::as_str).collect();
+        keys.sort_unstable();
+        assert_eq!(keys, vec!["name", "pid"]);
+    }
+
     #[test]
     fn cpu_above_hint_base_emits_for_lifetime_human_output() {
         assert!(should_emit_cpu_above_hint_base(
@@ -2371,36 +3572,6 @@ mod tests {
         ));
     }
 
-    #[test]
-    fn format_elapsed_values() {
-        assert_eq!(format_elapsed(0), "0s");
-        assert_eq!(format_elapsed(45), "45s");
-        assert_eq!(format_elapsed(60), "1m");
-        assert_eq!(format_elapsed(90), "1m30s");
-        assert_eq!(format_elapsed(3600), "1h");
-        assert_eq!(format_elapsed(3661), "1h1m");
-        assert_eq!(format_elapsed(86400), "1d");
-        assert_eq!(format_elapsed(90000), "1d1h");
-    }
-
-    #[test]
-    fn format_memory_values() {
-        assert_eq!(format_memory(512), "512K");
-        assert_eq!(format_memory(1024), "1M");
-        assert_eq!(format_memory(75584), "73M");
-        assert_eq!(format_memory(1048576), "1.0G");
-    }
-
-    #[test]
-    fn cpu_indicator_thresholds() {
-        assert_eq!(cpu_indicator(10.0), "");
-        assert_eq!(cpu_indicator(50.0), "");
-        assert_eq!(cpu_indicator(50.1), " ⚠ WARN");
-        assert_eq!(cpu_indicator(90.0), " ⚠ WARN");
-        assert_eq!(cpu_indicator(90.1), " ★ HIGH");
-        assert_eq!(cpu_indicator(102.0), " ★ HIGH");
-    }
-
     #[test]
     fn parse_max_levels_numeric() {
         assert_eq!(parse_max_levels("1").unwrap(), 1);
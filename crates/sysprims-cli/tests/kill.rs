@@ -30,3 +30,171 @@ fn kill_signal_glob_with_multiple_matches_errors() {
         .failure()
         .stderr(predicate::str::contains("matched multiple signals"));
 }
+
+/// Run `sysprims kill-port <port> --yes`, or `None` when port introspection is
+/// unavailable (container/musl environments).
+fn run_kill_port(port: u16) -> Option<std::process::Output> {
+    let output = cargo_bin_cmd!("sysprims")
+        .args([
+            "--log-level",
+            "error",
+            "kill-port",
+            &port.to_string(),
+            "--yes",
+        ])
+        .output()
+        .expect("run sysprims");
+    if String::from_utf8_lossy(&output.stderr).contains("not supported") {
+        eprintln!("SKIP: port bindings not supported in this environment");
+        return None;
+    }
+    Some(output)
+}
+
+#[test]
+fn kill_port_reports_unowned_port() {
+    // Bind and release to find a port nobody is listening on.
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+
+    let Some(output) = run_kill_port(port) else {
+        return;
+    };
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No process found listening"));
+}
+
+#[test]
+#[cfg(unix)]
+fn kill_port_refuses_parent_process() {
+    // The test harness is the CLI's parent, which the safety checks protect.
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    let Some(output) = run_kill_port(port) else {
+        return;
+    };
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains(&format!("Skipped PID {}", std::process::id())),
+        "{stderr}"
+    );
+    assert!(stderr.contains("self/PID1/parent"), "{stderr}");
+    assert!(stderr.contains("is protected"), "{stderr}");
+}
+
+#[cfg(unix)]
+fn kill_own_sleep_children(extra: &[&str], env: Option<(&str, String)>) {
+    let mut child = std::process::Command::new("sleep")
+        .arg("30")
+        .spawn()
+        .expect("spawn sleep");
+    let ppid = std::process::id().to_string();
+
+    let mut cmd = cargo_bin_cmd!("sysprims");
+    cmd.args([
+        "--log-level",
+        "error",
+        "kill",
+        "--ppid",
+        &ppid,
+        "--name",
+        "sleep",
+    ])
+    .args(["--include-own-group", "--yes"])
+    .args(extra);
+    if let Some((key, value)) = env {
+        cmd.env(key, value);
+    }
+    let output = cmd.output().expect("run sysprims");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    let alive = child.try_wait().expect("try_wait").is_none();
+    let _ = child.kill();
+    let _ = child.wait();
+    assert!(
+        stderr.contains(&format!(
+            "Skipped protected target sleep (PID {})",
+            child.id()
+        )),
+        "{stderr}"
+    );
+    assert!(alive, "protected child was signaled");
+}
+
+#[test]
+#[cfg(unix)]
+fn kill_skips_protect_name_targets() {
+    kill_own_sleep_children(&["--protect-name", "sleep"], None);
+}
+
+#[test]
+#[cfg(unix)]
+fn kill_skips_env_protected_names() {
+    kill_own_sleep_children(&[], Some(("SYSPRIMS_PROTECTED_NAMES", "init,sleep".into())));
+}
+
+#[test]
+#[cfg(unix)]
+fn kill_warns_when_signal_is_ignored_by_default() {
+    let mut child = std::process::Command::new("sleep")
+        .arg("30")
+        .spawn()
+        .expect("spawn sleep");
+    let pid = child.id().to_string();
+    let sigchld = if cfg!(target_os = "linux") {
+        "17"
+    } else {
+        "20"
+    };
+
+    let output = cargo_bin_cmd!("sysprims")
+        .args(["--log-level", "error", "kill", &pid, "--signal", sigchld])
+        .arg("--json")
+        .output()
+        .expect("run sysprims");
+    let _ = child.kill();
+    let _ = child.wait();
+
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    let warnings = json["warnings"].as_array().expect("warnings");
+    assert!(
+        warnings.iter().any(|w| w
+            .as_str()
+            .unwrap_or_default()
+            .contains("ignored by default")),
+        "{json}"
+    );
+}
+
+#[test]
+fn kill_list_json_emits_signal_catalog() {
+    let output = cargo_bin_cmd!("sysprims")
+        .args(["--log-level", "error", "kill", "--list", "--json"])
+        .output()
+        .expect("kill --list should run");
+    assert!(output.status.success());
+
+    let catalog: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout should be json");
+    assert_eq!(
+        catalog["schema_id"],
+        sysprims_core::schema::SIGNAL_CATALOG_V1
+    );
+    let term = catalog["signals"]
+        .as_array()
+        .expect("signals should be an array")
+        .iter()
+        .find(|s| s["name"] == "SIGTERM")
+        .expect("SIGTERM should be listed");
+    assert_eq!(term["number"], 15);
+    assert_eq!(term["id"], "term");
+    if cfg!(unix) {
+        assert_eq!(term["default_disposition"], "terminate");
+    }
+}
@@ -0,0 +1,47 @@
+use std::net::TcpListener;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use serde_json::Value;
+
+#[test]
+fn port_check_reports_own_listener_as_in_use() {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+    let port = listener.local_addr().unwrap().port();
+
+    let output = cargo_bin_cmd!("sysprims")
+        .args(["--log-level", "error", "port-check", "--json"])
+        .arg(port.to_string())
+        .output()
+        .expect("port-check should run");
+    let parsed: Value = serde_json::from_slice(&output.stdout).expect("stdout should be json");
+    let bindings = parsed["bindings"].as_array().expect("bindings array");
+
+    if bindings.is_empty() {
+        // Listener enumeration is best-effort (e.g. restricted containers).
+        assert_eq!(output.status.code(), Some(0));
+        return;
+    }
+    assert_eq!(output.status.code(), Some(1));
+    assert!(bindings.iter().all(|b| b["local_port"] == port));
+    if let Some(pid) = bindings[0]["pid"].as_u64() {
+        assert_eq!(pid, u64::from(std::process::id()));
+    }
+}
+
+#[test]
+fn port_check_prints_free_for_unused_port() {
+    // Take an ephemeral port, then release it.
+    let port = TcpListener::bind("127.0.0.1:0")
+        .expect("bind")
+        .local_addr()
+        .unwrap()
+        .port();
+
+    let output = cargo_bin_cmd!("sysprims")
+        .args(["--log-level", "error", "port-check"])
+        .arg(port.to_string())
+        .output()
+        .expect("port-check should run");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "free");
+}
@@ -79,3 +79,182 @@ fn pstat_pid_json_not_found_emits_compliant_empty_snapshot() {
         "not-found output should use empty processes array"
     );
 }
+
+#[test]
+fn pstat_output_writes_json_file_alongside_table() {
+    let pid = std::process::id();
+    let path = std::env::temp_dir().join(format!("sysprims-pstat-output-{pid}.json"));
+    let _ = std::fs::remove_file(&path);
+
+    let mut cmd = cargo_bin_cmd!("sysprims");
+    cmd.arg("--log-level")
+        .arg("error")
+        .arg("pstat")
+        .arg("--table")
+        .arg("--pid")
+        .arg(pid.to_string())
+        .arg("--output")
+        .arg(&path);
+
+    let output = cmd.output().expect("pstat should run");
+    assert!(
+        output.status.success(),
+        "expected success, stderr={}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8(output.stdout).expect("stdout should be utf-8");
+    assert!(
+        serde_json::from_str::<Value>(&stdout).is_err(),
+        "stdout should be the table, not json"
+    );
+
+    let written = std::fs::read_to_string(&path).expect("output file should exist");
+    let _ = std::fs::remove_file(&path);
+    let parsed: Value = serde_json::from_str(&written).expect("output file should be valid json");
+    let processes = parsed
+        .get("processes")
+        .and_then(Value::as_array)
+        .expect("processes should be an array");
+    assert_eq!(processes.len(), 1, "expected exactly one process for --pid");
+}
+
+#[test]
+fn pstat_format_yaml_emits_snapshot_envelope() {
+    let pid = std::process::id();
+
+    let mut cmd = cargo_bin_cmd!("sysprims");
+    cmd.arg("--log-level")
+        .arg("error")
+        .arg("pstat")
+        .arg("--format")
+        .arg("yaml")
+        .arg("--pid")
+        .arg(pid.to_string());
+
+    let output = cmd.output().expect("pstat should run");
+    assert!(
+        output.status.success(),
+        "expected success, stderr={}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8(output.stdout).expect("stdout should be utf-8");
+    assert!(
+        stdout.starts_with("schema_id:"),
+        "expected yaml envelope, got: {stdout}"
+    );
+    assert!(stdout.contains(&format!("pid: {pid}")));
+}
+
+#[test]
+fn pstat_self_reports_the_cli_process() {
+    let mut cmd = cargo_bin_cmd!("sysprims");
+    cmd.args(["--log-level", "error", "pstat", "--json", "--self"]);
+
+    let output = cmd.output().expect("pstat should run");
+    assert!(
+        output.status.success(),
+        "expected success, stderr={}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let parsed: Value = serde_json::from_slice(&output.stdout).expect("stdout should be json");
+    let processes = parsed
+        .get("processes")
+        .and_then(Value::as_array)
+        .expect("processes should be an array");
+    assert_eq!(
+        processes.len(),
+        1,
+        "expected exactly one process for --self"
+    );
+    assert_eq!(
+        processes[0].get("ppid").and_then(Value::as_u64),
+        Some(u64::from(std::process::id())),
+        "sysprims --self should be a child of the test process"
+    );
+}
+
+#[test]
+fn pstat_alert_if_any_exits_3_when_threshold_is_breached() {
+    let breached = cargo_bin_cmd!("sysprims")
+        .args(["--log-level", "error", "pstat", "--json"])
+        .args(["--memory-above", "1", "--alert-if-any"])
+        .output()
+        .expect("pstat should run");
+    assert_eq!(
+        breached.status.code(),
+        Some(3),
+        "stderr={}",
+        String::from_utf8_lossy(&breached.stderr)
+    );
+    let parsed: Value = serde_json::from_slice(&breached.stdout).expect("stdout should be json");
+    assert!(
+        parsed
+            .get("processes")
+            .and_then(Value::as_array)
+            .is_some_and(|p| !p.is_empty()),
+        "offenders should still be printed"
+    );
+
+    let quiet = cargo_bin_cmd!("sysprims")
+        .args(["--log-level", "error", "pstat", "--json"])
+        .args(["--memory-above", &u64::MAX.to_string(), "--alert-if-any"])
+        .output()
+        .expect("pstat should run");
+    assert_eq!(quiet.status.code(), Some(0));
+}
+
+#[test]
+fn pstat_alert_if_any_requires_a_threshold() {
+    let output = cargo_bin_cmd!("sysprims")
+        .args(["--log-level", "error", "pstat", "--alert-if-any"])
+        .output()
+        .expect("pstat should run");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--alert-if-any requires"));
+}
+
+#[test]
+#[cfg(unix)]
+fn pstat_no_username_reports_uid_only() {
+    let output = cargo_bin_cmd!("sysprims")
+        .args([
+            "--log-level",
+            "error",
+            "pstat",
+            "--json",
+            "--self",
+            "--no-username",
+        ])
+        .output()
+        .expect("pstat should run");
+    assert!(output.status.success());
+
+    let parsed: Value = serde_json::from_slice(&output.stdout).expect("stdout should be json");
+    let process = &parsed["processes"][0];
+    assert!(process.get("user").is_none(), "user should be omitted");
+    assert!(process.get("uid").and_then(Value::as_u64).is_some());
+}
+
+#[test]
+#[cfg(unix)]
+fn pstat_mine_lists_only_own_processes() {
+    let pstat = |extra: &str| -> Value {
+        let output = cargo_bin_cmd!("sysprims")
+            .args(["--log-level", "error", "pstat", "--json", extra])
+            .output()
+            .expect("pstat should run");
+        assert!(output.status.success());
+        serde_json::from_slice(&output.stdout).expect("stdout should be json")
+    };
+
+    let own_uid = pstat("--self")["processes"][0]["uid"].as_u64();
+    assert!(own_uid.is_some());
+
+    let mine = pstat("--mine");
+    let processes = mine["processes"].as_array().expect("processes array");
+    assert!(!processes.is_empty());
+    assert!(processes.iter().all(|p| p["uid"].as_u64() == own_uid));
+}
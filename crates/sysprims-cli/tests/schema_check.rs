@@ -0,0 +1,31 @@
+use assert_cmd::cargo::cargo_bin_cmd;
+
+/// Every JSON output must match its embedded schema with strict checking on.
+#[test]
+fn json_outputs_match_embedded_schemas() {
+    let pid = std::process::id().to_string();
+    let commands: &[&[&str]] = &[
+        &["pstat", "--json"],
+        &["pstat", "--json", "--pid", &pid],
+        &["fds", "--pid", &pid, "--json"],
+        &["ports", "--json"],
+        &["ports", "--pid", &pid, "--json"],
+        &["descendants", "1", "--json"],
+        &["capabilities"],
+        &["wait", &pid, "--timeout", "10ms", "--json"],
+    ];
+
+    for args in commands {
+        let output = cargo_bin_cmd!("sysprims")
+            .env("SYSPRIMS_VALIDATE_SCHEMA", "strict")
+            .args(["--log-level", "error"])
+            .args(*args)
+            .output()
+            .expect("sysprims should run");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(
+            !stderr.contains("does not match schema"),
+            "{args:?}: {stderr}"
+        );
+    }
+}
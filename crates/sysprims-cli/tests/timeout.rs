@@ -178,3 +178,23 @@ fn timeout_preserve_status() {
     // When escalation happens, we return 128 + SIGKILL (9 -> 137).
     cmd.assert().code(137);
 }
+
+/// Test a failing command that completes in time exits 0 unless --preserve-status.
+#[test]
+#[cfg(unix)]
+fn timeout_completed_exit_code_requires_preserve_status() {
+    let mut cmd = cargo_bin_cmd!("sysprims");
+    cmd.args(["--log-level", "error", "timeout", "5s", "false"]);
+    cmd.assert().code(0);
+
+    let mut cmd = cargo_bin_cmd!("sysprims");
+    cmd.args([
+        "--log-level",
+        "error",
+        "timeout",
+        "--preserve-status",
+        "5s",
+        "false",
+    ]);
+    cmd.assert().code(1);
+}
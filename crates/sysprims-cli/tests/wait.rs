@@ -0,0 +1,106 @@
+#[cfg(unix)]
+use std::process::{Command, Stdio};
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+#[cfg(unix)]
+use serde_json::Value;
+
+#[cfg(unix)]
+#[test]
+fn wait_exits_zero_when_process_exits() {
+    let mut child = Command::new("sleep")
+        .arg("0.2")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("Failed to spawn sleep process");
+
+    let output = cargo_bin_cmd!("sysprims")
+        .args(["--log-level", "error", "wait", "--timeout", "10s"])
+        .args(["--interval", "10ms", "--json"])
+        .arg(child.id().to_string())
+        .output()
+        .expect("wait should run");
+    let _ = child.wait();
+
+    assert_eq!(
+        output.status.code(),
+        Some(0),
+        "stderr={}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let parsed: Value = serde_json::from_slice(&output.stdout).expect("stdout should be json");
+    assert_eq!(parsed["exited"], Value::Bool(true));
+    assert_eq!(parsed["timed_out"], Value::Bool(false));
+}
+
+#[cfg(unix)]
+#[test]
+fn wait_exits_124_on_timeout() {
+    let mut child = Command::new("sleep")
+        .arg("30")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("Failed to spawn sleep process");
+
+    let output = cargo_bin_cmd!("sysprims")
+        .args([
+            "--log-level",
+            "error",
+            "wait",
+            "--timeout",
+            "100ms",
+            "--json",
+        ])
+        .arg(child.id().to_string())
+        .output()
+        .expect("wait should run");
+    let _ = child.kill();
+    let _ = child.wait();
+
+    assert_eq!(output.status.code(), Some(124));
+    let parsed: Value = serde_json::from_slice(&output.stdout).expect("stdout should be json");
+    assert_eq!(parsed["exited"], Value::Bool(false));
+    assert_eq!(parsed["timed_out"], Value::Bool(true));
+}
+
+#[cfg(unix)]
+#[test]
+fn wait_exit_on_exec_returns_when_wrapper_execs() {
+    let mut child = Command::new("sh")
+        .args(["-c", "sleep 0.3; exec sleep 30"])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("Failed to spawn wrapper");
+    std::thread::sleep(std::time::Duration::from_millis(50));
+
+    let output = cargo_bin_cmd!("sysprims")
+        .args(["--log-level", "error", "wait", "--timeout", "10s"])
+        .args(["--exit-on-exec", "--json"])
+        .arg(child.id().to_string())
+        .output()
+        .expect("wait should run");
+    let _ = child.kill();
+    let _ = child.wait();
+
+    assert_eq!(output.status.code(), Some(0));
+    let parsed: Value = serde_json::from_slice(&output.stdout).expect("stdout should be json");
+    assert_eq!(parsed["exited"], Value::Bool(true));
+    assert!(parsed["warnings"][0]["message"]
+        .as_str()
+        .unwrap()
+        .contains("exec'd"));
+}
+
+#[test]
+fn wait_rejects_zero_interval() {
+    cargo_bin_cmd!("sysprims")
+        .args(["--log-level", "error", "wait", "--interval", "0s"])
+        .arg(std::process::id().to_string())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--interval must be > 0"));
+}
@@ -0,0 +1,113 @@
+//! Platform capability reporting.
+//!
+//! Several sysprims features degrade per platform (no `killpg` on Windows,
+//! no fd enumeration on Windows, best-effort port attribution on macOS).
+//! [`capabilities()`] reports what the current target supports so callers
+//! can disable features up front instead of catching `NotSupported`.
+
+use serde::Serialize;
+
+use crate::get_platform;
+use crate::schema::CAPABILITIES_V1;
+
+/// How reliably listening ports are attributed to their owning PID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PortAttribution {
+    /// The OS reports owning PIDs directly (subject to permissions).
+    Full,
+
+    /// Attribution is reconstructed per process and may miss sockets.
+    BestEffort,
+
+    /// Port enumeration is not available.
+    None,
+}
+
+/// Features available on the current target.
+///
+/// Values are computed at compile time per target and do not probe
+/// privileges; a supported feature can still fail with `PermissionDenied`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Capabilities {
+    /// Schema identifier.
+    ///
+    /// Serialized together with `schema_version`, its major version.
+    #[serde(flatten, serialize_with = "crate::schema::serialize_with_version")]
+    pub schema_id: &'static str,
+
+    /// Platform name (see [`get_platform`]).
+    pub platform: &'static str,
+
+    /// Process-group signaling (`killpg`) is available.
+    pub supports_killpg: bool,
+
+    /// Open file descriptors can be listed for a PID.
+    pub supports_fd_enumeration: bool,
+
+    /// Listening-port to PID attribution quality.
+    pub port_attribution: PortAttribution,
+
+    /// Commands can be started in a new session (`setsid`).
+    pub supports_setsid: bool,
+}
+
+/// Report the capabilities of the current platform.
+///
+/// # Examples
+///
+/// ```rust
+/// use sysprims_core::capabilities;
+///
+/// let caps = capabilities();
+/// if !caps.supports_killpg {
+///     // fall back to per-PID signaling
+/// }
+/// ```
+pub fn capabilities() -> Capabilities {
+    Capabilities {
+        schema_id: CAPABILITIES_V1,
+        platform: get_platform(),
+        supports_killpg: cfg!(unix),
+        supports_fd_enumeration: cfg!(any(target_os = "linux", target_os = "macos")),
+        port_attribution: port_attribution(),
+        supports_setsid: cfg!(unix),
+    }
+}
+
+fn port_attribution() -> PortAttribution {
+    if cfg!(any(target_os = "linux", windows)) {
+        PortAttribution::Full
+    } else if cfg!(target_os = "macos") {
+        PortAttribution::BestEffort
+    } else {
+        PortAttribution::None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capabilities_match_platform() {
+        let caps = capabilities();
+        assert_eq!(caps.schema_id, CAPABILITIES_V1);
+        assert_eq!(caps.platform, get_platform());
+
+        #[cfg(target_os = "linux")]
+        {
+            assert!(caps.supports_killpg);
+            assert!(caps.supports_fd_enumeration);
+            assert!(caps.supports_setsid);
+            assert_eq!(caps.port_attribution, PortAttribution::Full);
+        }
+
+        #[cfg(windows)]
+        {
+            assert!(!caps.supports_killpg);
+            assert!(!caps.supports_fd_enumeration);
+            assert!(!caps.supports_setsid);
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! Human-friendly duration parsing shared by the CLI, FFI, and bindings.
+//!
+//! Accepts a number with an optional unit suffix: `ms`, `s`, `m`, or `h`.
+//! A bare number is seconds. Fractional values are allowed (`1.5s`, `0.25`);
+//! negative, NaN, and infinite values are rejected.
+
+use std::time::Duration;
+
+use crate::{SysprimsError, SysprimsResult};
+
+/// Parse a duration string like `"5s"`, `"100ms"`, `"2m"`, `"1h"`, or `"5"` (seconds).
+///
+/// # Errors
+///
+/// Returns [`SysprimsError::InvalidArgument`] if the string is not a number
+/// with a known suffix, or the value is negative or not finite.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use sysprims_core::parse_duration;
+///
+/// // Replaces: hand-rolled "30s"/"500ms" parsing in every caller
+/// assert_eq!(parse_duration("500ms").unwrap(), Duration::from_millis(500));
+/// assert_eq!(parse_duration("2m").unwrap(), Duration::from_secs(120));
+/// assert_eq!(parse_duration("5").unwrap(), Duration::from_secs(5));
+/// assert!(parse_duration("-1s").is_err());
+/// ```
+pub fn parse_duration(s: &str) -> SysprimsResult<Duration> {
+    let s = s.trim();
+
+    // Try to parse as plain number (seconds)
+    if let Ok(secs) = s.parse::<f64>() {
+        return secs_to_duration(secs, s);
+    }
+
+    // Try to parse with suffix
+    let (num_str, multiplier) = if let Some(n) = s.strip_suffix("ms") {
+        (n, 0.001)
+    } else if let Some(n) = s.strip_suffix('s') {
+        (n, 1.0)
+    } else if let Some(n) = s.strip_suffix('m') {
+        (n, 60.0)
+    } else if let Some(n) = s.strip_suffix('h') {
+        (n, 3600.0)
+    } else {
+        return Err(SysprimsError::invalid_argument(format!(
+            "invalid duration '{}': expected number or number with suffix (ms, s, m, h)",
+            s
+        )));
+    };
+
+    let num: f64 = num_str.trim().parse().map_err(|_| {
+        SysprimsError::invalid_argument(format!("invalid duration '{}': not a valid number", s))
+    })?;
+
+    secs_to_duration(num * multiplier, s)
+}
+
+fn secs_to_duration(secs: f64, input: &str) -> SysprimsResult<Duration> {
+    if secs < 0.0 {
+        return Err(SysprimsError::invalid_argument(
+            "duration cannot be negative",
+        ));
+    }
+    Duration::try_from_secs_f64(secs).map_err(|_| {
+        SysprimsError::invalid_argument(format!("invalid duration '{}': out of range", input))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_suffixes_and_bare_seconds() {
+        assert_eq!(parse_duration("100ms").unwrap(), Duration::from_millis(100));
+        assert_eq!(parse_duration("5s").unwrap(), Duration::from_secs(5));
+        assert_eq!(parse_duration("2m").unwrap(), Duration::from_secs(120));
+        assert_eq!(parse_duration("1h").unwrap(), Duration::from_secs(3600));
+        assert_eq!(parse_duration(" 5 ").unwrap(), Duration::from_secs(5));
+        assert_eq!(parse_duration("1.5s").unwrap(), Duration::from_millis(1500));
+        assert_eq!(parse_duration("0").unwrap(), Duration::ZERO);
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        for input in ["", "-1", "-5s", "abc", "5d", "ms", "inf", "NaN", "1e400s"] {
+            assert!(
+                matches!(
+                    parse_duration(input),
+                    Err(SysprimsError::InvalidArgument { .. })
+                ),
+                "{input:?} should be rejected"
+            );
+        }
+    }
+}
@@ -12,7 +12,10 @@
 //!
 //! See ADR-0008 for the full error handling strategy.
 
+use std::ffi::OsStr;
 use std::io;
+
+use serde::ser::{Serialize, SerializeMap, SerializeStruct, Serializer};
 use thiserror::Error;
 
 // ============================================================================
@@ -30,6 +33,7 @@ use thiserror::Error;
 /// | Variant | FFI Code |
 /// |---------|----------|
 /// | `InvalidArgument` | `SYSPRIMS_ERR_INVALID_ARGUMENT` (1) |
+/// | `IdentityMismatch` | `SYSPRIMS_ERR_INVALID_ARGUMENT` (1) |
 /// | `SpawnFailed` | `SYSPRIMS_ERR_SPAWN_FAILED` (2) |
 /// | `Timeout` | `SYSPRIMS_ERR_TIMEOUT` (3) |
 /// | `PermissionDenied` | `SYSPRIMS_ERR_PERMISSION_DENIED` (4) |
@@ -49,6 +53,19 @@ pub enum SysprimsError {
         message: String,
     },
 
+    /// Target PID no longer matches the process the caller meant.
+    ///
+    /// Returned before signaling when a required identity (start time, exe
+    /// path) does not match, i.e. the PID was likely reused. Shares the
+    /// `InvalidArgument` FFI code; [`kind`](Self::kind) tells them apart.
+    #[error("PID identity mismatch: {reason}")]
+    IdentityMismatch {
+        /// The PID whose identity did not match.
+        pid: u32,
+        /// Which attribute differed, with expected and actual values.
+        reason: String,
+    },
+
     /// Failed to spawn a child process.
     ///
     /// Wraps the underlying IO error from process creation.
@@ -74,6 +91,11 @@ pub enum SysprimsError {
         pid: u32,
         /// The operation that was denied (e.g., "terminate", "signal").
         operation: String,
+        /// Whether retrying as root/Administrator could succeed.
+        ///
+        /// `false` when the caller is already elevated (e.g. SIP-protected
+        /// processes on macOS) or the denial is not privilege-based.
+        needs_elevation: bool,
     },
 
     /// Target process not found.
@@ -87,11 +109,15 @@ pub enum SysprimsError {
 
     /// Command not found.
     ///
-    /// The specified command could not be found in PATH.
-    #[error("Command '{command}' not found")]
+    /// The specified command could not be found. When the command has no
+    /// path separator, `lookup` notes that PATH was searched; the PATH value
+    /// itself is only included when `SYSPRIMS_DEBUG` is set.
+    #[error("Command '{command}' not found{}", lookup_suffix(.lookup))]
     NotFoundCommand {
         /// The command that was not found.
         command: String,
+        /// How the command was resolved (e.g. "searched PATH"), if known.
+        lookup: Option<String>,
     },
 
     /// Permission denied for command execution.
@@ -146,12 +172,24 @@ pub enum SysprimsError {
 }
 
 impl SysprimsError {
+    /// Whether this is a permission error that root/Administrator could resolve.
+    pub fn needs_elevation(&self) -> bool {
+        matches!(
+            self,
+            SysprimsError::PermissionDenied {
+                needs_elevation: true,
+                ..
+            }
+        )
+    }
+
     /// Get the FFI error code for this error.
     ///
     /// Maps to `SysprimsErrorCode` enum in C-ABI.
     pub fn error_code(&self) -> i32 {
         match self {
             SysprimsError::InvalidArgument { .. } => 1,
+            SysprimsError::IdentityMismatch { .. } => 1,
             SysprimsError::SpawnFailed { .. } => 2,
             SysprimsError::Timeout => 3,
             SysprimsError::PermissionDenied { .. } => 4,
@@ -164,6 +202,97 @@ impl SysprimsError {
             SysprimsError::Internal { .. } => 99,
         }
     }
+
+    /// Stable snake_case name of the variant (e.g. `"permission_denied"`).
+    ///
+    /// Finer-grained than [`error_code`](Self::error_code): `NotFound` and
+    /// `NotFoundCommand` share code 5 but have distinct kinds.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            SysprimsError::InvalidArgument { .. } => "invalid_argument",
+            SysprimsError::IdentityMismatch { .. } => "identity_mismatch",
+            SysprimsError::SpawnFailed { .. } => "spawn_failed",
+            SysprimsError::Timeout => "timeout",
+            SysprimsError::PermissionDenied { .. } => "permission_denied",
+            SysprimsError::PermissionDeniedCommand { .. } => "permission_denied_command",
+            SysprimsError::NotFound { .. } => "not_found",
+            SysprimsError::NotFoundCommand { .. } => "not_found_command",
+            SysprimsError::NotSupported { .. } => "not_supported",
+            SysprimsError::GroupCreationFailed { .. } => "group_creation_failed",
+            SysprimsError::System { .. } => "system",
+            SysprimsError::Internal { .. } => "internal",
+        }
+    }
+}
+
+// ============================================================================
+// Serialization
+// ============================================================================
+
+/// Serializes as `{"code", "kind", "message", "context"}`.
+///
+/// `code` is [`SysprimsError::error_code`], `message` the `Display` text, and
+/// `context` an object with the variant's typed fields (e.g. `pid`,
+/// `operation`, `needs_elevation`); variants whose only field is the message
+/// have an empty context.
+impl Serialize for SysprimsError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("SysprimsError", 4)?;
+        state.serialize_field("code", &self.error_code())?;
+        state.serialize_field("kind", self.kind())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.serialize_field("context", &ErrorContext(self))?;
+        state.end()
+    }
+}
+
+/// The typed fields of one error variant, serialized as a JSON object.
+struct ErrorContext<'a>(&'a SysprimsError);
+
+impl Serialize for ErrorContext<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        match self.0 {
+            SysprimsError::SpawnFailed { source } => {
+                if let Some(os_error) = source.raw_os_error() {
+                    map.serialize_entry("os_error", &os_error)?;
+                }
+            }
+            SysprimsError::PermissionDenied {
+                pid,
+                operation,
+                needs_elevation,
+            } => {
+                map.serialize_entry("pid", pid)?;
+                map.serialize_entry("operation", operation)?;
+                map.serialize_entry("needs_elevation", needs_elevation)?;
+            }
+            SysprimsError::IdentityMismatch { pid, reason } => {
+                map.serialize_entry("pid", pid)?;
+                map.serialize_entry("reason", reason)?;
+            }
+            SysprimsError::NotFound { pid } => map.serialize_entry("pid", pid)?,
+            SysprimsError::NotFoundCommand { command, lookup } => {
+                map.serialize_entry("command", command)?;
+                if let Some(lookup) = lookup {
+                    map.serialize_entry("lookup", lookup)?;
+                }
+            }
+            SysprimsError::PermissionDeniedCommand { command } => {
+                map.serialize_entry("command", command)?;
+            }
+            SysprimsError::NotSupported { feature, platform } => {
+                map.serialize_entry("feature", feature)?;
+                map.serialize_entry("platform", platform)?;
+            }
+            SysprimsError::System { errno, .. } => map.serialize_entry("errno", errno)?,
+            SysprimsError::InvalidArgument { .. }
+            | SysprimsError::Timeout
+            | SysprimsError::GroupCreationFailed { .. }
+            | SysprimsError::Internal { .. } => {}
+        }
+        map.end()
+    }
 }
 
 // ============================================================================
@@ -178,16 +307,37 @@ impl SysprimsError {
         }
     }
 
+    /// Create an `IdentityMismatch` error.
+    pub fn identity_mismatch(pid: u32, reason: impl Into<String>) -> Self {
+        SysprimsError::IdentityMismatch {
+            pid,
+            reason: reason.into(),
+        }
+    }
+
     /// Create a `SpawnFailed` error from an IO error.
     pub fn spawn_failed_io(source: io::Error) -> Self {
         SysprimsError::SpawnFailed { source }
     }
 
-    /// Create a `PermissionDenied` error.
+    /// Create a `PermissionDenied` error that elevation could resolve.
     pub fn permission_denied(pid: u32, operation: impl Into<String>) -> Self {
+        Self::permission_denied_with_elevation(pid, operation, true)
+    }
+
+    /// Create a `PermissionDenied` error with an explicit `needs_elevation`.
+    ///
+    /// Platform code passes `false` when the caller is already privileged or
+    /// the denial would persist as root.
+    pub fn permission_denied_with_elevation(
+        pid: u32,
+        operation: impl Into<String>,
+        needs_elevation: bool,
+    ) -> Self {
         SysprimsError::PermissionDenied {
             pid,
             operation: operation.into(),
+            needs_elevation,
         }
     }
 
@@ -197,10 +347,24 @@ impl SysprimsError {
     }
 
     /// Create a `NotFoundCommand` error.
+    ///
+    /// Bare command names (no path separator) are resolved through PATH, so
+    /// the error notes that PATH was searched. Set `SYSPRIMS_DEBUG=1` to also
+    /// include the PATH value; it is omitted by default to keep environment
+    /// details out of error messages.
     pub fn not_found_command(command: impl Into<String>) -> Self {
-        SysprimsError::NotFoundCommand {
-            command: command.into(),
-        }
+        Self::not_found_command_in(command, std::env::var_os("PATH").as_deref())
+    }
+
+    /// Create a `NotFoundCommand` error for a child spawned with `path` as
+    /// its PATH (`None` when the child's environment has no PATH).
+    ///
+    /// Use this instead of [`not_found_command`](Self::not_found_command) when
+    /// the child's environment was cleared or overridden.
+    pub fn not_found_command_in(command: impl Into<String>, path: Option<&OsStr>) -> Self {
+        let command = command.into();
+        let lookup = path_lookup_note(&command, path);
+        SysprimsError::NotFoundCommand { command, lookup }
     }
 
     /// Create a `PermissionDeniedCommand` error.
@@ -218,6 +382,30 @@ impl SysprimsError {
         }
     }
 
+    /// Classify an `io::Error` from spawning `command`.
+    ///
+    /// `NotFound` (ENOENT) maps to `NotFoundCommand`, `PermissionDenied`
+    /// (EACCES) to `PermissionDeniedCommand`, anything else to `SpawnFailed`.
+    /// All spawning APIs use this so callers see the same error for the same
+    /// failure regardless of entry point.
+    pub fn from_spawn_error(command: impl Into<String>, err: &io::Error) -> Self {
+        Self::from_spawn_error_in(command, err, std::env::var_os("PATH").as_deref())
+    }
+
+    /// Like [`from_spawn_error`](Self::from_spawn_error), for a child spawned
+    /// with `path` as its PATH. See [`not_found_command_in`](Self::not_found_command_in).
+    pub fn from_spawn_error_in(
+        command: impl Into<String>,
+        err: &io::Error,
+        path: Option<&OsStr>,
+    ) -> Self {
+        match err.kind() {
+            io::ErrorKind::NotFound => Self::not_found_command_in(command, path),
+            io::ErrorKind::PermissionDenied => Self::permission_denied_command(command),
+            _ => Self::spawn_failed(command, err.to_string()),
+        }
+    }
+
     /// Create a `NotSupported` error.
     pub fn not_supported(feature: impl Into<String>, platform: impl Into<String>) -> Self {
         SysprimsError::NotSupported {
@@ -249,6 +437,41 @@ impl SysprimsError {
     }
 }
 
+// ============================================================================
+// Command Lookup Notes
+// ============================================================================
+
+/// Whether `command` is a bare name that the OS resolves through PATH.
+fn is_path_lookup(command: &str) -> bool {
+    let has_separator = if cfg!(windows) {
+        command.contains(['/', '\\'])
+    } else {
+        command.contains('/')
+    };
+    !has_separator
+}
+
+/// Lookup note for a missing command searched in `path`, honoring
+/// `SYSPRIMS_DEBUG`.
+fn path_lookup_note(command: &str, path: Option<&OsStr>) -> Option<String> {
+    if !is_path_lookup(command) {
+        return None;
+    }
+    let debug = matches!(std::env::var("SYSPRIMS_DEBUG"), Ok(v) if !v.is_empty() && v != "0");
+    match path {
+        Some(path) if debug => Some(format!("searched PATH={}", path.to_string_lossy())),
+        None => Some("PATH is not set".to_string()),
+        _ => Some("searched PATH".to_string()),
+    }
+}
+
+fn lookup_suffix(lookup: &Option<String>) -> String {
+    match lookup {
+        Some(note) => format!(" ({})", note),
+        None => String::new(),
+    }
+}
+
 // ============================================================================
 // Conversions
 // ============================================================================
@@ -323,6 +546,91 @@ mod tests {
         assert_eq!(SysprimsError::internal("").error_code(), 99);
     }
 
+    #[test]
+    fn test_error_kinds() {
+        assert_eq!(SysprimsError::Timeout.kind(), "timeout");
+        assert_eq!(SysprimsError::not_found(1).kind(), "not_found");
+        assert_eq!(
+            SysprimsError::not_found_command("x").kind(),
+            "not_found_command"
+        );
+        assert_eq!(
+            SysprimsError::permission_denied_command("x").kind(),
+            "permission_denied_command"
+        );
+    }
+
+    #[test]
+    fn test_serialize_includes_variant_context() {
+        let err = SysprimsError::not_found_command_in("make", None);
+        assert_eq!(
+            serde_json::to_value(&err).unwrap(),
+            serde_json::json!({
+                "code": 5,
+                "kind": "not_found_command",
+                "message": "Command 'make' not found (PATH is not set)",
+                "context": {"command": "make", "lookup": "PATH is not set"},
+            })
+        );
+
+        // No lookup note: the key is omitted rather than null.
+        let err = SysprimsError::not_found_command("./build/make");
+        let value = serde_json::to_value(&err).unwrap();
+        assert_eq!(
+            value["context"],
+            serde_json::json!({"command": "./build/make"})
+        );
+
+        let err = SysprimsError::system("kill failed", 3);
+        let value = serde_json::to_value(&err).unwrap();
+        assert_eq!(value["code"], 8);
+        assert_eq!(value["kind"], "system");
+        assert_eq!(value["context"], serde_json::json!({"errno": 3}));
+    }
+
+    #[test]
+    fn test_from_spawn_error_classifies_kind() {
+        let not_found = io::Error::from(io::ErrorKind::NotFound);
+        assert!(matches!(
+            SysprimsError::from_spawn_error("x", &not_found),
+            SysprimsError::NotFoundCommand { .. }
+        ));
+
+        let denied = io::Error::from(io::ErrorKind::PermissionDenied);
+        assert!(matches!(
+            SysprimsError::from_spawn_error("x", &denied),
+            SysprimsError::PermissionDeniedCommand { .. }
+        ));
+
+        let other = io::Error::other("boom");
+        assert!(matches!(
+            SysprimsError::from_spawn_error("x", &other),
+            SysprimsError::SpawnFailed { .. }
+        ));
+    }
+
+    #[test]
+    fn test_not_found_command_notes_path_lookup() {
+        let err = SysprimsError::not_found_command("make");
+        let msg = err.to_string();
+        assert!(msg.starts_with("Command 'make' not found ("), "{msg}");
+        assert!(msg.contains("PATH"), "{msg}");
+
+        let err = SysprimsError::not_found_command("./build/make");
+        assert_eq!(err.to_string(), "Command './build/make' not found");
+        assert!(matches!(
+            err,
+            SysprimsError::NotFoundCommand { lookup: None, .. }
+        ));
+
+        // The note describes the child's PATH, not ours.
+        let err = SysprimsError::not_found_command_in("make", None);
+        assert_eq!(
+            err.to_string(),
+            "Command 'make' not found (PATH is not set)"
+        );
+    }
+
     #[test]
     fn test_spawn_failed_source() {
         let io_err = io::Error::new(io::ErrorKind::NotFound, "command not found");
@@ -337,6 +645,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_needs_elevation() {
+        assert!(SysprimsError::permission_denied(1, "signal").needs_elevation());
+        assert!(
+            !SysprimsError::permission_denied_with_elevation(1, "signal", false).needs_elevation()
+        );
+        assert!(!SysprimsError::not_found(1).needs_elevation());
+    }
+
     #[test]
     fn test_pid_is_u32() {
         // Verify PIDs are unsigned (ABI alignment per ADR-0008)
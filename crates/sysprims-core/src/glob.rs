@@ -0,0 +1,104 @@
+//! Minimal glob matching shared by signal-name and process-name filters.
+//!
+//! Supports `*` (any sequence, including empty) and `?` (exactly one byte).
+//! All other characters match literally; there are no character classes or
+//! escapes. Matching is byte-wise and case-sensitive; callers that want
+//! case-insensitive semantics should normalize both sides first.
+
+use crate::{SysprimsError, SysprimsResult};
+
+/// Return `true` if `text` matches the glob `pattern`.
+///
+/// # Examples
+///
+/// ```rust
+/// use sysprims_core::glob::glob_match;
+///
+/// assert!(glob_match("worker-*", "worker-1"));
+/// assert!(glob_match("worker-?", "worker-1"));
+/// assert!(!glob_match("worker-?", "worker-backup"));
+/// ```
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.as_bytes();
+    let text = text.as_bytes();
+    let mut p_idx = 0;
+    let mut t_idx = 0;
+    let mut star_idx: Option<usize> = None;
+    let mut match_idx = 0;
+
+    while t_idx < text.len() {
+        if p_idx < pattern.len() && (pattern[p_idx] == b'?' || pattern[p_idx] == text[t_idx]) {
+            p_idx += 1;
+            t_idx += 1;
+            continue;
+        }
+        if p_idx < pattern.len() && pattern[p_idx] == b'*' {
+            star_idx = Some(p_idx);
+            match_idx = t_idx;
+            p_idx += 1;
+            continue;
+        }
+        if let Some(star) = star_idx {
+            p_idx = star + 1;
+            match_idx += 1;
+            t_idx = match_idx;
+            continue;
+        }
+        return false;
+    }
+
+    while p_idx < pattern.len() && pattern[p_idx] == b'*' {
+        p_idx += 1;
+    }
+
+    p_idx == pattern.len()
+}
+
+/// Validate a glob pattern supplied by a caller.
+///
+/// Rejects empty (or whitespace-only) patterns and patterns containing NUL.
+pub fn validate_glob(pattern: &str) -> SysprimsResult<()> {
+    if pattern.trim().is_empty() {
+        return Err(SysprimsError::invalid_argument(
+            "glob pattern must not be empty",
+        ));
+    }
+    if pattern.contains('\0') {
+        return Err(SysprimsError::invalid_argument(
+            "glob pattern must not contain NUL",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn star_matches_any_sequence() {
+        assert!(glob_match("*", ""));
+        assert!(glob_match("worker-*", "worker-"));
+        assert!(glob_match("worker-*", "worker-backup"));
+        assert!(glob_match("*-backup", "worker-backup"));
+        assert!(!glob_match("worker-*", "builder-1"));
+    }
+
+    #[test]
+    fn question_matches_single_byte() {
+        assert!(glob_match("w?rker", "worker"));
+        assert!(!glob_match("worker-?", "worker-12"));
+    }
+
+    #[test]
+    fn matching_is_case_sensitive() {
+        assert!(!glob_match("Worker*", "worker-1"));
+    }
+
+    #[test]
+    fn validate_glob_rejects_empty() {
+        assert!(validate_glob("").is_err());
+        assert!(validate_glob("  ").is_err());
+        assert!(validate_glob("worker-*").is_ok());
+    }
+}
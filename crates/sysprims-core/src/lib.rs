@@ -5,6 +5,13 @@
 //! - Schema ID constants for JSON output contracts
 //! - Re-exports from rsfulmen for signal and exit code constants
 //! - Platform detection utilities
+//! - Shared glob matching for name filters ([`glob`])
+//! - Per-platform feature reporting ([`capabilities()`])
+//! - Human-friendly duration parsing ([`parse_duration`])
+//! - The calling process's PID ([`self_pid`])
+//! - Structured warnings for degraded results ([`Warning`])
+//! - Opt-in output checks against the embedded schemas (`schema_check`,
+//!   `validate_output` feature)
 //!
 //! ## Error Handling
 //!
@@ -23,12 +30,24 @@
 
 use std::env::consts::OS;
 
+pub mod capabilities;
+pub mod duration;
 pub mod error;
+pub mod glob;
 pub mod schema;
+#[cfg(feature = "validate_output")]
+pub mod schema_check;
+#[doc(hidden)]
+pub mod sys;
+pub mod warning;
 
 // Re-export canonical error type at crate root
 pub use error::{SysprimsError, SysprimsResult};
 
+pub use capabilities::{capabilities, Capabilities, PortAttribution};
+pub use duration::parse_duration;
+pub use warning::{Warning, WarningCode};
+
 // Re-export rsfulmen foundry types for ecosystem alignment
 // Using module re-exports (not glob) to keep origin obvious and avoid pollution
 pub use rsfulmen::foundry::exit_codes;
@@ -74,6 +93,19 @@ pub const fn is_windows() -> bool {
     false
 }
 
+// ============================================================================
+// Process Identity
+// ============================================================================
+
+/// Get the PID of the calling process.
+///
+/// Single source of truth for "self" across sysprims crates, so self-targeting
+/// APIs and safety checks agree on the same value.
+#[inline]
+pub fn self_pid() -> u32 {
+    std::process::id()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -107,6 +139,11 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_self_pid_is_nonzero() {
+        assert_ne!(self_pid(), 0);
+    }
+
     #[test]
     fn test_rsfulmen_reexports() {
         // Verify rsfulmen constants are accessible through our re-exports
@@ -51,6 +51,8 @@
 //! };
 //! ```
 
+use serde::ser::{SerializeMap, Serializer};
+
 /// Schema ID for timeout result JSON output (v1.0.0).
 ///
 /// This schema defines the structure of `sysprims timeout --json` output.
@@ -59,6 +61,12 @@
 pub const TIMEOUT_RESULT_V1: &str =
     "https://schemas.3leaps.dev/sysprims/timeout/v1.0.0/timeout-result.schema.json";
 
+/// Schema ID for timeout-run config JSON input (v1.0.0).
+///
+/// Schema location: `schemas/timeout/v1.0.0/timeout-run-config.schema.json`
+pub const TIMEOUT_RUN_CONFIG_V1: &str =
+    "https://schemas.3leaps.dev/sysprims/timeout/v1.0.0/timeout-run-config.schema.json";
+
 /// Schema ID for process info JSON output (v1.1.0).
 ///
 /// This schema defines the structure of `sysprims pstat --json` output.
@@ -76,22 +84,22 @@ pub const PROCESS_INFO_V1: &str =
 pub const PROCESS_INFO_SAMPLED_V1: &str =
     "https://schemas.3leaps.dev/sysprims/process/v1.1.0/process-info-sampled.schema.json";
 
-/// Schema ID for process filter input (v1.0.0).
+/// Schema ID for process filter input (v1.1.0).
 ///
 /// This schema defines the structure of filter JSON accepted by
 /// `sysprims_proc_list()` FFI function.
 ///
-/// Schema location: `schemas/process/v1.0.0/process-filter.schema.json`
+/// Schema location: `schemas/process/v1.1.0/process-filter.schema.json`
 pub const PROC_FILTER_V1: &str =
-    "https://schemas.3leaps.dev/sysprims/process/v1.0.0/process-filter.schema.json";
+    "https://schemas.3leaps.dev/sysprims/process/v1.1.0/process-filter.schema.json";
 
-/// Schema ID for port binding snapshot output (v1.0.0).
+/// Schema ID for port binding snapshot output (v1.1.0).
 ///
 /// This schema defines the structure of `listening_ports()` output.
 ///
-/// Schema location: `schemas/process/v1.0.0/port-bindings.schema.json`
+/// Schema location: `schemas/process/v1.1.0/port-bindings.schema.json`
 pub const PORT_BINDINGS_V1: &str =
-    "https://schemas.3leaps.dev/sysprims/process/v1.0.0/port-bindings.schema.json";
+    "https://schemas.3leaps.dev/sysprims/process/v1.1.0/port-bindings.schema.json";
 
 /// Schema ID for port filter input (v1.0.0).
 ///
@@ -102,11 +110,17 @@ pub const PORT_BINDINGS_V1: &str =
 pub const PORT_FILTER_V1: &str =
     "https://schemas.3leaps.dev/sysprims/process/v1.0.0/port-filter.schema.json";
 
-/// Schema ID for file descriptor snapshot output (v1.0.0).
+/// Schema ID for file descriptor snapshot output (v1.1.0).
 ///
-/// Schema location: `schemas/process/v1.0.0/fd-snapshot.schema.json`
+/// Schema location: `schemas/process/v1.1.0/fd-snapshot.schema.json`
 pub const FD_SNAPSHOT_V1: &str =
-    "https://schemas.3leaps.dev/sysprims/process/v1.0.0/fd-snapshot.schema.json";
+    "https://schemas.3leaps.dev/sysprims/process/v1.1.0/fd-snapshot.schema.json";
+
+/// Schema ID for file descriptor growth report output (v1.1.0).
+///
+/// Schema location: `schemas/process/v1.1.0/fd-growth-report.schema.json`
+pub const FD_GROWTH_REPORT_V1: &str =
+    "https://schemas.3leaps.dev/sysprims/process/v1.1.0/fd-growth-report.schema.json";
 
 /// Schema ID for file descriptor filter input (v1.0.0).
 ///
@@ -114,62 +128,342 @@ pub const FD_SNAPSHOT_V1: &str =
 pub const FD_FILTER_V1: &str =
     "https://schemas.3leaps.dev/sysprims/process/v1.0.0/fd-filter.schema.json";
 
-/// Schema ID for wait-pid result JSON output (v1.0.0).
+/// Schema ID for wait-pid result JSON output (v1.1.0).
 ///
 /// This schema defines the structure of `wait_pid()` output.
 ///
-/// Schema location: `schemas/process/v1.0.0/wait-pid-result.schema.json`
+/// Schema location: `schemas/process/v1.1.0/wait-pid-result.schema.json`
 pub const WAIT_PID_RESULT_V1: &str =
-    "https://schemas.3leaps.dev/sysprims/process/v1.0.0/wait-pid-result.schema.json";
+    "https://schemas.3leaps.dev/sysprims/process/v1.1.0/wait-pid-result.schema.json";
 
-/// Schema ID for batch kill result JSON output (v1.0.0).
+/// Schema ID for batch kill result JSON output (v1.1.0).
 ///
 /// This schema defines the structure of `sysprims kill --json` output.
 ///
-/// Schema location: `schemas/signal/v1.0.0/batch-kill-result.schema.json`
+/// Schema location: `schemas/signal/v1.1.0/batch-kill-result.schema.json`
 pub const BATCH_KILL_RESULT_V1: &str =
-    "https://schemas.3leaps.dev/sysprims/signal/v1.0.0/batch-kill-result.schema.json";
+    "https://schemas.3leaps.dev/sysprims/signal/v1.1.0/batch-kill-result.schema.json";
+
+/// Schema ID for kill target preview output (v1.1.0).
+///
+/// Schema location: `schemas/signal/v1.1.0/kill-targets.schema.json`
+pub const KILL_TARGETS_V1: &str =
+    "https://schemas.3leaps.dev/sysprims/signal/v1.1.0/kill-targets.schema.json";
+
+/// Schema ID for signal catalog JSON output (v1.0.0).
+///
+/// This schema defines the structure of `sysprims kill --list --json` output.
+///
+/// Schema location: `schemas/signal/v1.0.0/signal-catalog.schema.json`
+pub const SIGNAL_CATALOG_V1: &str =
+    "https://schemas.3leaps.dev/sysprims/signal/v1.0.0/signal-catalog.schema.json";
 
-/// Schema ID for terminate-tree config JSON input (v1.0.0).
+/// Schema ID for terminate-tree config JSON input (v1.1.0).
 ///
-/// Schema location: `schemas/process/v1.0.0/terminate-tree-config.schema.json`
+/// Schema location: `schemas/process/v1.1.0/terminate-tree-config.schema.json`
 pub const TERMINATE_TREE_CONFIG_V1: &str =
-    "https://schemas.3leaps.dev/sysprims/process/v1.0.0/terminate-tree-config.schema.json";
+    "https://schemas.3leaps.dev/sysprims/process/v1.1.0/terminate-tree-config.schema.json";
 
-/// Schema ID for terminate-tree result JSON output (v1.0.0).
+/// Schema ID for terminate-tree result JSON output (v1.1.0).
 ///
-/// Schema location: `schemas/process/v1.0.0/terminate-tree-result.schema.json`
+/// Schema location: `schemas/process/v1.1.0/terminate-tree-result.schema.json`
 pub const TERMINATE_TREE_RESULT_V1: &str =
-    "https://schemas.3leaps.dev/sysprims/process/v1.0.0/terminate-tree-result.schema.json";
+    "https://schemas.3leaps.dev/sysprims/process/v1.1.0/terminate-tree-result.schema.json";
 
 /// Schema ID for spawn-in-group config JSON input (v1.0.0).
 ///
-/// Schema location: `schemas/process/v1.0.0/spawn-in-group-config.schema.json`
+/// Schema location: `schemas/process/v1.1.0/spawn-in-group-config.schema.json`
 pub const SPAWN_IN_GROUP_CONFIG_V1: &str =
-    "https://schemas.3leaps.dev/sysprims/process/v1.0.0/spawn-in-group-config.schema.json";
+    "https://schemas.3leaps.dev/sysprims/process/v1.1.0/spawn-in-group-config.schema.json";
 
-/// Schema ID for spawn-in-group result JSON output (v1.0.0).
+/// Schema ID for spawn-in-group result JSON output (v1.1.0).
 ///
-/// Schema location: `schemas/process/v1.0.0/spawn-in-group-result.schema.json`
+/// Schema location: `schemas/process/v1.1.0/spawn-in-group-result.schema.json`
 pub const SPAWN_IN_GROUP_RESULT_V1: &str =
-    "https://schemas.3leaps.dev/sysprims/process/v1.0.0/spawn-in-group-result.schema.json";
+    "https://schemas.3leaps.dev/sysprims/process/v1.1.0/spawn-in-group-result.schema.json";
+
+/// Schema ID for wait-group result JSON output (v1.0.0).
+///
+/// Schema location: `schemas/process/v1.0.0/wait-group-result.schema.json`
+pub const WAIT_GROUP_RESULT_V1: &str =
+    "https://schemas.3leaps.dev/sysprims/process/v1.0.0/wait-group-result.schema.json";
 
-/// Schema ID for descendants result JSON output (v1.0.0).
+/// Schema ID for descendants result JSON output (v1.1.0).
 ///
 /// This schema defines the structure of `sysprims descendants --json` output.
 ///
-/// Schema location: `schemas/process/v1.0.0/descendants-result.schema.json`
+/// Schema location: `schemas/process/v1.1.0/descendants-result.schema.json`
 pub const DESCENDANTS_RESULT_V1: &str =
-    "https://schemas.3leaps.dev/sysprims/process/v1.0.0/descendants-result.schema.json";
+    "https://schemas.3leaps.dev/sysprims/process/v1.1.0/descendants-result.schema.json";
 
-/// Schema ID for descendants result JSON output with sampled (monitor-style) CPU (v1.1.0).
+/// Schema ID for descendants result JSON output with sampled (monitor-style) CPU (v1.2.0).
 ///
 /// This schema matches the descendants result shape but relaxes `cpu_percent` to
 /// allow values > 100 when a process uses multiple cores.
 ///
-/// Schema location: `schemas/process/v1.1.0/descendants-result-sampled.schema.json`
+/// Schema location: `schemas/process/v1.2.0/descendants-result-sampled.schema.json`
 pub const DESCENDANTS_RESULT_SAMPLED_V1: &str =
-    "https://schemas.3leaps.dev/sysprims/process/v1.1.0/descendants-result-sampled.schema.json";
+    "https://schemas.3leaps.dev/sysprims/process/v1.2.0/descendants-result-sampled.schema.json";
+
+/// Schema ID for nested descendants tree JSON output (v1.0.0).
+///
+/// This schema defines the structure of `sysprims descendants --json-tree` output.
+///
+/// Schema location: `schemas/process/v1.0.0/descendants-tree.schema.json`
+pub const DESCENDANTS_TREE_V1: &str =
+    "https://schemas.3leaps.dev/sysprims/process/v1.0.0/descendants-tree.schema.json";
+
+/// Schema ID for platform capabilities JSON output (v1.1.0).
+///
+/// This schema defines the structure of `capabilities()` output.
+///
+/// Schema location: `schemas/core/v1.1.0/capabilities.schema.json`
+pub const CAPABILITIES_V1: &str =
+    "https://schemas.3leaps.dev/sysprims/core/v1.1.0/capabilities.schema.json";
+
+/// Major version encoded in a schema ID (`.../v1.1.0/...` yields `1`).
+///
+/// Result envelopes carry this as `schema_version` next to `schema_id` (see
+/// [`serialize_with_version`]), so consumers can gate on a number
+/// (`schema_version >= 1`) instead of comparing URLs. Returns `0` when the ID
+/// has no `/v<major>` segment.
+///
+/// # Examples
+///
+/// ```rust
+/// use sysprims_core::schema::{schema_version, PROCESS_INFO_V1};
+///
+/// assert_eq!(schema_version(PROCESS_INFO_V1), 1);
+/// ```
+pub const fn schema_version(schema_id: &str) -> u32 {
+    let bytes = schema_id.as_bytes();
+    let mut i = 0;
+    while i + 2 < bytes.len() {
+        if bytes[i] == b'/' && bytes[i + 1] == b'v' && bytes[i + 2].is_ascii_digit() {
+            let mut major = 0u32;
+            let mut j = i + 2;
+            while j < bytes.len() && bytes[j].is_ascii_digit() {
+                major = major * 10 + (bytes[j] - b'0') as u32;
+                j += 1;
+            }
+            return major;
+        }
+        i += 1;
+    }
+    0
+}
+
+/// Serialize a `schema_id` field followed by its derived `schema_version`.
+///
+/// Put this on the envelope's `schema_id` field as
+/// `#[serde(flatten, serialize_with = "sysprims_core::schema::serialize_with_version")]`;
+/// the version is computed from the ID, so the two can never disagree.
+pub fn serialize_with_version<T, S>(schema_id: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: AsRef<str>,
+    S: Serializer,
+{
+    let schema_id = schema_id.as_ref();
+    let mut map = serializer.serialize_map(Some(2))?;
+    map.serialize_entry("schema_id", schema_id)?;
+    map.serialize_entry("schema_version", &schema_version(schema_id))?;
+    map.end()
+}
+
+/// Whether an input `schema_id` is `current` or an earlier minor version of it.
+///
+/// Config inputs check their `schema_id` with this, so callers built against
+/// an older minor keep working after an additive bump (ADR-0010). The topic,
+/// file name and major version must match; newer minors are rejected.
+///
+/// # Examples
+///
+/// ```rust
+/// use sysprims_core::schema::{accepts_schema_id, SPAWN_IN_GROUP_CONFIG_V1};
+///
+/// let old = SPAWN_IN_GROUP_CONFIG_V1.replace("/v1.1.0/", "/v1.0.0/");
+/// assert!(accepts_schema_id(SPAWN_IN_GROUP_CONFIG_V1, &old));
+/// assert!(!accepts_schema_id(SPAWN_IN_GROUP_CONFIG_V1, "spawn-in-group-config"));
+/// ```
+pub fn accepts_schema_id(current: &str, schema_id: &str) -> bool {
+    fn split(id: &str) -> Option<(&str, u32, u32, &str)> {
+        let (rest, file) = id.rsplit_once('/')?;
+        let (prefix, version) = rest.rsplit_once('/')?;
+        let mut parts = version.strip_prefix('v')?.split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next()?.parse().ok()?;
+        Some((prefix, major, minor, file))
+    }
+    if schema_id == current {
+        return true;
+    }
+    match (split(current), split(schema_id)) {
+        (Some((prefix, major, minor, file)), Some((p, ma, mi, f))) => {
+            p == prefix && f == file && ma == major && mi <= minor
+        }
+        _ => false,
+    }
+}
+
+// ============================================================================
+// Embedded Schemas
+// ============================================================================
+
+/// A JSON Schema document compiled into the binary.
+///
+/// Lets consumers validate output against the exact schema this build
+/// produces, without fetching from `schemas.3leaps.dev`.
+#[derive(Debug, Clone, Copy)]
+pub struct EmbeddedSchema {
+    /// Short name (schema filename without `.schema.json`), e.g. `process-info`.
+    pub name: &'static str,
+
+    /// Canonical schema ID (one of the `*_V1` constants in this module).
+    pub schema_id: &'static str,
+
+    /// Schema document as JSON text.
+    pub json: &'static str,
+}
+
+/// All schemas shipped with this build, keyed by their schema ID constants.
+pub const EMBEDDED_SCHEMAS: &[EmbeddedSchema] = &[
+    EmbeddedSchema {
+        name: "timeout-result",
+        schema_id: TIMEOUT_RESULT_V1,
+        json: include_str!("../../../schemas/timeout/v1.0.0/timeout-result.schema.json"),
+    },
+    EmbeddedSchema {
+        name: "timeout-run-config",
+        schema_id: TIMEOUT_RUN_CONFIG_V1,
+        json: include_str!("../../../schemas/timeout/v1.0.0/timeout-run-config.schema.json"),
+    },
+    EmbeddedSchema {
+        name: "process-info",
+        schema_id: PROCESS_INFO_V1,
+        json: include_str!("../../../schemas/process/v1.1.0/process-info.schema.json"),
+    },
+    EmbeddedSchema {
+        name: "process-info-sampled",
+        schema_id: PROCESS_INFO_SAMPLED_V1,
+        json: include_str!("../../../schemas/process/v1.1.0/process-info-sampled.schema.json"),
+    },
+    EmbeddedSchema {
+        name: "process-filter",
+        schema_id: PROC_FILTER_V1,
+        json: include_str!("../../../schemas/process/v1.1.0/process-filter.schema.json"),
+    },
+    EmbeddedSchema {
+        name: "port-bindings",
+        schema_id: PORT_BINDINGS_V1,
+        json: include_str!("../../../schemas/process/v1.1.0/port-bindings.schema.json"),
+    },
+    EmbeddedSchema {
+        name: "port-filter",
+        schema_id: PORT_FILTER_V1,
+        json: include_str!("../../../schemas/process/v1.0.0/port-filter.schema.json"),
+    },
+    EmbeddedSchema {
+        name: "fd-snapshot",
+        schema_id: FD_SNAPSHOT_V1,
+        json: include_str!("../../../schemas/process/v1.1.0/fd-snapshot.schema.json"),
+    },
+    EmbeddedSchema {
+        name: "fd-growth-report",
+        schema_id: FD_GROWTH_REPORT_V1,
+        json: include_str!("../../../schemas/process/v1.1.0/fd-growth-report.schema.json"),
+    },
+    EmbeddedSchema {
+        name: "fd-filter",
+        schema_id: FD_FILTER_V1,
+        json: include_str!("../../../schemas/process/v1.0.0/fd-filter.schema.json"),
+    },
+    EmbeddedSchema {
+        name: "wait-pid-result",
+        schema_id: WAIT_PID_RESULT_V1,
+        json: include_str!("../../../schemas/process/v1.1.0/wait-pid-result.schema.json"),
+    },
+    EmbeddedSchema {
+        name: "batch-kill-result",
+        schema_id: BATCH_KILL_RESULT_V1,
+        json: include_str!("../../../schemas/signal/v1.1.0/batch-kill-result.schema.json"),
+    },
+    EmbeddedSchema {
+        name: "kill-targets",
+        schema_id: KILL_TARGETS_V1,
+        json: include_str!("../../../schemas/signal/v1.1.0/kill-targets.schema.json"),
+    },
+    EmbeddedSchema {
+        name: "signal-catalog",
+        schema_id: SIGNAL_CATALOG_V1,
+        json: include_str!("../../../schemas/signal/v1.0.0/signal-catalog.schema.json"),
+    },
+    EmbeddedSchema {
+        name: "terminate-tree-config",
+        schema_id: TERMINATE_TREE_CONFIG_V1,
+        json: include_str!("../../../schemas/process/v1.1.0/terminate-tree-config.schema.json"),
+    },
+    EmbeddedSchema {
+        name: "terminate-tree-result",
+        schema_id: TERMINATE_TREE_RESULT_V1,
+        json: include_str!("../../../schemas/process/v1.1.0/terminate-tree-result.schema.json"),
+    },
+    EmbeddedSchema {
+        name: "spawn-in-group-config",
+        schema_id: SPAWN_IN_GROUP_CONFIG_V1,
+        json: include_str!("../../../schemas/process/v1.1.0/spawn-in-group-config.schema.json"),
+    },
+    EmbeddedSchema {
+        name: "spawn-in-group-result",
+        schema_id: SPAWN_IN_GROUP_RESULT_V1,
+        json: include_str!("../../../schemas/process/v1.1.0/spawn-in-group-result.schema.json"),
+    },
+    EmbeddedSchema {
+        name: "wait-group-result",
+        schema_id: WAIT_GROUP_RESULT_V1,
+        json: include_str!("../../../schemas/process/v1.0.0/wait-group-result.schema.json"),
+    },
+    EmbeddedSchema {
+        name: "descendants-result",
+        schema_id: DESCENDANTS_RESULT_V1,
+        json: include_str!("../../../schemas/process/v1.1.0/descendants-result.schema.json"),
+    },
+    EmbeddedSchema {
+        name: "descendants-result-sampled",
+        schema_id: DESCENDANTS_RESULT_SAMPLED_V1,
+        json: include_str!(
+            "../../../schemas/process/v1.2.0/descendants-result-sampled.schema.json"
+        ),
+    },
+    EmbeddedSchema {
+        name: "descendants-tree",
+        schema_id: DESCENDANTS_TREE_V1,
+        json: include_str!("../../../schemas/process/v1.0.0/descendants-tree.schema.json"),
+    },
+    EmbeddedSchema {
+        name: "capabilities",
+        schema_id: CAPABILITIES_V1,
+        json: include_str!("../../../schemas/core/v1.1.0/capabilities.schema.json"),
+    },
+];
+
+/// Look up an embedded schema by short name (e.g. `port-bindings`) or by
+/// full schema ID.
+///
+/// # Examples
+///
+/// ```rust
+/// use sysprims_core::schema::{embedded_schema, PORT_BINDINGS_V1};
+///
+/// let by_name = embedded_schema("port-bindings").unwrap();
+/// assert_eq!(by_name.schema_id, PORT_BINDINGS_V1);
+/// assert!(embedded_schema(PORT_BINDINGS_V1).is_some());
+/// ```
+pub fn embedded_schema(name_or_id: &str) -> Option<&'static EmbeddedSchema> {
+    let key = name_or_id.trim();
+    EMBEDDED_SCHEMAS
+        .iter()
+        .find(|s| s.name == key || s.schema_id == key)
+}
 
 // ============================================================================
 // Schema Host Constants
@@ -185,6 +479,38 @@ pub const SCHEMA_MODULE: &str = "sysprims";
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_schema_version_reads_major() {
+        for schema in EMBEDDED_SCHEMAS {
+            assert_eq!(schema_version(schema.schema_id), 1, "{}", schema.schema_id);
+        }
+        assert_eq!(schema_version(TIMEOUT_RESULT_V1), 1);
+        assert_eq!(
+            schema_version("https://x/sysprims/process/v12.0.0/a.json"),
+            12
+        );
+        assert_eq!(schema_version("no-version"), 0);
+    }
+
+    #[test]
+    fn test_accepts_schema_id_allows_older_minors_only() {
+        let current = "https://x/sysprims/process/v1.2.0/a.schema.json";
+        assert!(accepts_schema_id(current, current));
+        assert!(accepts_schema_id(
+            current,
+            "https://x/sysprims/process/v1.0.0/a.schema.json"
+        ));
+        for other in [
+            "https://x/sysprims/process/v1.3.0/a.schema.json",
+            "https://x/sysprims/process/v2.0.0/a.schema.json",
+            "https://x/sysprims/signal/v1.0.0/a.schema.json",
+            "https://x/sysprims/process/v1.0.0/b.schema.json",
+            "a.schema.json",
+        ] {
+            assert!(!accepts_schema_id(current, other), "{other}");
+        }
+    }
+
     #[test]
     fn test_schema_ids_are_valid_urls() {
         // All schema IDs should be valid HTTPS URLs
@@ -202,6 +528,9 @@ mod tests {
         assert!(TERMINATE_TREE_RESULT_V1.starts_with("https://"));
         assert!(SPAWN_IN_GROUP_CONFIG_V1.starts_with("https://"));
         assert!(SPAWN_IN_GROUP_RESULT_V1.starts_with("https://"));
+        assert!(WAIT_GROUP_RESULT_V1.starts_with("https://"));
+        assert!(SIGNAL_CATALOG_V1.starts_with("https://"));
+        assert!(DESCENDANTS_TREE_V1.starts_with("https://"));
         assert!(DESCENDANTS_RESULT_V1.starts_with("https://"));
         assert!(DESCENDANTS_RESULT_SAMPLED_V1.starts_with("https://"));
     }
@@ -267,6 +596,18 @@ mod tests {
             SPAWN_IN_GROUP_RESULT_V1.starts_with(expected_prefix),
             "Expected 3leaps.dev host"
         );
+        assert!(
+            WAIT_GROUP_RESULT_V1.starts_with(expected_prefix),
+            "Expected 3leaps.dev host"
+        );
+        assert!(
+            SIGNAL_CATALOG_V1.starts_with(expected_prefix),
+            "Expected 3leaps.dev host"
+        );
+        assert!(
+            DESCENDANTS_TREE_V1.starts_with(expected_prefix),
+            "Expected 3leaps.dev host"
+        );
         assert!(
             DESCENDANTS_RESULT_V1.starts_with(expected_prefix),
             "Expected 3leaps.dev host"
@@ -296,28 +637,43 @@ mod tests {
         assert!(TERMINATE_TREE_RESULT_V1.ends_with(".schema.json"));
         assert!(SPAWN_IN_GROUP_CONFIG_V1.ends_with(".schema.json"));
         assert!(SPAWN_IN_GROUP_RESULT_V1.ends_with(".schema.json"));
+        assert!(WAIT_GROUP_RESULT_V1.ends_with(".schema.json"));
+        assert!(SIGNAL_CATALOG_V1.ends_with(".schema.json"));
+        assert!(DESCENDANTS_TREE_V1.ends_with(".schema.json"));
         assert!(DESCENDANTS_RESULT_V1.ends_with(".schema.json"));
         assert!(DESCENDANTS_RESULT_SAMPLED_V1.ends_with(".schema.json"));
 
         // Process snapshot schemas are v1.1.0 (additive ProcessInfo fields).
         assert!(PROCESS_INFO_V1.contains("/v1.1.0/"));
         assert!(PROCESS_INFO_SAMPLED_V1.contains("/v1.1.0/"));
-        assert!(DESCENDANTS_RESULT_SAMPLED_V1.contains("/v1.1.0/"));
+        assert!(DESCENDANTS_RESULT_SAMPLED_V1.contains("/v1.2.0/"));
+
+        // Results with structured warnings are v1.1.0.
+        assert!(PORT_BINDINGS_V1.contains("/v1.1.0/"));
+        assert!(FD_SNAPSHOT_V1.contains("/v1.1.0/"));
+        assert!(WAIT_PID_RESULT_V1.contains("/v1.1.0/"));
+        assert!(TERMINATE_TREE_RESULT_V1.contains("/v1.1.0/"));
+        assert!(DESCENDANTS_RESULT_V1.contains("/v1.1.0/"));
+
+        // Envelopes that gained schema_version are v1.1.0.
+        assert!(CAPABILITIES_V1.contains("/v1.1.0/"));
+        assert!(FD_GROWTH_REPORT_V1.contains("/v1.1.0/"));
+        assert!(SPAWN_IN_GROUP_RESULT_V1.contains("/v1.1.0/"));
+        assert!(BATCH_KILL_RESULT_V1.contains("/v1.1.0/"));
+        assert!(KILL_TARGETS_V1.contains("/v1.1.0/"));
+
+        // Filters accepting the disk_sleep state are v1.1.0.
+        assert!(PROC_FILTER_V1.contains("/v1.1.0/"));
 
         // Remaining schemas are currently v1.0.0.
         assert!(TIMEOUT_RESULT_V1.contains("/v1.0.0/"));
-        assert!(PROC_FILTER_V1.contains("/v1.0.0/"));
-        assert!(PORT_BINDINGS_V1.contains("/v1.0.0/"));
         assert!(PORT_FILTER_V1.contains("/v1.0.0/"));
-        assert!(FD_SNAPSHOT_V1.contains("/v1.0.0/"));
         assert!(FD_FILTER_V1.contains("/v1.0.0/"));
-        assert!(WAIT_PID_RESULT_V1.contains("/v1.0.0/"));
-        assert!(BATCH_KILL_RESULT_V1.contains("/v1.0.0/"));
-        assert!(TERMINATE_TREE_CONFIG_V1.contains("/v1.0.0/"));
-        assert!(TERMINATE_TREE_RESULT_V1.contains("/v1.0.0/"));
-        assert!(SPAWN_IN_GROUP_CONFIG_V1.contains("/v1.0.0/"));
-        assert!(SPAWN_IN_GROUP_RESULT_V1.contains("/v1.0.0/"));
-        assert!(DESCENDANTS_RESULT_V1.contains("/v1.0.0/"));
+        assert!(TERMINATE_TREE_CONFIG_V1.contains("/v1.1.0/"));
+        assert!(SPAWN_IN_GROUP_CONFIG_V1.contains("/v1.1.0/"));
+        assert!(WAIT_GROUP_RESULT_V1.contains("/v1.0.0/"));
+        assert!(DESCENDANTS_TREE_V1.contains("/v1.0.0/"));
+        assert!(SIGNAL_CATALOG_V1.contains("/v1.0.0/"));
     }
 
     #[test]
@@ -327,6 +683,10 @@ mod tests {
             TIMEOUT_RESULT_V1.contains("/timeout/"),
             "timeout schema should have timeout topic"
         );
+        assert!(
+            TIMEOUT_RUN_CONFIG_V1.contains("/timeout/"),
+            "timeout-run-config schema should have timeout topic"
+        );
         assert!(
             PROCESS_INFO_V1.contains("/process/"),
             "process-info schema should have process topic"
@@ -363,6 +723,10 @@ mod tests {
             BATCH_KILL_RESULT_V1.contains("/signal/"),
             "batch-kill-result schema should have signal topic"
         );
+        assert!(
+            SIGNAL_CATALOG_V1.contains("/signal/"),
+            "signal-catalog schema should have signal topic"
+        );
         assert!(
             TERMINATE_TREE_CONFIG_V1.contains("/process/"),
             "terminate-tree-config schema should have process topic"
@@ -379,6 +743,14 @@ mod tests {
             SPAWN_IN_GROUP_RESULT_V1.contains("/process/"),
             "spawn-in-group-result schema should have process topic"
         );
+        assert!(
+            WAIT_GROUP_RESULT_V1.contains("/process/"),
+            "wait-group-result schema should have process topic"
+        );
+        assert!(
+            DESCENDANTS_TREE_V1.contains("/process/"),
+            "descendants-tree schema should have process topic"
+        );
         assert!(
             DESCENDANTS_RESULT_V1.contains("/process/"),
             "descendants-result schema should have process topic"
@@ -393,6 +765,7 @@ mod tests {
     fn test_schema_ids_are_unique() {
         let ids = [
             TIMEOUT_RESULT_V1,
+            TIMEOUT_RUN_CONFIG_V1,
             PROCESS_INFO_V1,
             PROCESS_INFO_SAMPLED_V1,
             PROC_FILTER_V1,
@@ -406,6 +779,9 @@ mod tests {
             TERMINATE_TREE_RESULT_V1,
             SPAWN_IN_GROUP_CONFIG_V1,
             SPAWN_IN_GROUP_RESULT_V1,
+            WAIT_GROUP_RESULT_V1,
+            DESCENDANTS_TREE_V1,
+            SIGNAL_CATALOG_V1,
             DESCENDANTS_RESULT_V1,
             DESCENDANTS_RESULT_SAMPLED_V1,
         ];
@@ -420,6 +796,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_embedded_schemas_match_their_ids() {
+        for schema in EMBEDDED_SCHEMAS {
+            let expected = format!("\"$id\": \"{}\"", schema.schema_id);
+            assert!(
+                schema.json.contains(&expected),
+                "embedded schema {} does not declare $id {}",
+                schema.name,
+                schema.schema_id
+            );
+            let suffix = format!("/{}.schema.json", schema.name);
+            assert!(schema.schema_id.ends_with(&suffix));
+        }
+    }
+
+    #[test]
+    fn test_embedded_schema_lookup() {
+        assert_eq!(
+            embedded_schema("process-info").map(|s| s.schema_id),
+            Some(PROCESS_INFO_V1)
+        );
+        assert_eq!(
+            embedded_schema(DESCENDANTS_RESULT_V1).map(|s| s.name),
+            Some("descendants-result")
+        );
+        assert!(embedded_schema("nope").is_none());
+    }
+
     #[test]
     fn test_schema_host_constants() {
         assert_eq!(SCHEMA_HOST, "https://schemas.3leaps.dev");
@@ -428,6 +832,7 @@ mod tests {
         // All schema IDs should start with host/module
         let prefix = format!("{}/{}/", SCHEMA_HOST, SCHEMA_MODULE);
         assert!(TIMEOUT_RESULT_V1.starts_with(&prefix));
+        assert!(TIMEOUT_RUN_CONFIG_V1.starts_with(&prefix));
         assert!(PROCESS_INFO_V1.starts_with(&prefix));
         assert!(PROCESS_INFO_SAMPLED_V1.starts_with(&prefix));
         assert!(PROC_FILTER_V1.starts_with(&prefix));
@@ -441,6 +846,9 @@ mod tests {
         assert!(TERMINATE_TREE_RESULT_V1.starts_with(&prefix));
         assert!(SPAWN_IN_GROUP_CONFIG_V1.starts_with(&prefix));
         assert!(SPAWN_IN_GROUP_RESULT_V1.starts_with(&prefix));
+        assert!(WAIT_GROUP_RESULT_V1.starts_with(&prefix));
+        assert!(SIGNAL_CATALOG_V1.starts_with(&prefix));
+        assert!(DESCENDANTS_TREE_V1.starts_with(&prefix));
         assert!(DESCENDANTS_RESULT_V1.starts_with(&prefix));
         assert!(DESCENDANTS_RESULT_SAMPLED_V1.starts_with(&prefix));
     }
@@ -0,0 +1,322 @@
+//! Opt-in self-check of JSON output against the embedded schemas.
+//!
+//! Outputs carry a `schema_id`; [`validate_value`] looks that ID up in
+//! [`EMBEDDED_SCHEMAS`](crate::schema::EMBEDDED_SCHEMAS) and checks the value
+//! against it. The CLI and FFI call [`check_output`] at their serialization
+//! points so fields that drift out of sync with the published schemas are
+//! caught during development.
+//!
+//! Checking is off unless [`VALIDATE_SCHEMA_ENV`] is set: `1` reports
+//! mismatches, `strict` turns them into errors (for tests and CI).
+//!
+//! Only the keywords used by the sysprims schemas are supported: `type`,
+//! `enum`, `const`, `minimum`, `maximum`, `minLength`, `minItems`,
+//! `required`, `properties`, `additionalProperties`, `items`, and local
+//! `$ref`s. Other keywords are ignored.
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::schema::embedded_schema;
+use crate::{SysprimsError, SysprimsResult};
+
+/// Environment variable that enables output checking.
+pub const VALIDATE_SCHEMA_ENV: &str = "SYSPRIMS_VALIDATE_SCHEMA";
+
+/// How schema mismatches are handled, from [`VALIDATE_SCHEMA_ENV`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckMode {
+    /// No checking (unset, empty, or `0`).
+    Off,
+
+    /// Report mismatches and keep going (any other value).
+    Warn,
+
+    /// Fail on mismatch (`strict`).
+    Strict,
+}
+
+/// Read the check mode from [`VALIDATE_SCHEMA_ENV`].
+pub fn check_mode() -> CheckMode {
+    match std::env::var(VALIDATE_SCHEMA_ENV) {
+        Ok(v) if v.eq_ignore_ascii_case("strict") => CheckMode::Strict,
+        Ok(v) if !v.is_empty() && v != "0" => CheckMode::Warn,
+        _ => CheckMode::Off,
+    }
+}
+
+/// Check `value` against the embedded schema named by its `schema_id`.
+///
+/// Values without a `schema_id`, or with one this build does not embed,
+/// pass unchecked. On mismatch, returns one message per violation, each
+/// prefixed with a `$`-rooted path.
+///
+/// # Examples
+///
+/// ```rust
+/// use sysprims_core::schema::WAIT_PID_RESULT_V1;
+/// use sysprims_core::schema_check::validate_value;
+///
+/// let value = serde_json::json!({ "schema_id": WAIT_PID_RESULT_V1, "pid": "oops" });
+/// let errors = validate_value(&value).unwrap_err();
+/// assert!(errors.iter().any(|e| e.starts_with("$.pid")));
+/// ```
+pub fn validate_value(value: &Value) -> Result<(), Vec<String>> {
+    let Some(schema_id) = value.get("schema_id").and_then(Value::as_str) else {
+        return Ok(());
+    };
+    let Some(embedded) = embedded_schema(schema_id) else {
+        return Ok(());
+    };
+    let root: Value = match serde_json::from_str(embedded.json) {
+        Ok(root) => root,
+        Err(e) => return Err(vec![format!("embedded schema {schema_id} is invalid: {e}")]),
+    };
+
+    let mut errors = Vec::new();
+    check_node(&root, &root, value, "$", &mut errors);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Serialize `value` and check it according to [`check_mode`].
+///
+/// Returns the mismatch messages for the caller to log in [`CheckMode::Warn`]
+/// (empty when checking is off or the value matches).
+///
+/// # Errors
+///
+/// Returns `Internal` on mismatch in [`CheckMode::Strict`], or if `value`
+/// cannot be serialized while checking is enabled.
+pub fn check_output<T: Serialize + ?Sized>(value: &T) -> SysprimsResult<Vec<String>> {
+    let mode = check_mode();
+    if mode == CheckMode::Off {
+        return Ok(Vec::new());
+    }
+
+    let value = serde_json::to_value(value)
+        .map_err(|e| SysprimsError::internal(format!("failed to serialize output: {e}")))?;
+    let Err(errors) = validate_value(&value) else {
+        return Ok(Vec::new());
+    };
+
+    let schema_id = value
+        .get("schema_id")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    let messages: Vec<String> = errors
+        .into_iter()
+        .map(|e| format!("output does not match schema {schema_id}: {e}"))
+        .collect();
+    if mode == CheckMode::Strict {
+        return Err(SysprimsError::internal(messages.join("; ")));
+    }
+    Ok(messages)
+}
+
+fn check_node(root: &Value, schema: &Value, value: &Value, path: &str, errors: &mut Vec<String>) {
+    let Some(schema) = schema.as_object() else {
+        return;
+    };
+
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        let (doc, pointer) = reference.split_once('#').unwrap_or((reference, ""));
+        let other = if doc.is_empty() {
+            None
+        } else {
+            match referenced_schema(root, doc) {
+                Some(other) => Some(other),
+                None => {
+                    errors.push(format!("{path}: unresolvable $ref {reference}"));
+                    return;
+                }
+            }
+        };
+        let target_root = other.as_ref().unwrap_or(root);
+        match target_root.pointer(pointer) {
+            Some(target) => check_node(target_root, target, value, path, errors),
+            None => errors.push(format!("{path}: unresolvable $ref {reference}")),
+        }
+        return;
+    }
+
+    if let Some(types) = schema.get("type") {
+        let matches = match types {
+            Value::String(t) => type_matches(t, value),
+            Value::Array(ts) => ts
+                .iter()
+                .filter_map(Value::as_str)
+                .any(|t| type_matches(t, value)),
+            _ => true,
+        };
+        if !matches {
+            errors.push(format!("{path}: expected type {types}, got {value}"));
+            return;
+        }
+    }
+
+    if let Some(Value::Array(allowed)) = schema.get("enum") {
+        if !allowed.contains(value) {
+            errors.push(format!(
+                "{path}: {value} is not one of {}",
+                Value::Array(allowed.clone())
+            ));
+        }
+    }
+    if let Some(expected) = schema.get("const") {
+        if value != expected {
+            errors.push(format!("{path}: expected {expected}, got {value}"));
+        }
+    }
+
+    if let Some(n) = value.as_f64() {
+        if let Some(min) = schema.get("minimum").and_then(Value::as_f64) {
+            if n < min {
+                errors.push(format!("{path}: {value} is below minimum {min}"));
+            }
+        }
+        if let Some(max) = schema.get("maximum").and_then(Value::as_f64) {
+            if n > max {
+                errors.push(format!("{path}: {value} is above maximum {max}"));
+            }
+        }
+    }
+
+    if let (Some(s), Some(min)) = (
+        value.as_str(),
+        schema.get("minLength").and_then(Value::as_u64),
+    ) {
+        if (s.chars().count() as u64) < min {
+            errors.push(format!("{path}: string shorter than {min}"));
+        }
+    }
+
+    if let Some(items) = value.as_array() {
+        if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+            if (items.len() as u64) < min {
+                errors.push(format!("{path}: fewer than {min} items"));
+            }
+        }
+        if let Some(item_schema) = schema.get("items") {
+            for (i, item) in items.iter().enumerate() {
+                check_node(root, item_schema, item, &format!("{path}[{i}]"), errors);
+            }
+        }
+    }
+
+    if let Some(object) = value.as_object() {
+        if let Some(Value::Array(required)) = schema.get("required") {
+            for key in required.iter().filter_map(Value::as_str) {
+                if !object.contains_key(key) {
+                    errors.push(format!("{path}: missing required field {key}"));
+                }
+            }
+        }
+
+        let properties = schema.get("properties").and_then(Value::as_object);
+        let additional = schema.get("additionalProperties");
+        for (key, field) in object {
+            let field_path = format!("{path}.{key}");
+            match properties.and_then(|p| p.get(key)) {
+                Some(field_schema) => check_node(root, field_schema, field, &field_path, errors),
+                None => match additional {
+                    Some(Value::Bool(false)) => {
+                        errors.push(format!("{path}: unexpected field {key}"));
+                    }
+                    Some(extra @ Value::Object(_)) => {
+                        check_node(root, extra, field, &field_path, errors);
+                    }
+                    _ => {}
+                },
+            }
+        }
+    }
+}
+
+/// Load the schema a relative `$ref` document name points at.
+///
+/// Resolves `doc` against the `$id` of `root`; if that exact version is not
+/// embedded, falls back to the embedded schema with the same file name, which
+/// is the version this build emits.
+fn referenced_schema(root: &Value, doc: &str) -> Option<Value> {
+    let base = root
+        .get("$id")
+        .and_then(Value::as_str)
+        .and_then(|id| id.rsplit_once('/'))
+        .map(|(base, _)| base)
+        .unwrap_or_default();
+    let embedded = embedded_schema(&format!("{base}/{doc}"))
+        .or_else(|| embedded_schema(doc))
+        .or_else(|| embedded_schema(doc.strip_suffix(".schema.json")?))?;
+    serde_json::from_str(embedded.json).ok()
+}
+
+fn type_matches(expected: &str, value: &Value) -> bool {
+    match expected {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "string" => value.is_string(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        "number" => value.is_number(),
+        "integer" => {
+            value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|n| n.fract() == 0.0)
+        }
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::FD_SNAPSHOT_V1;
+    use serde_json::json;
+
+    fn fd_snapshot(fds: Value) -> Value {
+        json!({
+            "schema_id": FD_SNAPSHOT_V1,
+            "timestamp": "2026-01-01T00:00:00Z",
+            "platform": "linux",
+            "pid": 42,
+            "fds": fds,
+            "kind_counts": {},
+            "warnings": [],
+        })
+    }
+
+    #[test]
+    fn valid_output_passes() {
+        let value = fd_snapshot(json!([{ "fd": 0, "kind": "pipe" }]));
+        assert_eq!(validate_value(&value), Ok(()));
+    }
+
+    #[test]
+    fn drift_is_reported_with_paths() {
+        let value = fd_snapshot(json!([{ "fd": -1, "kind": "door", "extra": true }]));
+        let errors = validate_value(&value).unwrap_err();
+        assert!(
+            errors.iter().any(|e| e.starts_with("$.fds[0].fd")),
+            "{errors:?}"
+        );
+        assert!(
+            errors.iter().any(|e| e.starts_with("$.fds[0].kind")),
+            "{errors:?}"
+        );
+        assert!(
+            errors.iter().any(|e| e.contains("unexpected field extra")),
+            "{errors:?}"
+        );
+    }
+
+    #[test]
+    fn values_without_known_schema_pass() {
+        assert_eq!(validate_value(&json!({ "pid": "x" })), Ok(()));
+        assert_eq!(
+            validate_value(&json!({ "schema_id": "urn:unknown" })),
+            Ok(())
+        );
+    }
+}
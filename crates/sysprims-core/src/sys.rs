@@ -0,0 +1,165 @@
+//! OS call helpers shared by the platform crates.
+//!
+//! Not part of the public API: these exist so `sysprims-proc`,
+//! `sysprims-signal` and friends wrap raw OS calls and judge privilege the
+//! same way.
+
+/// Call `f` (a raw libc call returning `-1` with `errno` on failure) again
+/// while it fails with `EINTR`.
+///
+/// A signal delivered to the calling process must not surface as a spurious
+/// error. `errno` from the final attempt is left in place for the caller.
+#[cfg(unix)]
+pub fn retry_eintr(mut f: impl FnMut() -> libc::c_int) -> libc::c_int {
+    loop {
+        let rc = f();
+        if rc != -1 || std::io::Error::last_os_error().kind() != std::io::ErrorKind::Interrupted {
+            return rc;
+        }
+    }
+}
+
+/// Whether the calling process runs with full privilege (root, or an
+/// elevated token on Windows).
+#[cfg(unix)]
+pub fn is_elevated() -> bool {
+    // SAFETY: geteuid takes no arguments, cannot fail, and has no side effects.
+    unsafe { libc::geteuid() == 0 }
+}
+
+/// Whether the calling process runs with full privilege (root, or an
+/// elevated token on Windows).
+#[cfg(windows)]
+pub fn is_elevated() -> bool {
+    use windows_sys::Win32::Foundation::{CloseHandle, HANDLE};
+    use windows_sys::Win32::Security::{
+        GetTokenInformation, TokenElevation, TOKEN_ELEVATION, TOKEN_QUERY,
+    };
+    use windows_sys::Win32::System::Threading::{GetCurrentProcess, OpenProcessToken};
+
+    let mut token: HANDLE = 0;
+    // SAFETY: GetCurrentProcess returns a pseudo-handle that needs no cleanup;
+    // `token` is a valid out-pointer.
+    if unsafe { OpenProcessToken(GetCurrentProcess(), TOKEN_QUERY, &mut token) } == 0 {
+        return false;
+    }
+    let mut elevation = TOKEN_ELEVATION { TokenIsElevated: 0 };
+    let mut len = 0u32;
+    // SAFETY: `token` was opened above with TOKEN_QUERY; the buffer and its
+    // length describe `elevation`, and the handle is closed exactly once.
+    let ok = unsafe {
+        let ok = GetTokenInformation(
+            token,
+            TokenElevation,
+            &mut elevation as *mut TOKEN_ELEVATION as *mut _,
+            std::mem::size_of::<TOKEN_ELEVATION>() as u32,
+            &mut len,
+        );
+        CloseHandle(token);
+        ok
+    };
+    ok != 0 && elevation.TokenIsElevated != 0
+}
+
+/// Whether a permission failure reading `pid` could be overcome by retrying
+/// with full privilege.
+///
+/// Already-privileged callers get `false`: further denials come from kernel
+/// policy (Yama/LSMs on Linux, protected processes on Windows), not missing
+/// privilege. On macOS, targets shielded by System Integrity Protection get
+/// `false` too, since SIP denies root as well.
+pub fn elevation_would_help(pid: u32) -> bool {
+    #[cfg(target_os = "macos")]
+    if sip_protected(pid) {
+        return false;
+    }
+    #[cfg(not(target_os = "macos"))]
+    let _ = pid;
+    !is_elevated()
+}
+
+/// Whether SIP is enforced and `pid` runs a restricted or platform binary,
+/// which SIP keeps out of reach of every caller, root included.
+#[cfg(target_os = "macos")]
+fn sip_protected(pid: u32) -> bool {
+    extern "C" {
+        fn csr_check(mask: u32) -> libc::c_int;
+        fn csops(
+            pid: libc::pid_t,
+            ops: libc::c_uint,
+            useraddr: *mut libc::c_void,
+            usersize: libc::size_t,
+        ) -> libc::c_int;
+    }
+    const CSR_ALLOW_TASK_FOR_PID: u32 = 1 << 2;
+    const CS_OPS_STATUS: libc::c_uint = 0;
+    const CS_RESTRICT: u32 = 0x0000_0800;
+    const CS_PLATFORM_BINARY: u32 = 0x0400_0000;
+
+    // SAFETY: csr_check only reads the active SIP configuration.
+    if unsafe { csr_check(CSR_ALLOW_TASK_FOR_PID) } == 0 {
+        return false;
+    }
+    let mut flags: u32 = 0;
+    // SAFETY: CS_OPS_STATUS writes a single u32 into `flags`, whose size is
+    // passed alongside.
+    let rc = unsafe {
+        csops(
+            pid as libc::pid_t,
+            CS_OPS_STATUS,
+            &mut flags as *mut u32 as *mut libc::c_void,
+            std::mem::size_of::<u32>(),
+        )
+    };
+    rc == 0 && flags & (CS_RESTRICT | CS_PLATFORM_BINARY) != 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    fn set_errno(value: i32) {
+        // SAFETY: errno is thread-local; writing it only affects this thread.
+        #[cfg(target_os = "linux")]
+        unsafe {
+            *libc::__errno_location() = value
+        };
+        #[cfg(target_os = "macos")]
+        unsafe {
+            *libc::__error() = value
+        };
+    }
+
+    #[test]
+    #[cfg(not(target_os = "macos"))]
+    fn elevation_helps_only_unprivileged_callers() {
+        assert_eq!(elevation_would_help(std::process::id()), !is_elevated());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn retry_eintr_retries_interrupted_calls() {
+        let mut calls = 0;
+        let rc = retry_eintr(|| {
+            calls += 1;
+            if calls < 3 {
+                set_errno(libc::EINTR);
+                -1
+            } else {
+                0
+            }
+        });
+        assert_eq!((rc, calls), (0, 3));
+
+        let rc = retry_eintr(|| {
+            set_errno(libc::ESRCH);
+            -1
+        });
+        assert_eq!(rc, -1);
+        assert_eq!(
+            std::io::Error::last_os_error().raw_os_error(),
+            Some(libc::ESRCH)
+        );
+    }
+}
@@ -0,0 +1,88 @@
+//! Structured, machine-readable warnings.
+//!
+//! Result types that can succeed with degraded coverage carry a list of
+//! [`Warning`]s. The [`WarningCode`] lets automation count and categorize
+//! warnings without parsing messages; `Display` prints only the message, so
+//! human output reads the same as a plain string.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Category of a non-fatal [`Warning`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WarningCode {
+    /// Some entries could not be read or signaled due to permissions.
+    PermissionDenied,
+
+    /// Results may be incomplete (read errors, skipped or racing entries).
+    PartialVisibility,
+
+    /// Data was cut short by a size or count limit.
+    Truncated,
+
+    /// A PID was skipped or re-checked to avoid acting on a reused PID.
+    PidReuseGuard,
+
+    /// A weaker fallback mechanism was used (e.g. per-PID instead of group kill).
+    Degraded,
+
+    /// Protected processes (self, PID 1, parent) were skipped.
+    Protected,
+
+    /// The configuration is accepted but looks like a mistake.
+    Config,
+
+    /// Informational note that does not indicate a problem.
+    Info,
+
+    /// Anything not covered above.
+    Other,
+}
+
+/// A non-fatal warning with a stable code and a human-readable message.
+///
+/// # Examples
+///
+/// ```rust
+/// use sysprims_core::{Warning, WarningCode};
+///
+/// let w = Warning::new(WarningCode::PermissionDenied, "Skipped 3 pid entries");
+/// assert_eq!(w.to_string(), "Skipped 3 pid entries");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Warning {
+    /// Machine-readable category.
+    pub code: WarningCode,
+
+    /// Human-readable description.
+    pub message: String,
+}
+
+impl Warning {
+    /// Create a warning with the given code and message.
+    pub fn new(code: WarningCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_is_message_only() {
+        let w = Warning::new(WarningCode::Truncated, "cmdline truncated");
+        assert_eq!(w.to_string(), "cmdline truncated");
+    }
+}
@@ -41,15 +41,18 @@
 //! let filtered = sysprims_proc::snapshot_filtered(&filter).unwrap();
 //! ```
 
+use base64::prelude::{Engine as _, BASE64_STANDARD};
 use serde::{Deserialize, Serialize};
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::net::IpAddr;
-use std::time::Duration;
+use std::sync::{mpsc, Arc, Mutex};
+use std::time::{Duration, Instant};
 use sysprims_core::schema::{
-    DESCENDANTS_RESULT_SAMPLED_V1, DESCENDANTS_RESULT_V1, FD_SNAPSHOT_V1, PORT_BINDINGS_V1,
-    PORT_FILTER_V1, PROCESS_INFO_SAMPLED_V1, PROCESS_INFO_V1, WAIT_PID_RESULT_V1,
+    DESCENDANTS_RESULT_SAMPLED_V1, DESCENDANTS_RESULT_V1, DESCENDANTS_TREE_V1, FD_GROWTH_REPORT_V1,
+    FD_SNAPSHOT_V1, PORT_BINDINGS_V1, PORT_FILTER_V1, PROCESS_INFO_SAMPLED_V1, PROCESS_INFO_V1,
+    WAIT_PID_RESULT_V1,
 };
-use sysprims_core::{get_platform, SysprimsError, SysprimsResult};
+use sysprims_core::{get_platform, SysprimsError, SysprimsResult, Warning, WarningCode};
 
 // Platform-specific implementations
 #[cfg(target_os = "linux")]
@@ -59,6 +62,17 @@ mod macos;
 #[cfg(windows)]
 mod windows;
 
+pub mod protected;
+mod reader;
+#[cfg(feature = "render")]
+pub mod render;
+pub use protected::{
+    filter_protected, protected_pids, ProtectedTarget, UserProtections, PROTECTED_NAMES_ENV,
+    PROTECTED_PIDS_ENV,
+};
+
+use reader::BoundedRead;
+
 // Re-export the platform implementation
 #[cfg(target_os = "linux")]
 use linux as platform;
@@ -77,6 +91,12 @@ use windows as platform;
 #[derive(Debug, Clone, Serialize)]
 pub struct ProcessSnapshot {
     /// Schema identifier for version detection.
+    ///
+    /// Serialized together with `schema_version`, its major version.
+    #[serde(
+        flatten,
+        serialize_with = "sysprims_core::schema::serialize_with_version"
+    )]
     pub schema_id: &'static str,
 
     /// Timestamp of snapshot (ISO 8601).
@@ -84,6 +104,38 @@ pub struct ProcessSnapshot {
 
     /// List of processes.
     pub processes: Vec<ProcessInfo>,
+
+    /// Whether `cpu_percent` is normalized to 0-100 across all cores.
+    ///
+    /// `true` for lifetime-average snapshots, which are clamped to 0-100.
+    /// `false` for sampled snapshots (`PROCESS_INFO_SAMPLED_V1`), where
+    /// `cpu_percent` is per-core and exceeds 100 for multi-threaded processes.
+    pub cpu_percent_is_normalized: bool,
+
+    /// Whether a filter was applied, so an empty `processes` can be told
+    /// apart from an enumeration that saw nothing.
+    pub filtered: bool,
+
+    /// Number of processes enumerated before filtering. Set only when
+    /// `filtered` is true.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_before_filter: Option<usize>,
+
+    /// Non-fatal enumeration warnings (e.g. PIDs skipped after a read timeout).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<Warning>,
+}
+
+impl ProcessSnapshot {
+    /// Switch the envelope to sampled-CPU semantics.
+    ///
+    /// Sets `schema_id` to `PROCESS_INFO_SAMPLED_V1` and clears
+    /// `cpu_percent_is_normalized`, since sampled values are per-core and can
+    /// exceed 100 on multi-core systems.
+    pub fn mark_sampled(&mut self) {
+        self.schema_id = PROCESS_INFO_SAMPLED_V1;
+        self.cpu_percent_is_normalized = false;
+    }
 }
 
 /// Result of waiting for a PID to exit.
@@ -94,6 +146,12 @@ pub struct ProcessSnapshot {
 #[derive(Debug, Clone, Serialize)]
 pub struct WaitPidResult {
     /// Schema identifier for version detection.
+    ///
+    /// Serialized together with `schema_version`, its major version.
+    #[serde(
+        flatten,
+        serialize_with = "sysprims_core::schema::serialize_with_version"
+    )]
     pub schema_id: &'static str,
 
     /// Timestamp of result creation (ISO 8601).
@@ -112,17 +170,88 @@ pub struct WaitPidResult {
     pub timed_out: bool,
 
     /// Exit code when available.
+    ///
+    /// On Unix this is only known when the caller is the parent of `pid` (for
+    /// example a child from `spawn_in_group`) and the child exited normally;
+    /// polling a non-child, or a child killed by a signal, yields `None`.
+    /// Windows reports the exit code for any process it can open.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub exit_code: Option<i32>,
 
     /// Warnings about degraded visibility.
-    pub warnings: Vec<String>,
+    pub warnings: Vec<Warning>,
+}
+
+impl WaitPidResult {
+    /// Result for a PID already known to be gone (e.g. `kill` reported it
+    /// missing), without polling.
+    pub fn already_exited(pid: u32) -> Self {
+        make_wait_pid_result(pid, true, false, None, Vec::new())
+    }
+}
+
+/// Configuration for [`wait_pid_with_config`].
+#[derive(Debug, Clone, Copy)]
+pub struct WaitPidConfig {
+    /// Report an exited-but-unreaped (zombie) process as exited.
+    ///
+    /// On Unix a zombie still answers `kill(pid, 0)`, so without this a
+    /// supervisor that is not the parent would wait out the full timeout.
+    /// Linux and macOS both honor this flag; Windows has no zombie state and
+    /// ignores it.
+    ///
+    /// Default: `true`
+    pub zombie_is_exited: bool,
+
+    /// Fixed interval between liveness polls on Unix.
+    ///
+    /// `None` uses an adaptive backoff (5ms growing to 200ms). Windows waits
+    /// on the process handle and ignores this.
+    ///
+    /// Default: `None`
+    pub poll_interval: Option<Duration>,
+
+    /// What to do when the PID execs into a different program mid-wait.
+    ///
+    /// Default: [`OnExec::Ignore`]
+    pub on_exec: OnExec,
+}
+
+impl Default for WaitPidConfig {
+    fn default() -> Self {
+        Self {
+            zombie_is_exited: true,
+            poll_interval: None,
+            on_exec: OnExec::Ignore,
+        }
+    }
+}
+
+/// Policy for a waited-on PID whose identity changes during the wait.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OnExec {
+    /// Keep waiting on the PID regardless of what it runs.
+    #[default]
+    Ignore,
+
+    /// Return early (`exited = true`, with a warning) once the PID's
+    /// `exe_path` or start time differs from when the wait began.
+    ///
+    /// Catches wrappers that re-exec into the real workload. Checks run every
+    /// `poll_interval` (default 50ms), including on Windows.
+    TreatAsExit,
 }
 
 /// Snapshot of listening ports at a point in time.
 #[derive(Debug, Clone, Serialize)]
 pub struct PortBindingsSnapshot {
     /// Schema identifier for version detection.
+    ///
+    /// Serialized together with `schema_version`, its major version.
+    #[serde(
+        flatten,
+        serialize_with = "sysprims_core::schema::serialize_with_version"
+    )]
     pub schema_id: &'static str,
 
     /// Timestamp of snapshot (ISO 8601).
@@ -134,8 +263,13 @@ pub struct PortBindingsSnapshot {
     /// List of socket bindings.
     pub bindings: Vec<PortBinding>,
 
+    /// Whether rerunning with elevated privileges (root/Administrator) would
+    /// attribute bindings that are missing a `pid` now, e.g. sockets owned by
+    /// other users on Linux. A warning gives the count.
+    pub needs_elevation: bool,
+
     /// Warnings about partial visibility or skipped entries.
-    pub warnings: Vec<String>,
+    pub warnings: Vec<Warning>,
 }
 
 /// Listening socket binding information.
@@ -151,7 +285,8 @@ pub struct PortBinding {
     /// Local port for the socket.
     pub local_port: u16,
 
-    /// Socket state (e.g., "listen" for TCP).
+    /// Socket state (e.g., "listen" for TCP; None for bound UDP sockets,
+    /// which have no listen state).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<String>,
 
@@ -169,7 +304,7 @@ pub struct PortBinding {
 }
 
 /// Protocol for a socket binding.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Protocol {
     Tcp,
@@ -185,10 +320,16 @@ pub struct PortFilter {
 
     /// Filter by local port.
     pub local_port: Option<u16>,
+
+    /// Filter by owning process name substring (case-insensitive).
+    ///
+    /// Matched against the best-effort `PortBinding::process`; bindings whose
+    /// owner could not be resolved are excluded.
+    pub process_name_contains: Option<String>,
 }
 
 /// File descriptor kind.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum FdKind {
     File,
@@ -224,12 +365,30 @@ pub struct FdInfo {
     /// Best-effort resolved path/target.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub path: Option<String>,
+
+    /// Raw bytes of `path` when it is not valid UTF-8 (Linux only).
+    ///
+    /// `path` holds a lossy conversion; these are the true bytes. Base64
+    /// (standard, padded) in JSON. Unlike [`ProcessInfo::name_bytes`] this
+    /// needs no opt-in: the link target is already read, so it costs nothing
+    /// and only appears when `path` would otherwise be wrong.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_base64"
+    )]
+    pub path_bytes: Option<Vec<u8>>,
 }
 
 /// Snapshot of open file descriptors for a process.
 #[derive(Debug, Clone, Serialize)]
 pub struct FdSnapshot {
     /// Schema identifier for version detection.
+    ///
+    /// Serialized together with `schema_version`, its major version.
+    #[serde(
+        flatten,
+        serialize_with = "sysprims_core::schema::serialize_with_version"
+    )]
     pub schema_id: &'static str,
 
     /// Timestamp of snapshot (ISO 8601).
@@ -244,15 +403,73 @@ pub struct FdSnapshot {
     /// List of open file descriptors.
     pub fds: Vec<FdInfo>,
 
+    /// Number of entries in `fds` per kind (after filtering).
+    ///
+    /// Kinds with no descriptors are omitted.
+    pub kind_counts: BTreeMap<FdKind, u64>,
+
     /// Warnings about partial visibility.
-    pub warnings: Vec<String>,
+    pub warnings: Vec<Warning>,
+}
+
+/// File descriptor counts sampled over time by [`fd_growth`].
+#[derive(Debug, Clone, Serialize)]
+pub struct FdGrowthReport {
+    /// Schema identifier for version detection.
+    ///
+    /// Serialized together with `schema_version`, its major version.
+    #[serde(
+        flatten,
+        serialize_with = "sysprims_core::schema::serialize_with_version"
+    )]
+    pub schema_id: &'static str,
+
+    /// Timestamp of the final sample (ISO 8601).
+    pub timestamp: String,
+
+    /// Current platform (e.g., "linux", "macos", "windows").
+    pub platform: &'static str,
+
+    /// Target PID.
+    pub pid: u32,
+
+    /// Interval between samples in milliseconds.
+    pub interval_ms: u64,
+
+    /// Open fd count at each sample, oldest first.
+    pub counts: Vec<u64>,
+
+    /// Smallest sampled count.
+    pub min: u64,
+
+    /// Largest sampled count.
+    pub max: u64,
+
+    /// Least-squares growth rate in descriptors per second.
+    pub slope_per_sec: f64,
+
+    /// Counts never decreased and the last sample exceeds the first.
+    pub likely_leaking: bool,
+
+    /// Warnings from the final sample.
+    pub warnings: Vec<Warning>,
 }
 
+/// Default cap on captured `cmdline` bytes (see [`ProcessOptions::max_cmdline_bytes`]).
+pub const DEFAULT_MAX_CMDLINE_BYTES: usize = 256 * 1024;
+
+/// Default cap on captured `cmdline` arguments (see [`ProcessOptions::max_cmdline_args`]).
+pub const DEFAULT_MAX_CMDLINE_ARGS: usize = 4096;
+
+/// Final `cmdline` element marking an argument list cut by the configured limits.
+pub const CMDLINE_TRUNCATED_SENTINEL: &str = "...(truncated)";
+
 /// Options controlling optional process detail collection.
 ///
-/// These options are additive and opt-in. Existing APIs default to both values
-/// disabled to avoid extra syscall/parse overhead.
-#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+/// The `include_*` options are additive and opt-in; existing APIs default to
+/// them disabled to avoid extra syscall/parse overhead. The `cmdline` limits
+/// default to generous caps that only affect pathological argument lists.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct ProcessOptions {
     /// Include environment variables in `ProcessInfo.env`.
@@ -264,6 +481,101 @@ pub struct ProcessOptions {
 
     /// Include thread count in `ProcessInfo.thread_count`.
     pub include_threads: bool,
+
+    /// Include cgroup membership in `ProcessInfo.cgroup`.
+    ///
+    /// Linux only; macOS and Windows leave `cgroup` as `None`.
+    pub include_cgroup: bool,
+
+    /// Include open socket count in `ProcessInfo.socket_count`.
+    ///
+    /// Cheaper than [`list_fds`]: sockets are counted, not resolved.
+    /// Windows leaves `socket_count` as `None`.
+    pub include_socket_count: bool,
+
+    /// Include network byte counters in `ProcessInfo.net_rx_bytes` and
+    /// `net_tx_bytes`.
+    ///
+    /// Linux only (from `/proc/[pid]/net/dev`); macOS and Windows leave both
+    /// as `None`.
+    pub include_net: bool,
+
+    /// Include the open-file limit in `ProcessInfo.fd_limit_soft` and
+    /// `fd_limit_hard`.
+    ///
+    /// Linux only (the "Max open files" line of `/proc/[pid]/limits`); macOS
+    /// and Windows leave both as `None`.
+    pub include_limits: bool,
+
+    /// Include signal masks in `ProcessInfo.sig_blocked`, `sig_ignored`, and
+    /// `sig_caught`.
+    ///
+    /// Linux only (`SigBlk`/`SigIgn`/`SigCgt` in `/proc/[pid]/status`); macOS
+    /// and Windows leave them as `None`.
+    pub include_signal_masks: bool,
+
+    /// Stat the executable into `ProcessInfo.exe_inode`, `exe_device`, and
+    /// `exe_deleted`.
+    ///
+    /// Windows leaves all three as `None`.
+    pub include_exe_stat: bool,
+
+    /// Include [`ProcessInfo::identity_key`] in `ProcessInfo.identity`.
+    pub include_identity: bool,
+
+    /// Include `cmdline` joined into one shell-quoted string in
+    /// `ProcessInfo.cmdline_string`.
+    pub include_cmdline_string: bool,
+
+    /// Resolve `ProcessInfo.uid` to `ProcessInfo.user` (default: `true`).
+    ///
+    /// Each lookup is a `getpwuid_r` call, which can dominate snapshot time
+    /// when it goes to a slow directory service (LDAP/AD). With `false`,
+    /// `user` is left `None` and only `uid` is reported. Filters on
+    /// `user_equals` resolve usernames regardless.
+    pub resolve_usernames: bool,
+
+    /// Keep the raw bytes of non-UTF-8 names, executable paths, and command
+    /// lines in `ProcessInfo.name_bytes`, `exe_path_bytes`, and `cmdline_bytes`.
+    ///
+    /// Linux only; the fields are set only when lossy UTF-8 conversion
+    /// actually altered the value.
+    pub preserve_raw_bytes: bool,
+
+    /// Maximum bytes of `ProcessInfo.cmdline` to keep, counting one separator
+    /// per argument.
+    ///
+    /// Longer command lines are cut (at a UTF-8 boundary) and end with
+    /// [`CMDLINE_TRUNCATED_SENTINEL`]. `None` disables the limit.
+    /// Default: [`DEFAULT_MAX_CMDLINE_BYTES`].
+    pub max_cmdline_bytes: Option<usize>,
+
+    /// Maximum number of `ProcessInfo.cmdline` arguments to keep.
+    ///
+    /// Extra arguments are dropped and replaced by [`CMDLINE_TRUNCATED_SENTINEL`].
+    /// `None` disables the limit. Default: [`DEFAULT_MAX_CMDLINE_ARGS`].
+    pub max_cmdline_args: Option<usize>,
+}
+
+impl Default for ProcessOptions {
+    fn default() -> Self {
+        Self {
+            include_env: false,
+            include_threads: false,
+            include_cgroup: false,
+            include_socket_count: false,
+            include_net: false,
+            include_limits: false,
+            include_signal_masks: false,
+            include_exe_stat: false,
+            include_identity: false,
+            include_cmdline_string: false,
+            resolve_usernames: true,
+            preserve_raw_bytes: false,
+            max_cmdline_bytes: Some(DEFAULT_MAX_CMDLINE_BYTES),
+            max_cmdline_args: Some(DEFAULT_MAX_CMDLINE_ARGS),
+        }
+    }
 }
 
 impl ProcessOptions {
@@ -278,6 +590,213 @@ impl ProcessOptions {
         self.include_threads = true;
         self
     }
+
+    /// Enable cgroup membership collection.
+    pub fn with_cgroup(mut self) -> Self {
+        self.include_cgroup = true;
+        self
+    }
+
+    /// Enable open socket counting.
+    pub fn with_socket_count(mut self) -> Self {
+        self.include_socket_count = true;
+        self
+    }
+
+    /// Enable network byte counter collection.
+    pub fn with_net(mut self) -> Self {
+        self.include_net = true;
+        self
+    }
+
+    /// Enable open-file limit collection.
+    pub fn with_limits(mut self) -> Self {
+        self.include_limits = true;
+        self
+    }
+
+    /// Enable signal mask collection.
+    pub fn with_signal_masks(mut self) -> Self {
+        self.include_signal_masks = true;
+        self
+    }
+
+    /// Enable executable inode/device collection.
+    pub fn with_exe_stat(mut self) -> Self {
+        self.include_exe_stat = true;
+        self
+    }
+
+    /// Enable the restart-stable identity key.
+    pub fn with_identity(mut self) -> Self {
+        self.include_identity = true;
+        self
+    }
+
+    /// Enable the shell-quoted `cmdline_string`.
+    pub fn with_cmdline_string(mut self) -> Self {
+        self.include_cmdline_string = true;
+        self
+    }
+
+    /// Skip username resolution, reporting only `uid`.
+    pub fn without_usernames(mut self) -> Self {
+        self.resolve_usernames = false;
+        self
+    }
+
+    /// Keep raw bytes of non-UTF-8 names, paths, and command lines.
+    pub fn with_raw_bytes(mut self) -> Self {
+        self.preserve_raw_bytes = true;
+        self
+    }
+
+    /// Set the `cmdline` byte and argument limits (`None` = unlimited).
+    pub fn with_cmdline_limits(
+        mut self,
+        max_bytes: Option<usize>,
+        max_args: Option<usize>,
+    ) -> Self {
+        self.max_cmdline_bytes = max_bytes;
+        self.max_cmdline_args = max_args;
+        self
+    }
+}
+
+/// A `cmdline` argument that [`limit_cmdline`] can cut: decoded (`String`)
+/// or raw (`Vec<u8>`, for `ProcessInfo.cmdline_bytes`).
+pub(crate) trait CmdlineArg {
+    fn byte_len(&self) -> usize;
+
+    /// Shorten to at most `max` bytes, returning the new length.
+    fn cut(&mut self, max: usize) -> usize;
+
+    fn truncated_sentinel() -> Self;
+}
+
+impl CmdlineArg for String {
+    fn byte_len(&self) -> usize {
+        self.len()
+    }
+
+    fn cut(&mut self, max: usize) -> usize {
+        let mut end = max;
+        while !self.is_char_boundary(end) {
+            end -= 1;
+        }
+        self.truncate(end);
+        end
+    }
+
+    fn truncated_sentinel() -> Self {
+        CMDLINE_TRUNCATED_SENTINEL.to_string()
+    }
+}
+
+impl CmdlineArg for Vec<u8> {
+    fn byte_len(&self) -> usize {
+        self.len()
+    }
+
+    fn cut(&mut self, max: usize) -> usize {
+        self.truncate(max);
+        max
+    }
+
+    fn truncated_sentinel() -> Self {
+        CMDLINE_TRUNCATED_SENTINEL.as_bytes().to_vec()
+    }
+}
+
+/// Cut `cmdline` to the limits in `options`, appending the truncation sentinel.
+///
+/// Returns `true` when the command line was truncated.
+pub(crate) fn limit_cmdline<A: CmdlineArg>(cmdline: &mut Vec<A>, options: &ProcessOptions) -> bool {
+    let max_args = options.max_cmdline_args.unwrap_or(usize::MAX);
+    let max_bytes = options.max_cmdline_bytes.unwrap_or(usize::MAX);
+
+    let mut used = 0usize;
+    let mut keep = None;
+    for (i, arg) in cmdline.iter_mut().enumerate() {
+        if i >= max_args {
+            keep = Some(i);
+            break;
+        }
+        let remaining = max_bytes.saturating_sub(used);
+        if arg.byte_len() > remaining {
+            let end = arg.cut(remaining);
+            keep = Some(if end == 0 { i } else { i + 1 });
+            break;
+        }
+        used = used.saturating_add(arg.byte_len() + 1);
+    }
+
+    match keep {
+        Some(len) => {
+            cmdline.truncate(len);
+            cmdline.push(A::truncated_sentinel());
+            true
+        }
+        None => false,
+    }
+}
+
+/// Add a snapshot warning when any process had its `cmdline` truncated.
+fn note_truncated_cmdlines(snap: &mut ProcessSnapshot) {
+    let truncated = snap
+        .processes
+        .iter()
+        .filter(|p| p.cmdline.last().map(String::as_str) == Some(CMDLINE_TRUNCATED_SENTINEL))
+        .count();
+    if truncated > 0 {
+        snap.warnings.push(Warning::new(
+            WarningCode::Truncated,
+            format!(
+                "Truncated cmdline of {} processes to the configured cmdline limits",
+                truncated
+            ),
+        ));
+    }
+}
+
+/// Configuration for [`snapshot_with_config`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SnapshotConfig {
+    /// Maximum time to spend reading a single process.
+    ///
+    /// When set, each PID is read on a pooled reader thread; a PID whose read
+    /// exceeds the budget is skipped and reported in `ProcessSnapshot::warnings`.
+    /// `None` reads inline with no deadline (same as [`snapshot`]).
+    pub per_process_timeout: Option<Duration>,
+
+    /// Optional extended fields to collect.
+    pub options: ProcessOptions,
+
+    /// Only enumerate processes owned by this user ID (Unix only).
+    ///
+    /// Other users' processes are skipped on their owner alone (the
+    /// `/proc/[pid]` directory owner on Linux, `pbi_uid` on macOS) before any
+    /// per-process reads, so unprivileged tools watching their own processes
+    /// avoid the cost of scanning everyone else's. Rejected on Windows.
+    pub uid: Option<u32>,
+}
+
+impl SnapshotConfig {
+    /// Validate configuration values.
+    pub fn validate(&self) -> SysprimsResult<()> {
+        if self.per_process_timeout == Some(Duration::ZERO) {
+            return Err(SysprimsError::invalid_argument(
+                "per_process_timeout must be > 0",
+            ));
+        }
+        if cfg!(windows) && self.uid.is_some() {
+            return Err(SysprimsError::not_supported(
+                "uid-scoped snapshot",
+                "windows",
+            ));
+        }
+        validate_process_options(&self.options)
+    }
 }
 
 /// Safety caps for environment collection when proc_ext is enabled.
@@ -305,16 +824,45 @@ pub struct ProcessInfo {
     /// Process name (executable name, max 255 chars).
     pub name: String,
 
-    /// Owner username (None if unavailable due to permissions).
+    /// Raw bytes of `name` when it is not valid UTF-8 (Linux only, opt-in
+    /// via `ProcessOptions`).
+    ///
+    /// `name` holds a lossy conversion with U+FFFD replacement characters;
+    /// these are the true bytes. Base64 (standard, padded) in JSON.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_base64"
+    )]
+    pub name_bytes: Option<Vec<u8>>,
+
+    /// Owner username (None if unavailable due to permissions, or when
+    /// `ProcessOptions::resolve_usernames` is off).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 
+    /// Owner user ID (Unix only; `None` on Windows or when unreadable).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uid: Option<u32>,
+
     /// CPU usage normalized 0-100 across all cores.
     ///
     /// Note: This is an instantaneous value and may be 0 for short-lived
-    /// processes or processes that were just started.
+    /// processes or processes that were just started. Lifetime values are
+    /// clamped to 0-100; sampled values are per-core and may exceed 100 (see
+    /// [`ProcessSnapshot::cpu_percent_is_normalized`]).
     pub cpu_percent: f64,
 
+    /// Total CPU time spent in user mode, in nanoseconds, when available.
+    ///
+    /// With `cpu_system_ns`, splits [`cpu_total_time_ns`] into user and
+    /// kernel time. Linux resolution is one clock tick (usually 10ms).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu_user_ns: Option<u64>,
+
+    /// Total CPU time spent in kernel mode, in nanoseconds, when available.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu_system_ns: Option<u64>,
+
     /// Memory usage in kilobytes.
     pub memory_kb: u64,
 
@@ -327,12 +875,45 @@ pub struct ProcessInfo {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub start_time_unix_ms: Option<u64>,
 
+    /// Process start time as RFC3339 (UTC), derived from `start_time_unix_ms`.
+    ///
+    /// Present whenever `start_time_unix_ms` is.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<String>,
+
     /// Executable path (absolute), when available.
     ///
     /// Best-effort: omitted if the platform cannot provide it or access is denied.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub exe_path: Option<String>,
 
+    /// Raw bytes of `exe_path` when it is not valid UTF-8 (see
+    /// [`ProcessInfo::name_bytes`]).
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_base64"
+    )]
+    pub exe_path_bytes: Option<Vec<u8>>,
+
+    /// Inode of the running executable (Unix, opt-in via `ProcessOptions`).
+    ///
+    /// Compare across snapshots, or against a fresh `stat` of `exe_path`, to
+    /// detect a binary replaced on disk while the process keeps running.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exe_inode: Option<u64>,
+
+    /// Device ID of the filesystem holding the executable (Unix, opt-in via `ProcessOptions`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exe_device: Option<u64>,
+
+    /// Whether the executable was deleted or replaced since the process started
+    /// (opt-in via `ProcessOptions`).
+    ///
+    /// Linux reads the `(deleted)` marker on `/proc/[pid]/exe`; macOS reports
+    /// `true` when `exe_path` no longer exists. `None` when unknown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exe_deleted: Option<bool>,
+
     /// Process state.
     pub state: ProcessState,
 
@@ -341,6 +922,25 @@ pub struct ProcessInfo {
     /// May be empty if command line cannot be read (permissions, zombie process).
     pub cmdline: Vec<String>,
 
+    /// Raw bytes of every argument when any is not valid UTF-8 (see
+    /// [`ProcessInfo::name_bytes`]).
+    ///
+    /// Cut to the same `cmdline` limits, in raw bytes, and ending with
+    /// [`CMDLINE_TRUNCATED_SENTINEL`] when truncated.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_base64_list"
+    )]
+    pub cmdline_bytes: Option<Vec<Vec<u8>>>,
+
+    /// `cmdline` joined with spaces, POSIX shell-quoted (opt-in via `ProcessOptions`).
+    ///
+    /// Arguments containing spaces or shell metacharacters are single-quoted,
+    /// so the string is suitable for display and for pasting into `sh`. The
+    /// same quoting is used on every platform.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cmdline_string: Option<String>,
+
     /// Process environment variables (best-effort, opt-in via `ProcessOptions`).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub env: Option<BTreeMap<String, String>>,
@@ -348,61 +948,283 @@ pub struct ProcessInfo {
     /// Thread count (best-effort, opt-in via `ProcessOptions`).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thread_count: Option<u32>,
-}
 
-/// Process state.
-///
-/// Maps platform-specific states to a common enum.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum ProcessState {
-    /// Process is running or runnable.
-    Running,
-    /// Process is sleeping (interruptible or uninterruptible).
-    Sleeping,
-    /// Process is stopped (e.g., by a signal).
-    Stopped,
-    /// Process is a zombie (terminated but not reaped).
-    Zombie,
-    /// Process state could not be determined.
-    Unknown,
-}
+    /// Cgroup path (Linux only, opt-in via `ProcessOptions`).
+    ///
+    /// Taken from the cgroup v2 unified entry (`0::/...`) in `/proc/[pid]/cgroup`,
+    /// falling back to the v1 `name=systemd` hierarchy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cgroup: Option<String>,
 
-/// Filter for process queries.
-///
-/// All fields are optional. Processes must match ALL specified criteria (AND logic).
-/// Unknown fields in JSON input will result in `InvalidArgument` error.
-#[derive(Debug, Clone, Default, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub struct ProcessFilter {
-    /// Filter by process name substring (case-insensitive).
-    pub name_contains: Option<String>,
+    /// Number of open socket descriptors (best-effort, opt-in via `ProcessOptions`).
+    ///
+    /// Omitted on Windows and when the fd table cannot be read (permissions).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub socket_count: Option<u64>,
 
-    /// Filter by exact process name (case-sensitive).
-    pub name_equals: Option<String>,
+    /// Bytes received in the process's network namespace (Linux only, opt-in
+    /// via `ProcessOptions`).
+    ///
+    /// Summed over the non-loopback interfaces in `/proc/[pid]/net/dev`. This
+    /// is namespace-wide traffic, not traffic attributable to the process
+    /// alone: processes sharing a netns (e.g. everything on the host
+    /// namespace) report the same totals. Useful per container or per
+    /// service that runs in its own namespace.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub net_rx_bytes: Option<u64>,
 
-    /// Filter by owner username (exact match).
-    pub user_equals: Option<String>,
+    /// Bytes transmitted in the process's network namespace (see
+    /// [`ProcessInfo::net_rx_bytes`]).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub net_tx_bytes: Option<u64>,
 
-    /// Filter to specific PIDs.
-    pub pid_in: Option<Vec<u32>>,
+    /// Soft `RLIMIT_NOFILE` (Linux only, opt-in via `ProcessOptions`).
+    ///
+    /// Compare with `socket_count` or [`count_fds`] to see how close the
+    /// process is to `EMFILE`. An unlimited limit is reported as `u64::MAX`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fd_limit_soft: Option<u64>,
 
-    /// Filter by parent PID.
-    pub ppid: Option<u32>,
+    /// Hard `RLIMIT_NOFILE` (see [`ProcessInfo::fd_limit_soft`]).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fd_limit_hard: Option<u64>,
 
-    /// Filter by process state.
-    pub state_in: Option<Vec<ProcessState>>,
+    /// Blocked signals as a bitmask, bit `n - 1` for signal `n` (Linux only,
+    /// opt-in via `ProcessOptions`). Test with [`signal_is_blocked`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sig_blocked: Option<u64>,
 
-    /// Filter by minimum CPU usage (0-100).
-    pub cpu_above: Option<f64>,
+    /// Ignored signals (`SIG_IGN`), same layout as `sig_blocked`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sig_ignored: Option<u64>,
 
-    /// Filter by minimum memory usage in KB.
-    pub memory_above_kb: Option<u64>,
+    /// Signals with an installed handler, same layout as `sig_blocked`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sig_caught: Option<u64>,
 
-    /// Filter by minimum process age in seconds.
-    ///
-    /// Uses `elapsed_seconds` (best-effort, already cross-platform).
+    /// Session ID (Unix only; `None` on Windows or when unreadable).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sid: Option<u32>,
+
+    /// Restart-stable identity (see [`ProcessInfo::identity_key`]; opt-in via `ProcessOptions`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity: Option<String>,
+}
+
+impl ProcessInfo {
+    /// Stable key for the logical service this process runs.
+    ///
+    /// Unlike the PID, the key survives restarts: two processes started from
+    /// the same executable with the same arguments share it on every platform.
+    ///
+    /// The key is the 64-bit FNV-1a hash, as 16 lowercase hex digits, of
+    /// `exe_path` (or `name` when absent) followed by the `cmdline` arguments
+    /// sorted bytewise, each field terminated by a NUL byte. Sorting makes the
+    /// key independent of argument order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// // Group restarts of the same service across snapshots.
+    /// let info = sysprims_proc::get_self().unwrap();
+    /// println!("{} -> {}", info.pid, info.identity_key());
+    /// ```
+    pub fn identity_key(&self) -> String {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let mut args: Vec<&str> = self.cmdline.iter().map(String::as_str).collect();
+        args.sort_unstable();
+        let exe = self.exe_path.as_deref().unwrap_or(&self.name);
+
+        let mut hash = FNV_OFFSET_BASIS;
+        for field in std::iter::once(exe).chain(args) {
+            for byte in field.bytes().chain(std::iter::once(0)) {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(FNV_PRIME);
+            }
+        }
+        format!("{hash:016x}")
+    }
+
+    /// Coarse age class of this process, derived from `elapsed_seconds`.
+    ///
+    /// See [`AgeBucket`] for the boundaries.
+    pub fn age_bucket(&self) -> AgeBucket {
+        AgeBucket::from_elapsed_secs(self.elapsed_seconds)
+    }
+}
+
+/// Coarse process age class for triage, from [`ProcessInfo::age_bucket`].
+///
+/// Lower bounds are inclusive: a process exactly one minute old is `Recent`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AgeBucket {
+    /// Under one minute.
+    JustStarted,
+    /// One minute up to (not including) one hour.
+    Recent,
+    /// One hour up to (not including) one day.
+    Established,
+    /// One day or more.
+    LongRunning,
+}
+
+impl AgeBucket {
+    /// Bucket for a process that has been running for `secs` seconds.
+    pub fn from_elapsed_secs(secs: u64) -> Self {
+        match secs {
+            0..60 => AgeBucket::JustStarted,
+            60..3_600 => AgeBucket::Recent,
+            3_600..86_400 => AgeBucket::Established,
+            _ => AgeBucket::LongRunning,
+        }
+    }
+}
+
+/// Fill the fields derived from other `ProcessInfo` fields (`identity`,
+/// `cmdline_string`) when `options` asks for them.
+pub(crate) fn with_derived_fields(mut info: ProcessInfo, options: &ProcessOptions) -> ProcessInfo {
+    if options.include_identity {
+        info.identity = Some(info.identity_key());
+    }
+    if options.include_cmdline_string {
+        info.cmdline_string = Some(join_cmdline(&info.cmdline));
+    }
+    info
+}
+
+/// Raw byte fields are standard (RFC 4648, padded) base64 in JSON.
+fn serialize_base64<S: serde::Serializer>(
+    value: &Option<Vec<u8>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(bytes) => serializer.serialize_str(&BASE64_STANDARD.encode(bytes)),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn serialize_base64_list<S: serde::Serializer>(
+    value: &Option<Vec<Vec<u8>>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(items) => serializer.collect_seq(items.iter().map(|b| BASE64_STANDARD.encode(b))),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Join `args` with spaces, single-quoting any argument a POSIX shell would split
+/// or expand.
+fn join_cmdline(args: &[String]) -> String {
+    fn is_plain(c: char) -> bool {
+        c.is_ascii_alphanumeric() || "@%+=:,./_-".contains(c)
+    }
+
+    let quoted: Vec<String> = args
+        .iter()
+        .map(|arg| {
+            if !arg.is_empty() && arg.chars().all(is_plain) {
+                arg.clone()
+            } else {
+                format!("'{}'", arg.replace('\'', r"'\''"))
+            }
+        })
+        .collect();
+    quoted.join(" ")
+}
+
+/// Process state.
+///
+/// Maps platform-specific states to a common enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessState {
+    /// Process is running or runnable.
+    Running,
+    /// Process is sleeping (interruptible).
+    Sleeping,
+    /// Process is in uninterruptible sleep, usually waiting on I/O (Linux `D`).
+    ///
+    /// Signals, including `SIGKILL`, take effect only once the wait ends.
+    DiskSleep,
+    /// Process is stopped (e.g., by a signal).
+    Stopped,
+    /// Process is a zombie (terminated but not reaped).
+    Zombie,
+    /// Process state could not be determined.
+    Unknown,
+}
+
+/// Filter for process queries.
+///
+/// All fields are optional. Processes must match ALL specified criteria (AND logic).
+/// Unknown fields in JSON input will result in `InvalidArgument` error.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProcessFilter {
+    /// Filter by process name substring (case-insensitive).
+    pub name_contains: Option<String>,
+
+    /// Filter by exact process name (case-sensitive).
+    pub name_equals: Option<String>,
+
+    /// Filter by glob pattern on the process name (case-sensitive).
+    ///
+    /// Supports `*` and `?`. Matches against `ProcessInfo.name` only, not the
+    /// full command line; e.g. `worker-?` matches `worker-1` but not `worker-backup`.
+    pub name_glob: Option<String>,
+
+    /// Filter by owner username (exact match).
+    pub user_equals: Option<String>,
+
+    /// Filter to specific PIDs.
+    pub pid_in: Option<Vec<u32>>,
+
+    /// Filter by parent PID.
+    pub ppid: Option<u32>,
+
+    /// Filter by process state.
+    pub state_in: Option<Vec<ProcessState>>,
+
+    /// Filter by minimum CPU usage (0-100).
+    pub cpu_above: Option<f64>,
+
+    /// Filter by minimum memory usage in KB.
+    pub memory_above_kb: Option<u64>,
+
+    /// Filter by minimum process age in seconds.
+    ///
+    /// Uses `elapsed_seconds` (best-effort, already cross-platform).
     pub running_for_at_least_secs: Option<u64>,
+
+    /// Filter by coarse age class (see [`AgeBucket`]).
+    ///
+    /// Uses `elapsed_seconds`, like `running_for_at_least_secs`.
+    pub age_bucket_in: Option<Vec<AgeBucket>>,
+
+    /// Drop Linux kernel threads (`[kworker/0:1]` and friends).
+    ///
+    /// A kernel thread is kthreadd (PID 2) or any child of it with an empty
+    /// cmdline. No-op on macOS and Windows.
+    #[serde(default)]
+    pub exclude_kernel_threads: bool,
+
+    /// Filter by cgroup path substring (case-sensitive).
+    ///
+    /// Use a container ID or systemd unit name to target one container's
+    /// processes. Processes without a cgroup (macOS, Windows) never match.
+    pub cgroup_contains: Option<String>,
+
+    /// Keep only processes running from a deleted or replaced executable.
+    ///
+    /// Linux only, via the `(deleted)` marker on `/proc/[pid]/exe`; processes
+    /// whose executable link is unreadable (permissions) never match. Matches
+    /// nothing on macOS and Windows.
+    #[serde(default)]
+    pub exe_deleted_only: bool,
 }
 
 impl ProcessFilter {
@@ -410,6 +1232,9 @@ impl ProcessFilter {
     ///
     /// Returns an error if any values are out of range.
     pub fn validate(&self) -> SysprimsResult<()> {
+        if let Some(ref pattern) = self.name_glob {
+            sysprims_core::glob::validate_glob(pattern)?;
+        }
         if let Some(cpu) = self.cpu_above {
             if !(0.0..=100.0).contains(&cpu) {
                 return Err(SysprimsError::invalid_argument(
@@ -421,6 +1246,51 @@ impl ProcessFilter {
     }
 }
 
+/// Whether `proc` runs from an executable that was deleted after it started.
+///
+/// Linux only: other platforms have no equivalent marker on the exe path.
+fn runs_deleted_exe(proc: &ProcessInfo) -> bool {
+    #[cfg(target_os = "linux")]
+    {
+        proc.exe_path
+            .as_deref()
+            .is_some_and(linux::is_deleted_exe_link)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = proc;
+        false
+    }
+}
+
+/// Whether `proc` is a Linux kernel thread.
+///
+/// Every kernel thread is spawned by kthreadd (PID 2), so checking the direct
+/// parent is enough; the empty cmdline excludes userspace children of PID 2.
+fn is_kernel_thread(proc: &ProcessInfo) -> bool {
+    const KTHREADD_PID: u32 = 2;
+    cfg!(target_os = "linux")
+        && proc.cmdline.is_empty()
+        && (proc.pid == KTHREADD_PID || proc.ppid == KTHREADD_PID)
+}
+
+/// Reject PID 0 and PIDs above `i32::MAX`.
+///
+/// Larger values turn negative when cast to `pid_t` on Unix (ADR-0011).
+fn validate_pid(pid: u32) -> SysprimsResult<()> {
+    const MAX_SAFE_PID: u32 = i32::MAX as u32;
+    if pid == 0 {
+        return Err(SysprimsError::invalid_argument("PID 0 is not valid"));
+    }
+    if pid > MAX_SAFE_PID {
+        return Err(SysprimsError::invalid_argument(format!(
+            "PID {} exceeds maximum safe value {}",
+            pid, MAX_SAFE_PID
+        )));
+    }
+    Ok(())
+}
+
 fn validate_process_options(options: &ProcessOptions) -> SysprimsResult<()> {
     #[cfg(feature = "proc_ext")]
     {
@@ -449,6 +1319,13 @@ impl PortFilter {
                 ));
             }
         }
+        if let Some(ref name) = self.process_name_contains {
+            if name.is_empty() {
+                return Err(SysprimsError::invalid_argument(
+                    "process_name_contains must not be empty",
+                ));
+            }
+        }
         Ok(())
     }
 
@@ -483,6 +1360,13 @@ impl PortBinding {
             }
         }
 
+        if let Some(ref pattern) = filter.process_name_contains {
+            match &self.process {
+                Some(proc) if proc.name.to_lowercase().contains(&pattern.to_lowercase()) => {}
+                _ => return false,
+            }
+        }
+
         true
     }
 }
@@ -504,6 +1388,23 @@ impl ProcessFilter {
             }
         }
 
+        // Name glob (case-sensitive)
+        if let Some(ref pattern) = self.name_glob {
+            if !sysprims_core::glob::glob_match(pattern, &proc.name) {
+                return false;
+            }
+        }
+
+        // Kernel threads (Linux only)
+        if self.exclude_kernel_threads && is_kernel_thread(proc) {
+            return false;
+        }
+
+        // Deleted executable (Linux only)
+        if self.exe_deleted_only && !runs_deleted_exe(proc) {
+            return false;
+        }
+
         // User equals
         if let Some(ref user) = self.user_equals {
             match &proc.user {
@@ -554,6 +1455,21 @@ impl ProcessFilter {
             }
         }
 
+        // Age bucket in list
+        if let Some(ref buckets) = self.age_bucket_in {
+            if !buckets.contains(&proc.age_bucket()) {
+                return false;
+            }
+        }
+
+        // Cgroup path substring
+        if let Some(ref pattern) = self.cgroup_contains {
+            match &proc.cgroup {
+                Some(cgroup) if cgroup.contains(pattern.as_str()) => {}
+                _ => return false,
+            }
+        }
+
         true
     }
 }
@@ -593,7 +1509,174 @@ pub fn snapshot() -> SysprimsResult<ProcessSnapshot> {
 /// ```
 pub fn snapshot_with_options(options: ProcessOptions) -> SysprimsResult<ProcessSnapshot> {
     validate_process_options(&options)?;
-    platform::snapshot_impl(&options)
+    let mut snap = platform::snapshot_impl(&options)?;
+    note_truncated_cmdlines(&mut snap);
+    Ok(snap)
+}
+
+/// Get a snapshot of all processes, bounding the time spent on each PID.
+///
+/// Reading `/proc/[pid]/*` (or libproc) can block indefinitely on wedged or
+/// NFS-backed systems. With `per_process_timeout` set, reads run on a small
+/// process-wide pool of reader threads; a PID whose read overruns is skipped
+/// with a warning and enumeration continues on another reader. A stuck reader
+/// rejoins the pool when its read returns, so repeated calls never accumulate
+/// threads. If every reader is stuck, the remaining PIDs are skipped with a
+/// warning.
+///
+/// Windows enumerates from a single Toolhelp32 snapshot with no per-process
+/// reads, so the timeout does not apply there.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use sysprims_proc::{snapshot_with_config, SnapshotConfig};
+///
+/// // Replaces: timeout 5 ps -e (which loses everything on one stuck process)
+/// let snap = snapshot_with_config(SnapshotConfig {
+///     per_process_timeout: Some(Duration::from_millis(500)),
+///     ..Default::default()
+/// })
+/// .unwrap();
+/// for w in &snap.warnings {
+///     eprintln!("warning: {w}");
+/// }
+/// ```
+pub fn snapshot_with_config(config: SnapshotConfig) -> SysprimsResult<ProcessSnapshot> {
+    config.validate()?;
+    let mut snap = match (config.per_process_timeout, config.uid) {
+        (Some(budget), uid) => bounded_snapshot(config.options, uid, Some(budget), None)?.0,
+        (None, Some(uid)) => snapshot_for_uid(uid, &config.options)?,
+        (None, None) => platform::snapshot_impl(&config.options)?,
+    };
+    note_truncated_cmdlines(&mut snap);
+    Ok(snap)
+}
+
+/// Visible PIDs, limited to those owned by `uid` when set.
+#[cfg(unix)]
+fn candidate_pids(uid: Option<u32>) -> SysprimsResult<Vec<u32>> {
+    let mut pids = platform::list_pids_impl()?;
+    if let Some(uid) = uid {
+        pids.retain(|&pid| platform::owner_uid_impl(pid) == Some(uid));
+    }
+    Ok(pids)
+}
+
+#[cfg(unix)]
+fn snapshot_for_uid(uid: u32, options: &ProcessOptions) -> SysprimsResult<ProcessSnapshot> {
+    let processes = candidate_pids(Some(uid))?
+        .into_iter()
+        // Silently skip processes we can't read (same as snapshot()).
+        .filter_map(|pid| platform::get_process_impl(pid, options).ok())
+        .collect();
+    Ok(make_snapshot(processes))
+}
+
+#[cfg(windows)]
+fn snapshot_for_uid(_uid: u32, options: &ProcessOptions) -> SysprimsResult<ProcessSnapshot> {
+    // Unreachable: SnapshotConfig::validate rejects uid on Windows.
+    platform::snapshot_impl(options)
+}
+
+/// Snapshot processes with optional per-PID and overall time bounds.
+///
+/// The single bounded-enumeration path behind [`snapshot_with_config`] and
+/// deadline-limited descendants traversal. Each read runs on the shared
+/// reader pool, so neither a slow PID nor a hung one holds the caller past
+/// `per_process` or `deadline`. Returns the snapshot and whether `deadline`
+/// cut it short.
+#[cfg(unix)]
+fn bounded_snapshot(
+    options: ProcessOptions,
+    uid: Option<u32>,
+    per_process: Option<Duration>,
+    deadline: Option<Instant>,
+) -> SysprimsResult<(ProcessSnapshot, bool)> {
+    let pids = candidate_pids(uid)?;
+    let mut processes = Vec::new();
+    let mut timed_out: Vec<u32> = Vec::new();
+    let mut unread = 0;
+    let mut truncated = false;
+
+    for (i, &pid) in pids.iter().enumerate() {
+        let remaining = deadline.map(|d| d.saturating_duration_since(Instant::now()));
+        if remaining.is_some_and(|r| r.is_zero()) {
+            truncated = true;
+            break;
+        }
+        let budget = match (per_process, remaining) {
+            (Some(per_process), Some(remaining)) => per_process.min(remaining),
+            (Some(budget), None) | (None, Some(budget)) => budget,
+            (None, None) => {
+                // Silently skip processes we can't read (same as snapshot()).
+                processes.extend(platform::get_process_impl(pid, &options).ok());
+                continue;
+            }
+        };
+
+        let read = move || platform::get_process_impl(pid, &options);
+        match reader::READERS.read_within(budget, read) {
+            BoundedRead::Done(result) => processes.extend(result.ok()),
+            BoundedRead::TimedOut if deadline.is_some_and(|d| Instant::now() >= d) => {
+                truncated = true;
+                break;
+            }
+            BoundedRead::TimedOut => timed_out.push(pid),
+            BoundedRead::NoReader => {
+                unread = pids.len() - i;
+                break;
+            }
+        }
+    }
+
+    let mut snap = make_snapshot(processes);
+    if let (false, Some(budget)) = (timed_out.is_empty(), per_process) {
+        snap.warnings.push(Warning::new(
+            WarningCode::PartialVisibility,
+            format!(
+                "Skipped {} processes whose reads exceeded {}ms: {:?}",
+                timed_out.len(),
+                budget.as_millis(),
+                timed_out
+            ),
+        ));
+    }
+    if unread > 0 {
+        snap.warnings.push(Warning::new(
+            WarningCode::PartialVisibility,
+            format!(
+                "Skipped {} processes: all {} snapshot readers are blocked in earlier reads",
+                unread,
+                reader::MAX_READERS
+            ),
+        ));
+    }
+    Ok((snap, truncated))
+}
+
+/// Windows enumerates from one Toolhelp32 snapshot with no per-process reads,
+/// so `per_process` does not apply; the whole enumeration is bounded by
+/// `deadline` instead.
+#[cfg(windows)]
+fn bounded_snapshot(
+    options: ProcessOptions,
+    _uid: Option<u32>,
+    _per_process: Option<Duration>,
+    deadline: Option<Instant>,
+) -> SysprimsResult<(ProcessSnapshot, bool)> {
+    let Some(deadline) = deadline else {
+        return Ok((platform::snapshot_impl(&options)?, false));
+    };
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    if remaining.is_zero() {
+        return Ok((make_snapshot(Vec::new()), true));
+    }
+    match reader::READERS.read_within(remaining, move || platform::snapshot_impl(&options)) {
+        BoundedRead::Done(snap) => Ok((snap?, false)),
+        BoundedRead::TimedOut | BoundedRead::NoReader => Ok((make_snapshot(Vec::new()), true)),
+    }
 }
 
 /// Get total CPU time consumed by a process (kernel + user) in nanoseconds.
@@ -628,18 +1711,45 @@ pub fn cpu_total_time_ns(pid: u32) -> SysprimsResult<u64> {
 /// let filter = PortFilter {
 ///     protocol: Some(Protocol::Tcp),
 ///     local_port: Some(8080),
+///     ..Default::default()
 /// };
 /// let snap = listening_ports(Some(&filter)).unwrap();
 /// println!("bindings: {}", snap.bindings.len());
 /// ```
 pub fn listening_ports(filter: Option<&PortFilter>) -> SysprimsResult<PortBindingsSnapshot> {
+    listening_ports_with_options(filter, ProcessOptions::default())
+}
+
+/// Get a snapshot of listening ports, with the owning process's details
+/// gathered per `options`.
+///
+/// Each binding's embedded `process` carries the same optional fields a
+/// direct [`get_process_with_options`] call would return. [`listening_ports`]
+/// uses [`ProcessOptions::default`], the cheap set.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sysprims_proc::{listening_ports_with_options, ProcessOptions};
+///
+/// let options = ProcessOptions::default().with_identity();
+/// let snap = listening_ports_with_options(None, options).unwrap();
+/// for binding in &snap.bindings {
+///     if let Some(process) = &binding.process {
+///         println!("{} {:?}", binding.local_port, process.identity);
+///     }
+/// }
+/// ```
+pub fn listening_ports_with_options(
+    filter: Option<&PortFilter>,
+    options: ProcessOptions,
+) -> SysprimsResult<PortBindingsSnapshot> {
     let filter = filter.cloned().unwrap_or_default();
     filter.validate()?;
+    validate_process_options(&options)?;
 
-    let mut snapshot = platform::listening_ports_impl()?;
-    if filter.protocol.is_some() || filter.local_port.is_some() {
-        snapshot.bindings.retain(|binding| binding.matches(&filter));
-    }
+    let mut snapshot = platform::listening_ports_impl(&options)?;
+    snapshot.bindings.retain(|binding| binding.matches(&filter));
 
     if snapshot.bindings.is_empty() && snapshot.warnings.is_empty() {
         let platform = get_platform();
@@ -649,6 +1759,56 @@ pub fn listening_ports(filter: Option<&PortFilter>) -> SysprimsResult<PortBindin
     Ok(snapshot)
 }
 
+/// List the listening sockets owned by a single PID.
+///
+/// Cheaper than [`listening_ports`] followed by a PID filter: only that
+/// process's socket fds are inspected.
+/// - Linux: matches `/proc/<pid>/fd` socket inodes against the tables in
+///   `/proc/<pid>/net` (so processes in other network namespaces work too).
+/// - macOS: reads only that PID's socket fds via libproc.
+/// - Windows: filters the owner-PID TCP/UDP tables.
+///
+/// Unlike [`listening_ports`], an empty result is not an error: the process
+/// may simply not be listening.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sysprims_proc::listening_ports_for_pid;
+///
+/// // Replaces: lsof -nP -a -p 1234 -iTCP -sTCP:LISTEN
+/// let snap = listening_ports_for_pid(1234, None).unwrap();
+/// for binding in &snap.bindings {
+///     println!("{:?} {}", binding.protocol, binding.local_port);
+/// }
+/// ```
+pub fn listening_ports_for_pid(
+    pid: u32,
+    filter: Option<&PortFilter>,
+) -> SysprimsResult<PortBindingsSnapshot> {
+    listening_ports_for_pid_with_options(pid, filter, ProcessOptions::default())
+}
+
+/// List the listening sockets owned by a single PID, with the embedded
+/// process details gathered per `options`.
+///
+/// See [`listening_ports_for_pid`] and [`listening_ports_with_options`].
+pub fn listening_ports_for_pid_with_options(
+    pid: u32,
+    filter: Option<&PortFilter>,
+    options: ProcessOptions,
+) -> SysprimsResult<PortBindingsSnapshot> {
+    validate_pid(pid)?;
+
+    let filter = filter.cloned().unwrap_or_default();
+    filter.validate()?;
+    validate_process_options(&options)?;
+
+    let mut snapshot = platform::listening_ports_for_pid_impl(pid, &options)?;
+    snapshot.bindings.retain(|binding| binding.matches(&filter));
+    Ok(snapshot)
+}
+
 /// List open file descriptors for a PID.
 ///
 /// Best-effort cross-platform behavior:
@@ -665,17 +1825,7 @@ pub fn listening_ports(filter: Option<&PortFilter>) -> SysprimsResult<PortBindin
 /// println!("fd count: {}", snap.fds.len());
 /// ```
 pub fn list_fds(pid: u32, filter: Option<&FdFilter>) -> SysprimsResult<FdSnapshot> {
-    // Safety: avoid negative pid_t casting semantics on Unix.
-    const MAX_SAFE_PID: u32 = i32::MAX as u32;
-    if pid == 0 {
-        return Err(SysprimsError::invalid_argument("PID 0 is not valid"));
-    }
-    if pid > MAX_SAFE_PID {
-        return Err(SysprimsError::invalid_argument(format!(
-            "PID {} exceeds maximum safe value {}",
-            pid, MAX_SAFE_PID
-        )));
-    }
+    validate_pid(pid)?;
 
     let filter = filter.cloned().unwrap_or_default();
     filter.validate()?;
@@ -687,24 +1837,149 @@ pub fn list_fds(pid: u32, filter: Option<&FdFilter>) -> SysprimsResult<FdSnapsho
 
     // Best-effort: provide a helpful warning if nothing visible.
     if fds.is_empty() {
-        warnings.push("No file descriptors visible".to_string());
+        warnings.push(Warning::new(
+            WarningCode::Info,
+            "No file descriptors visible",
+        ));
     }
 
     Ok(make_fd_snapshot(pid, fds, warnings))
 }
 
-/// Resolve a process by port and protocol.
+/// Count open file descriptors for a PID.
+///
+/// Same platform support and validation as [`list_fds`].
 ///
 /// # Examples
 ///
 /// ```rust,no_run
-/// use sysprims_proc::Protocol;
+/// // Replaces: ls /proc/<pid>/fd | wc -l
+/// let n = sysprims_proc::count_fds(std::process::id()).unwrap();
+/// println!("open fds: {n}");
+/// ```
+pub fn count_fds(pid: u32) -> SysprimsResult<u64> {
+    Ok(list_fds(pid, None)?.fds.len() as u64)
+}
+
+/// Sample a PID's open fd count `samples` times, `interval` apart.
+///
+/// Reports min/max, a least-squares slope, and `likely_leaking` when the count
+/// climbs without ever dropping. Takes `(samples - 1) * interval` to run.
+///
+/// Returns `InvalidArgument` if `samples < 2` or `interval` is zero, and
+/// propagates errors from [`list_fds`] (e.g. the process exiting mid-watch).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// // Replaces: while sleep 1; do ls /proc/<pid>/fd | wc -l; done
+/// let report = sysprims_proc::fd_growth(1234, 10, Duration::from_secs(1)).unwrap();
+/// println!("leaking={} slope={:.2}/s", report.likely_leaking, report.slope_per_sec);
+/// ```
+pub fn fd_growth(pid: u32, samples: u32, interval: Duration) -> SysprimsResult<FdGrowthReport> {
+    if samples < 2 {
+        return Err(SysprimsError::invalid_argument(
+            "fd_growth requires at least 2 samples",
+        ));
+    }
+    if interval.is_zero() {
+        return Err(SysprimsError::invalid_argument(
+            "fd_growth interval must be > 0",
+        ));
+    }
+
+    let mut counts = Vec::with_capacity(samples as usize);
+    let mut warnings = Vec::new();
+    for i in 0..samples {
+        if i > 0 {
+            std::thread::sleep(interval);
+        }
+        let snapshot = list_fds(pid, None)?;
+        counts.push(snapshot.fds.len() as u64);
+        warnings = snapshot.warnings;
+    }
+
+    let min = counts.iter().copied().min().unwrap_or(0);
+    let max = counts.iter().copied().max().unwrap_or(0);
+    Ok(FdGrowthReport {
+        schema_id: FD_GROWTH_REPORT_V1,
+        timestamp: current_timestamp(),
+        platform: get_platform(),
+        pid,
+        interval_ms: interval.as_millis() as u64,
+        slope_per_sec: fd_count_slope(&counts, interval),
+        likely_leaking: fd_counts_climbing(&counts),
+        counts,
+        min,
+        max,
+        warnings,
+    })
+}
+
+/// Least-squares slope of evenly spaced samples, in units per second.
+fn fd_count_slope(counts: &[u64], interval: Duration) -> f64 {
+    let n = counts.len() as f64;
+    let mean_x = (n - 1.0) / 2.0;
+    let mean_y = counts.iter().map(|&c| c as f64).sum::<f64>() / n;
+    let (mut num, mut den) = (0.0, 0.0);
+    for (i, &c) in counts.iter().enumerate() {
+        let dx = i as f64 - mean_x;
+        num += dx * (c as f64 - mean_y);
+        den += dx * dx;
+    }
+    if den == 0.0 {
+        return 0.0;
+    }
+    num / den / interval.as_secs_f64()
+}
+
+/// Whether counts never decrease and end higher than they started.
+fn fd_counts_climbing(counts: &[u64]) -> bool {
+    counts.windows(2).all(|w| w[0] <= w[1]) && counts.last() > counts.first()
+}
+
+/// Resolve a process by port and protocol.
+///
+/// When several processes share the port (IPv4 and IPv6 listeners, or
+/// `SO_REUSEPORT` workers), returns the first; use [`processes_by_port`] to
+/// get every owner.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sysprims_proc::Protocol;
 ///
 /// // Replaces: lsof -nP -iTCP:8080 -sTCP:LISTEN
 /// let proc = sysprims_proc::process_by_port(8080, Protocol::Tcp).unwrap();
 /// println!("{} {}", proc.pid, proc.name);
 /// ```
 pub fn process_by_port(port: u16, protocol: Protocol) -> SysprimsResult<ProcessInfo> {
+    processes_by_port(port, protocol)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| SysprimsError::not_found(port as u32))
+}
+
+/// Resolve every process bound to a port and protocol.
+///
+/// Returns distinct owners (deduplicated by PID) in binding order, so an
+/// `SO_REUSEPORT` server pool yields each worker once. Returns an empty list
+/// when no visible process owns the port. Owners that exit while being
+/// resolved are skipped.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sysprims_proc::Protocol;
+///
+/// // Replaces: lsof -t -nP -iTCP:8080 -sTCP:LISTEN
+/// for proc in sysprims_proc::processes_by_port(8080, Protocol::Tcp).unwrap() {
+///     println!("{} {}", proc.pid, proc.name);
+/// }
+/// ```
+pub fn processes_by_port(port: u16, protocol: Protocol) -> SysprimsResult<Vec<ProcessInfo>> {
     if port == 0 {
         return Err(SysprimsError::invalid_argument(
             "port must be between 1 and 65535",
@@ -714,40 +1989,188 @@ pub fn process_by_port(port: u16, protocol: Protocol) -> SysprimsResult<ProcessI
     let filter = PortFilter {
         protocol: Some(protocol),
         local_port: Some(port),
+        ..Default::default()
     };
     let snapshot = listening_ports(Some(&filter))?;
-    let binding = snapshot
-        .bindings
+    binding_owners(snapshot.bindings)
+}
+
+/// Resolve the owners of many ports from a single [`listening_ports`] scan.
+///
+/// Equivalent to calling [`processes_by_port`] for each entry, but the system
+/// is enumerated once instead of once per port. Every requested
+/// `(port, protocol)` pair gets an entry; ports nobody owns map to an empty
+/// list. Owners follow [`processes_by_port`] rules (deduplicated by PID, in
+/// binding order).
+///
+/// # Errors
+///
+/// Returns [`SysprimsError::InvalidArgument`] if any port is 0.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sysprims_proc::Protocol;
+///
+/// // Replaces: lsof -nP -iTCP:80 -iTCP:443 -iUDP:53
+/// let ports = [(80, Protocol::Tcp), (443, Protocol::Tcp), (53, Protocol::Udp)];
+/// for ((port, protocol), owners) in sysprims_proc::processes_by_ports(&ports).unwrap() {
+///     let pids: Vec<u32> = owners.iter().map(|p| p.pid).collect();
+///     println!("{port}/{protocol:?}: {pids:?}");
+/// }
+/// ```
+pub fn processes_by_ports(
+    ports: &[(u16, Protocol)],
+) -> SysprimsResult<HashMap<(u16, Protocol), Vec<ProcessInfo>>> {
+    if ports.iter().any(|&(port, _)| port == 0) {
+        return Err(SysprimsError::invalid_argument(
+            "port must be between 1 and 65535",
+        ));
+    }
+
+    let mut wanted: HashMap<(u16, Protocol), Vec<PortBinding>> =
+        ports.iter().map(|&key| (key, Vec::new())).collect();
+    if wanted.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let snapshot = listening_ports(None)?;
+    for binding in snapshot.bindings {
+        if let Some(list) = wanted.get_mut(&(binding.local_port, binding.protocol)) {
+            list.push(binding);
+        }
+    }
+
+    wanted
         .into_iter()
-        .find(|binding| binding.pid.is_some())
-        .ok_or_else(|| SysprimsError::not_found(port as u32))?;
+        .map(|(key, bindings)| Ok((key, binding_owners(bindings)?)))
+        .collect()
+}
+
+/// Distinct owning processes of `bindings`, deduplicated by PID in order.
+///
+/// Owners that exit before they can be read are skipped.
+fn binding_owners(bindings: Vec<PortBinding>) -> SysprimsResult<Vec<ProcessInfo>> {
+    let mut seen: HashSet<u32> = HashSet::new();
+    let mut owners = Vec::new();
+    for binding in bindings {
+        let Some(pid) = binding.pid else {
+            continue;
+        };
+        if !seen.insert(pid) {
+            continue;
+        }
+        match binding.process {
+            Some(process) => owners.push(process),
+            None => match get_process(pid) {
+                Ok(process) => owners.push(process),
+                Err(SysprimsError::NotFound { .. }) => {}
+                Err(e) => return Err(e),
+            },
+        }
+    }
+
+    Ok(owners)
+}
+
+/// Find every process holding `path` (or anything under it) open.
+///
+/// Equivalent to [`processes_using_path_filtered`] with no filter.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// // Replaces: lsof /var/log/app.log
+/// for (proc, fds) in sysprims_proc::processes_using_path("/var/log/app.log").unwrap() {
+///     println!("{} {} ({} fds)", proc.pid, proc.name, fds.len());
+/// }
+/// ```
+pub fn processes_using_path(path: &str) -> SysprimsResult<Vec<(ProcessInfo, Vec<FdInfo>)>> {
+    processes_using_path_filtered(path, &ProcessFilter::default())
+}
 
-    match binding.process {
-        Some(process) => Ok(process),
-        None => binding
-            .pid
-            .map(get_process)
-            .unwrap_or_else(|| Err(SysprimsError::not_found(port as u32))),
+/// Find processes matching `filter` that hold `path` (or anything under it) open.
+///
+/// Snapshots processes, lists each one's fds, and keeps the fds whose
+/// resolved path equals `path` or lies beneath it (component-wise, so
+/// `/tmp/a` does not match `/tmp/ab`). `path` is canonicalized when it
+/// exists so symlinked or relative inputs match the kernel-resolved targets.
+///
+/// This is expensive (one fd listing per process) and best-effort:
+/// processes whose fds cannot be read (permissions, exited mid-scan) are
+/// skipped silently. Narrow the scan with `filter`, e.g. `user_equals`.
+///
+/// # Errors
+///
+/// Returns `InvalidArgument` if `path` is empty or the filter is invalid.
+/// Returns `NotSupported` where fd listing is unavailable (Windows).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sysprims_proc::ProcessFilter;
+///
+/// // Replaces: lsof -u "$USER" +D /srv/data
+/// let filter = ProcessFilter {
+///     user_equals: std::env::var("USER").ok(),
+///     ..Default::default()
+/// };
+/// let users = sysprims_proc::processes_using_path_filtered("/srv/data", &filter).unwrap();
+/// println!("{} processes", users.len());
+/// ```
+pub fn processes_using_path_filtered(
+    path: &str,
+    filter: &ProcessFilter,
+) -> SysprimsResult<Vec<(ProcessInfo, Vec<FdInfo>)>> {
+    if path.is_empty() {
+        return Err(SysprimsError::invalid_argument("path must not be empty"));
     }
+    let target = std::fs::canonicalize(path).unwrap_or_else(|_| std::path::PathBuf::from(path));
+
+    let snap = snapshot_filtered(filter)?;
+    let mut users = Vec::new();
+    for proc in snap.processes {
+        let fds = match platform::list_fds_impl(proc.pid) {
+            Ok((fds, _)) => fds,
+            Err(e @ SysprimsError::NotSupported { .. }) => return Err(e),
+            Err(_) => continue,
+        };
+        let matching: Vec<FdInfo> = fds
+            .into_iter()
+            .filter(|fd| {
+                fd.path
+                    .as_deref()
+                    .is_some_and(|p| std::path::Path::new(p).starts_with(&target))
+            })
+            .collect();
+        if !matching.is_empty() {
+            users.push((proc, matching));
+        }
+    }
+
+    Ok(users)
 }
 
 #[cfg(unix)]
-fn aggregate_permission_warning(skipped: usize, label: &str) -> Option<String> {
+fn aggregate_permission_warning(skipped: usize, label: &str) -> Option<Warning> {
     if skipped == 0 {
         None
     } else {
-        Some(format!(
-            "Skipped {} {} due to permission errors",
-            skipped, label
+        Some(Warning::new(
+            WarningCode::PermissionDenied,
+            format!("Skipped {} {} due to permission errors", skipped, label),
         ))
     }
 }
 
-fn aggregate_error_warning(skipped: usize, label: &str) -> Option<String> {
+fn aggregate_error_warning(skipped: usize, label: &str) -> Option<Warning> {
     if skipped == 0 {
         None
     } else {
-        Some(format!("Skipped {} {} due to read errors", skipped, label))
+        Some(Warning::new(
+            WarningCode::PartialVisibility,
+            format!("Skipped {} {} due to read errors", skipped, label),
+        ))
     }
 }
 
@@ -793,16 +2216,145 @@ pub fn snapshot_filtered(filter: &ProcessFilter) -> SysprimsResult<ProcessSnapsh
 /// ```
 pub fn snapshot_filtered_with_options(
     filter: &ProcessFilter,
-    options: ProcessOptions,
+    mut options: ProcessOptions,
 ) -> SysprimsResult<ProcessSnapshot> {
     filter.validate()?;
     validate_process_options(&options)?;
 
+    // cgroup_contains can only match when cgroups are collected.
+    if filter.cgroup_contains.is_some() {
+        options.include_cgroup = true;
+    }
+    // user_equals matches on the resolved name.
+    if filter.user_equals.is_some() {
+        options.resolve_usernames = true;
+    }
+
     let mut snap = snapshot_with_options(options)?;
+    snap.total_before_filter = Some(snap.processes.len());
+    snap.filtered = true;
     snap.processes.retain(|p| filter.matches(p));
     Ok(snap)
 }
 
+/// Apply `filter` to an existing snapshot instead of re-enumerating.
+///
+/// Lets one snapshot (e.g. from a [`SnapshotCache`]) serve many filters.
+/// Matched processes are cloned into a new envelope with the same
+/// `schema_id`, `timestamp`, and `warnings`; `total_before_filter` counts the
+/// processes the original enumeration saw. Fields the snapshot did not
+/// collect never match: `cgroup_contains` needs a snapshot taken with
+/// `include_cgroup`.
+///
+/// The filter is not validated; call [`ProcessFilter::validate`] first for
+/// filters built from user input.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sysprims_proc::{filter_snapshot, ProcessFilter};
+///
+/// let snap = sysprims_proc::snapshot().unwrap();
+/// for name in ["nginx", "postgres"] {
+///     let filter = ProcessFilter {
+///         name_contains: Some(name.into()),
+///         ..Default::default()
+///     };
+///     let matched = filter_snapshot(&snap, &filter);
+///     println!("{name}: {}", matched.processes.len());
+/// }
+/// ```
+pub fn filter_snapshot(snapshot: &ProcessSnapshot, filter: &ProcessFilter) -> ProcessSnapshot {
+    ProcessSnapshot {
+        schema_id: snapshot.schema_id,
+        timestamp: snapshot.timestamp.clone(),
+        processes: snapshot
+            .processes
+            .iter()
+            .filter(|p| filter.matches(p))
+            .cloned()
+            .collect(),
+        cpu_percent_is_normalized: snapshot.cpu_percent_is_normalized,
+        filtered: true,
+        total_before_filter: Some(
+            snapshot
+                .total_before_filter
+                .unwrap_or(snapshot.processes.len()),
+        ),
+        warnings: snapshot.warnings.clone(),
+    }
+}
+
+/// A process snapshot reused for up to `ttl` before re-enumerating.
+///
+/// For tools that query processes many times per second. Safe to share
+/// between threads; concurrent callers of an expired cache wait for a single
+/// re-enumeration rather than each taking their own.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use sysprims_proc::SnapshotCache;
+///
+/// let cache = SnapshotCache::new(Duration::from_millis(500));
+/// let a = cache.get().unwrap();
+/// let b = cache.get().unwrap(); // same snapshot, no re-enumeration
+/// assert_eq!(a.timestamp, b.timestamp);
+/// ```
+#[derive(Debug)]
+pub struct SnapshotCache {
+    ttl: Duration,
+    config: SnapshotConfig,
+    cached: Mutex<Option<(Instant, Arc<ProcessSnapshot>)>>,
+}
+
+impl SnapshotCache {
+    /// Cache default [`snapshot`] results for `ttl`.
+    pub fn new(ttl: Duration) -> Self {
+        Self::with_config(ttl, SnapshotConfig::default())
+    }
+
+    /// Cache [`snapshot_with_config`] results for `ttl`.
+    pub fn with_config(ttl: Duration, config: SnapshotConfig) -> Self {
+        Self {
+            ttl,
+            config,
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// How long a snapshot is reused.
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Return the cached snapshot, re-enumerating if it is older than `ttl`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`snapshot_with_config`] error when re-enumeration fails;
+    /// the previous snapshot is discarded either way.
+    pub fn get(&self) -> SysprimsResult<Arc<ProcessSnapshot>> {
+        let mut cached = self.cached.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some((taken_at, snap)) = cached.as_ref() {
+            if taken_at.elapsed() < self.ttl {
+                return Ok(Arc::clone(snap));
+            }
+        }
+
+        *cached = None;
+        let snap = Arc::new(snapshot_with_config(self.config)?);
+        *cached = Some((Instant::now(), Arc::clone(&snap)));
+        Ok(snap)
+    }
+
+    /// Drop the cached snapshot so the next [`get`](Self::get) re-enumerates.
+    pub fn invalidate(&self) {
+        *self.cached.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+}
+
 /// Get information for a single process.
 ///
 /// # Errors
@@ -821,6 +2373,21 @@ pub fn get_process(pid: u32) -> SysprimsResult<ProcessInfo> {
     get_process_with_options(pid, ProcessOptions::default())
 }
 
+/// Get information for the calling process.
+///
+/// Equivalent to `get_process(sysprims_core::self_pid())`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// // Replaces: ps -p $$ -o ... plus parsing
+/// let me = sysprims_proc::get_self().unwrap();
+/// println!("{} ({})", me.name, me.pid);
+/// ```
+pub fn get_self() -> SysprimsResult<ProcessInfo> {
+    get_process(sysprims_core::self_pid())
+}
+
 /// Get information for a single process with optional extended fields.
 ///
 /// # Examples
@@ -841,6 +2408,40 @@ pub fn get_process_with_options(pid: u32, options: ProcessOptions) -> SysprimsRe
     platform::get_process_impl(pid, &options)
 }
 
+/// Get information for several processes in one call.
+///
+/// Returns one `(pid, result)` entry per input PID, in input order, so a
+/// missing or unreadable process doesn't fail the batch. The list itself is
+/// validated up front: it must be non-empty and contain no PID 0 or PID above
+/// `i32::MAX`. This is the read complement to `sysprims_signal::kill_many`.
+///
+/// On Windows all PIDs are resolved from a single process snapshot.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// // Replaces: ps -p 1234,5678 -o ... plus parsing
+/// for (pid, result) in sysprims_proc::get_processes(&[1234, 5678]).unwrap() {
+///     match result {
+///         Ok(info) => println!("{pid}: {}", info.name),
+///         Err(e) => println!("{pid}: {e}"),
+///     }
+/// }
+/// ```
+pub fn get_processes(pids: &[u32]) -> SysprimsResult<Vec<(u32, SysprimsResult<ProcessInfo>)>> {
+    if pids.is_empty() {
+        return Err(SysprimsError::invalid_argument("pids must not be empty"));
+    }
+    for &pid in pids {
+        validate_pid(pid)?;
+    }
+
+    Ok(platform::get_processes_impl(
+        pids,
+        &ProcessOptions::default(),
+    ))
+}
+
 // ============================================================================
 // Descendants API
 // ============================================================================
@@ -851,7 +2452,7 @@ pub struct DescendantsLevel {
     /// Depth level (1 = direct children, 2 = grandchildren, etc.).
     pub level: u32,
 
-    /// Processes at this level.
+    /// Processes at this level, sorted by PID.
     pub processes: Vec<ProcessInfo>,
 }
 
@@ -859,6 +2460,12 @@ pub struct DescendantsLevel {
 #[derive(Debug, Clone, Serialize)]
 pub struct DescendantsResult {
     /// Schema identifier for version detection.
+    ///
+    /// Serialized together with `schema_version`, its major version.
+    #[serde(
+        flatten,
+        serialize_with = "sysprims_core::schema::serialize_with_version"
+    )]
     pub schema_id: &'static str,
 
     /// Root PID that was traversed from.
@@ -881,6 +2488,176 @@ pub struct DescendantsResult {
 
     /// Platform identifier.
     pub platform: &'static str,
+
+    /// Descendant PIDs that were seen while polling but had exited by the
+    /// final snapshot (sorted). Present only when `DescendantsConfig::poll` is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transient_pids: Option<Vec<u32>>,
+
+    /// True when `DescendantsConfig::deadline` expired before the traversal
+    /// finished, so the levels may be incomplete.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub truncated: bool,
+
+    /// Non-fatal issues encountered during the traversal.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<Warning>,
+}
+
+/// A process and its descendants, nested parent to child.
+///
+/// Built by [`DescendantsResult::to_tree`].
+#[derive(Debug, Clone, Serialize)]
+pub struct TreeNode {
+    /// Process ID.
+    pub pid: u32,
+
+    /// Process details. `None` for the root, which a traversal does not
+    /// report; callers can fill it in with [`get_process`].
+    pub info: Option<ProcessInfo>,
+
+    /// Child nodes, sorted by PID.
+    pub children: Vec<TreeNode>,
+}
+
+/// A descendants traversal rendered as a nested tree, with the standard
+/// result envelope.
+///
+/// Built by [`DescendantsResult::to_tree_result`].
+#[derive(Debug, Clone, Serialize)]
+pub struct DescendantsTreeResult {
+    /// Schema identifier for version detection.
+    ///
+    /// Serialized together with `schema_version`, its major version.
+    #[serde(
+        flatten,
+        serialize_with = "sysprims_core::schema::serialize_with_version"
+    )]
+    pub schema_id: &'static str,
+
+    /// Maximum depth that was requested.
+    pub max_levels: u32,
+
+    /// Timestamp (ISO 8601).
+    pub timestamp: String,
+
+    /// Platform identifier.
+    pub platform: &'static str,
+
+    /// The traversal root and its descendants.
+    pub root: TreeNode,
+
+    /// Copied from [`DescendantsResult::truncated`].
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub truncated: bool,
+
+    /// Copied from [`DescendantsResult::warnings`].
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<Warning>,
+}
+
+impl DescendantsResult {
+    /// Nest the flat `levels` into a tree rooted at `root_pid`.
+    ///
+    /// Children are sorted by PID so the output is stable. When a filter
+    /// dropped a process's parent, the process is attached directly under
+    /// the root rather than lost.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use sysprims_proc::descendants;
+    ///
+    /// let tree = descendants(1234, u32::MAX, None).unwrap().to_tree();
+    /// for child in &tree.children {
+    ///     println!("{} has {} children", child.pid, child.children.len());
+    /// }
+    /// ```
+    pub fn to_tree(&self) -> TreeNode {
+        let mut children_of: HashMap<u32, Vec<&ProcessInfo>> = HashMap::new();
+        for process in self.levels.iter().flat_map(|level| &level.processes) {
+            if process.pid != self.root_pid {
+                children_of.entry(process.ppid).or_default().push(process);
+            }
+        }
+
+        let mut children = build_subtree(self.root_pid, &mut children_of);
+
+        // Whatever is left has a parent outside the result (filtered out).
+        let stranded: Vec<&ProcessInfo> = children_of.values().flatten().copied().collect();
+        let stranded_pids: HashSet<u32> = stranded.iter().map(|p| p.pid).collect();
+        for process in stranded {
+            if !stranded_pids.contains(&process.ppid) {
+                children.push(TreeNode {
+                    pid: process.pid,
+                    info: Some(process.clone()),
+                    children: build_subtree(process.pid, &mut children_of),
+                });
+            }
+        }
+        children.sort_by_key(|node| node.pid);
+
+        TreeNode {
+            pid: self.root_pid,
+            info: None,
+            children,
+        }
+    }
+
+    /// Like [`to_tree`](Self::to_tree), wrapped in a [`DescendantsTreeResult`]
+    /// envelope for JSON output.
+    pub fn to_tree_result(&self) -> DescendantsTreeResult {
+        DescendantsTreeResult {
+            schema_id: DESCENDANTS_TREE_V1,
+            max_levels: self.max_levels,
+            timestamp: self.timestamp.clone(),
+            platform: self.platform,
+            root: self.to_tree(),
+            truncated: self.truncated,
+            warnings: self.warnings.clone(),
+        }
+    }
+}
+
+fn build_subtree(pid: u32, children_of: &mut HashMap<u32, Vec<&ProcessInfo>>) -> Vec<TreeNode> {
+    let mut children = children_of.remove(&pid).unwrap_or_default();
+    children.sort_by_key(|p| p.pid);
+    children
+        .into_iter()
+        .map(|process| TreeNode {
+            pid: process.pid,
+            info: Some(process.clone()),
+            children: build_subtree(process.pid, children_of),
+        })
+        .collect()
+}
+
+/// Repeated-sampling window for descendants traversal.
+///
+/// A single snapshot misses children that spawn and exit between enumeration
+/// passes. Polling takes a snapshot every `interval` for `duration` and unions
+/// what it sees, so short-lived processes (e.g. compiler invocations under a
+/// build system) are still attributed to the tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PollConfig {
+    /// Delay between snapshots. Must be > 0.
+    pub interval: Duration,
+
+    /// Total sampling window. Must be > 0.
+    pub duration: Duration,
+}
+
+impl PollConfig {
+    /// Validate poll values.
+    pub fn validate(&self) -> SysprimsResult<()> {
+        if self.interval.is_zero() {
+            return Err(SysprimsError::invalid_argument("poll interval must be > 0"));
+        }
+        if self.duration.is_zero() {
+            return Err(SysprimsError::invalid_argument("poll duration must be > 0"));
+        }
+        Ok(())
+    }
 }
 
 /// CPU measurement mode for descendants traversal.
@@ -916,6 +2693,20 @@ pub struct DescendantsConfig {
     ///
     /// Defaults to 1 second when omitted.
     pub sample_duration: Option<Duration>,
+
+    /// Optional repeated sampling to catch short-lived descendants.
+    ///
+    /// When set, snapshots taken over the poll window are merged. Processes
+    /// that had exited by the final snapshot are still reported (with their
+    /// last observed info) and listed in `DescendantsResult::transient_pids`.
+    pub poll: Option<PollConfig>,
+
+    /// Optional bound on the whole operation, including polling and sampling.
+    ///
+    /// When it expires, process reads stop and the descendants gathered so
+    /// far are returned with `DescendantsResult::truncated` set and a warning.
+    /// In monitor mode the sample interval is shortened to fit.
+    pub deadline: Option<Duration>,
 }
 
 /// Get descendants of a process using BFS traversal.
@@ -924,6 +2715,9 @@ pub struct DescendantsConfig {
 /// from `root_pid` up to `max_levels` deep. An optional filter is applied to
 /// the results after traversal.
 ///
+/// Processes within each level are sorted by PID, so output is stable across
+/// runs and platforms regardless of enumeration order.
+///
 /// # Arguments
 ///
 /// * `root_pid` - PID to start traversal from (must exist).
@@ -985,6 +2779,8 @@ pub fn descendants_with_options(
             filter: filter.cloned(),
             cpu_mode: CpuMode::Lifetime,
             sample_duration: None,
+            poll: None,
+            deadline: None,
         },
         options,
     )
@@ -1005,6 +2801,8 @@ pub fn descendants_with_options(
 ///     filter: None,
 ///     cpu_mode: CpuMode::Monitor,
 ///     sample_duration: Some(Duration::from_millis(500)),
+///     poll: None,
+///     deadline: None,
 /// })
 /// .unwrap();
 /// println!("total: {}", result.total_found);
@@ -1028,6 +2826,8 @@ pub fn descendants_with_config(config: DescendantsConfig) -> SysprimsResult<Desc
 ///         filter: None,
 ///         cpu_mode: CpuMode::Lifetime,
 ///         sample_duration: None,
+///         poll: None,
+///         deadline: None,
 ///     },
 ///     ProcessOptions::default().with_threads(),
 /// )
@@ -1036,43 +2836,58 @@ pub fn descendants_with_config(config: DescendantsConfig) -> SysprimsResult<Desc
 /// ```
 pub fn descendants_with_config_and_options(
     config: DescendantsConfig,
-    options: ProcessOptions,
+    mut options: ProcessOptions,
 ) -> SysprimsResult<DescendantsResult> {
-    const MAX_SAFE_PID: u32 = i32::MAX as u32;
-
-    if config.root_pid == 0 {
-        return Err(SysprimsError::invalid_argument("PID 0 is not valid"));
-    }
-    if config.root_pid > MAX_SAFE_PID {
-        return Err(SysprimsError::invalid_argument(format!(
-            "PID {} exceeds maximum safe value {}",
-            config.root_pid, MAX_SAFE_PID
-        )));
-    }
+    let started = Instant::now();
+    validate_pid(config.root_pid)?;
 
     validate_process_options(&options)?;
 
     if let Some(f) = config.filter.as_ref() {
         f.validate()?;
+        if f.cgroup_contains.is_some() {
+            options.include_cgroup = true;
+        }
     }
 
     // Verify root exists before any traversal/sampling work.
     let _ = get_process_with_options(config.root_pid, options)?;
 
-    // Snapshot for consistent traversal; monitor mode samples at the snapshot level.
-    let snap = snapshot_for_descendants(options, config.cpu_mode, config.sample_duration)?;
-
-    // Build parent → children map.
-    let mut children_map: HashMap<u32, Vec<ProcessInfo>> = HashMap::new();
-    for proc in snap.processes {
-        children_map.entry(proc.ppid).or_default().push(proc);
+    if let Some(poll) = config.poll.as_ref() {
+        poll.validate()?;
+    }
+    if config.deadline.is_some_and(|d| d.is_zero()) {
+        return Err(SysprimsError::invalid_argument("deadline must be > 0"));
     }
+    let deadline = config.deadline.map(|d| started + d);
+
+    // Snapshot for consistent traversal; monitor mode samples at the snapshot level.
+    // With polling, several snapshots are merged before the final (sampled) one.
+    let (children_map, transient, truncated) = match config.poll {
+        Some(poll) => polled_children_map(options, &config, poll, deadline)?,
+        None => {
+            let (snap, truncated) = snapshot_for_descendants(
+                options,
+                config.cpu_mode,
+                config.sample_duration,
+                deadline,
+            )?;
+            let mut children_map = ChildrenMap::new();
+            for proc in snap.processes {
+                children_map.entry(proc.ppid).or_default().push(proc);
+            }
+            (children_map, HashSet::new(), truncated)
+        }
+    };
 
     // BFS traversal.
     let mut levels: Vec<DescendantsLevel> = Vec::new();
     let mut current_pids = vec![config.root_pid];
     let mut total_found: usize = 0;
     let max_levels = config.max_levels.unwrap_or(u32::MAX);
+    // Guards against parent cycles, which merged poll snapshots can produce
+    // under PID reuse.
+    let mut visited: HashSet<u32> = HashSet::from([config.root_pid]);
 
     for depth in 1..=max_levels {
         let mut level_procs = Vec::new();
@@ -1081,6 +2896,9 @@ pub fn descendants_with_config_and_options(
         for &pid in &current_pids {
             if let Some(children) = children_map.get(&pid) {
                 for child in children {
+                    if !visited.insert(child.pid) {
+                        continue;
+                    }
                     level_procs.push(child.clone());
                     next_pids.push(child.pid);
                 }
@@ -1091,6 +2909,10 @@ pub fn descendants_with_config_and_options(
             break;
         }
 
+        // Stable-by-pid ordering; the next level is expanded in the same order.
+        level_procs.sort_unstable_by_key(|p| p.pid);
+        next_pids.sort_unstable();
+
         total_found += level_procs.len();
         levels.push(DescendantsLevel {
             level: depth,
@@ -1110,11 +2932,33 @@ pub fn descendants_with_config_and_options(
         levels.retain(|l| !l.processes.is_empty());
     }
 
+    let transient_pids = config.poll.map(|_| {
+        let mut pids: Vec<u32> = levels
+            .iter()
+            .flat_map(|l| l.processes.iter().map(|p| p.pid))
+            .filter(|pid| transient.contains(pid))
+            .collect();
+        pids.sort_unstable();
+        pids
+    });
+
+    let mut warnings = Vec::new();
+    if let (true, Some(d)) = (truncated, config.deadline) {
+        warnings.push(Warning::new(
+            WarningCode::Truncated,
+            format!(
+                "Descendants traversal stopped at the {}ms deadline; results are partial",
+                d.as_millis()
+            ),
+        ));
+    }
+
+    let schema_id = match config.cpu_mode {
+        CpuMode::Lifetime => DESCENDANTS_RESULT_V1,
+        CpuMode::Monitor => DESCENDANTS_RESULT_SAMPLED_V1,
+    };
     Ok(DescendantsResult {
-        schema_id: match config.cpu_mode {
-            CpuMode::Lifetime => DESCENDANTS_RESULT_V1,
-            CpuMode::Monitor => DESCENDANTS_RESULT_SAMPLED_V1,
-        },
+        schema_id,
         root_pid: config.root_pid,
         max_levels,
         levels,
@@ -1122,23 +2966,149 @@ pub fn descendants_with_config_and_options(
         matched_by_filter,
         timestamp: current_timestamp(),
         platform: get_platform(),
+        transient_pids,
+        truncated,
+        warnings,
     })
 }
 
+/// Parent PID → direct children, as used by descendants traversal.
+type ChildrenMap = HashMap<u32, Vec<ProcessInfo>>;
+
+/// Build a parent → children map from snapshots merged over a poll window.
+///
+/// Each process is attached under the parent it had when first observed, so
+/// children orphaned mid-window (reparented to init/subreaper) stay in the
+/// tree. Reported info is the most recent observation. Returns the set of PIDs
+/// that were not present in the final snapshot, and whether `deadline` cut the
+/// window short.
+fn polled_children_map(
+    options: ProcessOptions,
+    config: &DescendantsConfig,
+    poll: PollConfig,
+    deadline: Option<Instant>,
+) -> SysprimsResult<(ChildrenMap, HashSet<u32>, bool)> {
+    // pid -> (first observed ppid, latest info)
+    let mut seen: HashMap<u32, (u32, ProcessInfo)> = HashMap::new();
+    let mut merge = |processes: Vec<ProcessInfo>| {
+        for proc in processes {
+            match seen.get_mut(&proc.pid) {
+                // PID reuse guard: a different start time means a new process.
+                Some((_, prev))
+                    if prev.start_time_unix_ms.is_some()
+                        && proc.start_time_unix_ms.is_some()
+                        && prev.start_time_unix_ms != proc.start_time_unix_ms =>
+                {
+                    let ppid = proc.ppid;
+                    seen.insert(proc.pid, (ppid, proc));
+                }
+                Some((_, prev)) => *prev = proc,
+                None => {
+                    seen.insert(proc.pid, (proc.ppid, proc));
+                }
+            }
+        }
+    };
+
+    let start = Instant::now();
+    let mut truncated = false;
+    loop {
+        let (snap, cut) = match deadline {
+            Some(deadline) => snapshot_until(options, deadline)?,
+            None => (snapshot_with_options(options)?, false),
+        };
+        merge(snap.processes);
+        if cut {
+            truncated = true;
+            break;
+        }
+        let elapsed = start.elapsed();
+        if elapsed >= poll.duration {
+            break;
+        }
+        let mut pause = poll.interval.min(poll.duration - elapsed);
+        if let Some(deadline) = deadline {
+            pause = pause.min(deadline.saturating_duration_since(Instant::now()));
+        }
+        std::thread::sleep(pause);
+    }
+
+    // The final snapshot decides which PIDs are still alive. A partial one
+    // would misreport unread processes as transient, so none are reported
+    // once the deadline cuts in.
+    let mut alive: Option<HashSet<u32>> = None;
+    if !truncated {
+        let (last, cut) =
+            snapshot_for_descendants(options, config.cpu_mode, config.sample_duration, deadline)?;
+        truncated = cut;
+        if !cut {
+            alive = Some(last.processes.iter().map(|p| p.pid).collect());
+        }
+        merge(last.processes);
+    }
+
+    let mut transient = HashSet::new();
+    let mut children_map = ChildrenMap::new();
+    for (pid, (ppid, proc)) in seen {
+        if alive.as_ref().is_some_and(|alive| !alive.contains(&pid)) {
+            transient.insert(pid);
+        }
+        children_map.entry(ppid).or_default().push(proc);
+    }
+    for children in children_map.values_mut() {
+        children.sort_by_key(|p| p.pid);
+    }
+
+    Ok((children_map, transient, truncated))
+}
+
+/// Take the traversal snapshot, stopping early at `deadline`.
+///
+/// Returns the snapshot and whether the deadline cut it short. In monitor
+/// mode the sample interval is shortened to end at the deadline.
 fn snapshot_for_descendants(
     options: ProcessOptions,
     cpu_mode: CpuMode,
     sample_duration: Option<Duration>,
-) -> SysprimsResult<ProcessSnapshot> {
+    deadline: Option<Instant>,
+) -> SysprimsResult<(ProcessSnapshot, bool)> {
     match cpu_mode {
-        CpuMode::Lifetime => snapshot_with_options(options),
+        CpuMode::Lifetime => match deadline {
+            Some(deadline) => snapshot_until(options, deadline),
+            None => Ok((snapshot_with_options(options)?, false)),
+        },
         CpuMode::Monitor => {
-            let sample = sample_duration.unwrap_or_else(|| Duration::from_secs(1));
-            snapshot_with_sampled_cpu(options, sample)
+            let mut sample = sample_duration.unwrap_or_else(|| Duration::from_secs(1));
+            let mut truncated = false;
+            if let Some(deadline) = deadline {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                if remaining.is_zero() {
+                    let mut snap = make_snapshot(Vec::new());
+                    snap.mark_sampled();
+                    return Ok((snap, true));
+                }
+                if remaining < sample {
+                    sample = remaining;
+                    truncated = true;
+                }
+            }
+            Ok((snapshot_with_sampled_cpu(options, sample)?, truncated))
         }
     }
 }
 
+/// Snapshot processes, stopping once `deadline` passes.
+///
+/// Returns the processes read so far and whether the deadline was hit.
+fn snapshot_until(
+    options: ProcessOptions,
+    deadline: Instant,
+) -> SysprimsResult<(ProcessSnapshot, bool)> {
+    let (mut snap, truncated) = bounded_snapshot(options, None, None, Some(deadline))?;
+    note_truncated_cmdlines(&mut snap);
+    Ok((snap, truncated))
+}
+
 fn snapshot_with_sampled_cpu(
     options: ProcessOptions,
     sample_duration: Duration,
@@ -1180,11 +3150,165 @@ fn snapshot_with_sampled_cpu(
         }
     }
 
-    // Sampled CPU semantics can exceed 100 on multi-core.
-    snap1.schema_id = PROCESS_INFO_SAMPLED_V1;
+    snap1.mark_sampled();
     Ok(snap1)
 }
 
+/// Whether `pid` is a descendant of `ancestor` (child, grandchild, ...).
+///
+/// Walks the parent chain upward from `pid` in a single snapshot, so the
+/// answer is consistent and cheaper than collecting `ancestor`'s whole
+/// subtree. A PID is not its own descendant. Parent cycles (possible under PID
+/// reuse) end the walk with `false`. On Windows, where orphans keep their
+/// exited parent's PID, a parent that started after its child is a reused PID
+/// and also ends the walk.
+///
+/// # Errors
+///
+/// Returns `InvalidArgument` if either PID is 0, and `NotFound` if `pid` is
+/// not in the snapshot.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// // Replaces: walking `ps -o ppid= -p <pid>` in a shell loop
+/// let supervisor = std::process::id();
+/// let target = 4242;
+/// if !sysprims_proc::is_descendant_of(target, supervisor).unwrap_or(false) {
+///     eprintln!("refusing to signal {target}: outside our subtree");
+/// }
+/// ```
+pub fn is_descendant_of(pid: u32, ancestor: u32) -> SysprimsResult<bool> {
+    if pid == 0 || ancestor == 0 {
+        return Err(SysprimsError::invalid_argument("PID 0 is not valid"));
+    }
+
+    // Only ppid links are needed; skip the username lookups.
+    let snap = snapshot_with_options(ProcessOptions::default().without_usernames())?;
+    let parents: HashMap<u32, (u32, Option<u64>)> = snap
+        .processes
+        .iter()
+        .map(|p| (p.pid, (p.ppid, p.start_time_unix_ms)))
+        .collect();
+    if !parents.contains_key(&pid) {
+        return Err(SysprimsError::not_found(pid));
+    }
+
+    let mut visited = HashSet::from([pid]);
+    let mut current = pid;
+    while let Some(&(parent, started)) = parents.get(&current) {
+        let parent_started = parents.get(&parent).and_then(|&(_, s)| s);
+        if cfg!(windows) && started_after(parent_started, started) {
+            break;
+        }
+        if parent == ancestor {
+            return Ok(true);
+        }
+        if parent == 0 || !visited.insert(parent) {
+            break;
+        }
+        current = parent;
+    }
+    Ok(false)
+}
+
+/// Whether a process started at `parent` began after one started at `child`,
+/// so it cannot really be that child's parent (its PID was reused).
+fn started_after(parent: Option<u64>, child: Option<u64>) -> bool {
+    matches!((parent, child), (Some(p), Some(c)) if p > c)
+}
+
+/// Whether `signal`'s bit is set in a signal mask.
+///
+/// Works on any of [`ProcessInfo::sig_blocked`], `sig_ignored`, or
+/// `sig_caught`, where bit `n - 1` stands for signal `n`. Signals outside
+/// `1..=64` are never set.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sysprims_proc::{get_process_with_options, signal_is_blocked, ProcessOptions};
+///
+/// // Replaces: grep SigBlk /proc/<pid>/status and decoding hex by hand
+/// let info = get_process_with_options(1234, ProcessOptions::default().with_signal_masks()).unwrap();
+/// if info.sig_blocked.is_some_and(|m| signal_is_blocked(m, 15)) {
+///     println!("SIGTERM is blocked; it stays pending until unblocked");
+/// }
+/// ```
+pub fn signal_is_blocked(mask: u64, signal: i32) -> bool {
+    (1..=64).contains(&signal) && mask & (1u64 << (signal - 1)) != 0
+}
+
+/// Count visible processes by [`ProcessState`].
+///
+/// Reads only each process's state (the state character of
+/// `/proc/[pid]/stat` on Linux, `pbi_status` on macOS), skipping the name,
+/// CPU, and memory reads a [`snapshot`] does. States with no processes are
+/// absent from the map; the values sum to the total. Uninterruptible sleep
+/// (`D`) has its own `DiskSleep` bucket, and the calling process always
+/// counts as `Running`. Windows has no simple run state, so every process
+/// counts as `Unknown` there.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sysprims_proc::ProcessState;
+///
+/// // Replaces: ps -eo stat | grep -c '^Z'
+/// let counts = sysprims_proc::state_counts().unwrap();
+/// let zombies = counts.get(&ProcessState::Zombie).copied().unwrap_or(0);
+/// println!("{zombies} zombies of {}", counts.values().sum::<usize>());
+/// ```
+pub fn state_counts() -> SysprimsResult<HashMap<ProcessState, usize>> {
+    platform::state_counts_impl()
+}
+
+/// List the direct children of `pid`, sorted by PID.
+///
+/// Shorthand for the first level of [`descendants`]`(pid, 1, None)`, with the
+/// same per-process fields. On Linux the children are read from
+/// `/proc/[pid]/task/*/children` when the kernel provides it, avoiding a scan
+/// of every PID; elsewhere (or without that file) a full snapshot is used.
+/// Children that exit while being read are omitted.
+///
+/// # Errors
+///
+/// Returns `InvalidArgument` for PID 0 or a PID above `i32::MAX`, and
+/// `NotFound` if `pid` does not exist.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// // Replaces: ps --ppid <pid>
+/// for child in sysprims_proc::children(std::process::id()).unwrap() {
+///     println!("{} {}", child.pid, child.name);
+/// }
+/// ```
+pub fn children(pid: u32) -> SysprimsResult<Vec<ProcessInfo>> {
+    validate_pid(pid)?;
+
+    let options = ProcessOptions::default();
+    let _ = get_process_with_options(pid, options)?;
+
+    #[cfg(target_os = "linux")]
+    if let Some(pids) = platform::child_pids_impl(pid) {
+        // A PID reused between listing and reading may have a new parent.
+        return Ok(pids
+            .into_iter()
+            .filter_map(|child| platform::get_process_impl(child, &options).ok())
+            .filter(|p| p.ppid == pid && p.pid != pid)
+            .collect());
+    }
+
+    let mut children: Vec<ProcessInfo> = snapshot_with_options(options)?
+        .processes
+        .into_iter()
+        .filter(|p| p.ppid == pid && p.pid != pid)
+        .collect();
+    children.sort_unstable_by_key(|p| p.pid);
+    Ok(children)
+}
+
 /// Wait for a PID to exit, up to the provided timeout.
 ///
 /// Returns:
@@ -1193,6 +3317,9 @@ fn snapshot_with_sampled_cpu(
 /// - `Err(NotFound)` if the PID does not exist at the time of the first check.
 /// - `Err(PermissionDenied)` if the platform forbids even querying liveness.
 ///
+/// Zombies are reported as exited on every platform; see
+/// [`WaitPidConfig::zombie_is_exited`].
+///
 /// # Examples
 ///
 /// ```rust,no_run
@@ -1203,20 +3330,210 @@ fn snapshot_with_sampled_cpu(
 /// println!("timed_out: {}", res.timed_out);
 /// ```
 pub fn wait_pid(pid: u32, timeout: Duration) -> SysprimsResult<WaitPidResult> {
+    wait_pid_with_config(pid, timeout, WaitPidConfig::default())
+}
+
+/// Wait for a PID to exit with explicit configuration.
+///
+/// Same semantics as [`wait_pid`], with zombie handling, poll cadence, and
+/// exec handling ([`OnExec`]) controlled by `config`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use sysprims_proc::{wait_pid_with_config, WaitPidConfig};
+///
+/// // Keep waiting until the zombie is reaped by its parent.
+/// let config = WaitPidConfig { zombie_is_exited: false, ..WaitPidConfig::default() };
+/// let res = wait_pid_with_config(1234, Duration::from_secs(5), config).unwrap();
+/// println!("exited: {}", res.exited);
+/// ```
+pub fn wait_pid_with_config(
+    pid: u32,
+    timeout: Duration,
+    config: WaitPidConfig,
+) -> SysprimsResult<WaitPidResult> {
     if pid == 0 {
         return Err(SysprimsError::invalid_argument("PID 0 is not valid"));
     }
-    platform::wait_pid_impl(pid, timeout)
+    match config.on_exec {
+        OnExec::Ignore => platform::wait_pid_impl(pid, timeout, &config),
+        OnExec::TreatAsExit => wait_pid_watching_exec(pid, timeout, &config),
+    }
+}
+
+/// Start waiting for a PID on a background thread.
+///
+/// Runs [`wait_pid`] on a dedicated thread and returns immediately, so async
+/// runtimes and event loops can await process exit without blocking a worker:
+/// poll [`WaitHandle::try_recv`] from a timer or task, or block in
+/// [`WaitHandle::join`]. No runtime is assumed.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// let handle = sysprims_proc::wait_pid_handle(1234, Duration::from_secs(30));
+/// loop {
+///     if let Some(result) = handle.try_recv() {
+///         println!("exited: {}", result.unwrap().exited);
+///         break;
+///     }
+///     // ... do other work, or yield to the executor ...
+///     std::thread::sleep(Duration::from_millis(50));
+/// }
+/// ```
+pub fn wait_pid_handle(pid: u32, timeout: Duration) -> WaitHandle {
+    let (tx, rx) = mpsc::channel();
+    let spawn_error_tx = tx.clone();
+    let spawned = std::thread::Builder::new()
+        .name(format!("sysprims-wait-{pid}"))
+        .spawn(move || {
+            // The handle may have been dropped; nobody is left to tell.
+            let _ = tx.send(wait_pid(pid, timeout));
+        });
+    if let Err(e) = spawned {
+        let _ = spawn_error_tx.send(Err(SysprimsError::internal(format!(
+            "failed to spawn wait thread: {}",
+            e
+        ))));
+    }
+    WaitHandle { pid, rx }
+}
+
+/// A [`wait_pid`] running on a background thread, from [`wait_pid_handle`].
+///
+/// The result is delivered once: after [`try_recv`](Self::try_recv) returns
+/// it, later calls return `None`. Dropping the handle detaches the thread,
+/// which still runs until the PID exits or the timeout elapses.
+#[derive(Debug)]
+pub struct WaitHandle {
+    pid: u32,
+    rx: mpsc::Receiver<SysprimsResult<WaitPidResult>>,
+}
+
+impl WaitHandle {
+    /// The PID being waited on.
+    pub fn pid(&self) -> u32 {
+        self.pid
+    }
+
+    /// Return the wait result if it is ready, without blocking.
+    pub fn try_recv(&self) -> Option<SysprimsResult<WaitPidResult>> {
+        self.rx.try_recv().ok()
+    }
+
+    /// Block until the wait finishes and return its result.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`wait_pid`] error, or `Internal` if the result was
+    /// already taken by [`try_recv`](Self::try_recv) or the thread died.
+    pub fn join(self) -> SysprimsResult<WaitPidResult> {
+        self.rx.recv().map_err(|_| {
+            SysprimsError::internal(format!(
+                "wait thread for PID {} produced no result",
+                self.pid
+            ))
+        })?
+    }
+}
+
+/// Poll cadence for [`OnExec::TreatAsExit`] identity checks when
+/// `poll_interval` is unset.
+const EXEC_CHECK_INTERVAL: Duration = Duration::from_millis(50);
+
+/// [`wait_pid_with_config`] for [`OnExec::TreatAsExit`].
+///
+/// Waits in short slices via the platform wait and re-reads the process
+/// between slices, returning early once its identity changes.
+fn wait_pid_watching_exec(
+    pid: u32,
+    timeout: Duration,
+    config: &WaitPidConfig,
+) -> SysprimsResult<WaitPidResult> {
+    let options = ProcessOptions::default().without_usernames();
+    let baseline = match platform::get_process_impl(pid, &options) {
+        Ok(info) => info,
+        Err(SysprimsError::NotFound { .. }) => return Err(SysprimsError::not_found(pid)),
+        Err(_) => {
+            let mut result = platform::wait_pid_impl(pid, timeout, config)?;
+            result.warnings.push(Warning::new(
+                WarningCode::Degraded,
+                format!("Cannot read identity of PID {pid}; exec detection disabled"),
+            ));
+            return Ok(result);
+        }
+    };
+
+    let interval = config.poll_interval.unwrap_or(EXEC_CHECK_INTERVAL);
+    let start = Instant::now();
+    let mut first_check = true;
+    loop {
+        let slice = interval.min(timeout.saturating_sub(start.elapsed()));
+        match platform::wait_pid_impl(pid, slice, config) {
+            Ok(result) if !result.timed_out => return Ok(result),
+            Ok(_) => {}
+            // Exited between the identity read and the wait.
+            Err(SysprimsError::NotFound { .. }) if !first_check => {
+                return Ok(WaitPidResult::already_exited(pid));
+            }
+            Err(e) => return Err(e),
+        }
+        first_check = false;
+
+        if let Ok(current) = platform::get_process_impl(pid, &options) {
+            if let Some(warning) = exec_change_warning(&baseline, &current) {
+                return Ok(make_wait_pid_result(pid, true, false, None, vec![warning]));
+            }
+        }
+
+        if start.elapsed() >= timeout {
+            return Ok(make_wait_pid_result(pid, false, true, None, vec![]));
+        }
+    }
+}
+
+/// Warning describing how `current` differs from `baseline`, if it does.
+///
+/// A new start time means the PID now belongs to another process; a new
+/// executable with the same start time means the process exec'd. Fields
+/// unreadable at either point are not compared.
+fn exec_change_warning(baseline: &ProcessInfo, current: &ProcessInfo) -> Option<Warning> {
+    let pid = baseline.pid;
+    if let (Some(before), Some(now)) = (baseline.start_time_unix_ms, current.start_time_unix_ms) {
+        if before != now {
+            return Some(Warning::new(
+                WarningCode::PidReuseGuard,
+                format!("PID {pid} was reused by another process; treating as exited"),
+            ));
+        }
+    }
+
+    // Linux marks a replaced or removed executable with " (deleted)"; that is
+    // not an exec.
+    let strip = |p: &str| p.strip_suffix(" (deleted)").unwrap_or(p).to_string();
+    if let (Some(before), Some(now)) = (&baseline.exe_path, &current.exe_path) {
+        if strip(before) != strip(now) {
+            return Some(Warning::new(
+                WarningCode::Info,
+                format!("Process {pid} exec'd from {before} to {now}; treating as exited"),
+            ));
+        }
+    }
+    None
 }
 
 // ============================================================================
 // Helpers
 // ============================================================================
 
-fn make_port_snapshot(bindings: Vec<PortBinding>, warnings: Vec<String>) -> PortBindingsSnapshot {
+fn make_port_snapshot(bindings: Vec<PortBinding>, warnings: Vec<Warning>) -> PortBindingsSnapshot {
     let mut warnings = warnings;
     if bindings.is_empty() {
-        warnings.push("No listening ports found".to_string());
+        warnings.push(Warning::new(WarningCode::Info, "No listening ports found"));
     }
 
     PortBindingsSnapshot {
@@ -1224,21 +3541,110 @@ fn make_port_snapshot(bindings: Vec<PortBinding>, warnings: Vec<String>) -> Port
         timestamp: current_timestamp(),
         platform: get_platform(),
         bindings,
+        needs_elevation: false,
         warnings,
     }
 }
 
-fn make_fd_snapshot(pid: u32, fds: Vec<FdInfo>, warnings: Vec<String>) -> FdSnapshot {
+fn make_fd_snapshot(pid: u32, fds: Vec<FdInfo>, warnings: Vec<Warning>) -> FdSnapshot {
+    let mut kind_counts = BTreeMap::new();
+    for fd in &fds {
+        *kind_counts.entry(fd.kind).or_insert(0) += 1;
+    }
+
     FdSnapshot {
         schema_id: FD_SNAPSHOT_V1,
         timestamp: current_timestamp(),
         platform: get_platform(),
         pid,
         fds,
+        kind_counts,
         warnings,
     }
 }
 
+/// Exit code of an exited-but-unreaped child of the caller, without reaping it.
+///
+/// Uses `waitid(WNOWAIT)` so the status stays available to whoever owns the
+/// child handle. Returns `None` for non-children (`ECHILD`), children that have
+/// not exited, and children terminated by a signal.
+#[cfg(unix)]
+pub(crate) fn peek_child_exit_code(pid: u32) -> Option<i32> {
+    // SAFETY: siginfo_t is plain data; an all-zero value is valid.
+    let mut info: libc::siginfo_t = unsafe { std::mem::zeroed() };
+    // SAFETY: waitid writes only into `info`; WNOWAIT leaves the child waitable.
+    let rc = sysprims_core::sys::retry_eintr(|| unsafe {
+        libc::waitid(
+            libc::P_PID,
+            pid as libc::id_t,
+            &mut info,
+            libc::WEXITED | libc::WNOHANG | libc::WNOWAIT,
+        )
+    });
+    // With WNOHANG and no state change, waitid succeeds but leaves si_pid zero.
+    // SAFETY: si_pid/si_status are valid to read after a successful waitid.
+    if rc != 0 || unsafe { info.si_pid() } == 0 || info.si_code != libc::CLD_EXITED {
+        return None;
+    }
+    Some(unsafe { info.si_status() })
+}
+
+/// First delay of the adaptive Unix `wait_pid` poll loop.
+#[cfg(unix)]
+const WAIT_POLL_INITIAL: Duration = Duration::from_millis(5);
+
+/// Upper bound on the adaptive Unix `wait_pid` poll delay.
+#[cfg(unix)]
+const WAIT_POLL_MAX: Duration = Duration::from_millis(200);
+
+/// Sleep cadence for the Unix `wait_pid` loops.
+///
+/// Uses `WaitPidConfig::poll_interval` when set; otherwise starts at 5ms and
+/// doubles up to 200ms, so quick exits are seen within milliseconds while long
+/// waits stay cheap. Sleeps never run past the remaining timeout.
+#[cfg(unix)]
+pub(crate) struct WaitBackoff {
+    next: Duration,
+    fixed: bool,
+}
+
+#[cfg(unix)]
+impl WaitBackoff {
+    pub(crate) fn new(config: &WaitPidConfig) -> Self {
+        match config.poll_interval {
+            Some(interval) => Self {
+                next: interval,
+                fixed: true,
+            },
+            None => Self {
+                next: WAIT_POLL_INITIAL,
+                fixed: false,
+            },
+        }
+    }
+
+    /// Delay for the next poll, capped at `remaining`.
+    pub(crate) fn next_delay(&mut self, remaining: Duration) -> Duration {
+        let delay = self.next.min(remaining);
+        if !self.fixed {
+            self.next = (self.next * 2).min(WAIT_POLL_MAX);
+        }
+        delay
+    }
+}
+
+/// Format Unix epoch milliseconds as RFC3339 (UTC), e.g. `2026-01-02T03:04:05.678Z`.
+pub(crate) fn rfc3339_from_unix_ms(ms: u64) -> Option<String> {
+    use time::format_description::well_known::Rfc3339;
+    use time::OffsetDateTime;
+
+    let nanos = i128::from(ms) * 1_000_000;
+    OffsetDateTime::from_unix_timestamp_nanos(nanos)
+        .ok()?
+        .format(&Rfc3339)
+        .ok()
+}
+
 /// Get current timestamp in ISO 8601 format.
 fn current_timestamp() -> String {
     use time::format_description::well_known::Rfc3339;
@@ -1255,6 +3661,10 @@ fn make_snapshot(processes: Vec<ProcessInfo>) -> ProcessSnapshot {
         schema_id: PROCESS_INFO_V1,
         timestamp: current_timestamp(),
         processes,
+        cpu_percent_is_normalized: true,
+        filtered: false,
+        total_before_filter: None,
+        warnings: Vec::new(),
     }
 }
 
@@ -1263,7 +3673,7 @@ fn make_wait_pid_result(
     exited: bool,
     timed_out: bool,
     exit_code: Option<i32>,
-    warnings: Vec<String>,
+    warnings: Vec<Warning>,
 ) -> WaitPidResult {
     WaitPidResult {
         schema_id: WAIT_PID_RESULT_V1,
@@ -1310,6 +3720,107 @@ mod tests {
         assert!(snap.timestamp.contains('Z') || snap.timestamp.contains('+'));
     }
 
+    #[test]
+    fn test_rfc3339_from_unix_ms() {
+        assert_eq!(
+            rfc3339_from_unix_ms(0).as_deref(),
+            Some("1970-01-01T00:00:00Z")
+        );
+        assert_eq!(
+            rfc3339_from_unix_ms(1_700_000_000_123).as_deref(),
+            Some("2023-11-14T22:13:20.123Z")
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_get_process_start_time_matches_unix_ms() {
+        let info = get_process(std::process::id()).unwrap();
+        let ms = info.start_time_unix_ms.expect("start_time_unix_ms");
+        assert_eq!(info.start_time, rfc3339_from_unix_ms(ms));
+    }
+
+    #[test]
+    fn test_get_processes_returns_per_pid_results() {
+        let self_pid = std::process::id();
+        let missing = i32::MAX as u32;
+        let results = get_processes(&[self_pid, missing]).unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, self_pid);
+        assert_eq!(results[0].1.as_ref().unwrap().pid, self_pid);
+        assert_eq!(results[1].0, missing);
+        assert!(matches!(results[1].1, Err(SysprimsError::NotFound { .. })));
+    }
+
+    #[test]
+    fn test_get_processes_rejects_invalid_list() {
+        assert!(matches!(
+            get_processes(&[]),
+            Err(SysprimsError::InvalidArgument { .. })
+        ));
+        assert!(matches!(
+            get_processes(&[std::process::id(), 0]),
+            Err(SysprimsError::InvalidArgument { .. })
+        ));
+    }
+
+    #[test]
+    fn test_mark_sampled_clears_normalized_cpu() {
+        let mut snap = make_snapshot(Vec::new());
+        assert!(snap.cpu_percent_is_normalized);
+        snap.mark_sampled();
+        assert_eq!(snap.schema_id, PROCESS_INFO_SAMPLED_V1);
+        assert!(!snap.cpu_percent_is_normalized);
+
+        let value = serde_json::to_value(&snap).unwrap();
+        assert_eq!(value["cpu_percent_is_normalized"], false);
+        // schema_version follows the ID it was derived from.
+        assert_eq!(value["schema_id"], PROCESS_INFO_SAMPLED_V1);
+        assert_eq!(value["schema_version"], 1);
+    }
+
+    #[test]
+    fn test_snapshot_with_config_zero_timeout_rejected() {
+        let err = snapshot_with_config(SnapshotConfig {
+            per_process_timeout: Some(Duration::ZERO),
+            ..Default::default()
+        })
+        .unwrap_err();
+        assert!(matches!(err, SysprimsError::InvalidArgument { .. }));
+    }
+
+    #[test]
+    fn test_snapshot_with_config_timeout_includes_self() {
+        let snap = snapshot_with_config(SnapshotConfig {
+            per_process_timeout: Some(Duration::from_secs(5)),
+            ..Default::default()
+        })
+        .unwrap();
+        let own_pid = std::process::id();
+        assert!(snap.processes.iter().any(|p| p.pid == own_pid));
+        assert!(snap.warnings.is_empty(), "warnings: {:?}", snap.warnings);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_snapshot_with_config_uid_limits_to_owner() {
+        let uid = unsafe { libc::geteuid() };
+        for per_process_timeout in [None, Some(Duration::from_secs(5))] {
+            let snap = snapshot_with_config(SnapshotConfig {
+                per_process_timeout,
+                uid: Some(uid),
+                ..Default::default()
+            })
+            .unwrap();
+            assert!(snap.processes.iter().any(|p| p.pid == std::process::id()));
+            assert!(
+                snap.processes.iter().all(|p| p.uid == Some(uid)),
+                "foreign process in uid-scoped snapshot"
+            );
+        }
+    }
+
     #[test]
     #[cfg(unix)]
     fn test_snapshot_has_processes() {
@@ -1356,7 +3867,26 @@ mod tests {
     }
 
     #[test]
-    fn test_get_self_has_valid_fields() {
+    fn test_cpu_user_and_system_split_total() {
+        // Burn some user time so the split is not all zeros.
+        let start = Instant::now();
+        let mut x = 0u64;
+        while start.elapsed() < Duration::from_millis(100) {
+            x = std::hint::black_box(x.wrapping_add(1));
+        }
+
+        let pid = std::process::id();
+        let info = get_process(pid).unwrap();
+        let total_after = cpu_total_time_ns(pid).unwrap();
+        let (Some(user), Some(system)) = (info.cpu_user_ns, info.cpu_system_ns) else {
+            return; // Unreadable on this platform/permission context.
+        };
+        assert!(user > 0);
+        assert!(user + system <= total_after);
+    }
+
+    #[test]
+    fn test_get_self_has_valid_fields() {
         let pid = std::process::id();
         let info = get_process(pid).unwrap();
 
@@ -1381,11 +3911,50 @@ mod tests {
         assert!(
             info.state == ProcessState::Running
                 || info.state == ProcessState::Sleeping
+                || info.state == ProcessState::DiskSleep
                 || info.state == ProcessState::Unknown,
             "Test process should be running, sleeping, or unknown (Windows)"
         );
     }
 
+    #[test]
+    fn test_fd_count_trend_helpers() {
+        let interval = Duration::from_millis(500);
+        assert!(fd_counts_climbing(&[10, 10, 12, 15]));
+        assert!(!fd_counts_climbing(&[10, 12, 11, 15]));
+        assert!(!fd_counts_climbing(&[10, 10, 10]));
+        assert_eq!(fd_count_slope(&[10, 10, 10], interval), 0.0);
+        // +1 fd per 500ms sample == 2 fds/sec.
+        assert!((fd_count_slope(&[10, 11, 12, 13], interval) - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fd_growth_rejects_bad_arguments() {
+        let pid = std::process::id();
+        assert!(matches!(
+            fd_growth(pid, 1, Duration::from_millis(10)),
+            Err(SysprimsError::InvalidArgument { .. })
+        ));
+        assert!(matches!(
+            fd_growth(pid, 3, Duration::ZERO),
+            Err(SysprimsError::InvalidArgument { .. })
+        ));
+    }
+
+    #[test]
+    fn test_wait_pid_result_serializes_structured_warnings() {
+        let result = make_wait_pid_result(
+            1,
+            false,
+            true,
+            None,
+            vec![Warning::new(WarningCode::PartialVisibility, "example")],
+        );
+        let value = serde_json::to_value(&result).unwrap();
+        assert_eq!(value["warnings"][0]["code"], "partial_visibility");
+        assert_eq!(value["warnings"][0]["message"], "example");
+    }
+
     #[test]
     fn test_wait_pid_self_times_out() {
         let pid = std::process::id();
@@ -1395,6 +3964,234 @@ mod tests {
         assert!(!r.exited);
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_wait_backoff_grows_to_cap_and_honors_remaining() {
+        let mut backoff = WaitBackoff::new(&WaitPidConfig::default());
+        let long = Duration::from_secs(60);
+        let delays: Vec<_> = (0..8).map(|_| backoff.next_delay(long)).collect();
+        assert_eq!(delays[0], WAIT_POLL_INITIAL);
+        assert!(delays.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(*delays.last().unwrap(), WAIT_POLL_MAX);
+
+        assert_eq!(
+            backoff.next_delay(Duration::from_millis(3)),
+            Duration::from_millis(3)
+        );
+
+        let mut fixed = WaitBackoff::new(&WaitPidConfig {
+            poll_interval: Some(Duration::from_millis(50)),
+            ..WaitPidConfig::default()
+        });
+        assert_eq!(fixed.next_delay(long), Duration::from_millis(50));
+        assert_eq!(fixed.next_delay(long), Duration::from_millis(50));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_wait_pid_reports_zombie_as_exited() {
+        // The child exits immediately but is not reaped until `wait()` below,
+        // so it sits in the zombie state while we poll it.
+        let mut child = std::process::Command::new("true").spawn().unwrap();
+        let pid = child.id();
+        std::thread::sleep(Duration::from_millis(200));
+
+        let r = wait_pid(pid, Duration::from_secs(5)).unwrap();
+        assert!(r.exited);
+        assert!(!r.timed_out);
+
+        let config = WaitPidConfig {
+            zombie_is_exited: false,
+            ..WaitPidConfig::default()
+        };
+        let r = wait_pid_with_config(pid, Duration::from_millis(100), config).unwrap();
+        assert!(r.timed_out);
+        assert!(!r.exited);
+
+        child.wait().unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_state_counts_include_self() {
+        let counts = state_counts().unwrap();
+        let total: usize = counts.values().sum();
+        assert!(total > 0);
+        assert!(counts.values().all(|&n| n > 0));
+        // We are running the scan, so we are counted as Running.
+        assert!(counts.get(&ProcessState::Running).copied().unwrap_or(0) >= 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_children_matches_first_descendants_level() {
+        let mut kids: Vec<_> = (0..2)
+            .map(|_| {
+                std::process::Command::new("sleep")
+                    .arg("30")
+                    .spawn()
+                    .unwrap()
+            })
+            .collect();
+        let mut expected: Vec<u32> = kids.iter().map(|c| c.id()).collect();
+        expected.sort_unstable();
+
+        let pids: Vec<u32> = children(std::process::id())
+            .unwrap()
+            .iter()
+            .map(|p| p.pid)
+            .collect();
+        for pid in &expected {
+            assert!(pids.contains(pid), "missing child {pid} in {pids:?}");
+        }
+        assert!(pids.windows(2).all(|w| w[0] < w[1]));
+
+        let level: Vec<u32> = descendants(std::process::id(), 1, None)
+            .unwrap()
+            .levels
+            .first()
+            .map(|l| l.processes.iter().map(|p| p.pid).collect())
+            .unwrap_or_default();
+        for pid in &expected {
+            assert!(level.contains(pid));
+        }
+
+        for kid in &mut kids {
+            let _ = kid.kill();
+            let _ = kid.wait();
+        }
+    }
+
+    #[test]
+    fn test_children_validates_pid() {
+        assert!(matches!(
+            children(0),
+            Err(SysprimsError::InvalidArgument { .. })
+        ));
+        assert!(matches!(
+            children(u32::MAX),
+            Err(SysprimsError::InvalidArgument { .. })
+        ));
+        assert!(matches!(
+            children(i32::MAX as u32),
+            Err(SysprimsError::NotFound { .. })
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_wait_pid_treats_exec_as_exit() {
+        // The shell runs `sleep 0.3` first so it cannot exec `sleep 30` immediately.
+        let mut child = std::process::Command::new("sh")
+            .args(["-c", "sleep 0.3; exec sleep 30"])
+            .spawn()
+            .unwrap();
+        let pid = child.id();
+        std::thread::sleep(Duration::from_millis(50));
+
+        let ignore = wait_pid(pid, Duration::from_millis(600)).unwrap();
+        assert!(ignore.timed_out, "Ignore should keep waiting across exec");
+
+        let mut child2 = std::process::Command::new("sh")
+            .args(["-c", "sleep 0.3; exec sleep 30"])
+            .spawn()
+            .unwrap();
+        let pid2 = child2.id();
+        std::thread::sleep(Duration::from_millis(50));
+
+        let config = WaitPidConfig {
+            on_exec: OnExec::TreatAsExit,
+            ..WaitPidConfig::default()
+        };
+        let r = wait_pid_with_config(pid2, Duration::from_secs(10), config).unwrap();
+        assert!(r.exited);
+        assert!(!r.timed_out);
+        assert_eq!(r.warnings.len(), 1);
+        assert_eq!(r.warnings[0].code, WarningCode::Info);
+        assert!(r.warnings[0].message.contains("exec'd"));
+
+        for c in [&mut child, &mut child2] {
+            let _ = c.kill();
+            let _ = c.wait();
+        }
+    }
+
+    #[test]
+    fn test_exec_change_warning() {
+        let base = get_process(std::process::id()).unwrap();
+        assert!(exec_change_warning(&base, &base).is_none());
+
+        let mut execd = base.clone();
+        execd.exe_path = Some("/usr/bin/other".to_string());
+        if base.exe_path.is_some() {
+            let w = exec_change_warning(&base, &execd).unwrap();
+            assert_eq!(w.code, WarningCode::Info);
+        }
+
+        let mut deleted = base.clone();
+        deleted.exe_path = base.exe_path.as_ref().map(|p| format!("{p} (deleted)"));
+        assert!(exec_change_warning(&base, &deleted).is_none());
+
+        let mut reused = base.clone();
+        reused.start_time_unix_ms = base.start_time_unix_ms.map(|t| t + 1);
+        if base.start_time_unix_ms.is_some() {
+            let w = exec_change_warning(&base, &reused).unwrap();
+            assert_eq!(w.code, WarningCode::PidReuseGuard);
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_wait_pid_recovers_exit_code_of_own_child() {
+        let mut child = std::process::Command::new("sh")
+            .args(["-c", "exit 7"])
+            .spawn()
+            .unwrap();
+        let pid = child.id();
+
+        let r = wait_pid(pid, Duration::from_secs(5)).unwrap();
+        assert!(r.exited);
+        assert_eq!(r.exit_code, Some(7));
+
+        // The status was only peeked, so the owner can still reap it.
+        assert_eq!(child.wait().unwrap().code(), Some(7));
+    }
+
+    #[test]
+    fn test_wait_pid_handle_times_out_on_self() {
+        let pid = std::process::id();
+        let handle = wait_pid_handle(pid, Duration::from_millis(50));
+        assert_eq!(handle.pid(), pid);
+        let r = handle.join().unwrap();
+        assert!(r.timed_out);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_wait_pid_handle_delivers_exit_once() {
+        let mut child = std::process::Command::new("sh")
+            .args(["-c", "sleep 0.2; exit 4"])
+            .spawn()
+            .unwrap();
+        let handle = wait_pid_handle(child.id(), Duration::from_secs(5));
+        assert!(handle.try_recv().is_none(), "child should still be running");
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let r = loop {
+            if let Some(r) = handle.try_recv() {
+                break r.unwrap();
+            }
+            assert!(Instant::now() < deadline, "wait never finished");
+            std::thread::sleep(Duration::from_millis(10));
+        };
+        assert!(r.exited);
+        assert_eq!(r.exit_code, Some(4));
+        assert!(handle.try_recv().is_none());
+        assert!(handle.join().is_err());
+
+        let _ = child.wait();
+    }
+
     #[test]
     fn test_filter_by_name_contains() {
         // Filter for our own test process
@@ -1426,6 +4223,51 @@ mod tests {
         assert_eq!(snap.processes[0].pid, my_pid);
     }
 
+    #[test]
+    fn test_filter_with_no_matches_reports_total_before_filter() {
+        let filter = ProcessFilter {
+            name_contains: Some("no-such-process-\u{1}".into()),
+            ..Default::default()
+        };
+        let snap = snapshot_filtered(&filter).unwrap();
+
+        assert!(snap.processes.is_empty());
+        assert!(snap.filtered);
+        assert!(snap.total_before_filter.unwrap() > 0);
+
+        let unfiltered = snapshot().unwrap();
+        assert!(!unfiltered.filtered);
+        assert_eq!(unfiltered.total_before_filter, None);
+        let json = serde_json::to_value(&unfiltered).unwrap();
+        assert_eq!(json["filtered"], false);
+        assert!(json.get("total_before_filter").is_none());
+    }
+
+    #[test]
+    fn test_filter_by_name_glob() {
+        let own = get_process(std::process::id()).unwrap();
+        let pattern = format!("{}*", &own.name[..own.name.len().min(3)]);
+        let filter = ProcessFilter {
+            name_glob: Some(pattern.clone()),
+            ..Default::default()
+        };
+        let snap = snapshot_filtered(&filter).unwrap();
+
+        assert!(snap.processes.iter().any(|p| p.pid == own.pid));
+        for proc in &snap.processes {
+            assert!(sysprims_core::glob::glob_match(&pattern, &proc.name));
+        }
+    }
+
+    #[test]
+    fn test_filter_validation_rejects_empty_name_glob() {
+        let filter = ProcessFilter {
+            name_glob: Some(String::new()),
+            ..Default::default()
+        };
+        assert!(filter.validate().is_err());
+    }
+
     #[test]
     fn test_filter_validation_cpu_range() {
         let filter = ProcessFilter {
@@ -1450,6 +4292,48 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_filter_exe_deleted_only_finds_unlinked_binary() {
+        use std::process::{Command, Stdio};
+
+        let exe = std::env::temp_dir().join(format!("sysprims-deleted-exe-{}", std::process::id()));
+        std::fs::copy("/bin/sleep", &exe).unwrap();
+        let spawned = Command::new(&exe)
+            .arg("30")
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn();
+        std::fs::remove_file(&exe).unwrap();
+        let Ok(mut child) = spawned else {
+            eprintln!("SKIP: temp dir does not allow executing binaries");
+            return;
+        };
+
+        let filter = ProcessFilter {
+            pid_in: Some(vec![child.id(), std::process::id()]),
+            exe_deleted_only: true,
+            ..Default::default()
+        };
+        let snap = snapshot_filtered(&filter);
+        let _ = child.kill();
+        let _ = child.wait();
+
+        let pids: Vec<u32> = snap.unwrap().processes.iter().map(|p| p.pid).collect();
+        assert_eq!(pids, vec![child.id()]);
+    }
+
+    #[test]
+    fn test_filter_exclude_kernel_threads_keeps_userspace() {
+        let filter = ProcessFilter {
+            exclude_kernel_threads: true,
+            ..Default::default()
+        };
+        let snap = snapshot_filtered(&filter).unwrap();
+        assert!(snap.processes.iter().all(|p| !is_kernel_thread(p)));
+        assert!(snap.processes.iter().any(|p| p.pid == std::process::id()));
+    }
+
     #[test]
     fn test_nonexistent_pid() {
         // Use a very high PID that shouldn't exist
@@ -1496,6 +4380,9 @@ mod tests {
         let json = serde_json::to_string(&ProcessState::Sleeping).unwrap();
         assert_eq!(json, "\"sleeping\"");
 
+        let json = serde_json::to_string(&ProcessState::DiskSleep).unwrap();
+        assert_eq!(json, "\"disk_sleep\"");
+
         let json = serde_json::to_string(&ProcessState::Zombie).unwrap();
         assert_eq!(json, "\"zombie\"");
     }
@@ -1508,6 +4395,30 @@ mod tests {
         assert_eq!(filter.cpu_above, Some(5.0));
     }
 
+    #[test]
+    fn test_age_bucket_boundaries() {
+        assert_eq!(AgeBucket::from_elapsed_secs(0), AgeBucket::JustStarted);
+        assert_eq!(AgeBucket::from_elapsed_secs(59), AgeBucket::JustStarted);
+        assert_eq!(AgeBucket::from_elapsed_secs(60), AgeBucket::Recent);
+        assert_eq!(AgeBucket::from_elapsed_secs(3_599), AgeBucket::Recent);
+        assert_eq!(AgeBucket::from_elapsed_secs(3_600), AgeBucket::Established);
+        assert_eq!(AgeBucket::from_elapsed_secs(86_399), AgeBucket::Established);
+        assert_eq!(AgeBucket::from_elapsed_secs(86_400), AgeBucket::LongRunning);
+    }
+
+    #[test]
+    fn test_filter_by_age_bucket() {
+        let mut p = get_self().unwrap();
+        p.elapsed_seconds = 120;
+        assert_eq!(p.age_bucket(), AgeBucket::Recent);
+
+        let filter: ProcessFilter =
+            serde_json::from_str(r#"{"age_bucket_in": ["just_started", "recent"]}"#).unwrap();
+        assert!(filter.matches(&p));
+        p.elapsed_seconds = 7_200;
+        assert!(!filter.matches(&p));
+    }
+
     #[test]
     fn test_filter_unknown_field_rejected() {
         let json = r#"{"unknown_field": "value"}"#;
@@ -1522,6 +4433,54 @@ mod tests {
         assert!(result.is_err(), "Unknown fields should be rejected");
     }
 
+    #[test]
+    fn test_port_filter_process_name_contains() {
+        let filter: PortFilter =
+            serde_json::from_str(r#"{"process_name_contains": "NGINX"}"#).unwrap();
+        assert!(filter.validate().is_ok());
+
+        let mut binding = PortBinding {
+            protocol: Protocol::Tcp,
+            local_addr: None,
+            local_port: 80,
+            state: Some("listen".to_string()),
+            pid: None,
+            process: None,
+            inode: None,
+        };
+        assert!(!binding.matches(&filter), "unresolved owner is excluded");
+
+        let mut info = get_process(std::process::id()).unwrap();
+        info.name = "nginx: master".to_string();
+        binding.process = Some(info);
+        assert!(binding.matches(&filter));
+
+        let empty = PortFilter {
+            process_name_contains: Some(String::new()),
+            ..Default::default()
+        };
+        assert!(empty.validate().is_err());
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_needs_elevation_matches_unattributed_bindings() {
+        let snapshot = match listening_ports(None) {
+            Ok(snapshot) => snapshot,
+            Err(SysprimsError::NotSupported { .. }) => return,
+            Err(e) => panic!("listening_ports failed: {e}"),
+        };
+        let json = serde_json::to_value(&snapshot).unwrap();
+        assert_eq!(json["needs_elevation"], snapshot.needs_elevation);
+        if snapshot.needs_elevation {
+            assert!(snapshot.bindings.iter().any(|b| b.pid.is_none()));
+            assert!(snapshot
+                .warnings
+                .iter()
+                .any(|w| w.message.contains("requires matching uid or root")));
+        }
+    }
+
     #[test]
     fn test_port_filter_schema_id() {
         assert!(PortFilter::schema_id().contains("port-filter"));
@@ -1536,6 +4495,38 @@ mod tests {
         let opts = ProcessOptions::default().with_env().with_threads();
         assert!(opts.include_env);
         assert!(opts.include_threads);
+        assert!(!opts.include_cgroup);
+
+        let opts = ProcessOptions::default().with_cgroup();
+        assert!(opts.include_cgroup);
+
+        let opts = ProcessOptions::default().with_socket_count();
+        assert!(opts.include_socket_count);
+
+        let opts = ProcessOptions::default().with_net();
+        assert!(opts.include_net);
+
+        let opts = ProcessOptions::default().with_limits();
+        assert!(opts.include_limits);
+
+        let opts = ProcessOptions::default().with_signal_masks();
+        assert!(opts.include_signal_masks);
+
+        let opts = ProcessOptions::default().with_exe_stat();
+        assert!(opts.include_exe_stat);
+
+        let opts = ProcessOptions::default().with_identity();
+        assert!(opts.include_identity);
+
+        let opts = ProcessOptions::default().with_cmdline_string();
+        assert!(opts.include_cmdline_string);
+
+        let opts = ProcessOptions::default().with_raw_bytes();
+        assert!(opts.preserve_raw_bytes);
+
+        assert!(ProcessOptions::default().resolve_usernames);
+        let opts = ProcessOptions::default().without_usernames();
+        assert!(!opts.resolve_usernames);
     }
 
     #[test]
@@ -1544,6 +4535,390 @@ mod tests {
         let info = get_process_with_options(pid, ProcessOptions::default()).unwrap();
         assert!(info.env.is_none());
         assert!(info.thread_count.is_none());
+        assert!(info.cgroup.is_none());
+        assert!(info.socket_count.is_none());
+        assert!(info.net_rx_bytes.is_none());
+        assert!(info.net_tx_bytes.is_none());
+        assert!(info.fd_limit_soft.is_none());
+        assert!(info.sig_blocked.is_none());
+        assert!(info.exe_inode.is_none());
+        assert!(info.exe_deleted.is_none());
+        assert!(info.identity.is_none());
+        assert!(info.cmdline_string.is_none());
+        assert!(info.name_bytes.is_none());
+        assert!(info.cmdline_bytes.is_none());
+    }
+
+    #[test]
+    fn test_identity_key_is_stable_and_order_independent() {
+        let mut info = get_self().unwrap();
+        info.exe_path = Some("/usr/bin/app".into());
+        info.cmdline = args(&["app", "--b", "--a"]);
+        // Pinned so the algorithm cannot drift between releases or platforms.
+        assert_eq!(info.identity_key(), "6c5cd7b658633bac");
+
+        info.pid += 1;
+        info.cmdline = args(&["app", "--a", "--b"]);
+        assert_eq!(info.identity_key(), "6c5cd7b658633bac");
+
+        info.exe_path = None;
+        assert_ne!(info.identity_key(), "6c5cd7b658633bac");
+    }
+
+    #[test]
+    fn test_identity_option_fills_identity() {
+        let info = get_process_with_options(
+            std::process::id(),
+            ProcessOptions::default().with_identity(),
+        )
+        .unwrap();
+        assert_eq!(info.identity.as_deref(), Some(info.identity_key().as_str()));
+    }
+
+    #[test]
+    fn test_join_cmdline_quotes_only_when_needed() {
+        assert_eq!(
+            join_cmdline(&args(&["/usr/bin/app", "--port=8080", "-v"])),
+            "/usr/bin/app --port=8080 -v"
+        );
+        assert_eq!(
+            join_cmdline(&args(&["echo", "hello world", "", "it's", "$HOME"])),
+            r#"echo 'hello world' '' 'it'\''s' '$HOME'"#
+        );
+        assert_eq!(join_cmdline(&[]), "");
+    }
+
+    #[test]
+    fn test_cmdline_string_option_fills_cmdline_string() {
+        let info = get_process_with_options(
+            std::process::id(),
+            ProcessOptions::default().with_cmdline_string(),
+        )
+        .unwrap();
+        assert_eq!(
+            info.cmdline_string.as_deref(),
+            Some(join_cmdline(&info.cmdline).as_str())
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_without_usernames_reports_uid_only() {
+        let pid = std::process::id();
+        let info =
+            get_process_with_options(pid, ProcessOptions::default().without_usernames()).unwrap();
+        assert!(info.user.is_none());
+        assert_eq!(info.uid, Some(unsafe { libc::geteuid() }));
+
+        // user_equals still matches: the filter forces resolution.
+        let user = get_process(pid).unwrap().user;
+        if user.is_some() {
+            let filter = ProcessFilter {
+                pid_in: Some(vec![pid]),
+                user_equals: user.clone(),
+                ..Default::default()
+            };
+            let snap = snapshot_filtered_with_options(
+                &filter,
+                ProcessOptions::default().without_usernames(),
+            )
+            .unwrap();
+            assert_eq!(snap.processes.len(), 1);
+            assert_eq!(snap.processes[0].user, user);
+        }
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_is_descendant_of_walks_parent_chain() {
+        use std::process::{Command, Stdio};
+
+        let me = std::process::id();
+        let parent = get_self().unwrap().ppid;
+        assert!(is_descendant_of(me, parent).unwrap());
+        assert!(!is_descendant_of(me, me).unwrap());
+
+        let mut child = Command::new("sleep")
+            .arg("5")
+            .stdin(Stdio::null())
+            .spawn()
+            .expect("Failed to spawn sleep");
+        let child_pid = child.id();
+        let forward = is_descendant_of(child_pid, me);
+        let backward = is_descendant_of(me, child_pid);
+        let _ = child.kill();
+        let _ = child.wait();
+
+        assert!(forward.unwrap());
+        assert!(!backward.unwrap());
+        assert!(matches!(
+            is_descendant_of(0, me),
+            Err(SysprimsError::InvalidArgument { .. })
+        ));
+        assert!(matches!(
+            is_descendant_of(99_999_999, me),
+            Err(SysprimsError::NotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_started_after_flags_reused_parent_pids() {
+        assert!(started_after(Some(2_000), Some(1_000)));
+        assert!(!started_after(Some(1_000), Some(1_000)));
+        assert!(!started_after(Some(500), Some(1_000)));
+        // Unknown start times never break the chain.
+        assert!(!started_after(None, Some(1_000)));
+        assert!(!started_after(Some(2_000), None));
+    }
+
+    #[test]
+    fn test_snapshot_cache_reuses_within_ttl() {
+        let cache = SnapshotCache::new(Duration::from_secs(60));
+        let a = cache.get().unwrap();
+        let b = cache.get().unwrap();
+        assert!(Arc::ptr_eq(&a, &b));
+
+        cache.invalidate();
+        let c = cache.get().unwrap();
+        assert!(!Arc::ptr_eq(&a, &c));
+
+        let uncached = SnapshotCache::new(Duration::ZERO);
+        let d = uncached.get().unwrap();
+        assert!(!Arc::ptr_eq(&d, &uncached.get().unwrap()));
+    }
+
+    #[test]
+    fn test_filter_snapshot_matches_snapshot_filtered() {
+        let snap = snapshot().unwrap();
+        let filter = ProcessFilter {
+            pid_in: Some(vec![std::process::id()]),
+            ..Default::default()
+        };
+        let filtered = filter_snapshot(&snap, &filter);
+        assert_eq!(filtered.processes.len(), 1);
+        assert_eq!(filtered.processes[0].pid, std::process::id());
+        assert_eq!(filtered.timestamp, snap.timestamp);
+        assert_eq!(filtered.schema_id, snap.schema_id);
+
+        let everything = filter_snapshot(&snap, &ProcessFilter::default());
+        assert_eq!(everything.processes.len(), snap.processes.len());
+    }
+
+    fn args(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_limit_cmdline_within_limits_is_untouched() {
+        let mut cmdline = args(&["prog", "--flag", "value"]);
+        assert!(!limit_cmdline(&mut cmdline, &ProcessOptions::default()));
+        assert_eq!(cmdline, args(&["prog", "--flag", "value"]));
+    }
+
+    #[test]
+    fn test_limit_cmdline_caps_argument_count() {
+        let mut cmdline = args(&["prog", "a", "b", "c"]);
+        let opts = ProcessOptions::default().with_cmdline_limits(None, Some(2));
+        assert!(limit_cmdline(&mut cmdline, &opts));
+        assert_eq!(cmdline, args(&["prog", "a", CMDLINE_TRUNCATED_SENTINEL]));
+    }
+
+    #[test]
+    fn test_limit_cmdline_caps_bytes_at_char_boundary() {
+        // "prog" + separator uses 5 bytes, leaving 4 for "héllo" ('é' is 2 bytes).
+        let mut cmdline = args(&["prog", "héllo", "rest"]);
+        let opts = ProcessOptions::default().with_cmdline_limits(Some(9), None);
+        assert!(limit_cmdline(&mut cmdline, &opts));
+        assert_eq!(cmdline, args(&["prog", "hél", CMDLINE_TRUNCATED_SENTINEL]));
+
+        // No room left for the next argument: it is dropped entirely.
+        let mut cmdline = args(&["prog", "arg"]);
+        let opts = ProcessOptions::default().with_cmdline_limits(Some(5), None);
+        assert!(limit_cmdline(&mut cmdline, &opts));
+        assert_eq!(cmdline, args(&["prog", CMDLINE_TRUNCATED_SENTINEL]));
+    }
+
+    #[test]
+    fn test_limit_cmdline_caps_raw_bytes() {
+        // Raw arguments are cut at the byte limit, even mid-sequence.
+        let mut raw = vec![b"prog".to_vec(), b"h\xc3\xa9llo".to_vec()];
+        let opts = ProcessOptions::default().with_cmdline_limits(Some(7), None);
+        assert!(limit_cmdline(&mut raw, &opts));
+        assert_eq!(
+            raw,
+            vec![
+                b"prog".to_vec(),
+                b"h\xc3".to_vec(),
+                CMDLINE_TRUNCATED_SENTINEL.as_bytes().to_vec()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_raw_bytes_serialize_as_base64() {
+        let fd = FdInfo {
+            fd: 3,
+            kind: FdKind::File,
+            path: Some("\u{fffd}\u{fffd}".to_string()),
+            path_bytes: Some(vec![0xff, 0xfe, 0x00, 0x80]),
+        };
+        let value = serde_json::to_value(&fd).unwrap();
+        assert_eq!(value["path_bytes"], "//4AgA==");
+    }
+
+    #[test]
+    fn test_limit_cmdline_none_is_unlimited() {
+        let mut cmdline = args(&["prog"; 10_000]);
+        let opts = ProcessOptions::default().with_cmdline_limits(None, None);
+        assert!(!limit_cmdline(&mut cmdline, &opts));
+        assert_eq!(cmdline.len(), 10_000);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_get_process_truncates_spawned_cmdline() {
+        use std::process::{Command, Stdio};
+
+        let mut child = Command::new("sh")
+            .args(["-c", "sleep 5", "sh", "one", "two", "three"])
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("Failed to spawn sh process");
+
+        let opts = ProcessOptions::default().with_cmdline_limits(None, Some(2));
+        let result = get_process_with_options(child.id(), opts);
+        let _ = child.kill();
+        let _ = child.wait();
+
+        let info = result.unwrap();
+        assert_eq!(info.cmdline.len(), 3, "got {:?}", info.cmdline);
+        assert_eq!(info.cmdline[2], CMDLINE_TRUNCATED_SENTINEL);
+    }
+
+    #[test]
+    fn test_get_self_matches_own_pid() {
+        let info = get_self().unwrap();
+        assert_eq!(info.pid, std::process::id());
+    }
+
+    #[test]
+    fn test_net_option_reports_namespace_counters() {
+        let pid = std::process::id();
+        let info = get_process_with_options(pid, ProcessOptions::default().with_net()).unwrap();
+
+        if !cfg!(target_os = "linux") {
+            assert!(info.net_rx_bytes.is_none());
+            assert!(info.net_tx_bytes.is_none());
+        } else if std::path::Path::new("/proc/self/net/dev").exists() {
+            assert!(info.net_rx_bytes.is_some());
+            assert!(info.net_tx_bytes.is_some());
+        }
+    }
+
+    #[test]
+    fn test_limits_option_reports_own_nofile_limit() {
+        let pid = std::process::id();
+        let info = get_process_with_options(pid, ProcessOptions::default().with_limits()).unwrap();
+
+        if cfg!(target_os = "linux") {
+            let soft = info.fd_limit_soft.expect("soft limit");
+            let hard = info.fd_limit_hard.expect("hard limit");
+            assert!(soft <= hard, "soft {soft} > hard {hard}");
+        } else {
+            assert!(info.fd_limit_soft.is_none());
+            assert!(info.fd_limit_hard.is_none());
+        }
+    }
+
+    #[test]
+    fn test_signal_is_blocked() {
+        let mask = (1u64 << 14) | (1u64 << 63);
+        assert!(signal_is_blocked(mask, 15));
+        assert!(signal_is_blocked(mask, 64));
+        assert!(!signal_is_blocked(mask, 9));
+        assert!(!signal_is_blocked(u64::MAX, 0));
+        assert!(!signal_is_blocked(u64::MAX, 65));
+    }
+
+    #[test]
+    fn test_signal_masks_option_reports_own_masks() {
+        let pid = std::process::id();
+        let info =
+            get_process_with_options(pid, ProcessOptions::default().with_signal_masks()).unwrap();
+
+        if cfg!(target_os = "linux") {
+            assert!(info.sig_blocked.is_some());
+            assert!(info.sig_ignored.is_some());
+            let caught = info.sig_caught.expect("caught mask");
+            // SIGKILL can never be caught.
+            assert!(!signal_is_blocked(caught, 9));
+        } else {
+            assert!(info.sig_blocked.is_none());
+            assert!(info.sig_ignored.is_none());
+            assert!(info.sig_caught.is_none());
+        }
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_socket_count_sees_open_listener() {
+        let pid = std::process::id();
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let info =
+            get_process_with_options(pid, ProcessOptions::default().with_socket_count()).unwrap();
+        drop(listener);
+
+        assert!(info.socket_count.is_some_and(|n| n >= 1));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_exe_stat_matches_current_exe() {
+        use std::os::unix::fs::MetadataExt;
+
+        let info = get_process_with_options(
+            std::process::id(),
+            ProcessOptions::default().with_exe_stat(),
+        )
+        .unwrap();
+        let meta = std::fs::metadata(std::env::current_exe().unwrap()).unwrap();
+
+        assert_eq!(info.exe_inode, Some(meta.ino()));
+        assert_eq!(info.exe_device, Some(meta.dev()));
+        assert_eq!(info.exe_deleted, Some(false));
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_cgroup_contains_matches_own_cgroup() {
+        let pid = std::process::id();
+        let info = get_process_with_options(pid, ProcessOptions::default().with_cgroup()).unwrap();
+        // Minimal sandboxes may not mount /proc/[pid]/cgroup.
+        let Some(cgroup) = info.cgroup else {
+            return;
+        };
+
+        let filter = ProcessFilter {
+            pid_in: Some(vec![pid]),
+            cgroup_contains: Some(cgroup),
+            ..Default::default()
+        };
+        let snap = snapshot_filtered(&filter).unwrap();
+        assert_eq!(snap.processes.len(), 1);
+        assert!(snap.processes[0].cgroup.is_some());
+    }
+
+    #[test]
+    fn test_cgroup_contains_rejects_process_without_cgroup() {
+        let info = get_process(std::process::id()).unwrap();
+        let filter = ProcessFilter {
+            cgroup_contains: Some("/".into()),
+            ..Default::default()
+        };
+        assert!(info.cgroup.is_none());
+        assert!(!filter.matches(&info));
     }
 
     #[test]
@@ -1579,6 +4954,8 @@ mod tests {
             filter: None,
             cpu_mode: CpuMode::Lifetime,
             sample_duration: None,
+            poll: None,
+            deadline: None,
         };
 
         let result = descendants_with_config(config).unwrap();
@@ -1595,6 +4972,8 @@ mod tests {
             filter: None,
             cpu_mode: CpuMode::Monitor,
             sample_duration: Some(Duration::from_millis(1)),
+            poll: None,
+            deadline: None,
         };
 
         let result = descendants_with_config(config).unwrap();
@@ -1611,6 +4990,8 @@ mod tests {
             filter: None,
             cpu_mode: CpuMode::Monitor,
             sample_duration: Some(Duration::ZERO),
+            poll: None,
+            deadline: None,
         };
 
         let err = descendants_with_config(config).unwrap_err();
@@ -1625,12 +5006,192 @@ mod tests {
             filter: None,
             cpu_mode: CpuMode::Monitor,
             sample_duration: Some(Duration::from_secs(1)),
+            poll: None,
+            deadline: None,
+        };
+
+        let err = descendants_with_config(config).unwrap_err();
+        assert!(matches!(err, SysprimsError::InvalidArgument { .. }));
+    }
+
+    #[test]
+    fn test_descendants_with_config_zero_deadline_rejected() {
+        let config = DescendantsConfig {
+            root_pid: std::process::id(),
+            max_levels: Some(1),
+            filter: None,
+            cpu_mode: CpuMode::Lifetime,
+            sample_duration: None,
+            poll: None,
+            deadline: Some(Duration::ZERO),
         };
 
         let err = descendants_with_config(config).unwrap_err();
         assert!(matches!(err, SysprimsError::InvalidArgument { .. }));
     }
 
+    #[test]
+    fn test_descendants_with_config_expired_deadline_truncates() {
+        // The root lookup alone outlasts a 1ns budget.
+        let config = DescendantsConfig {
+            root_pid: std::process::id(),
+            max_levels: None,
+            filter: None,
+            cpu_mode: CpuMode::Lifetime,
+            sample_duration: None,
+            poll: Some(PollConfig {
+                interval: Duration::from_millis(10),
+                duration: Duration::from_secs(30),
+            }),
+            deadline: Some(Duration::from_nanos(1)),
+        };
+
+        let started = Instant::now();
+        let result = descendants_with_config(config).unwrap();
+        assert!(started.elapsed() < Duration::from_secs(10));
+        assert!(result.truncated);
+        assert_eq!(result.warnings.len(), 1, "{:?}", result.warnings);
+        assert_eq!(result.transient_pids, Some(Vec::new()));
+    }
+
+    #[test]
+    fn test_descendants_with_config_poll_zero_interval_rejected() {
+        let config = DescendantsConfig {
+            root_pid: std::process::id(),
+            max_levels: Some(1),
+            filter: None,
+            cpu_mode: CpuMode::Lifetime,
+            sample_duration: None,
+            poll: Some(PollConfig {
+                interval: Duration::ZERO,
+                duration: Duration::from_millis(10),
+            }),
+            deadline: None,
+        };
+
+        let err = descendants_with_config(config).unwrap_err();
+        assert!(matches!(err, SysprimsError::InvalidArgument { .. }));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_descendants_with_config_poll_reports_transient_child() {
+        use std::process::{Command, Stdio};
+
+        // SAFETY: We spawn this child ourselves; it exits on its own.
+        let mut child = Command::new("sleep")
+            .arg("0.1")
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("Failed to spawn sleep process");
+        let child_pid = child.id();
+
+        // Reap in the background so the child does not linger as a zombie.
+        let reaper = std::thread::spawn(move || child.wait());
+
+        let config = DescendantsConfig {
+            root_pid: std::process::id(),
+            max_levels: Some(1),
+            filter: None,
+            cpu_mode: CpuMode::Lifetime,
+            sample_duration: None,
+            poll: Some(PollConfig {
+                interval: Duration::from_millis(20),
+                duration: Duration::from_millis(400),
+            }),
+            deadline: None,
+        };
+
+        let result = descendants_with_config(config).unwrap();
+        let _ = reaper.join();
+
+        let transient = result.transient_pids.expect("poll sets transient_pids");
+        assert!(
+            transient.contains(&child_pid),
+            "expected {child_pid} in transient set {transient:?}"
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_descendants_levels_sorted_by_pid() {
+        use std::process::{Command, Stdio};
+
+        let mut children: Vec<_> = (0..3)
+            .map(|_| {
+                Command::new("sleep")
+                    .arg("5")
+                    .stdin(Stdio::null())
+                    .stdout(Stdio::null())
+                    .stderr(Stdio::null())
+                    .spawn()
+                    .expect("Failed to spawn sleep process")
+            })
+            .collect();
+
+        let result = descendants(std::process::id(), 1, None);
+
+        for child in &mut children {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+
+        let result = result.unwrap();
+        for level in &result.levels {
+            let pids: Vec<u32> = level.processes.iter().map(|p| p.pid).collect();
+            let mut sorted = pids.clone();
+            sorted.sort_unstable();
+            assert_eq!(pids, sorted, "level {} not sorted by pid", level.level);
+        }
+    }
+
+    #[test]
+    fn test_descendants_to_tree_nests_and_sorts() {
+        let base = get_self().unwrap();
+        let proc_with = |pid: u32, ppid: u32| ProcessInfo {
+            pid,
+            ppid,
+            ..base.clone()
+        };
+        let result = DescendantsResult {
+            schema_id: DESCENDANTS_RESULT_V1,
+            root_pid: 10,
+            max_levels: u32::MAX,
+            levels: vec![
+                DescendantsLevel {
+                    level: 1,
+                    processes: vec![proc_with(30, 10), proc_with(20, 10)],
+                },
+                DescendantsLevel {
+                    level: 2,
+                    processes: vec![proc_with(31, 30), proc_with(99, 50)],
+                },
+            ],
+            total_found: 4,
+            matched_by_filter: 4,
+            timestamp: String::new(),
+            platform: get_platform(),
+            transient_pids: None,
+            truncated: false,
+            warnings: Vec::new(),
+        };
+
+        let tree = result.to_tree();
+        assert_eq!(tree.pid, 10);
+        assert!(tree.info.is_none());
+        let top: Vec<u32> = tree.children.iter().map(|n| n.pid).collect();
+        // 99's parent (50) was not in the result, so it hangs off the root.
+        assert_eq!(top, vec![20, 30, 99]);
+        assert_eq!(tree.children[1].children[0].pid, 31);
+        assert!(tree.children[0].children.is_empty());
+
+        let json = serde_json::to_value(result.to_tree_result()).unwrap();
+        assert_eq!(json["schema_id"], DESCENDANTS_TREE_V1);
+        assert_eq!(json["root"]["pid"], 10);
+    }
+
     #[test]
     fn test_snapshot_json_output() {
         let snap = snapshot().unwrap();
@@ -15,16 +15,19 @@ use crate::{
 use crate::{MAX_ENV_ENTRIES, MAX_ENV_KEY_BYTES, MAX_ENV_TOTAL_BYTES, MAX_ENV_VALUE_BYTES};
 #[cfg(feature = "proc_ext")]
 use std::collections::BTreeMap;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::ffi::CStr;
 use std::fs;
 use std::io;
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::fs::MetadataExt;
 use std::path::Path;
 use std::thread;
 use std::time::{Duration, Instant};
 use std::time::{SystemTime, UNIX_EPOCH};
-use sysprims_core::{SysprimsError, SysprimsResult};
+use sysprims_core::sys::{self, elevation_would_help};
+use sysprims_core::{SysprimsError, SysprimsResult, Warning, WarningCode};
 
 // ============================================================================
 // Implementation
@@ -33,6 +36,18 @@ use sysprims_core::{SysprimsError, SysprimsResult};
 pub fn snapshot_impl(options: &ProcessOptions) -> SysprimsResult<ProcessSnapshot> {
     let mut processes = Vec::new();
 
+    for pid in list_pids_impl()? {
+        // Silently skip processes we can't read
+        if let Ok(info) = read_process_info(pid, options) {
+            processes.push(info);
+        }
+    }
+
+    Ok(make_snapshot(processes))
+}
+
+/// List visible PIDs from the numeric entries of `/proc`.
+pub(crate) fn list_pids_impl() -> SysprimsResult<Vec<u32>> {
     // Read /proc directory for numeric entries (PIDs)
     let proc_dir = match fs::read_dir("/proc") {
         Ok(dir) => dir,
@@ -44,6 +59,7 @@ pub fn snapshot_impl(options: &ProcessOptions) -> SysprimsResult<ProcessSnapshot
         }
     };
 
+    let mut pids = Vec::new();
     for entry in proc_dir.flatten() {
         let name = entry.file_name();
         let name_str = name.to_string_lossy();
@@ -63,25 +79,76 @@ pub fn snapshot_impl(options: &ProcessOptions) -> SysprimsResult<ProcessSnapshot
             continue;
         }
 
-        // Silently skip processes we can't read
-        if let Ok(info) = read_process_info(pid, options) {
-            processes.push(info);
+        pids.push(pid);
+    }
+
+    Ok(pids)
+}
+
+/// Tally process states from the state field of `/proc/[pid]/stat` alone.
+pub(crate) fn state_counts_impl() -> SysprimsResult<HashMap<ProcessState, usize>> {
+    let self_pid = std::process::id();
+    let mut counts = HashMap::new();
+    for pid in list_pids_impl()? {
+        // stat reports the main thread, which may be parked while another
+        // thread runs this scan; the caller is running by definition.
+        if pid == self_pid {
+            *counts.entry(ProcessState::Running).or_insert(0) += 1;
+            continue;
         }
+        // Skip processes that exit mid-scan. comm may be non-UTF-8, so find
+        // the state byte after its closing paren without decoding.
+        let Ok(stat) = fs::read(format!("/proc/{pid}/stat")) else {
+            continue;
+        };
+        let state = stat
+            .iter()
+            .rposition(|&b| b == b')')
+            .and_then(|end| stat.get(end + 2))
+            .map_or(ProcessState::Unknown, |&b| state_from_stat_char(b as char));
+        *counts.entry(state).or_insert(0) += 1;
     }
+    Ok(counts)
+}
 
-    Ok(make_snapshot(processes))
+/// Owner of `/proc/[pid]`, or `None` if the process is gone.
+pub(crate) fn owner_uid_impl(pid: u32) -> Option<u32> {
+    fs::metadata(format!("/proc/{pid}")).ok().map(|m| m.uid())
+}
+
+/// Direct children of `pid` from `/proc/[pid]/task/*/children`.
+///
+/// Returns `None` when the kernel lacks the `children` files
+/// (`CONFIG_PROC_CHILDREN`) or they cannot be read, so the caller can fall
+/// back to a full scan. Children are listed per thread, so every task is read.
+pub(crate) fn child_pids_impl(pid: u32) -> Option<Vec<u32>> {
+    let tasks = fs::read_dir(format!("/proc/{pid}/task")).ok()?;
+    let mut pids = Vec::new();
+    for task in tasks.flatten() {
+        let contents = fs::read_to_string(task.path().join("children")).ok()?;
+        pids.extend(
+            contents
+                .split_ascii_whitespace()
+                .filter_map(|s| s.parse::<u32>().ok()),
+        );
+    }
+    pids.sort_unstable();
+    pids.dedup();
+    Some(pids)
 }
 
-pub fn list_fds_impl(pid: u32) -> SysprimsResult<(Vec<FdInfo>, Vec<String>)> {
+pub fn list_fds_impl(pid: u32) -> SysprimsResult<(Vec<FdInfo>, Vec<Warning>)> {
     let proc_fd_dir = Path::new("/proc").join(pid.to_string()).join("fd");
     let entries = match fs::read_dir(&proc_fd_dir) {
         Ok(d) => d,
         Err(e) => {
             return Err(match e.kind() {
                 io::ErrorKind::NotFound => SysprimsError::not_found(pid),
-                io::ErrorKind::PermissionDenied => {
-                    SysprimsError::permission_denied(pid, "list fds")
-                }
+                io::ErrorKind::PermissionDenied => SysprimsError::permission_denied_with_elevation(
+                    pid,
+                    "list fds",
+                    elevation_would_help(pid),
+                ),
                 _ => SysprimsError::internal(format!(
                     "Failed to read {}: {}",
                     proc_fd_dir.display(),
@@ -110,8 +177,11 @@ pub fn list_fds_impl(pid: u32) -> SysprimsResult<(Vec<FdInfo>, Vec<String>)> {
             Err(_) => continue,
         };
 
-        let target = match fs::read_link(entry.path()) {
-            Ok(t) => t.to_string_lossy().into_owned(),
+        let (target, path_bytes) = match fs::read_link(entry.path()) {
+            Ok(t) => (
+                t.to_string_lossy().into_owned(),
+                non_utf8(t.as_os_str().as_bytes()),
+            ),
             Err(_) => {
                 read_errors += 1;
                 continue;
@@ -132,6 +202,7 @@ pub fn list_fds_impl(pid: u32) -> SysprimsResult<(Vec<FdInfo>, Vec<String>)> {
             fd,
             kind,
             path: Some(target),
+            path_bytes,
         });
     }
 
@@ -149,26 +220,45 @@ pub fn get_process_impl(pid: u32, options: &ProcessOptions) -> SysprimsResult<Pr
     read_process_info(pid, options)
 }
 
-pub fn wait_pid_impl(pid: u32, timeout: Duration) -> SysprimsResult<crate::WaitPidResult> {
+pub fn get_processes_impl(
+    pids: &[u32],
+    options: &ProcessOptions,
+) -> Vec<(u32, SysprimsResult<ProcessInfo>)> {
+    pids.iter()
+        .map(|&pid| (pid, read_process_info(pid, options)))
+        .collect()
+}
+
+pub fn wait_pid_impl(
+    pid: u32,
+    timeout: Duration,
+    config: &crate::WaitPidConfig,
+) -> SysprimsResult<crate::WaitPidResult> {
     let start = Instant::now();
     let mut first_check = true;
+    let mut backoff = crate::WaitBackoff::new(config);
 
     loop {
         // SAFETY: kill(pid, 0) does not send a signal; it performs an existence/permission check.
-        let rc = unsafe { libc::kill(pid as libc::pid_t, 0) };
+        let rc = sysprims_core::sys::retry_eintr(|| unsafe { libc::kill(pid as libc::pid_t, 0) });
         if rc == 0 {
             // Still running.
             // On Unix, an exited-but-unreaped child remains as a zombie and still
             // responds to kill(pid, 0). Treat zombies as exited for supervisor use.
-            if let Ok(info) = read_process_info(pid, &ProcessOptions::default()) {
-                if info.state == crate::ProcessState::Zombie {
-                    return Ok(crate::make_wait_pid_result(pid, true, false, None, vec![]));
-                }
+            if config.zombie_is_exited && is_zombie(pid) {
+                let exit_code = crate::peek_child_exit_code(pid);
+                return Ok(crate::make_wait_pid_result(
+                    pid,
+                    true,
+                    false,
+                    exit_code,
+                    vec![],
+                ));
             }
             if start.elapsed() >= timeout {
                 return Ok(crate::make_wait_pid_result(pid, false, true, None, vec![]));
             }
-            thread::sleep(Duration::from_millis(25));
+            thread::sleep(backoff.next_delay(timeout.saturating_sub(start.elapsed())));
             first_check = false;
             continue;
         }
@@ -182,16 +272,20 @@ pub fn wait_pid_impl(pid: u32, timeout: Duration) -> SysprimsResult<crate::WaitP
             return Ok(crate::make_wait_pid_result(pid, true, false, None, vec![]));
         }
         if errno == libc::EPERM {
-            return Err(SysprimsError::permission_denied(pid, "wait pid"));
+            return Err(SysprimsError::permission_denied_with_elevation(
+                pid,
+                "wait pid",
+                elevation_would_help(pid),
+            ));
         }
 
         return Err(SysprimsError::system("kill(pid, 0) failed", errno));
     }
 }
 
-pub fn listening_ports_impl() -> SysprimsResult<PortBindingsSnapshot> {
+pub fn listening_ports_impl(options: &ProcessOptions) -> SysprimsResult<PortBindingsSnapshot> {
     let mut warnings = Vec::new();
-    let mut bindings = collect_socket_bindings()?;
+    let mut bindings = collect_socket_bindings("/proc/net")?;
 
     if bindings.is_empty() {
         return Ok(make_port_snapshot(bindings, warnings));
@@ -208,24 +302,82 @@ pub fn listening_ports_impl() -> SysprimsResult<PortBindingsSnapshot> {
             map
         }
         Err(err) => {
-            warnings.push(format!("Failed to map socket inodes to PIDs: {}", err));
+            warnings.push(Warning::new(
+                WarningCode::PartialVisibility,
+                format!("Failed to map socket inodes to PIDs: {}", err),
+            ));
             HashMap::new()
         }
     };
 
+    let mut unattributed = 0usize;
     for binding in &mut bindings {
         if let Some(inode) = binding_inode(binding) {
             if let Some(pid) = inode_to_pid.get(&inode) {
                 binding.pid = Some(*pid);
-                if let Ok(process) = read_process_info(*pid, &ProcessOptions::default()) {
+                if let Ok(process) = read_process_info(*pid, options) {
                     binding.process = Some(process);
                 }
+            } else {
+                unattributed += 1;
             }
         }
         binding.inode = None;
     }
 
-    Ok(make_port_snapshot(bindings, warnings))
+    // The socket is listed but no readable fd references it: its owner's
+    // /proc/[pid]/fd is closed to us.
+    let needs_elevation = unattributed > 0 && !sys::is_elevated();
+    if needs_elevation {
+        warnings.push(Warning::new(
+            WarningCode::PermissionDenied,
+            format!(
+                "PID attribution requires matching uid or root for {} bindings",
+                unattributed
+            ),
+        ));
+    }
+
+    let mut snapshot = make_port_snapshot(bindings, warnings);
+    snapshot.needs_elevation = needs_elevation;
+    Ok(snapshot)
+}
+
+pub fn listening_ports_for_pid_impl(
+    pid: u32,
+    options: &ProcessOptions,
+) -> SysprimsResult<PortBindingsSnapshot> {
+    let proc_path = Path::new("/proc").join(pid.to_string());
+    let entries = fs::read_dir(proc_path.join("fd")).map_err(|e| map_io_error(e, pid))?;
+    let inodes: HashSet<u64> = entries
+        .flatten()
+        .filter_map(|entry| fs::read_link(entry.path()).ok())
+        .filter_map(|target| parse_socket_inode(&target.to_string_lossy()))
+        .collect();
+    if inodes.is_empty() {
+        return Ok(make_port_snapshot(Vec::new(), Vec::new()));
+    }
+
+    let mut bindings = collect_socket_bindings(&format!("/proc/{pid}/net"))?;
+    bindings.retain(|binding| binding_inode(binding).is_some_and(|inode| inodes.contains(&inode)));
+
+    let process = read_process_info(pid, options).ok();
+    for binding in &mut bindings {
+        binding.pid = Some(pid);
+        binding.process = process.clone();
+        binding.inode = None;
+    }
+
+    Ok(make_port_snapshot(bindings, Vec::new()))
+}
+
+/// Whether `/proc/[pid]/stat` reports the process as a zombie (`Z`) or dead (`X`).
+fn is_zombie(pid: u32) -> bool {
+    let path = Path::new("/proc").join(pid.to_string()).join("stat");
+    read_file_lossy(&path)
+        .ok()
+        .and_then(|content| parse_stat(&content).ok())
+        .is_some_and(|stat| matches!(stat.state, 'Z' | 'X'))
 }
 
 /// Read process information from /proc/[pid]/*.
@@ -237,21 +389,29 @@ fn read_process_info(pid: u32, options: &ProcessOptions) -> SysprimsResult<Proce
         return Err(SysprimsError::not_found(pid));
     }
 
-    // Read /proc/[pid]/stat
-    let stat_content = read_file(&proc_path.join("stat")).map_err(|e| map_io_error(e, pid))?;
-    let stat = parse_stat(&stat_content)?;
+    // Read /proc/[pid]/stat (comm may be non-UTF-8)
+    let stat_bytes = fs::read(proc_path.join("stat")).map_err(|e| map_io_error(e, pid))?;
+    let stat = parse_stat(&String::from_utf8_lossy(&stat_bytes))?;
 
     // Read /proc/[pid]/status for UID
     let status_content = read_file(&proc_path.join("status")).unwrap_or_default();
     let uid = parse_uid(&status_content);
-    let user = uid.and_then(get_username);
+    let user = if options.resolve_usernames {
+        uid.and_then(get_username)
+    } else {
+        None
+    };
 
     // Read /proc/[pid]/statm for memory
     let statm_content = read_file(&proc_path.join("statm")).unwrap_or_default();
     let memory_kb = parse_memory(&statm_content);
 
     // Read /proc/[pid]/cmdline (handles non-UTF-8 gracefully)
-    let cmdline = read_cmdline(&proc_path.join("cmdline"));
+    let raw_cmdline = read_cmdline_raw(&proc_path.join("cmdline"));
+    let mut cmdline: Vec<String> = raw_cmdline
+        .iter()
+        .map(|arg| String::from_utf8_lossy(arg).into_owned())
+        .collect();
 
     #[cfg(not(feature = "proc_ext"))]
     let _ = options;
@@ -274,6 +434,48 @@ fn read_process_info(pid: u32, options: &ProcessOptions) -> SysprimsResult<Proce
     #[cfg(not(feature = "proc_ext"))]
     let thread_count = None;
 
+    let cgroup = if options.include_cgroup {
+        read_file(&proc_path.join("cgroup"))
+            .ok()
+            .and_then(|content| parse_cgroup(&content))
+    } else {
+        None
+    };
+
+    let socket_count = if options.include_socket_count {
+        count_socket_fds(&proc_path.join("fd"))
+    } else {
+        None
+    };
+
+    let (net_rx_bytes, net_tx_bytes) = if options.include_net {
+        read_file(&proc_path.join("net").join("dev"))
+            .ok()
+            .and_then(|content| parse_net_dev(&content))
+            .unzip()
+    } else {
+        (None, None)
+    };
+
+    let (sig_blocked, sig_ignored, sig_caught) = if options.include_signal_masks {
+        (
+            parse_status_mask(&status_content, "SigBlk:"),
+            parse_status_mask(&status_content, "SigIgn:"),
+            parse_status_mask(&status_content, "SigCgt:"),
+        )
+    } else {
+        (None, None, None)
+    };
+
+    let (fd_limit_soft, fd_limit_hard) = if options.include_limits {
+        read_file(&proc_path.join("limits"))
+            .ok()
+            .and_then(|content| parse_nofile_limits(&content))
+            .unzip()
+    } else {
+        (None, None)
+    };
+
     // Calculate elapsed time
     let boot_time = get_boot_time();
     let clock_ticks = get_clock_ticks();
@@ -286,9 +488,23 @@ fn read_process_info(pid: u32, options: &ProcessOptions) -> SysprimsResult<Proce
     let elapsed_seconds = now.saturating_sub(start_time_secs);
 
     // Best-effort executable path (/proc/<pid>/exe)
-    let exe_path = fs::read_link(proc_path.join("exe"))
-        .ok()
-        .map(|p| p.to_string_lossy().into_owned());
+    let exe_link = fs::read_link(proc_path.join("exe")).ok();
+    let exe_path = exe_link.as_ref().map(|p| p.to_string_lossy().into_owned());
+
+    let (exe_inode, exe_device, exe_deleted) = if options.include_exe_stat {
+        // stat follows the magic link, so this works even for deleted binaries.
+        let meta = fs::metadata(proc_path.join("exe")).ok();
+        (
+            meta.as_ref().map(|m| m.ino()),
+            meta.as_ref().map(|m| m.dev()),
+            exe_path.as_deref().map(is_deleted_exe_link),
+        )
+    } else {
+        (None, None, None)
+    };
+
+    let cpu_user_ns = ticks_to_ns(stat.utime, clock_ticks);
+    let cpu_system_ns = ticks_to_ns(stat.stime, clock_ticks);
 
     // Calculate CPU percentage (lifetime average)
     let total_cpu_ticks = stat.utime + stat.stime;
@@ -299,14 +515,7 @@ fn read_process_info(pid: u32, options: &ProcessOptions) -> SysprimsResult<Proce
         0.0
     };
 
-    // Process state
-    let state = match stat.state {
-        'R' => ProcessState::Running,
-        'S' | 'D' | 'I' => ProcessState::Sleeping,
-        'T' | 't' => ProcessState::Stopped,
-        'Z' | 'X' => ProcessState::Zombie,
-        _ => ProcessState::Unknown,
-    };
+    let state = state_from_stat_char(stat.state);
 
     // Use command name from cmdline if available, otherwise use comm
     let name = if cmdline.is_empty() || cmdline[0].is_empty() {
@@ -316,22 +525,70 @@ fn read_process_info(pid: u32, options: &ProcessOptions) -> SysprimsResult<Proce
         let cmd = &cmdline[0];
         cmd.rsplit('/').next().unwrap_or(cmd).to_string()
     };
+    crate::limit_cmdline(&mut cmdline, options);
+
+    // Raw bytes, only where lossy conversion altered the value.
+    let (name_bytes, exe_path_bytes, cmdline_bytes) = if options.preserve_raw_bytes {
+        let raw_name = match raw_cmdline.first() {
+            Some(arg0) if !arg0.is_empty() => arg0.rsplit(|&b| b == b'/').next().unwrap_or(arg0),
+            _ => raw_comm(&stat_bytes),
+        };
+        (
+            non_utf8(raw_name),
+            exe_link
+                .as_ref()
+                .and_then(|p| non_utf8(p.as_os_str().as_bytes())),
+            raw_cmdline
+                .iter()
+                .any(|arg| std::str::from_utf8(arg).is_err())
+                .then(|| {
+                    let mut raw = raw_cmdline;
+                    crate::limit_cmdline(&mut raw, options);
+                    raw
+                }),
+        )
+    } else {
+        (None, None, None)
+    };
 
-    Ok(ProcessInfo {
+    let info = ProcessInfo {
         pid,
         ppid: stat.ppid,
         name,
+        name_bytes,
         user,
+        uid,
         cpu_percent,
+        cpu_user_ns: Some(cpu_user_ns),
+        cpu_system_ns: Some(cpu_system_ns),
         memory_kb,
         elapsed_seconds,
         start_time_unix_ms: Some(start_time_unix_ms),
+        start_time: crate::rfc3339_from_unix_ms(start_time_unix_ms),
         exe_path,
+        exe_path_bytes,
+        exe_inode,
+        exe_device,
+        exe_deleted,
         state,
         cmdline,
+        cmdline_bytes,
+        cmdline_string: None,
         env,
         thread_count,
-    })
+        cgroup,
+        socket_count,
+        net_rx_bytes,
+        net_tx_bytes,
+        fd_limit_soft,
+        fd_limit_hard,
+        sig_blocked,
+        sig_ignored,
+        sig_caught,
+        sid: Some(stat.session),
+        identity: None,
+    };
+    Ok(crate::with_derived_fields(info, options))
 }
 
 pub(crate) fn cpu_total_time_ns_impl(pid: u32) -> SysprimsResult<u64> {
@@ -340,28 +597,29 @@ pub(crate) fn cpu_total_time_ns_impl(pid: u32) -> SysprimsResult<u64> {
         return Err(SysprimsError::not_found(pid));
     }
 
-    let stat_content = read_file(&proc_path.join("stat")).map_err(|e| map_io_error(e, pid))?;
+    let stat_content =
+        read_file_lossy(&proc_path.join("stat")).map_err(|e| map_io_error(e, pid))?;
     let stat = parse_stat(&stat_content)?;
-    let clock_ticks = get_clock_ticks();
-    let total_cpu_ticks = stat.utime + stat.stime;
+    Ok(ticks_to_ns(stat.utime + stat.stime, get_clock_ticks()))
+}
 
-    // Convert ticks -> nanoseconds.
-    // Use u128 to avoid overflow.
-    let ns = (total_cpu_ticks as u128)
+/// Convert clock ticks to nanoseconds (u128 intermediate to avoid overflow).
+fn ticks_to_ns(ticks: u64, clock_ticks: u64) -> u64 {
+    (ticks as u128)
         .saturating_mul(1_000_000_000u128)
         .checked_div(clock_ticks as u128)
-        .unwrap_or(0);
-
-    Ok(ns as u64)
+        .unwrap_or(0) as u64
 }
 
-fn collect_socket_bindings() -> SysprimsResult<Vec<PortBinding>> {
+/// Parse the socket tables under `net_dir` (`/proc/net`, or `/proc/<pid>/net`
+/// for that process's network namespace).
+fn collect_socket_bindings(net_dir: &str) -> SysprimsResult<Vec<PortBinding>> {
     let mut bindings = Vec::new();
 
-    let tcp = parse_proc_net("/proc/net/tcp", Protocol::Tcp, &mut bindings)?;
-    let tcp6 = parse_proc_net("/proc/net/tcp6", Protocol::Tcp, &mut bindings)?;
-    let udp = parse_proc_net("/proc/net/udp", Protocol::Udp, &mut bindings)?;
-    let udp6 = parse_proc_net("/proc/net/udp6", Protocol::Udp, &mut bindings)?;
+    let tcp = parse_proc_net(&format!("{net_dir}/tcp"), Protocol::Tcp, &mut bindings)?;
+    let tcp6 = parse_proc_net(&format!("{net_dir}/tcp6"), Protocol::Tcp, &mut bindings)?;
+    let udp = parse_proc_net(&format!("{net_dir}/udp"), Protocol::Udp, &mut bindings)?;
+    let udp6 = parse_proc_net(&format!("{net_dir}/udp6"), Protocol::Udp, &mut bindings)?;
 
     if !(tcp || tcp6 || udp || udp6) {
         return Err(SysprimsError::not_supported("port bindings", "linux"));
@@ -447,9 +705,9 @@ fn parse_local_socket(local: &str) -> SysprimsResult<(Option<IpAddr>, u16)> {
         .next()
         .ok_or_else(|| SysprimsError::internal("missing local port"))?;
 
-    let port_raw = u16::from_str_radix(port_hex, 16)
+    // The kernel prints the port already converted to host order (`ntohs`).
+    let port = u16::from_str_radix(port_hex, 16)
         .map_err(|_| SysprimsError::internal("invalid port hex"))?;
-    let port = u16::from_be(port_raw);
 
     let addr = match addr_hex.len() {
         8 => Some(IpAddr::V4(parse_ipv4(addr_hex)?)),
@@ -569,11 +827,24 @@ struct StatInfo {
     comm: String,
     state: char,
     ppid: u32,
+    session: u32,
     utime: u64,
     stime: u64,
     starttime: u64,
 }
 
+/// Map the state character of `/proc/[pid]/stat` to a [`ProcessState`].
+fn state_from_stat_char(state: char) -> ProcessState {
+    match state {
+        'R' => ProcessState::Running,
+        'S' | 'I' => ProcessState::Sleeping,
+        'D' => ProcessState::DiskSleep,
+        'T' | 't' => ProcessState::Stopped,
+        'Z' | 'X' => ProcessState::Zombie,
+        _ => ProcessState::Unknown,
+    }
+}
+
 /// Parse /proc/[pid]/stat content.
 ///
 /// Format: pid (comm) state ppid pgrp session tty_nr tpgid flags minflt cminflt
@@ -602,6 +873,7 @@ fn parse_stat(content: &str) -> SysprimsResult<StatInfo> {
 
     let state = fields[0].chars().next().unwrap_or('?');
     let ppid: u32 = fields[1].parse().unwrap_or(0);
+    let session: u32 = fields[3].parse().unwrap_or(0);
     let utime: u64 = fields[11].parse().unwrap_or(0);
     let stime: u64 = fields[12].parse().unwrap_or(0);
     let starttime: u64 = fields[19].parse().unwrap_or(0);
@@ -610,12 +882,22 @@ fn parse_stat(content: &str) -> SysprimsResult<StatInfo> {
         comm,
         state,
         ppid,
+        session,
         utime,
         stime,
         starttime,
     })
 }
 
+/// Parse a hex signal mask line (e.g. `SigIgn:\t0000000000001000`) from
+/// /proc/[pid]/status.
+fn parse_status_mask(content: &str, key: &str) -> Option<u64> {
+    content
+        .lines()
+        .find_map(|line| line.strip_prefix(key))
+        .and_then(|v| u64::from_str_radix(v.trim(), 16).ok())
+}
+
 /// Parse UID from /proc/[pid]/status.
 fn parse_uid(content: &str) -> Option<u32> {
     for line in content.lines() {
@@ -642,6 +924,94 @@ fn parse_thread_count(content: &str) -> Option<u32> {
     None
 }
 
+/// Whether a `/proc/[pid]/exe` target carries the kernel's `(deleted)` marker.
+///
+/// The kernel appends the marker when the backing file is unlinked, which
+/// includes the common replace-by-rename upgrade path.
+pub(crate) fn is_deleted_exe_link(target: &str) -> bool {
+    target.ends_with(" (deleted)")
+}
+
+/// Count `socket:[inode]` links in `/proc/[pid]/fd` without resolving them further.
+fn count_socket_fds(fd_dir: &Path) -> Option<u64> {
+    let entries = fs::read_dir(fd_dir).ok()?;
+    let count = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| fs::read_link(entry.path()).ok())
+        .filter(|target| target.to_string_lossy().starts_with("socket:["))
+        .count();
+    Some(count as u64)
+}
+
+/// Sum received/transmitted bytes over the non-loopback interfaces in
+/// `/proc/[pid]/net/dev`.
+///
+/// After two header lines, each line is `iface: rx_bytes ... (8 rx fields)
+/// tx_bytes ...`.
+fn parse_net_dev(content: &str) -> Option<(u64, u64)> {
+    let mut totals = None;
+    for line in content.lines().skip(2) {
+        let Some((iface, counters)) = line.split_once(':') else {
+            continue;
+        };
+        if iface.trim() == "lo" {
+            // A loopback-only namespace still reports zero traffic.
+            totals.get_or_insert((0u64, 0u64));
+            continue;
+        }
+        let fields: Vec<u64> = counters
+            .split_whitespace()
+            .filter_map(|f| f.parse().ok())
+            .collect();
+        if fields.len() < 9 {
+            continue;
+        }
+        let (rx, tx) = totals.get_or_insert((0u64, 0u64));
+        *rx = rx.saturating_add(fields[0]);
+        *tx = tx.saturating_add(fields[8]);
+    }
+    totals
+}
+
+/// Read the soft and hard limits from the "Max open files" line of
+/// `/proc/[pid]/limits`.
+///
+/// The line is `Max open files  <soft>  <hard>  files`; `unlimited` maps to
+/// `u64::MAX` (`RLIM_INFINITY`).
+fn parse_nofile_limits(content: &str) -> Option<(u64, u64)> {
+    let parse = |field: &str| match field {
+        "unlimited" => Some(u64::MAX),
+        n => n.parse().ok(),
+    };
+    let line = content
+        .lines()
+        .find_map(|line| line.strip_prefix("Max open files"))?;
+    let mut fields = line.split_whitespace();
+    Some((parse(fields.next()?)?, parse(fields.next()?)?))
+}
+
+/// Pick the cgroup path from `/proc/[pid]/cgroup`.
+///
+/// Lines are `hierarchy-ID:controllers:path`. The v2 unified entry (`0::/...`)
+/// wins; on v1-only hosts the `name=systemd` hierarchy names the unit/container.
+fn parse_cgroup(content: &str) -> Option<String> {
+    let mut systemd = None;
+    for line in content.lines() {
+        let mut parts = line.splitn(3, ':');
+        let (Some(id), Some(controllers), Some(path)) = (parts.next(), parts.next(), parts.next())
+        else {
+            continue;
+        };
+        if id == "0" && controllers.is_empty() {
+            return Some(path.to_string());
+        }
+        if systemd.is_none() && controllers == "name=systemd" {
+            systemd = Some(path.to_string());
+        }
+    }
+    systemd
+}
+
 #[cfg(feature = "proc_ext")]
 fn read_env(path: &Path) -> Option<BTreeMap<String, String>> {
     let bytes = fs::read(path).ok()?;
@@ -697,11 +1067,11 @@ fn parse_memory(content: &str) -> u64 {
     0
 }
 
-/// Read command line from /proc/[pid]/cmdline.
+/// Read command line arguments from /proc/[pid]/cmdline as raw bytes.
 ///
-/// Arguments are separated by null bytes. Uses lossy UTF-8 conversion
-/// to handle non-UTF-8 command line arguments gracefully.
-fn read_cmdline(path: &Path) -> Vec<String> {
+/// Arguments are separated by null bytes. Callers convert them with lossy
+/// UTF-8 conversion to handle non-UTF-8 command line arguments gracefully.
+fn read_cmdline_raw(path: &Path) -> Vec<Vec<u8>> {
     let bytes = match fs::read(path) {
         Ok(b) => b,
         Err(_) => return Vec::new(),
@@ -710,22 +1080,41 @@ fn read_cmdline(path: &Path) -> Vec<String> {
     bytes
         .split(|&b| b == 0)
         .filter(|s| !s.is_empty())
-        .map(|s| String::from_utf8_lossy(s).into_owned())
+        .map(<[u8]>::to_vec)
         .collect()
 }
 
+/// The raw `comm` field of /proc/[pid]/stat (between the first `(` and last `)`).
+fn raw_comm(stat: &[u8]) -> &[u8] {
+    let start = stat.iter().position(|&b| b == b'(').map_or(0, |i| i + 1);
+    let end = stat.iter().rposition(|&b| b == b')').unwrap_or(start);
+    stat.get(start..end).unwrap_or_default()
+}
+
+/// `bytes` as an owned vector when it is not valid UTF-8, else `None`.
+fn non_utf8(bytes: &[u8]) -> Option<Vec<u8>> {
+    std::str::from_utf8(bytes).is_err().then(|| bytes.to_vec())
+}
+
 /// Read file content as string.
 fn read_file(path: &Path) -> io::Result<String> {
     fs::read_to_string(path)
 }
 
+/// Read file content as string, replacing invalid UTF-8 (e.g. in `comm`).
+fn read_file_lossy(path: &Path) -> io::Result<String> {
+    fs::read(path).map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+}
+
 /// Map IO error to SysprimsError.
 fn map_io_error(e: io::Error, pid: u32) -> SysprimsError {
     match e.kind() {
         io::ErrorKind::NotFound => SysprimsError::not_found(pid),
-        io::ErrorKind::PermissionDenied => {
-            SysprimsError::permission_denied(pid, "read process info")
-        }
+        io::ErrorKind::PermissionDenied => SysprimsError::permission_denied_with_elevation(
+            pid,
+            "read process info",
+            elevation_would_help(pid),
+        ),
         _ => SysprimsError::not_found(pid),
     }
 }
@@ -826,6 +1215,60 @@ mod tests {
         assert_eq!(stat.starttime, 12345);
     }
 
+    #[test]
+    fn test_is_deleted_exe_link() {
+        assert!(is_deleted_exe_link("/usr/bin/app (deleted)"));
+        assert!(!is_deleted_exe_link("/usr/bin/app"));
+    }
+
+    #[test]
+    fn test_parse_local_socket_port_is_host_order() {
+        let (addr, port) = parse_local_socket("0100007F:A603").unwrap();
+        assert_eq!(addr, Some(IpAddr::V4(Ipv4Addr::LOCALHOST)));
+        assert_eq!(port, 42499);
+    }
+
+    #[test]
+    fn test_parse_net_dev_sums_non_loopback() {
+        let content = "\
+Inter-|   Receive                                                |  Transmit
+ face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed
+    lo:  5000      50    0    0    0     0          0         0     5000      50    0    0    0     0       0          0
+  eth0:  1200      10    0    0    0     0          0         0      300       3    0    0    0     0       0          0
+  eth1:    34       1    0    0    0     0          0         0        6       1    0    0    0     0       0          0
+";
+        assert_eq!(parse_net_dev(content), Some((1234, 306)));
+
+        let loopback_only = "h1\nh2\n    lo:  5 1 0 0 0 0 0 0 5 1 0 0 0 0 0 0\n";
+        assert_eq!(parse_net_dev(loopback_only), Some((0, 0)));
+        assert_eq!(parse_net_dev("h1\nh2\n"), None);
+    }
+
+    #[test]
+    fn test_parse_nofile_limits() {
+        let content = "\
+Limit                     Soft Limit           Hard Limit           Units
+Max cpu time              unlimited            unlimited            seconds
+Max open files            1024                 524288               files
+Max locked memory         8388608              8388608              bytes
+";
+        assert_eq!(parse_nofile_limits(content), Some((1024, 524288)));
+
+        let unlimited =
+            "Max open files            unlimited            unlimited            files\n";
+        assert_eq!(parse_nofile_limits(unlimited), Some((u64::MAX, u64::MAX)));
+        assert_eq!(parse_nofile_limits("Max cpu time  1  2  seconds\n"), None);
+    }
+
+    #[test]
+    fn test_parse_status_mask() {
+        let content = "Name:\tsh\nSigBlk:\t0000000000010000\nSigIgn:\t0000000000004006\nSigCgt:\t000000004b813efb\n";
+        assert_eq!(parse_status_mask(content, "SigBlk:"), Some(0x10000));
+        assert_eq!(parse_status_mask(content, "SigIgn:"), Some(0x4006));
+        assert_eq!(parse_status_mask(content, "SigCgt:"), Some(0x4b81_3efb));
+        assert_eq!(parse_status_mask(content, "SigPnd:"), None);
+    }
+
     #[test]
     fn test_parse_uid() {
         let content = "Name:\ttest\nUid:\t1000\t1000\t1000\t1000\nGid:\t1000\t1000\t1000\t1000\n";
@@ -833,6 +1276,90 @@ mod tests {
         assert_eq!(uid, Some(1000));
     }
 
+    #[test]
+    fn test_parse_cgroup_prefers_unified_entry() {
+        let content = "12:memory:/docker/abc\n1:name=systemd:/docker/abc\n0::/system.slice/docker-abc.scope\n";
+        assert_eq!(
+            parse_cgroup(content).as_deref(),
+            Some("/system.slice/docker-abc.scope")
+        );
+    }
+
+    #[test]
+    fn test_parse_cgroup_falls_back_to_systemd_hierarchy() {
+        let content = "12:memory:/docker/abc\n1:name=systemd:/docker/abc\n";
+        assert_eq!(parse_cgroup(content).as_deref(), Some("/docker/abc"));
+        assert_eq!(parse_cgroup("3:cpu,cpuacct:/x\n"), None);
+    }
+
+    #[test]
+    fn test_raw_comm_keeps_non_utf8_bytes() {
+        assert_eq!(raw_comm(b"42 (a) b) S 1"), b"a) b");
+        assert_eq!(raw_comm(b"42 (sl\xffep) S 1"), b"sl\xffep");
+        assert_eq!(raw_comm(b"garbage"), b"");
+    }
+
+    #[test]
+    fn test_preserve_raw_bytes_recovers_non_utf8_name() {
+        use std::ffi::OsStr;
+        use std::process::{Command, Stdio};
+
+        let dir = std::env::temp_dir().join(format!("sysprims-raw-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let link = dir.join(OsStr::from_bytes(b"sl\xffep"));
+        let _ = fs::remove_file(&link);
+        std::os::unix::fs::symlink("/bin/sleep", &link).unwrap();
+
+        let mut child = Command::new(&link)
+            .arg("5")
+            .stdin(Stdio::null())
+            .spawn()
+            .expect("Failed to spawn renamed sleep");
+        // Let exec finish so /proc reflects the new image.
+        thread::sleep(Duration::from_millis(100));
+        let raw = read_process_info(child.id(), &ProcessOptions::default().with_raw_bytes());
+        let limited = read_process_info(
+            child.id(),
+            &ProcessOptions::default()
+                .with_raw_bytes()
+                .with_cmdline_limits(None, Some(1)),
+        );
+        let plain = read_process_info(child.id(), &ProcessOptions::default());
+        let _ = child.kill();
+        let _ = child.wait();
+        let _ = fs::remove_dir_all(&dir);
+
+        let raw = raw.unwrap();
+        assert_eq!(raw.name, "sl\u{fffd}ep");
+        assert_eq!(raw.name_bytes.as_deref(), Some(&b"sl\xffep"[..]));
+        let args = raw.cmdline_bytes.expect("cmdline has a non-UTF-8 argument");
+        assert!(args[0].ends_with(b"sl\xffep"));
+        assert!(plain.unwrap().name_bytes.is_none());
+
+        // Raw arguments obey the same cmdline limits as the decoded ones.
+        let limited = limited.unwrap().cmdline_bytes.unwrap();
+        assert_eq!(limited.len(), 2);
+        assert_eq!(limited[1], crate::CMDLINE_TRUNCATED_SENTINEL.as_bytes());
+    }
+
+    #[test]
+    fn test_list_fds_keeps_non_utf8_path_bytes() {
+        use std::ffi::OsStr;
+
+        let mut name = format!("sysprims-fd-{}-", std::process::id()).into_bytes();
+        name.push(0xff);
+        let path = std::env::temp_dir().join(OsStr::from_bytes(&name));
+        let file = fs::File::create(&path).unwrap();
+        let (fds, _) = list_fds_impl(std::process::id()).unwrap();
+        drop(file);
+        let _ = fs::remove_file(&path);
+
+        let raw = path.as_os_str().as_bytes();
+        assert!(fds
+            .iter()
+            .any(|fd| fd.path_bytes.as_deref() == Some(raw) && fd.path.is_some()));
+    }
+
     #[test]
     fn test_clock_ticks() {
         let ticks = get_clock_ticks();
@@ -17,15 +17,17 @@ use crate::{
 use crate::{MAX_ENV_ENTRIES, MAX_ENV_KEY_BYTES, MAX_ENV_TOTAL_BYTES, MAX_ENV_VALUE_BYTES};
 use libc::{c_int, c_void, pid_t, uid_t};
 #[cfg(feature = "proc_ext")]
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::ffi::CStr;
 use std::mem;
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::os::unix::fs::MetadataExt;
 use std::sync::OnceLock;
 use std::thread;
 use std::time::Instant;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
-use sysprims_core::{SysprimsError, SysprimsResult};
+use sysprims_core::sys::{self, elevation_would_help};
+use sysprims_core::{SysprimsError, SysprimsResult, Warning, WarningCode};
 
 // ============================================================================
 // libproc FFI Bindings
@@ -38,12 +40,11 @@ const PROC_PIDTASKINFO: c_int = 4;
 const MAXCOMLEN: usize = 16;
 const MAXPATHLEN: usize = 1024;
 
-const PROC_PIDLISTFDS: c_int = 1;
 const PROC_PIDFDVNODEPATHINFO: c_int = 2;
 const PROC_PIDFDSOCKETINFO: c_int = 3;
-const PROX_FDTYPE_VNODE: u32 = 1;
-const PROX_FDTYPE_SOCKET: u32 = 2;
-const PROX_FDTYPE_PIPE: u32 = 6;
+const PROX_FDTYPE_VNODE: u32 = libc::PROX_FDTYPE_VNODE as u32;
+const PROX_FDTYPE_SOCKET: u32 = libc::PROX_FDTYPE_SOCKET as u32;
+const PROX_FDTYPE_PIPE: u32 = libc::PROX_FDTYPE_PIPE as u32;
 
 const SOCKINFO_IN: i32 = 1;
 const SOCKINFO_TCP: i32 = 2;
@@ -88,13 +89,6 @@ struct ProcBsdInfo {
     pbi_start_tvusec: u64,
 }
 
-#[repr(C)]
-#[derive(Debug, Default, Clone, Copy)]
-struct ProcFdInfo {
-    proc_fd: i32,
-    proc_fdtype: u32,
-}
-
 #[repr(C)]
 #[derive(Clone, Copy)]
 struct In4In6Addr {
@@ -165,47 +159,101 @@ struct SockbufInfo {
     sbi_timeo: i16,
 }
 
-// NOTE: We intentionally do not model socket_fdinfo/socket_info as Rust structs
-// for parsing. See read_socket_binding() for offset-based parsing.
-
-const PROC_FILEINFO_SIZE: usize = 24;
-
-fn compute_socket_info_offsets(vinfo_stat_size: usize) -> (usize, usize, usize) {
-    // Layout based on sys/proc_info.h:
-    // struct socket_fdinfo { struct proc_fileinfo pfi; struct socket_info psi; };
-    // struct socket_info { struct vinfo_stat soi_stat; ... int soi_kind; uint32_t rfu_1; union soi_proto; }
-
-    let base = PROC_FILEINFO_SIZE;
-    let mut off = base + vinfo_stat_size;
-
-    off += 8; // soi_so
-    off += 8; // soi_pcb
-
-    off += 4; // soi_type
-    let soi_protocol_off = off;
-    off += 4; // soi_protocol
+/// `struct proc_fileinfo` from <sys/proc_info.h>.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct ProcFileInfo {
+    fi_openflags: u32,
+    fi_status: u32,
+    fi_offset: libc::off_t,
+    fi_type: i32,
+    fi_guardflags: u32,
+}
 
-    off += 4; // soi_family
+/// `struct socket_fdinfo` up to (not including) the `soi_proto` union.
+///
+/// The union holds large members (e.g. unix domain socket addresses) and an
+/// undersized buffer makes proc_pidfdinfo() fail with EINVAL, so only this
+/// fixed head is modeled; the union is read from a generously sized buffer.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct SocketFdInfoHead {
+    pfi: ProcFileInfo,
+    soi_stat: libc::vinfo_stat,
+    soi_so: u64,
+    soi_pcb: u64,
+    soi_type: i32,
+    soi_protocol: i32,
+    soi_family: i32,
+    soi_options: i16,
+    soi_linger: i16,
+    soi_state: i16,
+    soi_qlen: i16,
+    soi_incqlen: i16,
+    soi_qlimit: i16,
+    soi_timeo: i16,
+    soi_error: u16,
+    soi_oobmark: u32,
+    soi_rcv: SockbufInfo,
+    soi_snd: SockbufInfo,
+    soi_kind: i32,
+    rfu_1: u32,
+}
 
-    off += 2; // soi_options
-    off += 2; // soi_linger
-    off += 2; // soi_state
-    off += 2; // soi_qlen
-    off += 2; // soi_incqlen
-    off += 2; // soi_qlimit
-    off += 2; // soi_timeo
-    off += 2; // soi_error
+/// Overrides the `vinfo_stat` size used to parse `socket_info` (decimal bytes).
+///
+/// For SDKs whose `vinfo_stat` differs from the one this build was compiled
+/// against. Read once per process.
+const SOCKINFO_STAT_SIZE_ENV: &str = "SYSPRIMS_MACOS_SOCKINFO_STAT_SIZE";
+
+/// Prefix of the error returned when the `socket_info` layout does not validate.
+const SOCKINFO_LAYOUT_ERROR: &str = "unrecognized socket_info layout";
+
+/// Byte offsets of the `socket_fdinfo` fields read for port bindings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SocketInfoLayout {
+    /// `vinfo_stat` size the offsets were derived from.
+    stat_size: usize,
+    protocol: usize,
+    kind: usize,
+    proto: usize,
+}
 
-    off += 4; // soi_oobmark
-    off += mem::size_of::<SockbufInfo>(); // soi_rcv
-    off += mem::size_of::<SockbufInfo>(); // soi_snd
+impl SocketInfoLayout {
+    /// Offsets of [`SocketFdInfoHead`] with `soi_stat` resized to `stat_size`.
+    fn with_stat_size(stat_size: usize) -> Self {
+        let shift = |off: usize| off - mem::size_of::<libc::vinfo_stat>() + stat_size;
+        Self {
+            stat_size,
+            protocol: shift(mem::offset_of!(SocketFdInfoHead, soi_protocol)),
+            kind: shift(mem::offset_of!(SocketFdInfoHead, soi_kind)),
+            proto: shift(mem::size_of::<SocketFdInfoHead>()),
+        }
+    }
 
-    let soi_kind_off = off;
-    off += 4; // soi_kind
-    off += 4; // rfu_1
-    let soi_proto_off = off;
+    /// Whether `buf` holds a plausible inet socket under this layout.
+    fn validates(&self, buf: &[u8]) -> bool {
+        let Some(kind) = read_i32_at(buf, self.kind) else {
+            return false;
+        };
+        let proto = read_i32_at(buf, self.protocol).unwrap_or(0);
+        let proto_ok = proto == libc::IPPROTO_TCP || proto == libc::IPPROTO_UDP || proto == 0;
+        let kind_ok = kind == SOCKINFO_TCP || kind == SOCKINFO_IN || kind == 0;
+        proto_ok && kind_ok
+    }
+}
 
-    (soi_protocol_off, soi_kind_off, soi_proto_off)
+/// The `socket_info` layout in effect: this SDK's `vinfo_stat`, unless
+/// [`SOCKINFO_STAT_SIZE_ENV`] overrides its size.
+fn socket_info_layout() -> SocketInfoLayout {
+    static LAYOUT: OnceLock<SocketInfoLayout> = OnceLock::new();
+    *LAYOUT.get_or_init(|| {
+        let stat_size = std::env::var(SOCKINFO_STAT_SIZE_ENV)
+            .ok()
+            .and_then(|v| v.trim().parse().ok())
+            .unwrap_or(mem::size_of::<libc::vinfo_stat>());
+        SocketInfoLayout::with_stat_size(stat_size)
+    })
 }
 
 fn read_i32_at(buf: &[u8], offset: usize) -> Option<i32> {
@@ -217,29 +265,6 @@ fn read_i32_at(buf: &[u8], offset: usize) -> Option<i32> {
     Some(i32::from_ne_bytes(bytes))
 }
 
-fn select_socket_info_layout(buf: &[u8]) -> Option<(usize, usize, usize)> {
-    // vinfo_stat size varies across SDKs. Try common candidates.
-    // Validate by checking that derived soi_kind and soi_protocol look plausible.
-    let candidates = [136usize, 144usize];
-
-    for stat_size in candidates {
-        let (proto_off, kind_off, proto_union_off) = compute_socket_info_offsets(stat_size);
-        let kind = read_i32_at(buf, kind_off)?;
-        if !(0..=7).contains(&kind) {
-            continue;
-        }
-
-        let proto = read_i32_at(buf, proto_off).unwrap_or(0);
-        let proto_ok = proto == libc::IPPROTO_TCP || proto == libc::IPPROTO_UDP || proto == 0;
-        let kind_ok = kind == SOCKINFO_TCP || kind == SOCKINFO_IN || kind == 0;
-        if proto_ok && kind_ok {
-            return Some((proto_off, kind_off, proto_union_off));
-        }
-    }
-
-    None
-}
-
 /// Task info structure returned by proc_pidinfo with PROC_PIDTASKINFO
 #[repr(C)]
 #[derive(Debug, Default)]
@@ -349,24 +374,86 @@ pub fn get_process_impl(pid: u32, options: &ProcessOptions) -> SysprimsResult<Pr
     read_process_info(pid, options)
 }
 
-pub fn wait_pid_impl(pid: u32, timeout: Duration) -> SysprimsResult<crate::WaitPidResult> {
+pub fn get_processes_impl(
+    pids: &[u32],
+    options: &ProcessOptions,
+) -> Vec<(u32, SysprimsResult<ProcessInfo>)> {
+    pids.iter()
+        .map(|&pid| (pid, read_process_info(pid, options)))
+        .collect()
+}
+
+/// List visible PIDs via `proc_listpids`.
+pub(crate) fn list_pids_impl() -> SysprimsResult<Vec<u32>> {
+    Ok(list_all_pids()?
+        .into_iter()
+        .filter(|&pid| pid > 0)
+        .map(|pid| pid as u32)
+        .collect())
+}
+
+/// Tally process states from `pbi_status` alone, skipping task and path reads.
+pub(crate) fn state_counts_impl() -> SysprimsResult<HashMap<ProcessState, usize>> {
+    let mut counts = HashMap::new();
+    for pid in list_pids_impl()? {
+        // Silently skip processes we can't read
+        if let Ok(bsd) = get_bsd_info(pid) {
+            *counts
+                .entry(state_from_bsd_status(bsd.pbi_status))
+                .or_insert(0) += 1;
+        }
+    }
+    Ok(counts)
+}
+
+/// Map `pbi_status` to a [`ProcessState`].
+fn state_from_bsd_status(status: u32) -> ProcessState {
+    match status {
+        SRUN => ProcessState::Running,
+        SSLEEP | SIDL => ProcessState::Sleeping,
+        SSTOP => ProcessState::Stopped,
+        SZOMB => ProcessState::Zombie,
+        _ => ProcessState::Unknown,
+    }
+}
+
+/// Owning uid from `PROC_PIDTBSDINFO`, or `None` if the process is unreadable.
+pub(crate) fn owner_uid_impl(pid: u32) -> Option<u32> {
+    get_bsd_info(pid).ok().map(|bsd| bsd.pbi_uid)
+}
+
+pub fn wait_pid_impl(
+    pid: u32,
+    timeout: Duration,
+    config: &crate::WaitPidConfig,
+) -> SysprimsResult<crate::WaitPidResult> {
     let start = Instant::now();
     let mut first_check = true;
+    let mut backoff = crate::WaitBackoff::new(config);
 
     loop {
         // SAFETY: kill(pid, 0) does not send a signal; it performs an existence/permission check.
-        let rc = unsafe { libc::kill(pid as libc::pid_t, 0) };
+        let rc = sysprims_core::sys::retry_eintr(|| unsafe { libc::kill(pid as libc::pid_t, 0) });
         if rc == 0 {
             // Treat zombies as exited (kill(pid, 0) still succeeds for zombies).
-            if let Ok(info) = read_process_info(pid, &ProcessOptions::default()) {
-                if info.state == crate::ProcessState::Zombie {
-                    return Ok(crate::make_wait_pid_result(pid, true, false, None, vec![]));
+            if config.zombie_is_exited {
+                if let Ok(info) = read_process_info(pid, &ProcessOptions::default()) {
+                    if info.state == crate::ProcessState::Zombie {
+                        let exit_code = crate::peek_child_exit_code(pid);
+                        return Ok(crate::make_wait_pid_result(
+                            pid,
+                            true,
+                            false,
+                            exit_code,
+                            vec![],
+                        ));
+                    }
                 }
             }
             if start.elapsed() >= timeout {
                 return Ok(crate::make_wait_pid_result(pid, false, true, None, vec![]));
             }
-            thread::sleep(Duration::from_millis(25));
+            thread::sleep(backoff.next_delay(timeout.saturating_sub(start.elapsed())));
             first_check = false;
             continue;
         }
@@ -379,23 +466,23 @@ pub fn wait_pid_impl(pid: u32, timeout: Duration) -> SysprimsResult<crate::WaitP
             return Ok(crate::make_wait_pid_result(pid, true, false, None, vec![]));
         }
         if errno == libc::EPERM {
-            return Err(SysprimsError::permission_denied(pid, "wait pid"));
+            return Err(SysprimsError::permission_denied_with_elevation(
+                pid,
+                "wait pid",
+                elevation_would_help(pid),
+            ));
         }
 
         return Err(SysprimsError::system("kill(pid, 0) failed", errno));
     }
 }
 
-pub fn listening_ports_impl() -> SysprimsResult<PortBindingsSnapshot> {
+pub fn listening_ports_impl(options: &ProcessOptions) -> SysprimsResult<PortBindingsSnapshot> {
     let pids = list_all_pids()?;
-    let mut bindings = Vec::new();
+    let mut scan = SocketScan::default();
     let mut permission_denied = 0usize;
     let mut read_errors = 0usize;
     let mut skipped_other_user = 0usize;
-    let mut socket_permission_denied = 0usize;
-    let mut socket_read_errors = 0usize;
-    let mut socket_unsupported = 0usize;
-    let mut socket_missing_port = 0usize;
 
     let current_uid = unsafe { libc::geteuid() };
 
@@ -424,26 +511,7 @@ pub fn listening_ports_impl() -> SysprimsResult<PortBindingsSnapshot> {
         }
 
         match list_socket_fds(pid) {
-            Ok(fds) => {
-                for fd in fds {
-                    match read_socket_binding(pid, fd) {
-                        Ok(binding) => bindings.push(binding),
-                        Err(SysprimsError::PermissionDenied { .. }) => {
-                            socket_permission_denied += 1
-                        }
-                        Err(SysprimsError::Internal { message }) => {
-                            if message.contains("unsupported socket") {
-                                socket_unsupported += 1;
-                            } else if message.contains("no local port") {
-                                socket_missing_port += 1;
-                            } else {
-                                socket_read_errors += 1;
-                            }
-                        }
-                        Err(_) => socket_read_errors += 1,
-                    }
-                }
-            }
+            Ok(fds) => scan.read_bindings(pid, fds, options),
             Err(err) => match err {
                 SysprimsError::PermissionDenied { .. } => permission_denied += 1,
                 _ => read_errors += 1,
@@ -454,13 +522,19 @@ pub fn listening_ports_impl() -> SysprimsResult<PortBindingsSnapshot> {
     let mut warnings = Vec::new();
 
     if skipped_other_user > 0 {
-        warnings.push(format!(
-            "macos port bindings are best-effort; scanning current user processes only (uid={})",
-            current_uid
+        warnings.push(Warning::new(
+            WarningCode::Degraded,
+            format!(
+                "macos port bindings are best-effort; scanning current user processes only (uid={})",
+                current_uid
+            ),
         ));
-        warnings.push(format!(
-            "Skipped {} pid entries owned by other users",
-            skipped_other_user
+        warnings.push(Warning::new(
+            WarningCode::PermissionDenied,
+            format!(
+                "Skipped {} pid entries owned by other users",
+                skipped_other_user
+            ),
         ));
     }
 
@@ -471,27 +545,121 @@ pub fn listening_ports_impl() -> SysprimsResult<PortBindingsSnapshot> {
         warnings.push(warning);
     }
 
-    if let Some(warning) = aggregate_permission_warning(socket_permission_denied, "socket entries")
-    {
-        warnings.push(warning);
-    }
-    if let Some(warning) = aggregate_error_warning(socket_read_errors, "socket entries") {
-        warnings.push(warning);
-    }
-    if socket_unsupported > 0 {
-        warnings.push(format!(
-            "Skipped {} socket entries due to unsupported socket kinds",
-            socket_unsupported
-        ));
-    }
-    if socket_missing_port > 0 {
-        warnings.push(format!(
-            "Skipped {} socket entries with no local port",
-            socket_missing_port
-        ));
+    scan.push_warnings(&mut warnings);
+    let mut snapshot = make_port_snapshot(scan.bindings, warnings);
+    // Other users' processes are skipped by design, so only permission
+    // failures within our own uid's view are helped by elevation.
+    snapshot.needs_elevation = permission_denied > 0 && !sys::is_elevated();
+    Ok(snapshot)
+}
+
+pub fn listening_ports_for_pid_impl(
+    pid: u32,
+    options: &ProcessOptions,
+) -> SysprimsResult<PortBindingsSnapshot> {
+    // Surfaces NotFound / PermissionDenied before listing fds.
+    get_bsd_info(pid)?;
+    let fds = list_socket_fds(pid as pid_t)?;
+    let mut scan = SocketScan::default();
+    scan.read_bindings(pid as pid_t, fds, options);
+
+    let mut warnings = Vec::new();
+    scan.push_warnings(&mut warnings);
+    Ok(make_port_snapshot(scan.bindings, warnings))
+}
+
+/// Socket bindings read from one or more PIDs, with per-socket failure tallies.
+#[derive(Default)]
+struct SocketScan {
+    bindings: Vec<PortBinding>,
+    permission_denied: usize,
+    read_errors: usize,
+    unsupported: usize,
+    missing_port: usize,
+    /// Entries whose `socket_info` layout did not validate, and the last
+    /// buffer size `proc_pidfdinfo` returned for one.
+    unknown_layout: usize,
+    unknown_layout_bytes: usize,
+}
+
+impl SocketScan {
+    /// Read `pid`'s socket bindings, resolving the owning process once.
+    fn read_bindings(&mut self, pid: pid_t, fds: Vec<i32>, options: &ProcessOptions) {
+        let first = self.bindings.len();
+        for fd in fds {
+            match read_socket_binding(pid, fd) {
+                Ok(binding) => self.bindings.push(binding),
+                Err(SysprimsError::PermissionDenied { .. }) => self.permission_denied += 1,
+                Err(SysprimsError::Internal { message }) => {
+                    if let Some(rest) = message.strip_prefix(SOCKINFO_LAYOUT_ERROR) {
+                        self.unknown_layout += 1;
+                        self.unknown_layout_bytes = rest
+                            .trim_start_matches(':')
+                            .split_whitespace()
+                            .next()
+                            .and_then(|n| n.parse().ok())
+                            .unwrap_or(0);
+                    } else if message.contains("unsupported socket") {
+                        self.unsupported += 1;
+                    } else if message.contains("no local port") {
+                        self.missing_port += 1;
+                    } else {
+                        self.read_errors += 1;
+                    }
+                }
+                Err(_) => self.read_errors += 1,
+            }
+        }
+
+        if self.bindings.len() > first {
+            let process = read_process_info(pid as u32, options).ok();
+            for binding in &mut self.bindings[first..] {
+                binding.process = process.clone();
+            }
+        }
     }
 
-    Ok(make_port_snapshot(bindings, warnings))
+    fn push_warnings(&self, warnings: &mut Vec<Warning>) {
+        if let Some(warning) =
+            aggregate_permission_warning(self.permission_denied, "socket entries")
+        {
+            warnings.push(warning);
+        }
+        if let Some(warning) = aggregate_error_warning(self.read_errors, "socket entries") {
+            warnings.push(warning);
+        }
+        if self.unsupported > 0 {
+            warnings.push(Warning::new(
+                WarningCode::PartialVisibility,
+                format!(
+                    "Skipped {} socket entries due to unsupported socket kinds",
+                    self.unsupported
+                ),
+            ));
+        }
+        if self.unknown_layout > 0 {
+            warnings.push(Warning::new(
+                WarningCode::PartialVisibility,
+                format!(
+                    "Skipped {} socket entries: socket_info layout not recognized \
+                     (proc_pidfdinfo returned {} bytes, vinfo_stat size {}); \
+                     set {SOCKINFO_STAT_SIZE_ENV} to this SDK's sizeof(struct vinfo_stat)",
+                    self.unknown_layout,
+                    self.unknown_layout_bytes,
+                    socket_info_layout().stat_size
+                ),
+            ));
+        }
+        if self.missing_port > 0 {
+            warnings.push(Warning::new(
+                WarningCode::PartialVisibility,
+                format!(
+                    "Skipped {} socket entries with no local port",
+                    self.missing_port
+                ),
+            ));
+        }
+    }
 }
 
 /// Get list of all PIDs on the system.
@@ -533,13 +701,19 @@ fn list_socket_fds(pid: pid_t) -> SysprimsResult<Vec<i32>> {
     let max_buffer_size: usize = 1024 * 1024;
 
     loop {
-        let count = buffer_size / mem::size_of::<ProcFdInfo>();
-        let mut fdinfo = vec![ProcFdInfo::default(); count];
+        let count = buffer_size / mem::size_of::<libc::proc_fdinfo>();
+        let mut fdinfo = vec![
+            libc::proc_fdinfo {
+                proc_fd: 0,
+                proc_fdtype: 0,
+            };
+            count
+        ];
 
         let actual = unsafe {
             proc_pidinfo(
                 pid,
-                PROC_PIDLISTFDS,
+                libc::PROC_PIDLISTFDS,
                 0,
                 fdinfo.as_mut_ptr() as *mut c_void,
                 buffer_size as c_int,
@@ -549,9 +723,10 @@ fn list_socket_fds(pid: pid_t) -> SysprimsResult<Vec<i32>> {
         if actual <= 0 {
             let errno = unsafe { *libc::__error() };
             if errno == libc::EPERM || errno == libc::EACCES {
-                return Err(SysprimsError::permission_denied(
+                return Err(SysprimsError::permission_denied_with_elevation(
                     pid as u32,
                     "list socket fds",
+                    elevation_would_help(pid as u32),
                 ));
             }
             return Err(SysprimsError::internal("proc_pidinfo list fds failed"));
@@ -560,7 +735,7 @@ fn list_socket_fds(pid: pid_t) -> SysprimsResult<Vec<i32>> {
         // proc_pidinfo returns the number of bytes written.
         let actual_bytes = actual as usize;
         if actual_bytes < buffer_size || buffer_size >= max_buffer_size {
-            let actual_count = actual_bytes / mem::size_of::<ProcFdInfo>();
+            let actual_count = actual_bytes / mem::size_of::<libc::proc_fdinfo>();
             fdinfo.truncate(actual_count);
             return Ok(fdinfo
                 .into_iter()
@@ -573,20 +748,26 @@ fn list_socket_fds(pid: pid_t) -> SysprimsResult<Vec<i32>> {
     }
 }
 
-fn list_all_fds(pid: pid_t) -> SysprimsResult<Vec<ProcFdInfo>> {
+fn list_all_fds(pid: pid_t) -> SysprimsResult<Vec<libc::proc_fdinfo>> {
     // libproc does not provide a reliable "size query" mode for PROC_PIDLISTFDS.
     // Instead, allocate a buffer and retry with growth if the result indicates truncation.
     let mut buffer_size: usize = 4096;
     let max_buffer_size: usize = 1024 * 1024;
 
     loop {
-        let count = buffer_size / mem::size_of::<ProcFdInfo>();
-        let mut fdinfo = vec![ProcFdInfo::default(); count];
+        let count = buffer_size / mem::size_of::<libc::proc_fdinfo>();
+        let mut fdinfo = vec![
+            libc::proc_fdinfo {
+                proc_fd: 0,
+                proc_fdtype: 0,
+            };
+            count
+        ];
 
         let actual = unsafe {
             proc_pidinfo(
                 pid,
-                PROC_PIDLISTFDS,
+                libc::PROC_PIDLISTFDS,
                 0,
                 fdinfo.as_mut_ptr() as *mut c_void,
                 buffer_size as c_int,
@@ -596,7 +777,11 @@ fn list_all_fds(pid: pid_t) -> SysprimsResult<Vec<ProcFdInfo>> {
         if actual <= 0 {
             let errno = unsafe { *libc::__error() };
             if errno == libc::EPERM || errno == libc::EACCES {
-                return Err(SysprimsError::permission_denied(pid as u32, "list fds"));
+                return Err(SysprimsError::permission_denied_with_elevation(
+                    pid as u32,
+                    "list fds",
+                    elevation_would_help(pid as u32),
+                ));
             }
             if errno == libc::ESRCH {
                 return Err(SysprimsError::not_found(pid as u32));
@@ -607,7 +792,7 @@ fn list_all_fds(pid: pid_t) -> SysprimsResult<Vec<ProcFdInfo>> {
         // proc_pidinfo returns the number of bytes written.
         let actual_bytes = actual as usize;
         if actual_bytes < buffer_size || buffer_size >= max_buffer_size {
-            let actual_count = actual_bytes / mem::size_of::<ProcFdInfo>();
+            let actual_count = actual_bytes / mem::size_of::<libc::proc_fdinfo>();
             fdinfo.truncate(actual_count);
             return Ok(fdinfo);
         }
@@ -654,7 +839,7 @@ fn read_vnode_fd_path(pid: pid_t, fd: i32) -> Option<String> {
     }
 }
 
-pub fn list_fds_impl(pid: u32) -> SysprimsResult<(Vec<FdInfo>, Vec<String>)> {
+pub fn list_fds_impl(pid: u32) -> SysprimsResult<(Vec<FdInfo>, Vec<Warning>)> {
     let pid = pid as pid_t;
     let infos = list_all_fds(pid)?;
 
@@ -685,16 +870,21 @@ pub fn list_fds_impl(pid: u32) -> SysprimsResult<(Vec<FdInfo>, Vec<String>)> {
             None
         };
 
-        fds.push(FdInfo { fd, kind, path });
+        fds.push(FdInfo {
+            fd,
+            kind,
+            path,
+            path_bytes: None,
+        });
     }
 
     fds.sort_by_key(|f| f.fd);
 
     let mut warnings = Vec::new();
     if path_missing > 0 {
-        warnings.push(format!(
-            "Failed to resolve paths for {} file fds",
-            path_missing
+        warnings.push(Warning::new(
+            WarningCode::PartialVisibility,
+            format!("Failed to resolve paths for {} file fds", path_missing),
         ));
     }
 
@@ -702,11 +892,8 @@ pub fn list_fds_impl(pid: u32) -> SysprimsResult<(Vec<FdInfo>, Vec<String>)> {
 }
 
 fn read_socket_binding(pid: pid_t, fd: i32) -> SysprimsResult<PortBinding> {
-    // Don't model the full socket_fdinfo union layout directly; it contains large
-    // members (e.g. unix domain socket addresses) and an undersized model can
-    // cause proc_pidfdinfo() to fail with EINVAL.
-    //
-    // Instead, request a generously sized buffer and parse the fixed prefix.
+    // Request a generously sized buffer and parse the fixed head (see
+    // SocketFdInfoHead) followed by the soi_proto union.
     let mut buf = [0u8; 2048];
     let size = buf.len() as c_int;
     let result = unsafe {
@@ -722,18 +909,24 @@ fn read_socket_binding(pid: pid_t, fd: i32) -> SysprimsResult<PortBinding> {
     if result <= 0 {
         let errno = unsafe { *libc::__error() };
         if errno == libc::EPERM || errno == libc::EACCES {
-            return Err(SysprimsError::permission_denied(
+            return Err(SysprimsError::permission_denied_with_elevation(
                 pid as u32,
                 "read socket info",
+                elevation_would_help(pid as u32),
             ));
         }
         return Err(SysprimsError::internal("proc_pidfdinfo socket failed"));
     }
 
     let written = result as usize;
+    let layout = socket_info_layout();
+    if !layout.validates(&buf[..written]) {
+        return Err(SysprimsError::internal(format!(
+            "{SOCKINFO_LAYOUT_ERROR}: {written} bytes"
+        )));
+    }
     let (soi_protocol_off, soi_kind_off, soi_proto_off) =
-        select_socket_info_layout(&buf[..written])
-            .ok_or_else(|| SysprimsError::internal("unsupported socket_info layout"))?;
+        (layout.protocol, layout.kind, layout.proto);
 
     let kind = read_i32_at(&buf[..written], soi_kind_off)
         .ok_or_else(|| SysprimsError::internal("socket kind missing"))?;
@@ -802,15 +995,13 @@ fn read_socket_binding(pid: pid_t, fd: i32) -> SysprimsResult<PortBinding> {
         None
     };
 
-    let process = read_process_info(pid as u32, &ProcessOptions::default()).ok();
-
     Ok(PortBinding {
         protocol,
         local_addr,
         local_port,
         state,
         pid: Some(pid as u32),
-        process,
+        process: None,
         inode: None,
     })
 }
@@ -848,7 +1039,11 @@ fn read_process_info(pid: u32, options: &ProcessOptions) -> SysprimsResult<Proce
     let bsd_info = get_bsd_info(pid)?;
     let task_info = get_task_info(pid).ok();
     let name = get_process_name(pid).unwrap_or_else(|| extract_name(&bsd_info));
-    let user = get_username(bsd_info.pbi_uid);
+    let user = if options.resolve_usernames {
+        get_username(bsd_info.pbi_uid)
+    } else {
+        None
+    };
 
     // Calculate elapsed time
     let start_time = Duration::new(
@@ -886,6 +1081,23 @@ fn read_process_info(pid: u32, options: &ProcessOptions) -> SysprimsResult<Proce
         }
     };
 
+    let (exe_inode, exe_device, exe_deleted) = if options.include_exe_stat {
+        match exe_path.as_deref().map(std::fs::metadata) {
+            Some(Ok(meta)) => (Some(meta.ino()), Some(meta.dev()), Some(false)),
+            Some(Err(e)) if e.kind() == std::io::ErrorKind::NotFound => (None, None, Some(true)),
+            _ => (None, None, None),
+        }
+    } else {
+        (None, None, None)
+    };
+
+    let cpu_user_ns = task_info
+        .as_ref()
+        .map(|t| mach_time_to_ns(t.pti_total_user));
+    let cpu_system_ns = task_info
+        .as_ref()
+        .map(|t| mach_time_to_ns(t.pti_total_system));
+
     // Calculate CPU percentage
     let cpu_percent = task_info
         .as_ref()
@@ -898,20 +1110,14 @@ fn read_process_info(pid: u32, options: &ProcessOptions) -> SysprimsResult<Proce
         .map(|t| t.pti_resident_size / 1024)
         .unwrap_or(0);
 
-    // Process state
-    let state = match bsd_info.pbi_status {
-        SRUN => ProcessState::Running,
-        SSLEEP | SIDL => ProcessState::Sleeping,
-        SSTOP => ProcessState::Stopped,
-        SZOMB => ProcessState::Zombie,
-        _ => ProcessState::Unknown,
-    };
+    let state = state_from_bsd_status(bsd_info.pbi_status);
 
     let procargs = read_procargs(pid);
-    let cmdline = procargs
+    let mut cmdline = procargs
         .as_ref()
         .map(|buf| parse_cmdline_from_procargs(buf))
         .unwrap_or_default();
+    crate::limit_cmdline(&mut cmdline, options);
 
     #[cfg(not(feature = "proc_ext"))]
     let _ = options;
@@ -938,21 +1144,53 @@ fn read_process_info(pid: u32, options: &ProcessOptions) -> SysprimsResult<Proce
     #[cfg(not(feature = "proc_ext"))]
     let thread_count = None;
 
-    Ok(ProcessInfo {
+    let socket_count = if options.include_socket_count {
+        list_socket_fds(pid as pid_t)
+            .ok()
+            .map(|fds| fds.len() as u64)
+    } else {
+        None
+    };
+
+    let info = ProcessInfo {
         pid,
         ppid: bsd_info.pbi_ppid,
         name,
+        name_bytes: None,
         user,
+        uid: Some(bsd_info.pbi_uid),
         cpu_percent,
+        cpu_user_ns,
+        cpu_system_ns,
         memory_kb,
         elapsed_seconds,
         start_time_unix_ms: Some(start_time_unix_ms),
+        start_time: crate::rfc3339_from_unix_ms(start_time_unix_ms),
         exe_path,
+        exe_path_bytes: None,
+        exe_inode,
+        exe_device,
+        exe_deleted,
         state,
         cmdline,
+        cmdline_bytes: None,
+        cmdline_string: None,
         env,
         thread_count,
-    })
+        cgroup: None,
+        socket_count,
+        net_rx_bytes: None,
+        net_tx_bytes: None,
+        fd_limit_soft: None,
+        fd_limit_hard: None,
+        sig_blocked: None,
+        sig_ignored: None,
+        sig_caught: None,
+        // SAFETY: getsid only queries the session of `pid`; -1 means unreadable.
+        sid: u32::try_from(unsafe { libc::getsid(pid as pid_t) }).ok(),
+        identity: None,
+    };
+    Ok(crate::with_derived_fields(info, options))
 }
 
 fn read_procargs(pid: u32) -> Option<Vec<u8>> {
@@ -1136,7 +1374,11 @@ fn get_bsd_info(pid: u32) -> SysprimsResult<ProcBsdInfo> {
         if errno == libc::ESRCH {
             return Err(SysprimsError::not_found(pid));
         } else if errno == libc::EPERM || errno == libc::EACCES {
-            return Err(SysprimsError::permission_denied(pid, "read process info"));
+            return Err(SysprimsError::permission_denied_with_elevation(
+                pid,
+                "read process info",
+                elevation_would_help(pid),
+            ));
         }
         return Err(SysprimsError::not_found(pid));
     }
@@ -1289,6 +1531,27 @@ pub(crate) fn cpu_total_time_ns_impl(pid: u32) -> SysprimsResult<u64> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_socket_info_layout_matches_sdk_offsets() {
+        // socket_fdinfo offsets with the SDK's 136-byte vinfo_stat.
+        let layout = SocketInfoLayout::with_stat_size(136);
+        assert_eq!(mem::size_of::<libc::vinfo_stat>(), 136);
+        assert_eq!(
+            (layout.protocol, layout.kind, layout.proto),
+            (180, 256, 264)
+        );
+        // An override shifts every field after soi_stat.
+        let wider = SocketInfoLayout::with_stat_size(144);
+        assert_eq!((wider.protocol, wider.kind, wider.proto), (188, 264, 272));
+
+        let mut buf = vec![0xffu8; layout.proto + 64];
+        buf[layout.kind..layout.kind + 4].copy_from_slice(&SOCKINFO_TCP.to_ne_bytes());
+        buf[layout.protocol..layout.protocol + 4].copy_from_slice(&libc::IPPROTO_TCP.to_ne_bytes());
+        assert!(layout.validates(&buf));
+        assert!(!wider.validates(&buf));
+        assert!(!layout.validates(&[0xffu8; 64]));
+    }
+
     #[test]
     fn test_list_pids() {
         let pids = list_all_pids().unwrap();
@@ -0,0 +1,229 @@
+//! Protected PID policy shared by kill workflows.
+//!
+//! Bulk signaling (filters, descendant sweeps) must never hit the caller
+//! itself, init/launchd, or the caller's parent (usually the user's shell or
+//! terminal). The CLI, FFI, and language bindings all apply this policy
+//! through [`filter_protected`], so extending it here extends it everywhere.
+//!
+//! [`UserProtections`] adds user-configured PIDs and process names on top
+//! (e.g. the window server or a session leader). It is opt-in: the CLI reads
+//! it from [`PROTECTED_PIDS_ENV`] / [`PROTECTED_NAMES_ENV`] for
+//! selection-based kills.
+
+use std::collections::HashMap;
+
+use sysprims_core::{SysprimsError, SysprimsResult};
+
+use crate::{get_process, get_processes};
+
+/// PID 1 (init/launchd); never a safe bulk target (ADR-0011).
+const INIT_PID: u32 = 1;
+
+/// PIDs that bulk kill workflows must not signal by default.
+///
+/// Returns the calling process, PID 1, and the caller's parent (when it can
+/// be resolved). Duplicates are removed.
+///
+/// # Examples
+///
+/// ```rust
+/// use sysprims_proc::protected_pids;
+///
+/// let protected = protected_pids();
+/// assert!(protected.contains(&std::process::id()));
+/// assert!(protected.contains(&1));
+/// ```
+pub fn protected_pids() -> Vec<u32> {
+    let self_pid = sysprims_core::self_pid();
+    let mut pids = vec![self_pid, INIT_PID];
+    if let Some(ppid) = get_process(self_pid).ok().map(|p| p.ppid) {
+        pids.push(ppid);
+    }
+    pids.sort_unstable();
+    pids.dedup();
+    pids
+}
+
+/// Remove protected PIDs (see [`protected_pids`]) from `pids`.
+///
+/// Returns the number of entries removed. When `force` is true the list is
+/// left untouched and 0 is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use sysprims_proc::filter_protected;
+///
+/// let mut pids = vec![1, std::process::id(), 99999];
+/// let removed = filter_protected(&mut pids, false);
+/// assert_eq!(removed, 2);
+/// assert_eq!(pids, vec![99999]);
+/// ```
+pub fn filter_protected(pids: &mut Vec<u32>, force: bool) -> usize {
+    if force {
+        return 0;
+    }
+    let protected = protected_pids();
+    let before = pids.len();
+    pids.retain(|pid| !protected.contains(pid));
+    before - pids.len()
+}
+
+/// Comma-separated PIDs read by [`UserProtections::from_env`].
+pub const PROTECTED_PIDS_ENV: &str = "SYSPRIMS_PROTECTED_PIDS";
+
+/// Comma-separated process names read by [`UserProtections::from_env`].
+pub const PROTECTED_NAMES_ENV: &str = "SYSPRIMS_PROTECTED_NAMES";
+
+/// User-configured additions to the protected set ("never kill these").
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UserProtections {
+    /// Extra PIDs to protect.
+    pub pids: Vec<u32>,
+
+    /// Process names to protect, matched exactly (ASCII case-insensitive).
+    pub names: Vec<String>,
+}
+
+/// A target removed by [`UserProtections::filter`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtectedTarget {
+    pub pid: u32,
+
+    /// Process name, when it could be resolved.
+    pub name: Option<String>,
+}
+
+impl UserProtections {
+    /// Read [`PROTECTED_PIDS_ENV`] and [`PROTECTED_NAMES_ENV`].
+    ///
+    /// Unset variables contribute nothing; empty list entries are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidArgument` if a PID entry is not a number.
+    pub fn from_env() -> SysprimsResult<Self> {
+        Self::parse(
+            std::env::var(PROTECTED_PIDS_ENV).ok().as_deref(),
+            std::env::var(PROTECTED_NAMES_ENV).ok().as_deref(),
+        )
+    }
+
+    fn parse(pids: Option<&str>, names: Option<&str>) -> SysprimsResult<Self> {
+        let pids = split_list(pids.unwrap_or_default())
+            .map(|entry| {
+                entry.parse::<u32>().map_err(|_| {
+                    SysprimsError::invalid_argument(format!(
+                        "{PROTECTED_PIDS_ENV}: invalid PID '{entry}'"
+                    ))
+                })
+            })
+            .collect::<SysprimsResult<Vec<_>>>()?;
+        let names = split_list(names.unwrap_or_default())
+            .map(str::to_string)
+            .collect();
+        Ok(Self { pids, names })
+    }
+
+    /// True when nothing is protected.
+    pub fn is_empty(&self) -> bool {
+        self.pids.is_empty() && self.names.is_empty()
+    }
+
+    /// Remove protected targets from `pids` and return them.
+    ///
+    /// Names are resolved from a single batch lookup; targets that cannot be
+    /// resolved are matched by PID only. When `force` is true the list is left
+    /// untouched.
+    pub fn filter(&self, pids: &mut Vec<u32>, force: bool) -> Vec<ProtectedTarget> {
+        if force || self.is_empty() || pids.is_empty() {
+            return Vec::new();
+        }
+
+        let names: HashMap<u32, String> = get_processes(pids)
+            .map(|results| {
+                results
+                    .into_iter()
+                    .filter_map(|(pid, info)| Some((pid, info.ok()?.name)))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut removed = Vec::new();
+        pids.retain(|pid| {
+            let name = names.get(pid);
+            let protected = self.pids.contains(pid)
+                || name.is_some_and(|name| self.names.iter().any(|n| n.eq_ignore_ascii_case(name)));
+            if protected {
+                removed.push(ProtectedTarget {
+                    pid: *pid,
+                    name: name.cloned(),
+                });
+            }
+            !protected
+        });
+        removed
+    }
+}
+
+fn split_list(value: &str) -> impl Iterator<Item = &str> {
+    value.split(',').map(str::trim).filter(|s| !s.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_protected_keeps_list_when_forced() {
+        let mut pids = vec![1, std::process::id()];
+        assert_eq!(filter_protected(&mut pids, true), 0);
+        assert_eq!(pids.len(), 2);
+    }
+
+    #[test]
+    fn protected_pids_include_parent() {
+        let ppid = get_process(std::process::id()).unwrap().ppid;
+        assert!(protected_pids().contains(&ppid));
+    }
+
+    #[test]
+    fn user_protections_parse_lists() {
+        let parsed = UserProtections::parse(Some(" 42, ,7 "), Some("Xorg,WindowServer")).unwrap();
+        assert_eq!(parsed.pids, vec![42, 7]);
+        assert_eq!(parsed.names, vec!["Xorg", "WindowServer"]);
+        assert!(UserProtections::parse(None, None).unwrap().is_empty());
+        assert!(UserProtections::parse(Some("init"), None).is_err());
+    }
+
+    #[test]
+    fn user_protections_filter_by_pid_and_name() {
+        let self_pid = std::process::id();
+        let self_name = get_process(self_pid).unwrap().name;
+        let protections = UserProtections {
+            pids: vec![99999],
+            names: vec![self_name.to_uppercase()],
+        };
+
+        let mut pids = vec![self_pid, 99998, 99999];
+        let removed = protections.filter(&mut pids, false);
+        assert_eq!(pids, vec![99998]);
+        assert_eq!(
+            removed,
+            vec![
+                ProtectedTarget {
+                    pid: self_pid,
+                    name: Some(self_name),
+                },
+                ProtectedTarget {
+                    pid: 99999,
+                    name: None,
+                },
+            ]
+        );
+
+        let mut pids = vec![self_pid, 99999];
+        assert!(protections.filter(&mut pids, true).is_empty());
+        assert_eq!(pids.len(), 2);
+    }
+}
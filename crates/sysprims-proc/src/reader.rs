@@ -0,0 +1,163 @@
+//! Bounded reads on a small, process-wide pool of reader threads.
+//!
+//! Reading `/proc/[pid]/*` or libproc can block indefinitely on wedged or
+//! NFS-backed systems, and a blocked syscall cannot be interrupted. Deadline
+//! snapshots therefore hand each read to a pooled thread and stop waiting when
+//! the budget runs out. A reader stuck in such a read rejoins the pool once the
+//! read returns; until then it is unavailable.
+//!
+//! The pool never grows past [`MAX_READERS`] threads, however many calls time
+//! out. When every reader is stuck, [`ReaderPool::read_within`] reports
+//! [`BoundedRead::NoReader`] and callers skip the remaining work.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{mpsc, Mutex, MutexGuard};
+use std::thread;
+use std::time::Duration;
+
+/// Upper bound on reader threads across all concurrent callers.
+pub(crate) const MAX_READERS: usize = 4;
+
+/// The pool used by deadline snapshots.
+pub(crate) static READERS: ReaderPool = ReaderPool::new(MAX_READERS);
+
+/// A unit of work plus the handle its reader uses to rejoin the pool.
+type Job = Box<dyn FnOnce(Reader) + Send>;
+
+/// Sending half of one reader thread's job queue.
+struct Reader(mpsc::Sender<Job>);
+
+struct PoolState {
+    idle: Vec<Reader>,
+    spawned: usize,
+}
+
+/// Reader threads shared by every caller, capped at `max`.
+pub(crate) struct ReaderPool {
+    max: usize,
+    state: Mutex<PoolState>,
+}
+
+/// Outcome of [`ReaderPool::read_within`].
+pub(crate) enum BoundedRead<T> {
+    /// The read finished within the budget.
+    Done(T),
+    /// The budget expired; the read keeps its reader until it returns.
+    TimedOut,
+    /// Every reader is stuck in an earlier read, so nothing was attempted.
+    NoReader,
+}
+
+impl ReaderPool {
+    pub(crate) const fn new(max: usize) -> Self {
+        Self {
+            max,
+            state: Mutex::new(PoolState {
+                idle: Vec::new(),
+                spawned: 0,
+            }),
+        }
+    }
+
+    /// Run `read` on a pooled reader thread, waiting at most `budget`.
+    ///
+    /// A panic inside `read` is resumed on the calling thread.
+    pub(crate) fn read_within<T, F>(&'static self, budget: Duration, read: F) -> BoundedRead<T>
+    where
+        T: Send + 'static,
+        F: FnOnce() -> T + Send + 'static,
+    {
+        let Some(reader) = self.acquire() else {
+            return BoundedRead::NoReader;
+        };
+
+        let (reply_tx, reply_rx) = mpsc::channel();
+        let job: Job = Box::new(move |reader| {
+            let out = panic::catch_unwind(AssertUnwindSafe(read));
+            // Rejoin before replying so the caller's next read finds us idle.
+            self.lock().idle.push(reader);
+            let _ = reply_tx.send(out);
+        });
+        if reader.0.send(job).is_err() {
+            // Reader threads never exit; treat a dead one as lost capacity.
+            self.lock().spawned -= 1;
+            return BoundedRead::NoReader;
+        }
+
+        match reply_rx.recv_timeout(budget) {
+            Ok(Ok(out)) => BoundedRead::Done(out),
+            Ok(Err(payload)) => panic::resume_unwind(payload),
+            Err(_) => BoundedRead::TimedOut,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, PoolState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Take an idle reader, starting a new one while under `max`.
+    fn acquire(&'static self) -> Option<Reader> {
+        let mut state = self.lock();
+        if let Some(reader) = state.idle.pop() {
+            return Some(reader);
+        }
+        if state.spawned >= self.max {
+            return None;
+        }
+
+        let (job_tx, job_rx) = mpsc::channel::<Job>();
+        let own = job_tx.clone();
+        thread::Builder::new()
+            .name("sysprims-reader".to_string())
+            .spawn(move || {
+                for job in job_rx {
+                    job(Reader(own.clone()));
+                }
+            })
+            .ok()?;
+        state.spawned += 1;
+        Some(Reader(job_tx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    #[test]
+    fn stuck_reads_never_grow_the_pool_past_its_cap() {
+        static POOL: ReaderPool = ReaderPool::new(2);
+        let (unblock_tx, unblock_rx) = mpsc::channel::<()>();
+        let unblock_rx = Arc::new(Mutex::new(unblock_rx));
+
+        let outcomes: Vec<_> = (0..6)
+            .map(|_| {
+                let unblock_rx = Arc::clone(&unblock_rx);
+                POOL.read_within(Duration::from_millis(5), move || {
+                    let _ = unblock_rx.lock().unwrap().recv();
+                })
+            })
+            .collect();
+        let timed_out = outcomes
+            .iter()
+            .filter(|o| matches!(o, BoundedRead::TimedOut))
+            .count();
+        assert_eq!(timed_out, 2);
+        assert_eq!(POOL.lock().spawned, 2);
+
+        // Once the stuck reads return, their readers serve new work.
+        drop(unblock_tx);
+        let deadline = Instant::now() + Duration::from_secs(5);
+        loop {
+            if let BoundedRead::Done(v) = POOL.read_within(Duration::from_secs(1), || 7) {
+                assert_eq!(v, 7);
+                break;
+            }
+            assert!(Instant::now() < deadline, "readers never recovered");
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(POOL.lock().spawned, 2);
+    }
+}
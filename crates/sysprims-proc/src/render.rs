@@ -0,0 +1,233 @@
+//! Human-readable process tables and value formatting.
+//!
+//! The `sysprims` CLI renders its `--table` output through [`render_table`],
+//! so other frontends (FFI callers, language bindings, external tools) can
+//! produce identical column layouts and humanized values without
+//! reimplementing them. Enabled by the `render` feature.
+
+use std::fmt::Write;
+
+use crate::{ProcessInfo, ProcessState};
+
+/// Options for [`render_table`].
+///
+/// The default renders the CLI's `pstat --table` layout.
+#[derive(Debug, Clone, Default)]
+pub struct RenderOptions {
+    /// Show memory humanized (e.g. `73M`) in a `MEM` column instead of raw
+    /// kilobytes in `MEM(KB)`.
+    pub human_memory: bool,
+
+    /// Append [`cpu_indicator`] markers after each process name.
+    pub cpu_indicators: bool,
+}
+
+/// Render processes as a fixed-width table, one line per process.
+///
+/// The output has a header and rule line, and ends with a newline. An empty
+/// slice renders `(no matching processes)` under the header. Users are cut
+/// to 16 characters and names to 32.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sysprims_proc::render::{render_table, RenderOptions};
+///
+/// let snap = sysprims_proc::snapshot().unwrap();
+/// print!("{}", render_table(&snap.processes, &RenderOptions::default()));
+/// ```
+pub fn render_table(processes: &[ProcessInfo], opts: &RenderOptions) -> String {
+    let mut out = String::new();
+    let mem_header = if opts.human_memory { "MEM" } else { "MEM(KB)" };
+    let _ = writeln!(
+        out,
+        "{:>7} {:>7} {:>6} {:>10} {:>8} {:<16} NAME",
+        "PID", "PPID", "CPU%", mem_header, "STATE", "USER"
+    );
+    let _ = writeln!(out, "{:-<80}", "");
+
+    if processes.is_empty() {
+        out.push_str("(no matching processes)\n");
+        return out;
+    }
+
+    for p in processes {
+        let user = p.user.as_deref().unwrap_or("-");
+        let memory = if opts.human_memory {
+            format_memory(p.memory_kb)
+        } else {
+            p.memory_kb.to_string()
+        };
+        let indicator = if opts.cpu_indicators {
+            cpu_indicator(p.cpu_percent)
+        } else {
+            ""
+        };
+        let _ = writeln!(
+            out,
+            "{:>7} {:>7} {:>6.1} {:>10} {:>8} {:<16} {}{}",
+            p.pid,
+            p.ppid,
+            p.cpu_percent,
+            memory,
+            state_code(p.state),
+            truncate(user, 16),
+            truncate(&p.name, 32),
+            indicator
+        );
+    }
+    out
+}
+
+/// One-letter `ps`-style code for a process state.
+pub fn state_code(state: ProcessState) -> &'static str {
+    match state {
+        ProcessState::Running => "R",
+        ProcessState::Sleeping => "S",
+        ProcessState::DiskSleep => "D",
+        ProcessState::Stopped => "T",
+        ProcessState::Zombie => "Z",
+        ProcessState::Unknown => "?",
+    }
+}
+
+/// Format elapsed seconds as a compact duration (`45s`, `1m30s`, `2h5m`, `1d1h`).
+pub fn format_elapsed(secs: u64) -> String {
+    if secs < 60 {
+        format!("{secs}s")
+    } else if secs < 3600 {
+        let m = secs / 60;
+        let s = secs % 60;
+        if s == 0 {
+            format!("{m}m")
+        } else {
+            format!("{m}m{s}s")
+        }
+    } else if secs < 86400 {
+        let h = secs / 3600;
+        let m = (secs % 3600) / 60;
+        if m == 0 {
+            format!("{h}h")
+        } else {
+            format!("{h}h{m}m")
+        }
+    } else {
+        let d = secs / 86400;
+        let h = (secs % 86400) / 3600;
+        if h == 0 {
+            format!("{d}d")
+        } else {
+            format!("{d}d{h}h")
+        }
+    }
+}
+
+/// Format memory in KB as a human-readable string (`512K`, `73M`, `1.0G`).
+pub fn format_memory(kb: u64) -> String {
+    if kb < 1024 {
+        format!("{kb}K")
+    } else if kb < 1024 * 1024 {
+        format!("{}M", kb / 1024)
+    } else {
+        format!("{:.1}G", kb as f64 / (1024.0 * 1024.0))
+    }
+}
+
+/// CPU threshold marker: ` ★ HIGH` above 90%, ` ⚠ WARN` above 50%, else empty.
+pub fn cpu_indicator(cpu: f64) -> &'static str {
+    if cpu > 90.0 {
+        " ★ HIGH"
+    } else if cpu > 50.0 {
+        " ⚠ WARN"
+    } else {
+        ""
+    }
+}
+
+/// Truncate a string to at most `max_chars` characters (not bytes).
+///
+/// Safe for UTF-8 strings with multi-byte characters.
+pub fn truncate(s: &str, max_chars: usize) -> &str {
+    match s.char_indices().nth(max_chars) {
+        Some((byte_idx, _)) => &s[..byte_idx],
+        None => s, // String has fewer than max_chars characters
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_elapsed_values() {
+        assert_eq!(format_elapsed(0), "0s");
+        assert_eq!(format_elapsed(45), "45s");
+        assert_eq!(format_elapsed(60), "1m");
+        assert_eq!(format_elapsed(90), "1m30s");
+        assert_eq!(format_elapsed(3600), "1h");
+        assert_eq!(format_elapsed(3661), "1h1m");
+        assert_eq!(format_elapsed(86400), "1d");
+        assert_eq!(format_elapsed(90000), "1d1h");
+    }
+
+    #[test]
+    fn format_memory_values() {
+        assert_eq!(format_memory(512), "512K");
+        assert_eq!(format_memory(1024), "1M");
+        assert_eq!(format_memory(75584), "73M");
+        assert_eq!(format_memory(1048576), "1.0G");
+    }
+
+    #[test]
+    fn cpu_indicator_thresholds() {
+        assert_eq!(cpu_indicator(10.0), "");
+        assert_eq!(cpu_indicator(50.0), "");
+        assert_eq!(cpu_indicator(50.1), " ⚠ WARN");
+        assert_eq!(cpu_indicator(90.0), " ⚠ WARN");
+        assert_eq!(cpu_indicator(90.1), " ★ HIGH");
+        assert_eq!(cpu_indicator(102.0), " ★ HIGH");
+    }
+
+    #[test]
+    fn truncate_counts_characters() {
+        assert_eq!(truncate("héllo", 2), "hé");
+        assert_eq!(truncate("ok", 5), "ok");
+    }
+
+    #[test]
+    fn render_table_layout() {
+        let mut p = crate::get_process(std::process::id()).unwrap();
+        p.pid = 42;
+        p.ppid = 1;
+        p.name = "worker".to_string();
+        p.user = Some("svc".to_string());
+        p.cpu_percent = 95.0;
+        p.memory_kb = 75584;
+        p.state = ProcessState::Running;
+
+        let plain = render_table(std::slice::from_ref(&p), &RenderOptions::default());
+        let lines: Vec<&str> = plain.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].contains("MEM(KB)"));
+        assert_eq!(
+            lines[2],
+            "     42       1   95.0      75584        R svc              worker"
+        );
+
+        let human = render_table(
+            &[p],
+            &RenderOptions {
+                human_memory: true,
+                cpu_indicators: true,
+            },
+        );
+        assert!(human
+            .lines()
+            .nth(2)
+            .unwrap()
+            .ends_with("73M        R svc              worker ★ HIGH"));
+
+        let empty = render_table(&[], &RenderOptions::default());
+        assert!(empty.ends_with("(no matching processes)\n"));
+    }
+}
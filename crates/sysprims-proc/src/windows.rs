@@ -6,15 +6,17 @@
 //! - `OpenProcess` / `GetProcessTimes` - CPU timing
 //! - `GetProcessMemoryInfo` - memory usage
 //! - `QueryFullProcessImageName` - process path
+//! - `GetExtendedTcpTable` / `GetExtendedUdpTable` - listening ports with owning PIDs
 
 use crate::{
     aggregate_error_warning, make_port_snapshot, make_snapshot, FdInfo, PortBinding,
     PortBindingsSnapshot, ProcessInfo, ProcessOptions, ProcessSnapshot, ProcessState, Protocol,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::mem;
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
-use sysprims_core::{SysprimsError, SysprimsResult};
+use sysprims_core::sys::{self, elevation_would_help};
+use sysprims_core::{SysprimsError, SysprimsResult, Warning, WarningCode};
 use windows_sys::Win32::Foundation::{
     CloseHandle, GetLastError, ERROR_ACCESS_DENIED, ERROR_INSUFFICIENT_BUFFER,
     INVALID_HANDLE_VALUE, NO_ERROR,
@@ -86,7 +88,18 @@ pub fn snapshot_impl(options: &ProcessOptions) -> SysprimsResult<ProcessSnapshot
     Ok(make_snapshot(processes))
 }
 
-pub fn list_fds_impl(_pid: u32) -> SysprimsResult<(Vec<FdInfo>, Vec<String>)> {
+/// Count processes from one Toolhelp32 pass.
+///
+/// Windows exposes no simple run state, so every process counts as `Unknown`.
+pub(crate) fn state_counts_impl() -> SysprimsResult<HashMap<ProcessState, usize>> {
+    let snap = snapshot_impl(&ProcessOptions::default().without_usernames())?;
+    Ok(HashMap::from([(
+        ProcessState::Unknown,
+        snap.processes.len(),
+    )]))
+}
+
+pub fn list_fds_impl(_pid: u32) -> SysprimsResult<(Vec<FdInfo>, Vec<Warning>)> {
     Err(SysprimsError::not_supported(
         "open file descriptor enumeration",
         sysprims_core::get_platform(),
@@ -114,13 +127,68 @@ pub fn get_process_impl(pid: u32, options: &ProcessOptions) -> SysprimsResult<Pr
     Ok(process)
 }
 
-pub fn wait_pid_impl(pid: u32, timeout: Duration) -> SysprimsResult<crate::WaitPidResult> {
+/// Batch lookup from a single Toolhelp snapshot instead of one per PID.
+pub fn get_processes_impl(
+    pids: &[u32],
+    options: &ProcessOptions,
+) -> Vec<(u32, SysprimsResult<ProcessInfo>)> {
+    let snap = match snapshot_impl(&ProcessOptions::default()) {
+        Ok(snap) => snap,
+        Err(e) => {
+            let msg = e.to_string();
+            return pids
+                .iter()
+                .map(|&pid| (pid, Err(SysprimsError::internal(msg.clone()))))
+                .collect();
+        }
+    };
+
+    #[cfg(feature = "proc_ext")]
+    let thread_counts = if options.include_threads {
+        collect_thread_counts().unwrap_or_default()
+    } else {
+        HashMap::new()
+    };
+
+    #[cfg(not(feature = "proc_ext"))]
+    let _ = options;
+
+    let by_pid: HashMap<u32, &ProcessInfo> = snap.processes.iter().map(|p| (p.pid, p)).collect();
+    pids.iter()
+        .map(|&pid| {
+            let result = match by_pid.get(&pid) {
+                Some(&info) => {
+                    #[allow(unused_mut)]
+                    let mut process = info.clone();
+                    #[cfg(feature = "proc_ext")]
+                    if options.include_threads {
+                        process.thread_count = thread_counts.get(&pid).copied();
+                    }
+                    Ok(process)
+                }
+                None => Err(SysprimsError::not_found(pid)),
+            };
+            (pid, result)
+        })
+        .collect()
+}
+
+// Windows has no zombie state, so `WaitPidConfig::zombie_is_exited` has nothing to act on.
+pub fn wait_pid_impl(
+    pid: u32,
+    timeout: Duration,
+    _config: &crate::WaitPidConfig,
+) -> SysprimsResult<crate::WaitPidResult> {
     unsafe {
         let handle = OpenProcess(SYNCHRONIZE | PROCESS_QUERY_LIMITED_INFORMATION, 0, pid);
         if handle == 0 {
             let err = GetLastError();
             if err == ERROR_ACCESS_DENIED {
-                return Err(SysprimsError::permission_denied(pid, "wait pid"));
+                return Err(SysprimsError::permission_denied_with_elevation(
+                    pid,
+                    "wait pid",
+                    elevation_would_help(pid),
+                ));
             }
             return Err(SysprimsError::not_found(pid));
         }
@@ -139,7 +207,10 @@ pub fn wait_pid_impl(pid: u32, timeout: Duration) -> SysprimsResult<crate::WaitP
                     true,
                     false,
                     None,
-                    vec!["GetExitCodeProcess failed".to_string()],
+                    vec![Warning::new(
+                        WarningCode::PartialVisibility,
+                        "GetExitCodeProcess failed",
+                    )],
                 ));
             }
             return Ok(crate::make_wait_pid_result(
@@ -163,7 +234,7 @@ pub fn wait_pid_impl(pid: u32, timeout: Duration) -> SysprimsResult<crate::WaitP
     }
 }
 
-pub fn listening_ports_impl() -> SysprimsResult<PortBindingsSnapshot> {
+pub fn listening_ports_impl(options: &ProcessOptions) -> SysprimsResult<PortBindingsSnapshot> {
     let mut warnings = Vec::new();
     let mut bindings = Vec::new();
 
@@ -191,9 +262,67 @@ pub fn listening_ports_impl() -> SysprimsResult<PortBindingsSnapshot> {
         warnings.push(warning);
     }
 
+    attach_owning_processes(&mut bindings, options, &mut warnings);
+
     Ok(make_port_snapshot(bindings, warnings))
 }
 
+/// Best-effort: fill in `PortBinding::process` from one Toolhelp snapshot.
+///
+/// The owner-PID tables always report PIDs, but processes running as other
+/// users (services, SYSTEM) can only be opened from an elevated process. Those
+/// keep their name but lose path and stats, and are counted in a warning.
+fn attach_owning_processes(
+    bindings: &mut [PortBinding],
+    options: &ProcessOptions,
+    warnings: &mut Vec<Warning>,
+) {
+    let processes: HashMap<u32, ProcessInfo> = match snapshot_impl(options) {
+        Ok(snap) => snap.processes.into_iter().map(|p| (p.pid, p)).collect(),
+        Err(err) => {
+            warnings.push(Warning::new(
+                WarningCode::PartialVisibility,
+                format!("Failed to resolve owning processes: {}", err),
+            ));
+            return;
+        }
+    };
+
+    let mut limited = HashSet::new();
+    for binding in bindings.iter_mut() {
+        let Some(pid) = binding.pid else {
+            continue;
+        };
+        // Absent when the owner exited after the table was read.
+        if let Some(process) = processes.get(&pid) {
+            if process.exe_path.is_none() {
+                limited.insert(pid);
+            }
+            binding.process = Some(process.clone());
+        }
+    }
+
+    if !limited.is_empty() {
+        warnings.push(Warning::new(
+            WarningCode::PermissionDenied,
+            format!(
+                "Limited details for {} owning processes; run elevated for full attribution",
+                limited.len()
+            ),
+        ));
+    }
+}
+
+pub fn listening_ports_for_pid_impl(
+    pid: u32,
+    options: &ProcessOptions,
+) -> SysprimsResult<PortBindingsSnapshot> {
+    // The owner-PID tables are system-wide; keep only this PID's rows.
+    let mut snapshot = listening_ports_impl(options)?;
+    snapshot.bindings.retain(|binding| binding.pid == Some(pid));
+    Ok(snapshot)
+}
+
 /// Convert PROCESSENTRY32W to ProcessInfo.
 unsafe fn process_entry_to_info(
     entry: &PROCESSENTRY32W,
@@ -214,8 +343,8 @@ unsafe fn process_entry_to_info(
     };
 
     // Try to get additional info by opening the process
-    let (cpu_percent, memory_kb, elapsed_seconds, start_time_unix_ms) =
-        get_process_stats(pid).unwrap_or((0.0, 0, 0, None));
+    let (cpu_percent, memory_kb, elapsed_seconds, start_time_unix_ms, cpu_times) =
+        get_process_stats(pid).unwrap_or((0.0, 0, 0, None, None));
 
     let exe_path = get_process_exe_path(pid);
 
@@ -231,21 +360,44 @@ unsafe fn process_entry_to_info(
     #[cfg(not(feature = "proc_ext"))]
     let thread_count = None;
 
-    Ok(ProcessInfo {
+    let info = ProcessInfo {
         pid,
         ppid,
         name: name.clone(),
+        name_bytes: None,
         user: None, // Would require more complex token queries
+        uid: None,
         cpu_percent,
+        cpu_user_ns: cpu_times.map(|(user, _)| user),
+        cpu_system_ns: cpu_times.map(|(_, system)| system),
         memory_kb,
         elapsed_seconds,
         start_time_unix_ms,
+        start_time: start_time_unix_ms.and_then(crate::rfc3339_from_unix_ms),
         exe_path,
+        exe_path_bytes: None,
+        exe_inode: None,
+        exe_device: None,
+        exe_deleted: None,
         state: ProcessState::Unknown, // Windows doesn't expose this simply
         cmdline: vec![name],
+        cmdline_bytes: None,
+        cmdline_string: None,
         env: None,
         thread_count,
-    })
+        cgroup: None,
+        socket_count: None,
+        net_rx_bytes: None,
+        net_tx_bytes: None,
+        fd_limit_soft: None,
+        fd_limit_hard: None,
+        sig_blocked: None,
+        sig_ignored: None,
+        sig_caught: None,
+        sid: None,
+        identity: None,
+    };
+    Ok(crate::with_derived_fields(info, options))
 }
 
 #[cfg(feature = "proc_ext")]
@@ -292,7 +444,11 @@ fn read_tcp_table(af: u16) -> SysprimsResult<(Vec<PortBinding>, usize)> {
 
     if result != ERROR_INSUFFICIENT_BUFFER {
         if result == ERROR_ACCESS_DENIED {
-            return Err(SysprimsError::permission_denied(0, "list tcp table"));
+            return Err(SysprimsError::permission_denied_with_elevation(
+                0,
+                "list tcp table",
+                !sys::is_elevated(),
+            ));
         }
         if result != NO_ERROR {
             return Err(SysprimsError::system(
@@ -316,7 +472,11 @@ fn read_tcp_table(af: u16) -> SysprimsResult<(Vec<PortBinding>, usize)> {
 
     if result != NO_ERROR {
         if result == ERROR_ACCESS_DENIED {
-            return Err(SysprimsError::permission_denied(0, "list tcp table"));
+            return Err(SysprimsError::permission_denied_with_elevation(
+                0,
+                "list tcp table",
+                !sys::is_elevated(),
+            ));
         }
         return Err(SysprimsError::system(
             "GetExtendedTcpTable failed",
@@ -389,7 +549,11 @@ fn read_udp_table(af: u16) -> SysprimsResult<(Vec<PortBinding>, usize)> {
 
     if result != ERROR_INSUFFICIENT_BUFFER {
         if result == ERROR_ACCESS_DENIED {
-            return Err(SysprimsError::permission_denied(0, "list udp table"));
+            return Err(SysprimsError::permission_denied_with_elevation(
+                0,
+                "list udp table",
+                !sys::is_elevated(),
+            ));
         }
         if result != NO_ERROR {
             return Err(SysprimsError::system(
@@ -413,7 +577,11 @@ fn read_udp_table(af: u16) -> SysprimsResult<(Vec<PortBinding>, usize)> {
 
     if result != NO_ERROR {
         if result == ERROR_ACCESS_DENIED {
-            return Err(SysprimsError::permission_denied(0, "list udp table"));
+            return Err(SysprimsError::permission_denied_with_elevation(
+                0,
+                "list udp table",
+                !sys::is_elevated(),
+            ));
         }
         return Err(SysprimsError::system(
             "GetExtendedUdpTable failed",
@@ -547,8 +715,12 @@ fn udp6_row_to_binding(row: &MIB_UDP6ROW_OWNER_PID) -> Option<PortBinding> {
     })
 }
 
+/// `(cpu_percent, memory_kb, elapsed_seconds, start_time_unix_ms,
+/// (cpu_user_ns, cpu_system_ns))` from [`get_process_stats`].
+type ProcessStats = (f64, u64, u64, Option<u64>, Option<(u64, u64)>);
+
 /// Get CPU and memory stats for a process.
-unsafe fn get_process_stats(pid: u32) -> Option<(f64, u64, u64, Option<u64>)> {
+unsafe fn get_process_stats(pid: u32) -> Option<ProcessStats> {
     let handle = OpenProcess(PROCESS_QUERY_INFORMATION | PROCESS_VM_READ, 0, pid);
     if handle == 0 {
         return None;
@@ -587,7 +759,7 @@ unsafe fn get_process_stats(pid: u32) -> Option<(f64, u64, u64, Option<u64>)> {
     };
 
     // Calculate elapsed time and CPU percent
-    let (cpu_percent, elapsed_seconds, start_time_unix_ms) = if times_ok {
+    let (cpu_percent, elapsed_seconds, start_time_unix_ms, cpu_times) = if times_ok {
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap_or_default();
@@ -618,12 +790,23 @@ unsafe fn get_process_stats(pid: u32) -> Option<(f64, u64, u64, Option<u64>)> {
             0.0
         };
 
-        (cpu, elapsed, Some(creation_ms))
+        (
+            cpu,
+            elapsed,
+            Some(creation_ms),
+            Some((user_100ns * 100, kernel_100ns * 100)),
+        )
     } else {
-        (0.0, 0, None)
+        (0.0, 0, None, None)
     };
 
-    Some((cpu_percent, memory_kb, elapsed_seconds, start_time_unix_ms))
+    Some((
+        cpu_percent,
+        memory_kb,
+        elapsed_seconds,
+        start_time_unix_ms,
+        cpu_times,
+    ))
 }
 
 pub(crate) fn cpu_total_time_ns_impl(pid: u32) -> SysprimsResult<u64> {
@@ -632,7 +815,11 @@ pub(crate) fn cpu_total_time_ns_impl(pid: u32) -> SysprimsResult<u64> {
         if handle == 0 {
             // Map missing handle to not found vs permission denied is ambiguous.
             // Align with other best-effort functions by returning PermissionDenied.
-            return Err(SysprimsError::permission_denied(pid, "cpu_total_time_ns"));
+            return Err(SysprimsError::permission_denied_with_elevation(
+                pid,
+                "cpu_total_time_ns",
+                elevation_would_help(pid),
+            ));
         }
 
         let mut creation_time = mem::zeroed();
@@ -1,8 +1,10 @@
 use std::fs::File;
 use std::net::TcpListener;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use sysprims_proc::{list_fds, FdFilter, FdKind};
+use sysprims_proc::{
+    fd_growth, list_fds, processes_using_path_filtered, FdFilter, FdKind, ProcessFilter,
+};
 
 #[test]
 fn list_fds_includes_open_file_and_socket_for_self() {
@@ -91,3 +93,84 @@ fn list_fds_filter_by_kind_socket_only() {
         "expected only socket fds"
     );
 }
+
+#[test]
+fn list_fds_kind_counts_match_fds() {
+    let snapshot = match list_fds(std::process::id(), None) {
+        Ok(s) => s,
+        Err(sysprims_core::SysprimsError::NotSupported { .. }) => {
+            eprintln!("SKIP: list_fds returned NotSupported on this platform");
+            return;
+        }
+        Err(e) => panic!("list_fds: {e}"),
+    };
+
+    let total: u64 = snapshot.kind_counts.values().sum();
+    assert_eq!(total, snapshot.fds.len() as u64);
+    for (kind, count) in &snapshot.kind_counts {
+        let actual = snapshot.fds.iter().filter(|fd| fd.kind == *kind).count() as u64;
+        assert_eq!(*count, actual, "count mismatch for {kind:?}");
+    }
+}
+
+#[test]
+fn fd_growth_samples_self() {
+    let pid = std::process::id();
+    let report = match fd_growth(pid, 3, Duration::from_millis(10)) {
+        Ok(r) => r,
+        Err(sysprims_core::SysprimsError::NotSupported { .. }) => {
+            eprintln!("SKIP: fd_growth returned NotSupported on this platform");
+            return;
+        }
+        Err(e) => panic!("fd_growth: {e}"),
+    };
+
+    assert_eq!(report.pid, pid);
+    assert_eq!(report.counts.len(), 3);
+    assert!(report.min <= report.max);
+    assert!(report.min > 0, "self should have open fds: {report:?}");
+}
+
+#[test]
+fn processes_using_path_finds_self_holding_file() {
+    let pid = std::process::id();
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    let dir = std::env::temp_dir().join(format!("sysprims-lsof-test-{pid}-{now}"));
+    std::fs::create_dir_all(&dir).expect("create temp dir");
+    let file_path = dir.join("held.txt");
+    let _file = File::create(&file_path).expect("create temp file");
+
+    let filter = ProcessFilter {
+        pid_in: Some(vec![pid]),
+        ..Default::default()
+    };
+    let lookup = |path: &std::path::Path| {
+        processes_using_path_filtered(path.to_str().expect("utf-8 temp path"), &filter)
+    };
+
+    let by_file = match lookup(&file_path) {
+        Ok(users) => users,
+        Err(sysprims_core::SysprimsError::NotSupported { .. }) => {
+            eprintln!("SKIP: processes_using_path returned NotSupported on this platform");
+            let _ = std::fs::remove_dir_all(&dir);
+            return;
+        }
+        Err(e) => panic!("processes_using_path: {e}"),
+    };
+    let by_dir = lookup(&dir).expect("lookup by directory");
+    // A string prefix of the directory name is a different path.
+    let dir_s = dir.to_string_lossy();
+    let prefix = std::path::PathBuf::from(&dir_s[..dir_s.len() - 1]);
+    let by_prefix = lookup(&prefix).expect("lookup by name prefix");
+    let _ = std::fs::remove_dir_all(&dir);
+
+    for users in [&by_file, &by_dir] {
+        assert_eq!(users.len(), 1, "expected self only: {users:?}");
+        assert_eq!(users[0].0.pid, pid);
+        assert!(!users[0].1.is_empty());
+    }
+    assert!(by_prefix.is_empty(), "name prefix matched: {by_prefix:?}");
+}
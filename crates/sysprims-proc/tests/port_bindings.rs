@@ -1,27 +1,41 @@
-use std::net::TcpListener;
+use std::net::{TcpListener, UdpSocket};
 
-use sysprims_core::SysprimsError;
-use sysprims_proc::{listening_ports, PortFilter, Protocol};
+use sysprims_core::{SysprimsError, WarningCode};
+use sysprims_proc::{
+    get_process, get_process_with_options, listening_ports, listening_ports_for_pid,
+    listening_ports_for_pid_with_options, processes_by_port, processes_by_ports, PortFilter,
+    ProcessOptions, Protocol,
+};
+
+/// Bind a loopback TCP listener on an ephemeral port.
+///
+/// Returns `None` (and the caller skips) when the sandbox denies even
+/// loopback binds.
+fn bind_loopback_listener() -> Option<(TcpListener, u16)> {
+    match TcpListener::bind("127.0.0.1:0") {
+        Ok(listener) => {
+            let port = listener.local_addr().expect("local_addr").port();
+            Some((listener, port))
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::PermissionDenied => {
+            eprintln!("skipping: TcpListener bind denied: {err}");
+            None
+        }
+        Err(err) => panic!("bind: {err}"),
+    }
+}
 
 #[test]
 fn test_listening_ports_self_listener_tcp() {
-    let listener = match TcpListener::bind("127.0.0.1:0") {
-        Ok(l) => l,
-        Err(err) => {
-            if err.kind() == std::io::ErrorKind::PermissionDenied {
-                // Some sandboxed environments disallow even loopback binds.
-                eprintln!("skipping: TcpListener bind denied: {err}");
-                return;
-            }
-            panic!("bind: {err}");
-        }
+    let Some((_listener, port)) = bind_loopback_listener() else {
+        return;
     };
-    let port = listener.local_addr().expect("local_addr").port();
     let pid = std::process::id();
 
     let filter = PortFilter {
         protocol: Some(Protocol::Tcp),
         local_port: Some(port),
+        ..Default::default()
     };
 
     let snapshot = match listening_ports(Some(&filter)) {
@@ -58,7 +72,7 @@ fn test_listening_ports_self_listener_tcp() {
         let has_permission_warnings = snapshot
             .warnings
             .iter()
-            .any(|w| w.contains("permission") || w.contains("Permission"));
+            .any(|w| w.code == WarningCode::PermissionDenied);
 
         if has_permission_warnings {
             eprintln!(
@@ -81,3 +95,252 @@ fn test_listening_ports_self_listener_tcp() {
         snapshot.bindings.len()
     );
 }
+
+#[test]
+fn test_listening_ports_name_only_filter() {
+    let Some((_listener, _port)) = bind_loopback_listener() else {
+        return;
+    };
+
+    let filter = PortFilter {
+        process_name_contains: Some("zzzz-no-such-process".to_string()),
+        ..Default::default()
+    };
+    match listening_ports(Some(&filter)) {
+        Ok(snapshot) => assert!(
+            snapshot.bindings.is_empty(),
+            "name filter was ignored: {} bindings",
+            snapshot.bindings.len()
+        ),
+        Err(SysprimsError::NotSupported { .. }) => {
+            eprintln!("SKIP: listening_ports returned NotSupported (container/musl environment)");
+        }
+        Err(e) => panic!("listening_ports: {e}"),
+    }
+
+    let name = get_process(std::process::id()).expect("self").name;
+    let filter = PortFilter {
+        process_name_contains: Some(name.clone()),
+        ..Default::default()
+    };
+    let snapshot = match listening_ports(Some(&filter)) {
+        Ok(s) => s,
+        Err(SysprimsError::NotSupported { .. }) => {
+            eprintln!("SKIP: listening_ports returned NotSupported (container/musl environment)");
+            return;
+        }
+        Err(e) => panic!("listening_ports: {e}"),
+    };
+    let needle = name.to_lowercase();
+    for binding in &snapshot.bindings {
+        let owner = binding.process.as_ref().map(|p| p.name.to_lowercase());
+        assert!(
+            owner.as_deref().is_some_and(|n| n.contains(&needle)),
+            "binding owned by {owner:?} does not match {name}"
+        );
+    }
+}
+
+#[test]
+fn test_listening_ports_self_bound_udp() {
+    let socket = match UdpSocket::bind("127.0.0.1:0") {
+        Ok(s) => s,
+        Err(err) => {
+            if err.kind() == std::io::ErrorKind::PermissionDenied {
+                eprintln!("skipping: UdpSocket bind denied: {err}");
+                return;
+            }
+            panic!("bind: {err}");
+        }
+    };
+    let port = socket.local_addr().expect("local_addr").port();
+    let pid = std::process::id();
+
+    let filter = PortFilter {
+        protocol: Some(Protocol::Udp),
+        local_port: Some(port),
+        ..Default::default()
+    };
+
+    let snapshot = match listening_ports(Some(&filter)) {
+        Ok(s) => s,
+        Err(SysprimsError::NotSupported { .. }) => {
+            eprintln!("SKIP: listening_ports returned NotSupported (container/musl environment)");
+            return;
+        }
+        Err(e) => panic!("listening_ports: {e}"),
+    };
+    let Some(binding) = snapshot.bindings.iter().find(|b| b.local_port == port) else {
+        if cfg!(target_os = "windows")
+            && snapshot
+                .warnings
+                .iter()
+                .any(|w| w.code == WarningCode::PermissionDenied)
+        {
+            eprintln!("SKIP: UDP binding not visible (permission-limited)");
+            return;
+        }
+        panic!(
+            "Did not find self UDP socket port={}; warnings={:?}",
+            port, snapshot.warnings
+        );
+    };
+
+    assert_eq!(binding.protocol, Protocol::Udp);
+    assert_eq!(binding.state, None, "UDP has no listen state");
+    assert_eq!(binding.pid, Some(pid));
+}
+
+#[test]
+fn test_processes_by_port_dedups_self_listeners() {
+    // Two listeners in this process on the same port (IPv4 + IPv6) must yield one owner.
+    let Some((_v4, port)) = bind_loopback_listener() else {
+        return;
+    };
+    let _v6 = TcpListener::bind(("::1", port)).ok();
+    let pid = std::process::id();
+
+    let owners = match processes_by_port(port, Protocol::Tcp) {
+        Ok(o) => o,
+        Err(SysprimsError::NotSupported { .. }) => {
+            eprintln!("SKIP: processes_by_port returned NotSupported (container/musl environment)");
+            return;
+        }
+        Err(e) => panic!("processes_by_port: {e}"),
+    };
+
+    let self_count = owners.iter().filter(|p| p.pid == pid).count();
+    assert!(self_count <= 1, "duplicate owners: {owners:?}");
+    if cfg!(target_os = "macos") {
+        assert_eq!(self_count, 1, "did not find self listener: {owners:?}");
+    }
+}
+
+#[test]
+fn test_processes_by_port_rejects_port_zero() {
+    let err = processes_by_port(0, Protocol::Tcp).unwrap_err();
+    assert!(matches!(err, SysprimsError::InvalidArgument { .. }));
+}
+
+#[test]
+fn test_processes_by_ports_resolves_batch_in_one_scan() {
+    let Some((_tcp, tcp_port)) = bind_loopback_listener() else {
+        return;
+    };
+    // A UDP port nobody is bound to: bind, read the port, release it.
+    let unused_udp = UdpSocket::bind("127.0.0.1:0")
+        .and_then(|s| s.local_addr())
+        .expect("udp bind")
+        .port();
+
+    let requested = [(tcp_port, Protocol::Tcp), (unused_udp, Protocol::Udp)];
+    let owners = match processes_by_ports(&requested) {
+        Ok(o) => o,
+        Err(SysprimsError::NotSupported { .. }) => {
+            eprintln!(
+                "SKIP: processes_by_ports returned NotSupported (container/musl environment)"
+            );
+            return;
+        }
+        Err(e) => panic!("processes_by_ports: {e}"),
+    };
+
+    assert_eq!(owners.len(), 2);
+    assert!(owners[&(unused_udp, Protocol::Udp)].is_empty());
+    if cfg!(target_os = "macos") {
+        let pid = std::process::id();
+        assert!(owners[&(tcp_port, Protocol::Tcp)]
+            .iter()
+            .any(|p| p.pid == pid));
+    }
+
+    let err = processes_by_ports(&[(0, Protocol::Tcp)]).unwrap_err();
+    assert!(matches!(err, SysprimsError::InvalidArgument { .. }));
+}
+
+#[test]
+fn test_listening_ports_for_pid_finds_only_own_listener() {
+    let Some((_listener, port)) = bind_loopback_listener() else {
+        return;
+    };
+    let pid = std::process::id();
+
+    let snapshot = match listening_ports_for_pid(pid, None) {
+        Ok(s) => s,
+        Err(SysprimsError::NotSupported { .. }) => {
+            eprintln!("SKIP: listening_ports_for_pid returned NotSupported");
+            return;
+        }
+        Err(e) => panic!("listening_ports_for_pid: {e}"),
+    };
+
+    assert!(
+        snapshot.bindings.iter().all(|b| b.pid == Some(pid)),
+        "bindings from other pids: {:?}",
+        snapshot.bindings
+    );
+    assert!(
+        snapshot
+            .bindings
+            .iter()
+            .any(|b| b.protocol == Protocol::Tcp && b.local_port == port),
+        "Did not find self listener pid={} port={}; warnings={:?}",
+        pid,
+        port,
+        snapshot.warnings
+    );
+
+    let other_port = PortFilter {
+        local_port: Some(port.wrapping_add(1).max(1)),
+        ..Default::default()
+    };
+    let filtered = listening_ports_for_pid(pid, Some(&other_port)).expect("filtered");
+    assert!(filtered.bindings.iter().all(|b| b.local_port != port));
+}
+
+#[test]
+fn test_listening_ports_for_pid_rejects_pid_zero() {
+    let err = listening_ports_for_pid(0, None).unwrap_err();
+    assert!(matches!(err, SysprimsError::InvalidArgument { .. }));
+}
+
+#[test]
+fn test_listening_ports_for_pid_with_options_populates_process_fields() {
+    let Some((_listener, port)) = bind_loopback_listener() else {
+        return;
+    };
+    let pid = std::process::id();
+
+    let options = ProcessOptions::default().with_identity();
+    let snapshot = match listening_ports_for_pid_with_options(pid, None, options) {
+        Ok(s) => s,
+        Err(SysprimsError::NotSupported { .. }) => {
+            eprintln!("SKIP: listening_ports_for_pid_with_options returned NotSupported");
+            return;
+        }
+        Err(e) => panic!("listening_ports_for_pid_with_options: {e}"),
+    };
+    let Some(binding) = snapshot.bindings.iter().find(|b| b.local_port == port) else {
+        eprintln!(
+            "SKIP: self listener not attributed; {:?}",
+            snapshot.warnings
+        );
+        return;
+    };
+    let Some(process) = &binding.process else {
+        eprintln!("SKIP: owning process not resolved");
+        return;
+    };
+
+    let direct = get_process_with_options(pid, options).expect("get_process_with_options");
+    assert!(process.identity.is_some());
+    assert_eq!(process.identity, direct.identity);
+
+    // The default stays the cheap set.
+    let cheap = listening_ports_for_pid(pid, None).expect("listening_ports_for_pid");
+    assert!(cheap
+        .bindings
+        .iter()
+        .filter_map(|b| b.process.as_ref())
+        .all(|p| p.identity.is_none()));
+}
@@ -47,6 +47,12 @@ pub struct SetsidConfig {
     /// This is a no-op placeholder for compatibility with util-linux setsid -c.
     /// Most use cases don't need this.
     pub ctty: bool,
+
+    /// Additional signals to ignore in the child (e.g. `SIGINT`, `SIGQUIT`).
+    ///
+    /// Applied with `sigaction(SIG_IGN)` before exec. `SIGKILL` and `SIGSTOP`
+    /// cannot be ignored and are rejected with `InvalidArgument`.
+    pub extra_ignored_signals: Vec<i32>,
 }
 
 /// Outcome of setsid execution.
@@ -129,6 +135,12 @@ pub struct NohupConfig {
 
     /// Wait for the child process to exit.
     pub wait: bool,
+
+    /// Signals to ignore in addition to `SIGHUP` (e.g. `SIGINT`, `SIGQUIT`).
+    ///
+    /// Applied with `sigaction(SIG_IGN)` before exec. `SIGKILL` and `SIGSTOP`
+    /// cannot be ignored and are rejected with `InvalidArgument`.
+    pub extra_ignored_signals: Vec<i32>,
 }
 
 /// Outcome of nohup execution.
@@ -250,6 +262,7 @@ mod tests {
         let config = SetsidConfig::default();
         assert!(!config.wait);
         assert!(!config.ctty);
+        assert!(config.extra_ignored_signals.is_empty());
     }
 
     #[test]
@@ -257,5 +270,6 @@ mod tests {
         let config = NohupConfig::default();
         assert!(config.output_file.is_none());
         assert!(!config.wait);
+        assert!(config.extra_ignored_signals.is_empty());
     }
 }
@@ -20,13 +20,17 @@ pub fn run_setsid_impl(
     args: &[&str],
     config: &SetsidConfig,
 ) -> SysprimsResult<SetsidOutcome> {
+    validate_ignored_signals(&config.extra_ignored_signals)?;
+
     let mut cmd = Command::new(command);
     cmd.args(args);
 
     // Set up setsid in the child process after fork
-    // SAFETY: setsid() is async-signal-safe per POSIX and safe to call after fork
+    // SAFETY: setsid() and sigaction() are async-signal-safe per POSIX and safe
+    // to call after fork
+    let ignored = config.extra_ignored_signals.clone();
     unsafe {
-        cmd.pre_exec(|| {
+        cmd.pre_exec(move || {
             // Create new session - the child becomes:
             // 1. Session leader of a new session
             // 2. Process group leader of a new process group
@@ -34,20 +38,14 @@ pub fn run_setsid_impl(
             if libc::setsid() == -1 {
                 return Err(std::io::Error::last_os_error());
             }
-            Ok(())
+            ignore_signals(&ignored)
         });
     }
 
     // Spawn the child
-    let mut child = cmd.spawn().map_err(|e| {
-        if e.kind() == std::io::ErrorKind::NotFound {
-            SysprimsError::not_found_command(command)
-        } else if e.kind() == std::io::ErrorKind::PermissionDenied {
-            SysprimsError::permission_denied_command(command)
-        } else {
-            SysprimsError::spawn_failed(command, e.to_string())
-        }
-    })?;
+    let mut child = cmd
+        .spawn()
+        .map_err(|e| SysprimsError::from_spawn_error(command, &e))?;
 
     let child_pid = child.id();
 
@@ -77,6 +75,8 @@ pub fn run_nohup_impl(
 ) -> SysprimsResult<NohupOutcome> {
     use std::fs::OpenOptions;
 
+    validate_ignored_signals(&config.extra_ignored_signals)?;
+
     let mut cmd = Command::new(command);
     cmd.args(args);
 
@@ -112,26 +112,21 @@ pub fn run_nohup_impl(
         }
     }
 
-    // Set up SIGHUP ignore in the child
-    // SAFETY: signal() is async-signal-safe per POSIX
+    // Set up SIGHUP (plus any extra signals) ignore in the child
+    // SAFETY: signal() and sigaction() are async-signal-safe per POSIX
+    let ignored = config.extra_ignored_signals.clone();
     unsafe {
-        cmd.pre_exec(|| {
+        cmd.pre_exec(move || {
             // Ignore SIGHUP so the process survives terminal close
             libc::signal(libc::SIGHUP, libc::SIG_IGN);
-            Ok(())
+            ignore_signals(&ignored)
         });
     }
 
     // Spawn the child
-    let mut child = cmd.spawn().map_err(|e| {
-        if e.kind() == std::io::ErrorKind::NotFound {
-            SysprimsError::not_found_command(command)
-        } else if e.kind() == std::io::ErrorKind::PermissionDenied {
-            SysprimsError::permission_denied_command(command)
-        } else {
-            SysprimsError::spawn_failed(command, e.to_string())
-        }
-    })?;
+    let mut child = cmd
+        .spawn()
+        .map_err(|e| SysprimsError::from_spawn_error(command, &e))?;
 
     let child_pid = child.id();
 
@@ -151,6 +146,57 @@ pub fn run_nohup_impl(
     }
 }
 
+// ============================================================================
+// Signal disposition helpers
+// ============================================================================
+
+/// Reject signals that cannot be ignored before we fork.
+///
+/// `sigaddset` validates the number against the platform's signal range, so
+/// we don't need per-OS upper bounds here.
+fn validate_ignored_signals(signals: &[i32]) -> SysprimsResult<()> {
+    for &sig in signals {
+        if sig == libc::SIGKILL || sig == libc::SIGSTOP {
+            return Err(SysprimsError::invalid_argument(format!(
+                "signal {} cannot be ignored",
+                sig
+            )));
+        }
+
+        // SAFETY: sigemptyset/sigaddset only write to the local set.
+        let valid = unsafe {
+            let mut set: libc::sigset_t = std::mem::zeroed();
+            libc::sigemptyset(&mut set);
+            sig > 0 && libc::sigaddset(&mut set, sig) == 0
+        };
+        if !valid {
+            return Err(SysprimsError::invalid_argument(format!(
+                "invalid signal number: {}",
+                sig
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Set `SIG_IGN` for each signal. Runs in `pre_exec`, so it must stay
+/// async-signal-safe (no allocation).
+fn ignore_signals(signals: &[i32]) -> std::io::Result<()> {
+    for &sig in signals {
+        // SAFETY: sigaction/sigemptyset are async-signal-safe; `action` is a
+        // fully initialized local.
+        unsafe {
+            let mut action: libc::sigaction = std::mem::zeroed();
+            action.sa_sigaction = libc::SIG_IGN;
+            libc::sigemptyset(&mut action.sa_mask);
+            if libc::sigaction(sig, &action, std::ptr::null_mut()) != 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Determine the output file for nohup.
 ///
 /// Per POSIX: Try "nohup.out" in current directory, then "$HOME/nohup.out"
@@ -227,7 +273,9 @@ pub fn setpgid_impl(pid: u32, pgid: u32) -> SysprimsResult<()> {
         if errno.raw_os_error() == Some(libc::ESRCH) {
             Err(SysprimsError::not_found(pid))
         } else if errno.raw_os_error() == Some(libc::EPERM) {
-            Err(SysprimsError::permission_denied(pid, "setpgid"))
+            Err(SysprimsError::permission_denied_with_elevation(
+                pid, "setpgid", false,
+            ))
         } else {
             Err(SysprimsError::system(
                 "setpgid failed",
@@ -291,6 +339,62 @@ mod tests {
         assert!(matches!(result, Err(SysprimsError::NotFoundCommand { .. })));
     }
 
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn setsid_extra_ignored_signals_applied() {
+        let result = run_setsid_impl(
+            "sleep",
+            &["5"],
+            &SetsidConfig {
+                extra_ignored_signals: vec![libc::SIGINT, libc::SIGQUIT],
+                ..Default::default()
+            },
+        );
+        let Ok(SetsidOutcome::Spawned { child_pid }) = result else {
+            panic!("expected Spawned, got {:?}", result);
+        };
+
+        // Wait for exec so we read the sleep image, not the forked parent.
+        let status_path = format!("/proc/{}/status", child_pid);
+        let mut sig_ign = None;
+        for _ in 0..200 {
+            let status = std::fs::read_to_string(&status_path).unwrap_or_default();
+            if status.lines().any(|l| l == "Name:\tsleep") {
+                sig_ign = status
+                    .lines()
+                    .find_map(|l| l.strip_prefix("SigIgn:"))
+                    .and_then(|v| u64::from_str_radix(v.trim(), 16).ok());
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+
+        unsafe {
+            libc::kill(child_pid as libc::pid_t, libc::SIGKILL);
+            libc::waitpid(child_pid as libc::pid_t, std::ptr::null_mut(), 0);
+        }
+
+        let mask = sig_ign.expect("SigIgn for spawned child");
+        for sig in [libc::SIGINT, libc::SIGQUIT] {
+            assert_ne!(mask & (1 << (sig - 1)), 0, "signal {sig} not ignored");
+        }
+    }
+
+    #[test]
+    fn extra_ignored_signals_rejects_uncatchable() {
+        for sig in [libc::SIGKILL, libc::SIGSTOP, 0, -1] {
+            let config = NohupConfig {
+                extra_ignored_signals: vec![sig],
+                ..Default::default()
+            };
+            let result = run_nohup_impl("true", &[], &config);
+            assert!(
+                matches!(result, Err(SysprimsError::InvalidArgument { .. })),
+                "signal {sig} should be rejected"
+            );
+        }
+    }
+
     #[test]
     fn getpgid_current_process() {
         let pgid = getpgid_impl(0);
@@ -307,6 +307,7 @@ fn nohup_ignores_sighup() {
         NohupConfig {
             wait: true,
             output_file: Some("/dev/null".to_string()),
+            ..Default::default()
         },
     );
 
@@ -343,6 +344,7 @@ fn nohup_exit_code_propagation() {
         NohupConfig {
             wait: true,
             output_file: Some("/dev/null".to_string()),
+            ..Default::default()
         },
     );
 
@@ -377,6 +379,7 @@ fn nohup_success_case() {
         NohupConfig {
             wait: true,
             output_file: Some("/dev/null".to_string()),
+            ..Default::default()
         },
     );
 
@@ -785,6 +788,7 @@ fn nohup_works_on_macos() {
         NohupConfig {
             wait: true,
             output_file: Some("/dev/null".to_string()),
+            ..Default::default()
         },
     );
 
@@ -871,6 +875,7 @@ fn nohup_background_mode() {
         NohupConfig {
             wait: false,
             output_file: Some("/dev/null".to_string()),
+            ..Default::default()
         },
     );
 
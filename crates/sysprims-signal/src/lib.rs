@@ -3,6 +3,7 @@
 //! This crate provides:
 //! - Process signal dispatch by PID ([`kill`])
 //! - Process group signal dispatch by PGID ([`killpg`], Unix-only)
+//! - Session signal dispatch by SID ([`kill_session`], Unix-only)
 //! - Convenience wrappers ([`terminate`], [`force_kill`], etc.)
 //!
 //! Errors use the canonical [`sysprims_core::SysprimsError`] type.
@@ -18,7 +19,11 @@
 //! See `docs/safety/signal-dispatch.md` for full details on POSIX signal
 //! semantics and why these restrictions exist.
 
-use sysprims_core::{SysprimsError, SysprimsResult};
+use std::time::Duration;
+
+use sysprims_core::glob::glob_match;
+use sysprims_core::{SysprimsError, SysprimsResult, Warning, WarningCode};
+use sysprims_proc::{WaitPidConfig, WaitPidResult};
 
 /// Maximum valid PID value.
 ///
@@ -62,6 +67,99 @@ pub struct BatchKillFailure {
 pub struct BatchKillResult {
     pub succeeded: Vec<u32>,
     pub failed: Vec<BatchKillFailure>,
+
+    /// PIDs dropped before signaling (see [`BatchKillOptions::guard_own_group`]).
+    pub skipped: Vec<u32>,
+
+    /// Non-fatal notes about the batch (e.g. skipped PIDs).
+    pub warnings: Vec<Warning>,
+}
+
+/// Options for [`kill_many_with_options`].
+#[derive(Debug, Clone, Copy)]
+pub struct BatchKillOptions {
+    /// Drop PIDs in the caller's own process group (Unix).
+    ///
+    /// Guards filter-selected batches (e.g. "all processes of this user")
+    /// from signaling the caller's own shell session. Windows has no process
+    /// groups and ignores this.
+    ///
+    /// Default: `true`
+    pub guard_own_group: bool,
+
+    /// Check each target's signal disposition before sending.
+    ///
+    /// See [`KillOptions::check_disposition`]. Blocked or ignored signals are
+    /// reported in [`BatchKillResult::warnings`].
+    ///
+    /// Default: `false`
+    pub check_disposition: bool,
+}
+
+impl Default for BatchKillOptions {
+    fn default() -> Self {
+        Self {
+            guard_own_group: true,
+            check_disposition: false,
+        }
+    }
+}
+
+/// Options for [`kill_with_options`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KillOptions {
+    /// Inspect the target's signal masks before sending.
+    ///
+    /// When `true`, a warning is added to the result if the target has the
+    /// requested signal blocked or ignored (the "I killed it but it won't die"
+    /// case). Linux reads `/proc/[pid]/status`; other platforms skip the check.
+    pub check_disposition: bool,
+}
+
+/// Result of [`kill_with_options`].
+#[derive(Debug, Default)]
+pub struct KillResult {
+    /// Non-fatal observations about the delivery (e.g. signal blocked/ignored).
+    pub warnings: Vec<Warning>,
+}
+
+/// How a target process currently handles a given signal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SignalDisposition {
+    /// Signal is in the process's blocked mask (delivery deferred).
+    pub blocked: bool,
+    /// Signal is ignored (`SIG_IGN`); delivery has no effect.
+    pub ignored: bool,
+}
+
+/// What a signal does to a process that has not installed a handler for it.
+///
+/// See [`default_disposition`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefaultAction {
+    /// The process terminates.
+    Terminate,
+    /// The process terminates and may write a core dump.
+    CoreDump,
+    /// The signal is discarded; the process keeps running.
+    Ignore,
+    /// The process is stopped (suspended).
+    Stop,
+    /// A stopped process resumes; a running one is unaffected.
+    Continue,
+}
+
+/// One entry of [`signal_catalog`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignalInfo {
+    /// Canonical name (e.g. `SIGTERM`).
+    pub name: String,
+    /// Signal number on this platform.
+    pub number: i32,
+    /// Short identifier (e.g. `term`).
+    pub id: String,
+    /// Default action on this platform (see [`default_disposition`]).
+    pub default_disposition: Option<DefaultAction>,
 }
 
 fn validate_pid_list(pids: &[u32], param_name: &str) -> SysprimsResult<()> {
@@ -115,42 +213,6 @@ fn resolve_signal_number(signal_name: &str) -> Option<i32> {
     lookup_signal_by_id(&lower).and_then(|signal| get_signal_number(&signal.name))
 }
 
-fn glob_match(pattern: &str, text: &str) -> bool {
-    let pattern = pattern.as_bytes();
-    let text = text.as_bytes();
-    let mut p_idx = 0;
-    let mut t_idx = 0;
-    let mut star_idx: Option<usize> = None;
-    let mut match_idx = 0;
-
-    while t_idx < text.len() {
-        if p_idx < pattern.len() && (pattern[p_idx] == b'?' || pattern[p_idx] == text[t_idx]) {
-            p_idx += 1;
-            t_idx += 1;
-            continue;
-        }
-        if p_idx < pattern.len() && pattern[p_idx] == b'*' {
-            star_idx = Some(p_idx);
-            match_idx = t_idx;
-            p_idx += 1;
-            continue;
-        }
-        if let Some(star) = star_idx {
-            p_idx = star + 1;
-            match_idx += 1;
-            t_idx = match_idx;
-            continue;
-        }
-        return false;
-    }
-
-    while p_idx < pattern.len() && pattern[p_idx] == b'*' {
-        p_idx += 1;
-    }
-
-    p_idx == pattern.len()
-}
-
 /// Send a signal to a process.
 ///
 /// # Errors
@@ -181,6 +243,139 @@ pub fn kill(pid: u32, signal: i32) -> SysprimsResult<()> {
     return windows::kill_impl(pid, signal);
 }
 
+/// Send a signal to a process, with optional pre-flight checks.
+///
+/// Behaves like [`kill`], but returns a [`KillResult`] carrying warnings.
+/// With `check_disposition`, the target's signal masks are read before the
+/// signal is sent; a blocked or ignored signal is reported as a warning rather
+/// than an error because the send itself still succeeds.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sysprims_signal::{kill_with_options, KillOptions, SIGTERM};
+///
+/// let opts = KillOptions { check_disposition: true };
+/// let result = kill_with_options(4242, SIGTERM, &opts).unwrap();
+/// for warning in result.warnings {
+///     eprintln!("warning: {warning}");
+/// }
+/// ```
+pub fn kill_with_options(
+    pid: u32,
+    signal: i32,
+    options: &KillOptions,
+) -> SysprimsResult<KillResult> {
+    validate_pid(pid, "pid")?;
+
+    let mut result = KillResult::default();
+    if options.check_disposition {
+        // Best-effort: an unreadable status file must not block the kill.
+        if let Ok(Some(disposition)) = signal_disposition(pid, signal) {
+            if disposition.ignored {
+                result.warnings.push(Warning::new(
+                    WarningCode::Other,
+                    format!("pid {pid} ignores signal {signal}; it will have no effect"),
+                ));
+            }
+            if disposition.blocked {
+                result.warnings.push(Warning::new(
+                    WarningCode::Other,
+                    format!(
+                        "pid {pid} has signal {signal} blocked; delivery is deferred until unblocked"
+                    ),
+                ));
+            }
+        }
+    }
+
+    kill(pid, signal)?;
+    Ok(result)
+}
+
+/// Report whether `pid` has `signal` blocked or ignored.
+///
+/// Returns `Ok(None)` when the platform does not expose signal masks
+/// (currently everything except Linux) or the signal number is out of range.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sysprims_signal::{signal_disposition, SIGTERM};
+///
+/// if let Ok(Some(d)) = signal_disposition(std::process::id(), SIGTERM) {
+///     println!("blocked={} ignored={}", d.blocked, d.ignored);
+/// }
+/// ```
+pub fn signal_disposition(pid: u32, signal: i32) -> SysprimsResult<Option<SignalDisposition>> {
+    validate_pid(pid, "pid")?;
+
+    #[cfg(unix)]
+    return unix::signal_disposition_impl(pid, signal);
+
+    #[cfg(windows)]
+    {
+        let _ = signal;
+        return Ok(None);
+    }
+}
+
+/// Look up what `signal` does by default (with no handler installed).
+///
+/// Useful before sending: `SIGCHLD`, for example, is ignored by default and
+/// will not stop a process that has not asked for it. The table follows the
+/// host platform's signal numbering and actions (`SIGIO` terminates on Linux
+/// but is ignored on macOS).
+///
+/// Returns `None` for unknown and realtime signals, and on Windows.
+///
+/// # Examples
+///
+/// ```rust
+/// use sysprims_signal::{default_disposition, DefaultAction, SIGTERM};
+///
+/// # #[cfg(unix)]
+/// assert_eq!(default_disposition(SIGTERM), Some(DefaultAction::Terminate));
+/// ```
+pub fn default_disposition(signal: i32) -> Option<DefaultAction> {
+    #[cfg(unix)]
+    return unix::default_disposition_impl(signal);
+
+    #[cfg(windows)]
+    {
+        let _ = signal;
+        return None;
+    }
+}
+
+/// All signals known to this build, with their numbers on this platform.
+///
+/// Built from the rsfulmen catalog (the source of `kill --list`), in catalog
+/// order. Signals with no number on this platform are omitted.
+///
+/// # Examples
+///
+/// ```rust
+/// // Replaces: kill -l plus column scraping
+/// let catalog = sysprims_signal::signal_catalog();
+/// let term = catalog.iter().find(|s| s.name == "SIGTERM").unwrap();
+/// assert_eq!(term.number, sysprims_signal::SIGTERM);
+/// ```
+pub fn signal_catalog() -> Vec<SignalInfo> {
+    list_signals()
+        .iter()
+        .filter_map(|signal| {
+            let number = get_signal_number(&signal.name)?;
+            Some(SignalInfo {
+                name: signal.name.clone(),
+                number,
+                id: signal.id.clone(),
+                default_disposition: default_disposition(number),
+            })
+        })
+        .collect()
+}
+
 /// Send a signal to multiple processes.
 ///
 /// PID validation happens for the entire slice before any signals are sent.
@@ -215,6 +410,120 @@ pub fn kill_many(pids: &[u32], signal: i32) -> SysprimsResult<BatchKillResult> {
     Ok(result)
 }
 
+/// Send a signal to multiple processes with batch safety options.
+///
+/// Like [`kill_many`], but PIDs excluded by `options` are moved to
+/// [`BatchKillResult::skipped`] with a warning instead of being signaled.
+/// With `check_disposition`, targets that block or ignore `signal` are also
+/// reported in [`BatchKillResult::warnings`].
+///
+/// # Errors
+///
+/// Same as [`kill_many`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sysprims_signal::{kill_many_with_options, BatchKillOptions, SIGTERM};
+///
+/// let result = kill_many_with_options(&[1234, 5678], SIGTERM, &BatchKillOptions::default())
+///     .unwrap();
+/// for warning in &result.warnings {
+///     eprintln!("warning: {warning}");
+/// }
+/// ```
+pub fn kill_many_with_options(
+    pids: &[u32],
+    signal: i32,
+    options: &BatchKillOptions,
+) -> SysprimsResult<BatchKillResult> {
+    validate_pid_list(pids, "pids")?;
+
+    let mut targets = pids.to_vec();
+    let mut result = BatchKillResult::default();
+    if options.guard_own_group {
+        result.skipped = filter_own_group(&mut targets);
+        if let Some(warning) = own_group_warning(&result.skipped) {
+            result.warnings.push(warning);
+        }
+    }
+
+    let kill_options = KillOptions {
+        check_disposition: options.check_disposition,
+    };
+    for pid in targets {
+        match kill_with_options(pid, signal, &kill_options) {
+            Ok(sent) => {
+                result.succeeded.push(pid);
+                result.warnings.extend(sent.warnings);
+            }
+            Err(error) => result.failed.push(BatchKillFailure { pid, error }),
+        }
+    }
+
+    Ok(result)
+}
+
+/// Remove PIDs that share the caller's process group; returns the removed PIDs.
+///
+/// PIDs whose group cannot be read (e.g. already exited) are kept. On
+/// Windows nothing is removed.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sysprims_signal::filter_own_group;
+///
+/// let mut pids = vec![std::process::id(), 99999];
+/// let skipped = filter_own_group(&mut pids);
+/// assert!(skipped.contains(&std::process::id()));
+/// ```
+pub fn filter_own_group(pids: &mut Vec<u32>) -> Vec<u32> {
+    #[cfg(unix)]
+    {
+        // SAFETY: getpgid only reads process state.
+        let own = unsafe { libc::getpgid(0) };
+        if own == -1 {
+            return Vec::new();
+        }
+        let mut skipped = Vec::new();
+        pids.retain(|&pid| {
+            if pid > MAX_SAFE_PID {
+                return true;
+            }
+            // SAFETY: pid is bounded by MAX_SAFE_PID, so the cast is non-negative.
+            let in_own_group = unsafe { libc::getpgid(pid as libc::pid_t) } == own;
+            if in_own_group {
+                skipped.push(pid);
+            }
+            !in_own_group
+        });
+        skipped
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = pids;
+        Vec::new()
+    }
+}
+
+/// Warning for PIDs dropped by [`filter_own_group`], if any.
+pub fn own_group_warning(skipped: &[u32]) -> Option<Warning> {
+    if skipped.is_empty() {
+        return None;
+    }
+    let list: Vec<String> = skipped.iter().map(u32::to_string).collect();
+    Some(Warning::new(
+        WarningCode::Protected,
+        format!(
+            "Skipped {} PIDs in the caller's process group: {}",
+            skipped.len(),
+            list.join(", ")
+        ),
+    ))
+}
+
 /// Convenience wrapper: send `SIGTERM` to multiple processes.
 ///
 /// # Examples
@@ -292,6 +601,130 @@ pub fn killpg(pgid: u32, signal: i32) -> SysprimsResult<()> {
     }
 }
 
+/// Send a signal to multiple process groups.
+///
+/// The process-group counterpart of [`kill_many`]: every PGID is validated
+/// up front, then each group is signaled via [`killpg`] and the per-group
+/// outcome recorded. `succeeded` and `failed` hold PGIDs.
+///
+/// On Windows, this always returns `NotSupported`.
+///
+/// # Errors
+///
+/// Returns [`SysprimsError::InvalidArgument`] if:
+/// - `pgids` is empty
+/// - any PGID in `pgids` is invalid (e.g. 0 or > [`MAX_SAFE_PID`])
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sysprims_signal::SIGTERM;
+///
+/// // Replaces: kill -TERM -- -4242 -4343
+/// let result = sysprims_signal::killpg_many(&[4242, 4343], SIGTERM).unwrap();
+/// for failure in &result.failed {
+///     eprintln!("pgid {}: {}", failure.pid, failure.error);
+/// }
+/// ```
+pub fn killpg_many(pgids: &[u32], signal: i32) -> SysprimsResult<BatchKillResult> {
+    validate_pid_list(pgids, "pgids")?;
+
+    #[cfg(unix)]
+    {
+        let mut result = BatchKillResult::default();
+        for &pgid in pgids {
+            match killpg(pgid, signal) {
+                Ok(()) => result.succeeded.push(pgid),
+                Err(error) => result.failed.push(BatchKillFailure { pid: pgid, error }),
+            }
+        }
+        Ok(result)
+    }
+
+    #[cfg(windows)]
+    {
+        let _ = signal; // Unused on Windows
+        Err(SysprimsError::not_supported("killpg_many", "windows"))
+    }
+}
+
+/// Send a signal to every process in a session.
+///
+/// There is no POSIX `killsession()`, so members are found by enumerating
+/// processes and matching `ProcessInfo::sid`, then signaled individually via
+/// [`kill_many`]. Per-PID outcomes are returned; processes that join the
+/// session after enumeration are not signaled. Protected PIDs (the caller,
+/// PID 1, and the caller's parent) are never signaled: they are moved to
+/// [`BatchKillResult::skipped`] with a warning.
+///
+/// On Windows, this always returns `NotSupported`.
+///
+/// # Errors
+///
+/// Returns [`SysprimsError::InvalidArgument`] if `sid` is 0 or exceeds
+/// [`MAX_SAFE_PID`], and [`SysprimsError::NotFound`] if no visible process
+/// belongs to the session.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sysprims_signal::SIGHUP;
+///
+/// // Replaces: pkill -HUP -s 4242
+/// let result = sysprims_signal::kill_session(4242, SIGHUP).unwrap();
+/// println!("signaled {} processes", result.succeeded.len());
+/// ```
+pub fn kill_session(sid: u32, signal: i32) -> SysprimsResult<BatchKillResult> {
+    validate_pid(sid, "sid")?;
+
+    #[cfg(unix)]
+    {
+        let mut pids: Vec<u32> = sysprims_proc::snapshot()?
+            .processes
+            .into_iter()
+            .filter(|p| p.sid == Some(sid))
+            .map(|p| p.pid)
+            .collect();
+        if pids.is_empty() {
+            return Err(SysprimsError::not_found(sid));
+        }
+        pids.sort_unstable();
+
+        let protected = sysprims_proc::protected_pids();
+        let skipped: Vec<u32> = pids
+            .iter()
+            .copied()
+            .filter(|pid| protected.contains(pid))
+            .collect();
+        pids.retain(|pid| !protected.contains(pid));
+
+        let mut result = if pids.is_empty() {
+            BatchKillResult::default()
+        } else {
+            kill_many(&pids, signal)?
+        };
+        if !skipped.is_empty() {
+            let list: Vec<String> = skipped.iter().map(u32::to_string).collect();
+            result.warnings.push(Warning::new(
+                WarningCode::Protected,
+                format!(
+                    "Skipped {} protected session members (self/PID1/parent): {}",
+                    skipped.len(),
+                    list.join(", ")
+                ),
+            ));
+            result.skipped = skipped;
+        }
+        Ok(result)
+    }
+
+    #[cfg(windows)]
+    {
+        let _ = signal; // Unused on Windows
+        Err(SysprimsError::not_supported("kill_session", "windows"))
+    }
+}
+
 /// Return signal names that match a simple glob pattern.
 ///
 /// Supports `*` (any sequence) and `?` (single char). Matching is
@@ -326,6 +759,127 @@ pub fn match_signal_names(pattern: &str) -> Vec<&'static str> {
     matches
 }
 
+/// A signal argument resolved by [`resolve_signal_or_pattern`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignalTarget {
+    /// Given as a number (e.g. `"15"`); passed through unchanged.
+    Number(i32),
+
+    /// Given as a name, short ID, or glob matching exactly one signal.
+    Named {
+        /// Canonical signal name (e.g. `"SIGTERM"`) or the name as given.
+        name: String,
+        /// Resolved signal number.
+        number: i32,
+    },
+}
+
+impl SignalTarget {
+    /// The signal number to send.
+    pub fn number(&self) -> i32 {
+        match self {
+            SignalTarget::Number(n) => *n,
+            SignalTarget::Named { number, .. } => *number,
+        }
+    }
+}
+
+/// Resolve a signal argument to its number.
+///
+/// Accepts numbers (`"15"`), names with or without the `SIG` prefix in any
+/// case (`"SIGTERM"`, `"TERM"`, `"term"`), and short IDs (`"int"`). Globs are
+/// not expanded; use [`resolve_signal_or_pattern`] for that.
+///
+/// # Errors
+///
+/// Returns [`SysprimsError::InvalidArgument`] if the string is empty, a
+/// negative number, or an unknown signal name.
+///
+/// # Examples
+///
+/// ```rust
+/// // Replaces: kill -l TERM
+/// assert_eq!(sysprims_signal::resolve_signal("term").unwrap(), sysprims_signal::SIGTERM);
+/// assert_eq!(sysprims_signal::resolve_signal("9").unwrap(), 9);
+/// ```
+pub fn resolve_signal(s: &str) -> SysprimsResult<i32> {
+    let trimmed = s.trim();
+    if trimmed.is_empty() {
+        return Err(SysprimsError::invalid_argument("signal cannot be empty"));
+    }
+
+    if let Ok(number) = trimmed.parse::<i32>() {
+        if number < 0 {
+            return Err(SysprimsError::invalid_argument(format!(
+                "signal number cannot be negative: {number}"
+            )));
+        }
+        return Ok(number);
+    }
+
+    resolve_signal_number(trimmed)
+        .ok_or_else(|| SysprimsError::invalid_argument(format!("unknown signal '{trimmed}'")))
+}
+
+/// Resolve a signal argument that may be a glob pattern.
+///
+/// Patterns containing `*` or `?` are matched with [`match_signal_names`] and
+/// must match exactly one signal. Anything else is resolved like
+/// [`resolve_signal`].
+///
+/// # Errors
+///
+/// Returns [`SysprimsError::InvalidArgument`] if a pattern matches zero or
+/// several signals, or if [`resolve_signal`] would fail.
+///
+/// # Examples
+///
+/// ```rust
+/// use sysprims_signal::SignalTarget;
+///
+/// // Replaces: kill -l | grep -i 'usr1'
+/// let target = sysprims_signal::resolve_signal_or_pattern("*usr1").unwrap();
+/// assert!(matches!(target, SignalTarget::Named { ref name, .. } if name == "SIGUSR1"));
+/// ```
+pub fn resolve_signal_or_pattern(s: &str) -> SysprimsResult<SignalTarget> {
+    let trimmed = s.trim();
+    if trimmed.is_empty() {
+        return Err(SysprimsError::invalid_argument("signal cannot be empty"));
+    }
+
+    if trimmed.contains('*') || trimmed.contains('?') {
+        let matches = match_signal_names(trimmed);
+        return match matches.as_slice() {
+            [] => Err(SysprimsError::invalid_argument(format!(
+                "signal pattern '{trimmed}' matched no signals"
+            ))),
+            [name] => {
+                let number = get_signal_number(name).ok_or_else(|| {
+                    SysprimsError::invalid_argument(format!("unknown signal '{name}'"))
+                })?;
+                Ok(SignalTarget::Named {
+                    name: name.to_string(),
+                    number,
+                })
+            }
+            _ => Err(SysprimsError::invalid_argument(format!(
+                "signal pattern '{trimmed}' matched multiple signals: {}",
+                matches.join(", ")
+            ))),
+        };
+    }
+
+    let number = resolve_signal(trimmed)?;
+    if trimmed.parse::<i32>().is_ok() {
+        return Ok(SignalTarget::Number(number));
+    }
+
+    Ok(SignalTarget::Named {
+        name: trimmed.to_string(),
+        number,
+    })
+}
+
 /// Convenience wrapper: send `SIGTERM` (or Windows terminate).
 ///
 /// # Examples
@@ -350,6 +904,48 @@ pub fn force_kill(pid: u32) -> SysprimsResult<()> {
     kill(pid, SIGKILL)
 }
 
+/// Send `SIGKILL` (or Windows terminate) and wait until the process is gone.
+///
+/// `SIGKILL` delivery is asynchronous: [`force_kill`] can return while the
+/// target still exists. This waits up to `timeout` for it to disappear, so
+/// resources it held (ports, files, locks) can be reused safely. A zombie or
+/// a PID that no longer exists counts as gone.
+///
+/// Check `timed_out` on the result: `true` means the process was still
+/// present when `timeout` elapsed (e.g. stuck in uninterruptible I/O).
+///
+/// # Errors
+///
+/// Returns [`SysprimsError::InvalidArgument`] for an invalid PID, and the
+/// [`kill`] error (e.g. `PermissionDenied`) if the signal cannot be sent.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// // Replaces: kill -KILL 1234; while kill -0 1234; do sleep 0.1; done
+/// let res = sysprims_signal::force_kill_sync(1234, Duration::from_secs(2)).unwrap();
+/// assert!(res.exited);
+/// ```
+pub fn force_kill_sync(pid: u32, timeout: Duration) -> SysprimsResult<WaitPidResult> {
+    match force_kill(pid) {
+        Ok(()) => {}
+        Err(SysprimsError::NotFound { .. }) => return Ok(WaitPidResult::already_exited(pid)),
+        Err(e) => return Err(e),
+    }
+
+    let config = WaitPidConfig {
+        zombie_is_exited: true,
+        ..WaitPidConfig::default()
+    };
+    match sysprims_proc::wait_pid_with_config(pid, timeout, config) {
+        // Gone before the first poll.
+        Err(SysprimsError::NotFound { .. }) => Ok(WaitPidResult::already_exited(pid)),
+        result => result,
+    }
+}
+
 /// Convenience wrapper: send `SIGTERM` to a process group.
 ///
 /// # Examples
@@ -396,6 +992,35 @@ mod tests {
         assert!(err.to_string().contains("must be > 0"));
     }
 
+    #[test]
+    fn kill_session_rejects_invalid_sid() {
+        let err = kill_session(0, SIGTERM).unwrap_err();
+        assert!(matches!(err, SysprimsError::InvalidArgument { .. }));
+        assert!(err.to_string().contains("sid must be > 0"));
+
+        let err = kill_session(u32::MAX, SIGTERM).unwrap_err();
+        assert!(matches!(err, SysprimsError::InvalidArgument { .. }));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn kill_session_skips_protected_pids() {
+        let self_pid = std::process::id();
+        let sid = sysprims_proc::get_process(self_pid)
+            .unwrap()
+            .sid
+            .expect("own session id");
+
+        // Signal 0 only checks permissions, so probing our own session is safe.
+        let result = kill_session(sid, 0).unwrap();
+        assert!(!result.succeeded.contains(&self_pid));
+        assert!(result.skipped.contains(&self_pid));
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.code == WarningCode::Protected));
+    }
+
     #[test]
     fn kill_rejects_pid_exceeding_max_safe() {
         // This is the critical safety test per ADR-0011.
@@ -440,6 +1065,21 @@ mod tests {
         assert_eq!(MAX_SAFE_PID, 2147483647);
     }
 
+    #[test]
+    fn kill_with_options_rejects_pid_zero() {
+        let err = kill_with_options(0, SIGTERM, &KillOptions::default()).unwrap_err();
+        assert!(matches!(err, SysprimsError::InvalidArgument { .. }));
+    }
+
+    #[test]
+    fn killpg_many_rejects_empty_or_invalid_pgids() {
+        let err = killpg_many(&[], SIGTERM).unwrap_err();
+        assert!(err.to_string().contains("must not be empty"));
+
+        let err = killpg_many(&[1234, 0], SIGTERM).unwrap_err();
+        assert!(matches!(err, SysprimsError::InvalidArgument { .. }));
+    }
+
     #[test]
     fn kill_many_rejects_empty_pid_list() {
         let err = kill_many(&[], SIGTERM).unwrap_err();
@@ -447,6 +1087,56 @@ mod tests {
         assert!(err.to_string().contains("must not be empty"));
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn default_disposition_covers_standard_signals() {
+        assert_eq!(default_disposition(SIGTERM), Some(DefaultAction::Terminate));
+        assert_eq!(
+            default_disposition(libc::SIGSEGV),
+            Some(DefaultAction::CoreDump)
+        );
+        assert_eq!(
+            default_disposition(libc::SIGCHLD),
+            Some(DefaultAction::Ignore)
+        );
+        assert_eq!(
+            default_disposition(libc::SIGTSTP),
+            Some(DefaultAction::Stop)
+        );
+        assert_eq!(
+            default_disposition(libc::SIGCONT),
+            Some(DefaultAction::Continue)
+        );
+        assert_eq!(default_disposition(0), None);
+        #[cfg(target_os = "linux")]
+        assert_eq!(default_disposition(libc::SIGRTMIN()), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn kill_many_with_options_skips_own_group() {
+        let pid = std::process::id();
+
+        // Signal 0 only checks existence, so targeting ourselves is harmless.
+        let guarded = kill_many_with_options(&[pid], 0, &BatchKillOptions::default()).unwrap();
+        assert_eq!(guarded.skipped, vec![pid]);
+        assert!(guarded.succeeded.is_empty());
+        assert_eq!(guarded.warnings[0].code, WarningCode::Protected);
+        assert!(guarded.warnings[0].message.contains(&pid.to_string()));
+
+        let unguarded = kill_many_with_options(
+            &[pid],
+            0,
+            &BatchKillOptions {
+                guard_own_group: false,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(unguarded.succeeded, vec![pid]);
+        assert!(unguarded.skipped.is_empty());
+    }
+
     // ========================================================================
     // rsfulmen Integration Tests
     // ========================================================================
@@ -480,6 +1170,61 @@ mod tests {
         assert!(matches.contains(&"SIGTERM"));
     }
 
+    #[test]
+    fn signal_catalog_lists_platform_numbers() {
+        let catalog = signal_catalog();
+        let kill = catalog.iter().find(|s| s.name == "SIGKILL").unwrap();
+        assert_eq!(kill.number, SIGKILL);
+        assert_eq!(kill.id, "kill");
+        #[cfg(unix)]
+        assert_eq!(kill.default_disposition, Some(DefaultAction::Terminate));
+        assert!(catalog.iter().any(|s| s.name == "SIGTERM"));
+    }
+
+    #[test]
+    fn resolve_signal_accepts_names_numbers_and_ids() {
+        assert_eq!(resolve_signal("15").unwrap(), 15);
+        assert_eq!(resolve_signal("SIGTERM").unwrap(), SIGTERM);
+        assert_eq!(resolve_signal("TERM").unwrap(), SIGTERM);
+        assert_eq!(resolve_signal(" term ").unwrap(), SIGTERM);
+        assert_eq!(resolve_signal("int").unwrap(), SIGINT);
+    }
+
+    #[test]
+    fn resolve_signal_rejects_invalid() {
+        for input in ["", "-1", "NOPE", "SIG*"] {
+            assert!(
+                matches!(
+                    resolve_signal(input),
+                    Err(SysprimsError::InvalidArgument { .. })
+                ),
+                "{input:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn resolve_signal_or_pattern_expands_single_match() {
+        assert_eq!(
+            resolve_signal_or_pattern("9").unwrap(),
+            SignalTarget::Number(9)
+        );
+        assert_eq!(
+            resolve_signal_or_pattern("sigte?m").unwrap(),
+            SignalTarget::Named {
+                name: "SIGTERM".to_string(),
+                number: SIGTERM,
+            }
+        );
+        assert_eq!(resolve_signal_or_pattern("term").unwrap().number(), SIGTERM);
+    }
+
+    #[test]
+    fn resolve_signal_or_pattern_rejects_ambiguous_or_empty_match() {
+        assert!(resolve_signal_or_pattern("SIG*").is_err());
+        assert!(resolve_signal_or_pattern("nomatch*").is_err());
+    }
+
     // ========================================================================
     // Platform-Specific Tests
     // ========================================================================
@@ -489,5 +1234,8 @@ mod tests {
     fn killpg_is_not_supported_on_windows() {
         let err = killpg(1234, SIGTERM).unwrap_err();
         assert!(matches!(err, SysprimsError::NotSupported { .. }));
+
+        let err = killpg_many(&[1234], SIGTERM).unwrap_err();
+        assert!(matches!(err, SysprimsError::NotSupported { .. }));
     }
 }
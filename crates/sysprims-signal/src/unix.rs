@@ -2,11 +2,14 @@ use std::io;
 
 use libc::{kill as libc_kill, killpg as libc_killpg, EINVAL, EPERM, ESRCH};
 
+use sysprims_core::sys::{is_elevated, retry_eintr};
 use sysprims_core::{SysprimsError, SysprimsResult};
 
+use crate::{DefaultAction, SignalDisposition};
+
 pub fn kill_impl(pid: u32, signal: i32) -> SysprimsResult<()> {
     // Safe: libc expects pid_t (signed), but we reject pid==0 at API boundary.
-    let result = unsafe { libc_kill(pid as i32, signal) };
+    let result = retry_eintr(|| unsafe { libc_kill(pid as i32, signal) });
 
     if result == 0 {
         return Ok(());
@@ -16,7 +19,11 @@ pub fn kill_impl(pid: u32, signal: i32) -> SysprimsResult<()> {
     let errno = os_error.raw_os_error().unwrap_or(0);
 
     match errno {
-        EPERM => Err(SysprimsError::permission_denied(pid, "signal")),
+        EPERM => Err(SysprimsError::permission_denied_with_elevation(
+            pid,
+            "signal",
+            !is_elevated(),
+        )),
         ESRCH => Err(SysprimsError::not_found(pid)),
         EINVAL => Err(SysprimsError::invalid_argument(format!(
             "invalid signal: {signal}"
@@ -26,7 +33,7 @@ pub fn kill_impl(pid: u32, signal: i32) -> SysprimsResult<()> {
 }
 
 pub fn killpg_impl(pgid: u32, signal: i32) -> SysprimsResult<()> {
-    let result = unsafe { libc_killpg(pgid as i32, signal) };
+    let result = retry_eintr(|| unsafe { libc_killpg(pgid as i32, signal) });
 
     if result == 0 {
         return Ok(());
@@ -36,7 +43,11 @@ pub fn killpg_impl(pgid: u32, signal: i32) -> SysprimsResult<()> {
     let errno = os_error.raw_os_error().unwrap_or(0);
 
     match errno {
-        EPERM => Err(SysprimsError::permission_denied(pgid, "signal_group")),
+        EPERM => Err(SysprimsError::permission_denied_with_elevation(
+            pgid,
+            "signal_group",
+            !is_elevated(),
+        )),
         ESRCH => Err(SysprimsError::not_found(pgid)),
         EINVAL => Err(SysprimsError::invalid_argument(format!(
             "invalid signal: {signal}"
@@ -45,6 +56,90 @@ pub fn killpg_impl(pgid: u32, signal: i32) -> SysprimsResult<()> {
     }
 }
 
+#[cfg(target_os = "linux")]
+pub fn signal_disposition_impl(pid: u32, signal: i32) -> SysprimsResult<Option<SignalDisposition>> {
+    if !(1..=64).contains(&signal) {
+        return Ok(None);
+    }
+
+    use sysprims_proc::{get_process_with_options, signal_is_blocked, ProcessOptions};
+
+    let info = get_process_with_options(pid, ProcessOptions::default().with_signal_masks())?;
+    if info.sig_blocked.is_none() && info.sig_ignored.is_none() {
+        return Ok(None);
+    }
+
+    Ok(Some(SignalDisposition {
+        blocked: info
+            .sig_blocked
+            .is_some_and(|m| signal_is_blocked(m, signal)),
+        ignored: info
+            .sig_ignored
+            .is_some_and(|m| signal_is_blocked(m, signal)),
+    }))
+}
+
+// macOS does not expose another process's signal masks without elevated
+// task access; report "unknown" rather than guessing.
+#[cfg(not(target_os = "linux"))]
+pub fn signal_disposition_impl(
+    _pid: u32,
+    _signal: i32,
+) -> SysprimsResult<Option<SignalDisposition>> {
+    Ok(None)
+}
+
+/// Default actions from signal(7) / sigaction(2). Realtime signals are omitted.
+const DEFAULT_ACTIONS: &[(i32, DefaultAction)] = &[
+    (libc::SIGHUP, DefaultAction::Terminate),
+    (libc::SIGINT, DefaultAction::Terminate),
+    (libc::SIGKILL, DefaultAction::Terminate),
+    (libc::SIGPIPE, DefaultAction::Terminate),
+    (libc::SIGALRM, DefaultAction::Terminate),
+    (libc::SIGTERM, DefaultAction::Terminate),
+    (libc::SIGUSR1, DefaultAction::Terminate),
+    (libc::SIGUSR2, DefaultAction::Terminate),
+    (libc::SIGPROF, DefaultAction::Terminate),
+    (libc::SIGVTALRM, DefaultAction::Terminate),
+    #[cfg(target_os = "linux")]
+    (libc::SIGIO, DefaultAction::Terminate),
+    #[cfg(target_os = "linux")]
+    (libc::SIGPWR, DefaultAction::Terminate),
+    #[cfg(target_os = "linux")]
+    (libc::SIGSTKFLT, DefaultAction::Terminate),
+    (libc::SIGQUIT, DefaultAction::CoreDump),
+    (libc::SIGILL, DefaultAction::CoreDump),
+    (libc::SIGTRAP, DefaultAction::CoreDump),
+    (libc::SIGABRT, DefaultAction::CoreDump),
+    (libc::SIGBUS, DefaultAction::CoreDump),
+    (libc::SIGFPE, DefaultAction::CoreDump),
+    (libc::SIGSEGV, DefaultAction::CoreDump),
+    (libc::SIGSYS, DefaultAction::CoreDump),
+    (libc::SIGXCPU, DefaultAction::CoreDump),
+    (libc::SIGXFSZ, DefaultAction::CoreDump),
+    #[cfg(target_os = "macos")]
+    (libc::SIGEMT, DefaultAction::CoreDump),
+    (libc::SIGCHLD, DefaultAction::Ignore),
+    (libc::SIGURG, DefaultAction::Ignore),
+    (libc::SIGWINCH, DefaultAction::Ignore),
+    #[cfg(target_os = "macos")]
+    (libc::SIGIO, DefaultAction::Ignore),
+    #[cfg(target_os = "macos")]
+    (libc::SIGINFO, DefaultAction::Ignore),
+    (libc::SIGSTOP, DefaultAction::Stop),
+    (libc::SIGTSTP, DefaultAction::Stop),
+    (libc::SIGTTIN, DefaultAction::Stop),
+    (libc::SIGTTOU, DefaultAction::Stop),
+    (libc::SIGCONT, DefaultAction::Continue),
+];
+
+pub fn default_disposition_impl(signal: i32) -> Option<DefaultAction> {
+    DEFAULT_ACTIONS
+        .iter()
+        .find(|(number, _)| *number == signal)
+        .map(|&(_, action)| action)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,3 +1,4 @@
+use sysprims_core::sys::is_elevated;
 use sysprims_core::{SysprimsError, SysprimsResult};
 use windows_sys::Win32::Foundation::{
     CloseHandle, GetLastError, ERROR_ACCESS_DENIED, ERROR_INVALID_PARAMETER,
@@ -15,7 +16,11 @@ pub fn kill_impl(pid: u32, signal: i32) -> SysprimsResult<()> {
             if handle == 0 {
                 let error = GetLastError();
                 return match error {
-                    ERROR_ACCESS_DENIED => Err(SysprimsError::permission_denied(pid, "terminate")),
+                    ERROR_ACCESS_DENIED => Err(SysprimsError::permission_denied_with_elevation(
+                        pid,
+                        "terminate",
+                        !is_elevated(),
+                    )),
                     ERROR_INVALID_PARAMETER => Err(SysprimsError::not_found(pid)),
                     _ => Err(SysprimsError::system(
                         "OpenProcess failed".to_string(),
@@ -46,7 +51,11 @@ pub fn kill_impl(pid: u32, signal: i32) -> SysprimsResult<()> {
             } else {
                 let error = GetLastError();
                 match error {
-                    ERROR_ACCESS_DENIED => Err(SysprimsError::permission_denied(pid, "signal")),
+                    ERROR_ACCESS_DENIED => Err(SysprimsError::permission_denied_with_elevation(
+                        pid,
+                        "signal",
+                        !is_elevated(),
+                    )),
                     ERROR_INVALID_PARAMETER => Err(SysprimsError::not_found(pid)),
                     _ => Err(SysprimsError::system(
                         "GenerateConsoleCtrlEvent failed".to_string(),
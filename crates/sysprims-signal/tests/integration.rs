@@ -26,7 +26,7 @@ use std::process::Child;
 #[cfg(unix)]
 use std::time::Duration;
 #[cfg(unix)]
-use sysprims_signal::{force_kill, terminate, SIGKILL};
+use sysprims_signal::{force_kill, force_kill_sync, terminate, SIGKILL};
 
 /// Helper to spawn a sleep process that we control.
 ///
@@ -296,6 +296,22 @@ fn force_kill_wrapper_kills_spawned_child() {
     );
 }
 
+#[test]
+#[cfg(unix)]
+fn force_kill_sync_confirms_child_is_gone() {
+    // SAFETY: We spawn this process ourselves and control its PID.
+    let mut child = spawn_sleep(60);
+    let pid = child.id();
+
+    // Our unreaped child lingers as a zombie, which counts as gone.
+    let result = force_kill_sync(pid, Duration::from_secs(5)).expect("force_kill_sync");
+    assert!(result.exited, "process should be confirmed gone");
+    assert!(!result.timed_out);
+
+    let status = child.wait().expect("Failed to wait for child");
+    assert_eq!(status.signal(), Some(SIGKILL));
+}
+
 #[test]
 #[cfg(unix)]
 fn kill_returns_not_found_for_exited_process() {
@@ -405,6 +421,93 @@ fn killpg_terminates_process_group() {
     );
 }
 
+#[test]
+#[cfg(unix)]
+fn killpg_many_signals_each_group_and_reports_failures() {
+    use std::os::unix::process::CommandExt;
+    use sysprims_signal::killpg_many;
+
+    let spawn_group_leader = || {
+        // SAFETY: We spawn this process in a new process group we control.
+        unsafe {
+            Command::new("sleep")
+                .arg("60")
+                .stdin(Stdio::null())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .pre_exec(|| {
+                    libc::setpgid(0, 0);
+                    Ok(())
+                })
+                .spawn()
+                .expect("Failed to spawn sleep in new process group")
+        }
+    };
+    let mut first = spawn_group_leader();
+    let mut second = spawn_group_leader();
+    std::thread::sleep(Duration::from_millis(50));
+
+    // A reaped group leader leaves an empty group to provoke ESRCH.
+    let mut gone = spawn_group_leader();
+    let gone_pgid = gone.id();
+    gone.kill().expect("kill gone");
+    gone.wait().expect("wait gone");
+
+    let result = killpg_many(&[first.id(), gone_pgid, second.id()], SIGTERM)
+        .expect("killpg_many() should succeed");
+    assert_eq!(result.succeeded, vec![first.id(), second.id()]);
+    assert_eq!(result.failed.len(), 1);
+    assert_eq!(result.failed[0].pid, gone_pgid);
+
+    for child in [&mut first, &mut second] {
+        let status = child.wait().expect("Failed to wait for child");
+        assert_eq!(status.signal(), Some(SIGTERM));
+    }
+}
+
+// ============================================================================
+// kill_session() Integration Tests (Unix only)
+// ============================================================================
+
+#[test]
+#[cfg(unix)]
+fn kill_session_terminates_session_members() {
+    use std::os::unix::process::CommandExt;
+    use sysprims_signal::kill_session;
+
+    // SAFETY: We spawn this process in a new session we control.
+    // The child becomes the session leader (sid == pid).
+    let mut child = unsafe {
+        Command::new("sleep")
+            .arg("60")
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .pre_exec(|| {
+                libc::setsid();
+                Ok(())
+            })
+            .spawn()
+            .expect("Failed to spawn sleep in new session")
+    };
+
+    let pid = child.id();
+
+    // Small delay to ensure the session is established
+    std::thread::sleep(Duration::from_millis(50));
+
+    let result = kill_session(pid, SIGTERM).expect("kill_session() should succeed");
+    assert_eq!(result.succeeded, vec![pid]);
+    assert!(result.failed.is_empty());
+
+    let status = child.wait().expect("Failed to wait for child");
+    assert_eq!(
+        status.signal(),
+        Some(SIGTERM),
+        "Session member should be killed by SIGTERM"
+    );
+}
+
 // ============================================================================
 // Process Group Convenience Wrappers (Unix only)
 // ============================================================================
@@ -548,3 +651,47 @@ fn kill_terminates_spawned_child_on_windows() {
     // On Windows, terminated processes have non-zero exit code
     assert!(!status.success(), "Child should not exit successfully");
 }
+
+// ============================================================================
+// kill_with_options() Integration Tests
+// ============================================================================
+
+#[test]
+#[cfg(target_os = "linux")]
+fn kill_with_options_warns_when_signal_is_ignored() {
+    use sysprims_signal::{kill_with_options, KillOptions};
+
+    // SAFETY: We spawn this process ourselves and control its PID.
+    // `trap '' TERM` sets SIGTERM to SIG_IGN in the shell.
+    let mut child = Command::new("sh")
+        .args(["-c", "trap '' TERM; while :; do sleep 1; done"])
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("Failed to spawn sh process");
+    let pid = child.id();
+
+    // Give the shell time to install the trap.
+    std::thread::sleep(Duration::from_millis(200));
+
+    let result = kill_with_options(
+        pid,
+        SIGTERM,
+        &KillOptions {
+            check_disposition: true,
+        },
+    )
+    .expect("kill_with_options() should succeed");
+    assert!(
+        result
+            .warnings
+            .iter()
+            .any(|w| w.message.contains("ignores")),
+        "expected ignored-signal warning, got: {:?}",
+        result.warnings
+    );
+
+    force_kill(pid).expect("cleanup kill should succeed");
+    let _ = child.wait();
+}
@@ -178,9 +178,12 @@ mod cross_user {
         assert!(
             matches!(
                 result,
-                Err(sysprims_core::SysprimsError::PermissionDenied { .. })
+                Err(sysprims_core::SysprimsError::PermissionDenied {
+                    needs_elevation: true,
+                    ..
+                })
             ),
-            "Expected PermissionDenied when signaling root-owned process as non-root, got: {:?}",
+            "Expected elevatable PermissionDenied when signaling root-owned process as non-root, got: {:?}",
             result
         );
     }
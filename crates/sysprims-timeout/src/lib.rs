@@ -2,6 +2,7 @@
 //!
 //! This crate provides:
 //! - Process execution with timeout ([`run_with_timeout`])
+//! - Non-blocking timeout enforcement ([`spawn_with_watchdog`])
 //! - Group-by-default semantics (entire process tree killed on timeout)
 //! - Signal escalation (SIGTERM → SIGKILL after configurable delay)
 //! - Observable fallback status for tree-kill reliability
@@ -30,38 +31,54 @@
 //! ).unwrap();
 //!
 //! match result {
-//!     TimeoutOutcome::Completed { exit_status } => {
+//!     TimeoutOutcome::Completed { exit_status, .. } => {
 //!         println!("Command completed: {:?}", exit_status);
 //!     }
 //!     TimeoutOutcome::TimedOut { signal_sent, escalated, .. } => {
 //!         println!("Timed out, sent signal {}, escalated: {}", signal_sent, escalated);
 //!     }
+//!     TimeoutOutcome::Cancelled { .. } => {
+//!         println!("Cancelled");
+//!     }
 //! }
 //! ```
 
 use std::process::ExitStatus;
-use std::time::Duration;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
 
 use serde::{Deserialize, Serialize};
 use sysprims_core::schema::TERMINATE_TREE_RESULT_V1;
-use sysprims_core::{get_platform, SysprimsError, SysprimsResult};
-use sysprims_proc::wait_pid;
+use sysprims_core::{get_platform, SysprimsError, SysprimsResult, Warning, WarningCode};
+use sysprims_proc::{wait_pid, WaitPidResult};
 use time::format_description::well_known::Rfc3339;
 use time::OffsetDateTime;
 
+#[cfg(unix)]
+mod sigchld;
 #[cfg(unix)]
 mod unix;
 #[cfg(windows)]
 mod windows;
 
+#[cfg(unix)]
+use unix::{spawn_timed, supervise};
+#[cfg(windows)]
+use windows::{spawn_timed, supervise};
+
 // Re-export signal constants for convenience
 pub use sysprims_signal::{SIGKILL, SIGTERM};
 
+/// Exit code for a command that timed out (GNU `timeout` convention).
+pub const EXIT_TIMED_OUT: i32 = 124;
+
 /// Process grouping strategy.
 ///
 /// Controls whether timeout creates a process group (Unix) or Job Object
 /// (Windows) to enable tree-kill on timeout.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum GroupingMode {
     /// Create new process group (Unix) or Job Object (Windows).
@@ -72,6 +89,27 @@ pub enum GroupingMode {
     /// Run in foreground. Only kills direct child on timeout.
     /// Use when the child must inherit the parent's process group.
     Foreground,
+
+    /// Create a new process group but make it the terminal's foreground group.
+    ///
+    /// Kills the entire group on timeout (like `GroupByDefault`) while keeping
+    /// terminal access for interactive children. On Unix, the child's group is
+    /// made the controlling terminal's foreground group via `tcsetpgrp()` when
+    /// stdin is a TTY, and the caller's group is restored when the child exits.
+    ///
+    /// **Windows**: there is no terminal process-group concept; this behaves
+    /// exactly like `GroupByDefault` (Job Object tree-kill).
+    NewGroupForeground,
+}
+
+impl GroupingMode {
+    /// Whether this mode places the child in its own process group / Job Object.
+    pub fn creates_group(self) -> bool {
+        matches!(
+            self,
+            GroupingMode::GroupByDefault | GroupingMode::NewGroupForeground
+        )
+    }
 }
 
 /// Configuration for timeout execution.
@@ -92,13 +130,48 @@ pub struct TimeoutConfig {
     /// Default: `GroupByDefault`
     pub grouping: GroupingMode,
 
-    /// Propagate child exit code when command completes normally.
+    /// Report the kill signal instead of 124 when the command times out.
     ///
-    /// When `true`, the timeout exit code matches the child's exit code.
-    /// When `false`, successful completion returns exit code 0.
+    /// When `true`, [`TimeoutOutcome::exit_code`] returns `128 + signal` for a
+    /// timed-out or cancelled command. A command that completes always
+    /// reports its own exit code.
     ///
     /// Default: `false`
     pub preserve_status: bool,
+
+    /// Expand `$VAR` / `${VAR}` references in the command and arguments
+    /// against the inherited environment before spawning.
+    ///
+    /// Undefined variables expand to the empty string and are reported in
+    /// [`TimeoutOutcome::warnings`].
+    ///
+    /// Default: `false` (arguments are passed literally)
+    pub expand_env: bool,
+
+    /// Start the command from an empty environment instead of inheriting
+    /// the parent's.
+    ///
+    /// Only variables named in `env_passthrough` are copied from the parent;
+    /// `expand_env` resolves against that reduced environment.
+    ///
+    /// Default: `false`
+    pub clear_env: bool,
+
+    /// Parent variables to keep when `clear_env` is set (e.g. `PATH`, `HOME`).
+    ///
+    /// Names not set in the parent are skipped. Ignored without `clear_env`.
+    ///
+    /// Default: empty
+    pub env_passthrough: Vec<String>,
+
+    /// Stop token polled alongside the timeout deadline.
+    ///
+    /// When the flag becomes `true`, the tree is killed exactly as on timeout
+    /// and the outcome is [`TimeoutOutcome::Cancelled`]. Lets callers tie a
+    /// run to their own shutdown handling.
+    ///
+    /// Default: `None`
+    pub cancel: Option<Arc<AtomicBool>>,
 }
 
 impl Default for TimeoutConfig {
@@ -108,6 +181,47 @@ impl Default for TimeoutConfig {
             kill_after: Duration::from_secs(10),
             grouping: GroupingMode::GroupByDefault,
             preserve_status: false,
+            expand_env: false,
+            clear_env: false,
+            env_passthrough: Vec::new(),
+            cancel: None,
+        }
+    }
+}
+
+impl TimeoutConfig {
+    /// Value of parent variable `name` as the child will see it.
+    ///
+    /// Windows variable names are matched case-insensitively.
+    fn child_var(&self, name: &str) -> Option<std::ffi::OsString> {
+        let kept = |n: &String| {
+            if cfg!(windows) {
+                n.eq_ignore_ascii_case(name)
+            } else {
+                n == name
+            }
+        };
+        if self.clear_env && !self.env_passthrough.iter().any(kept) {
+            return None;
+        }
+        std::env::var_os(name)
+    }
+
+    /// PATH the child is spawned with, used to explain a missing command.
+    pub(crate) fn child_path(&self) -> Option<std::ffi::OsString> {
+        self.child_var("PATH")
+    }
+
+    /// Apply `clear_env` / `env_passthrough` to `cmd`.
+    pub(crate) fn apply_env(&self, cmd: &mut std::process::Command) {
+        if !self.clear_env {
+            return;
+        }
+        cmd.env_clear();
+        for name in &self.env_passthrough {
+            if let Some(value) = std::env::var_os(name) {
+                cmd.env(name, value);
+            }
         }
     }
 }
@@ -120,7 +234,8 @@ impl Default for TimeoutConfig {
 #[serde(rename_all = "snake_case")]
 pub enum TreeKillReliability {
     /// Tree-kill guaranteed. Process group (Unix) or Job Object (Windows)
-    /// was successfully created and used.
+    /// was successfully created and used, or a post-kill check found none
+    /// of the child's descendants still running.
     Guaranteed,
 
     /// Best-effort only. Process group or Job Object creation failed;
@@ -154,6 +269,27 @@ pub struct TerminateTreeConfig {
     /// Signal to send on escalation (default SIGKILL).
     #[serde(default = "default_kill_signal")]
     pub kill_signal: i32,
+
+    /// Refuse to act unless the PID's start time (Unix epoch ms) matches.
+    ///
+    /// Checked before the first signal (mismatch is an error) and again
+    /// before escalation (mismatch skips escalation with a warning).
+    #[serde(default)]
+    pub require_start_time_ms: Option<u64>,
+
+    /// Refuse to act unless the PID's executable path matches.
+    ///
+    /// Checked at the same points as `require_start_time_ms`.
+    #[serde(default)]
+    pub require_exe_path: Option<String>,
+
+    /// Send `kill_signal` straight away, skipping `signal` and the grace wait.
+    ///
+    /// For force-stop paths where a graceful attempt is known to be futile.
+    /// The result reports `escalated: true` and `signal_sent: kill_signal`;
+    /// `grace_timeout_ms` is ignored and only `kill_timeout_ms` is waited.
+    #[serde(default)]
+    pub skip_grace: bool,
 }
 
 fn default_grace_timeout_ms() -> u64 {
@@ -179,12 +315,157 @@ impl Default for TerminateTreeConfig {
             kill_timeout_ms: default_kill_timeout_ms(),
             signal: default_grace_signal(),
             kill_signal: default_kill_signal(),
+            require_start_time_ms: None,
+            require_exe_path: None,
+            skip_grace: false,
+        }
+    }
+}
+
+/// Highest signal number accepted by [`TerminateTreeConfig::validate`].
+///
+/// `SIGRTMAX` on Linux; 31 (`NSIG - 1`) on macOS and the BSDs, which have no
+/// real-time signals. Windows only emulates signals and keeps the Linux
+/// default of 64.
+pub fn max_signal() -> i32 {
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        libc::SIGRTMAX()
+    }
+    #[cfg(all(unix, not(any(target_os = "linux", target_os = "android"))))]
+    {
+        31
+    }
+    #[cfg(windows)]
+    {
+        64
+    }
+}
+
+/// Grace periods above this are accepted but reported as a likely mistake.
+pub const GRACE_TIMEOUT_WARN_MS: u64 = 5 * 60 * 1000;
+
+impl TerminateTreeConfig {
+    /// Validate the configuration before any process is signaled.
+    ///
+    /// Rejects signals outside 1 through [`max_signal`] and a zero `grace_timeout_ms`
+    /// paired with a `kill_signal` different from `signal` (which would
+    /// escalate immediately). On success, returns non-fatal warnings such as a
+    /// grace period longer than [`GRACE_TIMEOUT_WARN_MS`].
+    pub fn validate(&self) -> SysprimsResult<Vec<Warning>> {
+        let max = max_signal();
+        for (name, value) in [("signal", self.signal), ("kill_signal", self.kill_signal)] {
+            if !(1..=max).contains(&value) {
+                return Err(SysprimsError::invalid_argument(format!(
+                    "{name} {value} is out of range (must be 1..={max})"
+                )));
+            }
+        }
+
+        if self.grace_timeout_ms == 0 && self.kill_signal != self.signal && !self.skip_grace {
+            return Err(SysprimsError::invalid_argument(format!(
+                "grace_timeout_ms of 0 with kill_signal {} different from signal {} escalates immediately; set a grace period or use the same signal",
+                self.kill_signal, self.signal
+            )));
+        }
+
+        let mut warnings = Vec::new();
+        if self.grace_timeout_ms > GRACE_TIMEOUT_WARN_MS {
+            warnings.push(Warning::new(
+                WarningCode::Config,
+                format!(
+                    "grace_timeout_ms {} exceeds {} ms; this is likely a mistake",
+                    self.grace_timeout_ms, GRACE_TIMEOUT_WARN_MS
+                ),
+            ));
+        }
+        Ok(warnings)
+    }
+
+    /// The first signal sent: `kill_signal` with `skip_grace`, else `signal`.
+    fn initial_signal(&self) -> i32 {
+        if self.skip_grace {
+            self.kill_signal
+        } else {
+            self.signal
+        }
+    }
+
+    /// Compare `pid` against `require_start_time_ms` / `require_exe_path`.
+    ///
+    /// Returns a description of the first mismatch, or `None` when the PID
+    /// matches (or nothing is required). A required field the platform cannot
+    /// report is an error, since the guard could not be enforced.
+    fn identity_mismatch(&self, pid: u32) -> SysprimsResult<Option<String>> {
+        if self.require_start_time_ms.is_none() && self.require_exe_path.is_none() {
+            return Ok(None);
+        }
+        let info = sysprims_proc::get_process_with_options(
+            pid,
+            sysprims_proc::ProcessOptions::default().without_usernames(),
+        )?;
+
+        if let Some(expected) = self.require_start_time_ms {
+            let actual = info.start_time_unix_ms.ok_or_else(|| {
+                SysprimsError::invalid_argument(
+                    "start_time_unix_ms unavailable; cannot enforce require_start_time_ms",
+                )
+            })?;
+            if actual != expected {
+                return Ok(Some(format!(
+                    "start_time_unix_ms expected {expected}, got {actual}"
+                )));
+            }
+        }
+
+        if let Some(expected) = &self.require_exe_path {
+            let actual = info.exe_path.ok_or_else(|| {
+                SysprimsError::invalid_argument(
+                    "exe_path unavailable; cannot enforce require_exe_path",
+                )
+            })?;
+            if &actual != expected {
+                return Ok(Some(format!(
+                    "exe_path expected '{expected}', got '{actual}'"
+                )));
+            }
         }
+        Ok(None)
+    }
+
+    /// Fail with `IdentityMismatch` if `pid` does not match the required identity.
+    fn verify_identity(&self, pid: u32) -> SysprimsResult<()> {
+        match self.identity_mismatch(pid)? {
+            Some(reason) => Err(SysprimsError::identity_mismatch(pid, reason)),
+            None => Ok(()),
+        }
+    }
+
+    /// Re-check identity right before escalating.
+    ///
+    /// Returns a warning when escalation must be skipped: the PID now belongs
+    /// to something else, or its identity can no longer be read. A PID that
+    /// has simply exited is not a mismatch.
+    fn escalation_guard(&self, pid: u32) -> Option<Warning> {
+        let reason = match self.identity_mismatch(pid) {
+            Ok(None) | Err(SysprimsError::NotFound { .. }) => return None,
+            Ok(Some(reason)) => reason,
+            Err(e) => format!("could not re-verify identity: {e}"),
+        };
+        Some(Warning::new(
+            WarningCode::PidReuseGuard,
+            format!("PID {pid} identity changed before escalation ({reason}); not escalating"),
+        ))
     }
 }
 
 #[derive(Debug, Clone, Serialize)]
 pub struct TerminateTreeResult {
+    /// Serialized together with `schema_version`, its major version.
+    #[serde(
+        flatten,
+        serialize_with = "sysprims_core::schema::serialize_with_version"
+    )]
     pub schema_id: &'static str,
     pub timestamp: String,
     pub platform: &'static str,
@@ -199,8 +480,14 @@ pub struct TerminateTreeResult {
     pub escalated: bool,
     pub exited: bool,
     pub timed_out: bool,
+
+    /// Exit code of `pid`, when the caller is its parent (see
+    /// [`sysprims_proc::WaitPidResult::exit_code`]).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+
     pub tree_kill_reliability: String,
-    pub warnings: Vec<String>,
+    pub warnings: Vec<Warning>,
 }
 
 // =============================================================================
@@ -223,10 +510,61 @@ pub struct SpawnInGroupConfig {
     /// By default the child inherits the parent's environment.
     #[serde(default)]
     pub env: Option<std::collections::BTreeMap<String, String>>,
+
+    /// Start from an empty environment instead of inheriting the parent's.
+    ///
+    /// Variables named in `env_passthrough` are copied from the parent, then
+    /// `env` is applied on top.
+    #[serde(default)]
+    pub clear_env: bool,
+
+    /// Parent variables to keep when `clear_env` is set (e.g. `PATH`, `HOME`).
+    ///
+    /// Names not set in the parent are skipped. Ignored without `clear_env`.
+    #[serde(default)]
+    pub env_passthrough: Vec<String>,
+
+    /// Expand `$VAR` / `${VAR}` references in `argv` and `cwd` before spawning.
+    ///
+    /// Variables resolve against `env` overlaid on the inherited environment.
+    /// Undefined variables expand to the empty string and add a warning to the
+    /// result. Default `false` passes `argv` and `cwd` literally.
+    #[serde(default)]
+    pub expand_env: bool,
+}
+
+impl SpawnInGroupConfig {
+    /// PATH the child is spawned with, used to explain a missing command.
+    ///
+    /// `env` wins over the inherited value; with `clear_env` there is nothing
+    /// to inherit. Windows variable names are matched case-insensitively.
+    pub(crate) fn child_path(&self) -> Option<std::ffi::OsString> {
+        let overridden = self.env.as_ref().and_then(|env| {
+            env.iter()
+                .find(|(k, _)| {
+                    if cfg!(windows) {
+                        k.eq_ignore_ascii_case("PATH")
+                    } else {
+                        k.as_str() == "PATH"
+                    }
+                })
+                .map(|(_, v)| v.into())
+        });
+        match overridden {
+            Some(path) => Some(path),
+            None if self.clear_env => None,
+            None => std::env::var_os("PATH"),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
 pub struct SpawnInGroupResult {
+    /// Serialized together with `schema_version`, its major version.
+    #[serde(
+        flatten,
+        serialize_with = "sysprims_core::schema::serialize_with_version"
+    )]
     pub schema_id: &'static str,
     pub timestamp: String,
     pub platform: &'static str,
@@ -244,6 +582,9 @@ pub struct SpawnInGroupResult {
 /// Use this when you would otherwise shell out to `setsid`/wrapper scripts to
 /// make jobs kill-tree safe.
 ///
+/// The caller stays the child's parent, so a later [`wait_pid`] or
+/// [`terminate_tree`] on the returned PID reports the child's real exit code.
+///
 /// # Examples
 ///
 /// ```rust,no_run
@@ -254,20 +595,213 @@ pub struct SpawnInGroupResult {
 ///     argv: vec!["sleep".into(), "5".into()],
 ///     cwd: None,
 ///     env: None,
+///     expand_env: false,
+///     clear_env: false,
+///     env_passthrough: vec![],
 /// })
 /// .unwrap();
 /// println!("spawned pid: {}", result.pid);
 /// ```
-pub fn spawn_in_group(config: SpawnInGroupConfig) -> SysprimsResult<SpawnInGroupResult> {
+pub fn spawn_in_group(mut config: SpawnInGroupConfig) -> SysprimsResult<SpawnInGroupResult> {
     if config.argv.is_empty() {
         return Err(SysprimsError::invalid_argument("argv must not be empty"));
     }
 
+    if config.clear_env {
+        let mut env: std::collections::BTreeMap<String, String> = config
+            .env_passthrough
+            .iter()
+            .filter_map(|name| Some((name.clone(), std::env::var(name).ok()?)))
+            .collect();
+        env.extend(config.env.take().unwrap_or_default());
+        config.env = Some(env);
+    }
+
+    let mut undefined: Vec<String> = Vec::new();
+    if config.expand_env {
+        let overlay = config.env.clone().unwrap_or_default();
+        let inherit = !config.clear_env;
+        let lookup = |name: &str| {
+            overlay
+                .get(name)
+                .cloned()
+                .or_else(|| inherit.then(|| std::env::var(name).ok()).flatten())
+        };
+        for arg in &mut config.argv {
+            *arg = expand_env_refs(arg, &lookup, &mut undefined);
+        }
+        if let Some(cwd) = config.cwd.as_mut() {
+            *cwd = expand_env_refs(cwd, &lookup, &mut undefined);
+        }
+    }
+
+    #[cfg(unix)]
+    let mut result = unix::spawn_in_group_impl(config)?;
+
+    #[cfg(windows)]
+    let mut result = windows::spawn_in_group_impl(config)?;
+
+    result.warnings.extend(undefined.into_iter().map(|name| {
+        format!(
+            "Environment variable {} is not set; expanded to empty",
+            name
+        )
+    }));
+    Ok(result)
+}
+
+/// Outcome of [`wait_group`].
+#[derive(Debug, Clone, Serialize)]
+pub struct WaitGroupResult {
+    /// Serialized together with `schema_version`, its major version.
+    #[serde(
+        flatten,
+        serialize_with = "sysprims_core::schema::serialize_with_version"
+    )]
+    pub schema_id: &'static str,
+
+    /// Process group waited on.
+    pub pgid: u32,
+
+    /// True if every member of the group exited (and the leader was reaped).
+    pub exited: bool,
+
+    /// True if the timeout elapsed with members still running.
+    pub timed_out: bool,
+
+    /// Exit code of the group leader, when it was our child and exited
+    /// normally (`None` if it was killed by a signal or is not our child).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub leader_exit_code: Option<i32>,
+
+    pub warnings: Vec<Warning>,
+}
+
+/// Block until process group `pgid` is empty or `timeout` elapses.
+///
+/// Intended for groups from [`spawn_in_group`] (where `pgid` is the leader's
+/// PID). While the leader is the caller's running child, this sleeps on
+/// SIGCHLD instead of polling, so it wakes as soon as the leader exits and
+/// costs nothing in between. The leader is reaped here and its status returned
+/// in `leader_exit_code`; a later `wait_pid` on it will not see the code.
+///
+/// Members left after the leader exits are reparented away from the caller
+/// and raise no SIGCHLD for it, so they are polled every 50ms; the same
+/// applies when `pgid` is not the caller's child.
+///
+/// # Signal handler caveats
+///
+/// The first call installs a process-wide SIGCHLD handler (self-pipe plus one
+/// listener thread named `sysprims-sigchld`) that is never removed:
+///
+/// - A handler already installed is called after ours; one installed later
+///   that does not chain to the previous handler disables the wakeups, and
+///   waits degrade to the 50ms poll only after the leader exits.
+/// - If SIGCHLD is set to `SIG_IGN`, no handler is installed (that would stop
+///   the kernel from auto-reaping children) and the group is polled; a warning
+///   is added to the result.
+/// - Blocking system calls in other threads may see `EINTR` for SIGCHLD; the
+///   handler uses `SA_RESTART`, so most are restarted transparently.
+///
+/// On Windows, this always returns `NotSupported`.
+///
+/// # Errors
+///
+/// Returns [`SysprimsError::InvalidArgument`] if `pgid` is 0 or exceeds
+/// [`sysprims_signal::MAX_SAFE_PID`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use sysprims_timeout::{spawn_in_group, wait_group, SpawnInGroupConfig};
+///
+/// let spawned = spawn_in_group(SpawnInGroupConfig {
+///     argv: vec!["sh".into(), "-c".into(), "sleep 1 & sleep 2".into()],
+///     cwd: None,
+///     env: None,
+///     expand_env: false,
+///     clear_env: false,
+///     env_passthrough: vec![],
+/// })
+/// .unwrap();
+/// let pgid = spawned.pgid.unwrap();
+/// let result = wait_group(pgid, Duration::from_secs(10)).unwrap();
+/// println!("exited={} code={:?}", result.exited, result.leader_exit_code);
+/// ```
+pub fn wait_group(pgid: u32, timeout: Duration) -> SysprimsResult<WaitGroupResult> {
+    validate_tree_pid(pgid)?;
+
     #[cfg(unix)]
-    return unix::spawn_in_group_impl(config);
+    return unix::wait_group_impl(pgid, timeout);
 
     #[cfg(windows)]
-    return windows::spawn_in_group_impl(config);
+    {
+        let _ = timeout; // Unused on Windows
+        Err(SysprimsError::not_supported("wait_group", "windows"))
+    }
+}
+
+/// Expand `$VAR` and `${VAR}` references in `input` using `lookup`.
+///
+/// Names follow shell rules (`[A-Za-z_][A-Za-z0-9_]*`). A `$` that does not
+/// start a valid reference is kept literally. Undefined variables expand to
+/// the empty string and their names are recorded once in `undefined`.
+fn expand_env_refs(
+    input: &str,
+    lookup: &dyn Fn(&str) -> Option<String>,
+    undefined: &mut Vec<String>,
+) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(idx) = rest.find('$') {
+        out.push_str(&rest[..idx]);
+        let after = &rest[idx + 1..];
+
+        let reference = match after.strip_prefix('{') {
+            Some(braced) => braced
+                .find('}')
+                .map(|end| &braced[..end])
+                .filter(|name| is_env_name(name))
+                .map(|name| (name, name.len() + 2)),
+            None => {
+                let len = after
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .unwrap_or(after.len());
+                Some(&after[..len])
+                    .filter(|name| is_env_name(name))
+                    .map(|name| (name, len))
+            }
+        };
+
+        match reference {
+            Some((name, consumed)) => {
+                match lookup(name) {
+                    Some(value) => out.push_str(&value),
+                    None => {
+                        if !undefined.iter().any(|n| n == name) {
+                            undefined.push(name.to_string());
+                        }
+                    }
+                }
+                rest = &after[consumed..];
+            }
+            None => {
+                out.push('$');
+                rest = after;
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+fn is_env_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
 }
 
 pub(crate) fn current_timestamp() -> String {
@@ -281,6 +815,9 @@ pub(crate) fn current_timestamp() -> String {
 /// PID-only API: if the target PID is a process group leader (Unix only), this will
 /// prefer group kill for better coverage. Otherwise it signals the PID directly.
 ///
+/// On Windows, PIDs not spawned via [`spawn_in_group`] have no Job Object; they
+/// are terminated with [`terminate_tree_by_descendants`] instead.
+///
 /// # Examples
 ///
 /// ```rust,no_run
@@ -294,26 +831,28 @@ pub fn terminate_tree(
     pid: u32,
     config: TerminateTreeConfig,
 ) -> SysprimsResult<TerminateTreeResult> {
-    if pid == 0 {
-        return Err(SysprimsError::invalid_argument("pid must be > 0"));
-    }
+    validate_tree_pid(pid)?;
+    let warnings = config.validate()?;
+    config.verify_identity(pid)?;
 
-    // Defense in depth: avoid unsafe casts on Unix.
-    // See ADR-0011 (PID Validation Safety).
-    if pid > sysprims_signal::MAX_SAFE_PID {
-        return Err(SysprimsError::invalid_argument(format!(
-            "pid {} exceeds maximum safe value {}",
-            pid,
-            sysprims_signal::MAX_SAFE_PID
-        )));
-    }
+    #[cfg(unix)]
+    return terminate_tree_unix(pid, config, warnings);
+
+    #[cfg(windows)]
+    return terminate_tree_windows(pid, config, warnings);
+}
 
-    let mut warnings: Vec<String> = Vec::new();
+/// Unix [`terminate_tree`]: prefer `killpg` when the target leads its own group.
+#[cfg(unix)]
+fn terminate_tree_unix(
+    pid: u32,
+    config: TerminateTreeConfig,
+    mut warnings: Vec<Warning>,
+) -> SysprimsResult<TerminateTreeResult> {
     let mut pgid: Option<u32> = None;
     let mut reliability = TreeKillReliability::BestEffort;
 
-    // Decide whether we can safely use group kill (Unix only).
-    #[cfg(unix)]
+    // Decide whether we can safely use group kill.
     {
         use sysprims_signal::MAX_SAFE_PID;
         if pid <= MAX_SAFE_PID {
@@ -322,104 +861,113 @@ pub fn terminate_tree(
             let target_pgid = unsafe { libc::getpgid(pid_i32) };
 
             if target_pgid == -1 {
-                warnings.push("Could not determine process group for pid".to_string());
+                warnings.push(Warning::new(
+                    WarningCode::Degraded,
+                    "Could not determine process group for pid",
+                ));
             } else if target_pgid == pid_i32 {
                 // Target is a group leader. Only use killpg if it isn't our own group.
                 if self_pgid != -1 && target_pgid == self_pgid {
-                    warnings.push(
-                        "Target pid is in caller's process group; refusing group kill".to_string(),
-                    );
+                    warnings.push(Warning::new(
+                        WarningCode::Degraded,
+                        "Target pid is in caller's process group; refusing group kill",
+                    ));
                 } else {
                     pgid = Some(target_pgid as u32);
                     reliability = TreeKillReliability::Guaranteed;
                 }
             } else {
-                warnings
-                    .push("Target pid is not a process group leader; using pid kill".to_string());
+                warnings.push(Warning::new(
+                    WarningCode::Degraded,
+                    "Target pid is not a process group leader; using pid kill",
+                ));
             }
         } else {
-            warnings.push("pid exceeds max safe pid for POSIX kill".to_string());
+            warnings.push(Warning::new(
+                WarningCode::Degraded,
+                "pid exceeds max safe pid for POSIX kill",
+            ));
         }
     }
 
-    #[cfg(windows)]
-    {
-        // If this PID was spawned via spawn_in_group_impl(), we may have a Job Object.
-        // Prefer terminating the Job Object for better tree coverage.
-        if crate::windows::terminate_job_for_pid(pid).is_some() {
-            warnings.push("Terminated via Job Object (spawn_in_group)".to_string());
+    if !config.skip_grace {
+        // Step 1: send graceful signal
+        // If group kill fails (e.g. permission-limited), fall back to PID kill.
+        if let Some(g) = pgid {
+            match sysprims_signal::killpg(g, config.signal) {
+                Ok(()) => {}
+                Err(SysprimsError::PermissionDenied { .. }) => {
+                    warnings.push(Warning::new(
+                        WarningCode::PermissionDenied,
+                        "Permission denied signaling process group; falling back to pid",
+                    ));
+                    pgid = None;
+                    reliability = TreeKillReliability::BestEffort;
+                    sysprims_signal::kill(pid, config.signal)?;
+                }
+                Err(e) => return Err(e),
+            }
+        } else {
+            sysprims_signal::kill(pid, config.signal)?;
+        }
 
-            let grace_wait = wait_pid(pid, Duration::from_millis(config.grace_timeout_ms))?;
+        // Step 2: wait for exit
+        let grace = Duration::from_millis(config.grace_timeout_ms);
+        let grace_wait = wait_pid(pid, grace)?;
+        if grace_wait.exited {
             return Ok(TerminateTreeResult {
                 schema_id: TERMINATE_TREE_RESULT_V1,
                 timestamp: current_timestamp(),
                 platform: get_platform(),
                 pid,
-                pgid: None,
+                pgid,
                 signal_sent: config.signal,
                 kill_signal: None,
                 escalated: false,
-                exited: grace_wait.exited,
-                timed_out: grace_wait.timed_out,
-                tree_kill_reliability: "guaranteed".to_string(),
+                exited: true,
+                timed_out: false,
+                exit_code: grace_wait.exit_code,
+                tree_kill_reliability: match reliability {
+                    TreeKillReliability::Guaranteed => "guaranteed".to_string(),
+                    TreeKillReliability::BestEffort => "best_effort".to_string(),
+                },
                 warnings,
             });
         }
 
-        warnings.push("Windows PID termination is best-effort without Job Object".to_string());
-    }
-
-    // Step 1: send graceful signal
-    // If group kill fails (e.g. permission-limited), fall back to PID kill.
-    if let Some(g) = pgid {
-        match sysprims_signal::killpg(g, config.signal) {
-            Ok(()) => {}
-            Err(SysprimsError::PermissionDenied { .. }) => {
-                warnings.push(
-                    "Permission denied signaling process group; falling back to pid".to_string(),
-                );
-                pgid = None;
-                reliability = TreeKillReliability::BestEffort;
-                sysprims_signal::kill(pid, config.signal)?;
-            }
-            Err(e) => return Err(e),
+        // Step 3: escalate, unless the PID was reused during the grace period.
+        if let Some(warning) = config.escalation_guard(pid) {
+            warnings.push(warning);
+            return Ok(TerminateTreeResult {
+                schema_id: TERMINATE_TREE_RESULT_V1,
+                timestamp: current_timestamp(),
+                platform: get_platform(),
+                pid,
+                pgid,
+                signal_sent: config.signal,
+                kill_signal: None,
+                escalated: false,
+                exited: false,
+                timed_out: true,
+                exit_code: None,
+                tree_kill_reliability: match reliability {
+                    TreeKillReliability::Guaranteed => "guaranteed".to_string(),
+                    TreeKillReliability::BestEffort => "best_effort".to_string(),
+                },
+                warnings,
+            });
         }
-    } else {
-        sysprims_signal::kill(pid, config.signal)?;
-    }
-
-    // Step 2: wait for exit
-    let grace = Duration::from_millis(config.grace_timeout_ms);
-    let grace_wait = wait_pid(pid, grace)?;
-    if grace_wait.exited {
-        return Ok(TerminateTreeResult {
-            schema_id: TERMINATE_TREE_RESULT_V1,
-            timestamp: current_timestamp(),
-            platform: get_platform(),
-            pid,
-            pgid,
-            signal_sent: config.signal,
-            kill_signal: None,
-            escalated: false,
-            exited: true,
-            timed_out: false,
-            tree_kill_reliability: match reliability {
-                TreeKillReliability::Guaranteed => "guaranteed".to_string(),
-                TreeKillReliability::BestEffort => "best_effort".to_string(),
-            },
-            warnings,
-        });
     }
 
-    // Step 3: escalate
+    // Escalate (the only signal with `skip_grace`).
     if let Some(g) = pgid {
         match sysprims_signal::killpg(g, config.kill_signal) {
             Ok(()) => {}
             Err(SysprimsError::PermissionDenied { .. }) => {
-                warnings.push(
-                    "Permission denied signaling process group (kill); falling back to pid"
-                        .to_string(),
-                );
+                warnings.push(Warning::new(
+                    WarningCode::PermissionDenied,
+                    "Permission denied signaling process group (kill); falling back to pid",
+                ));
                 pgid = None;
                 reliability = TreeKillReliability::BestEffort;
                 sysprims_signal::kill(pid, config.kill_signal)?;
@@ -445,13 +993,22 @@ pub fn terminate_tree(
             Err(SysprimsError::NotFound { .. }) => {
                 exited = true;
                 timed_out = false;
-                warnings.push("PID no longer found after timeout; treating as exited".to_string());
+                warnings.push(Warning::new(
+                    WarningCode::PartialVisibility,
+                    "PID no longer found after timeout; treating as exited",
+                ));
             }
             Err(SysprimsError::PermissionDenied { .. }) => {
-                warnings.push("Permission denied while confirming exit after timeout".to_string());
+                warnings.push(Warning::new(
+                    WarningCode::PermissionDenied,
+                    "Permission denied while confirming exit after timeout",
+                ));
             }
             Err(e) => {
-                warnings.push(format!("Failed to confirm exit after timeout: {}", e));
+                warnings.push(Warning::new(
+                    WarningCode::PartialVisibility,
+                    format!("Failed to confirm exit after timeout: {}", e),
+                ));
             }
         }
     }
@@ -462,11 +1019,12 @@ pub fn terminate_tree(
         platform: get_platform(),
         pid,
         pgid,
-        signal_sent: config.signal,
+        signal_sent: config.initial_signal(),
         kill_signal: Some(config.kill_signal),
         escalated: true,
         exited,
         timed_out,
+        exit_code: kill_wait.exit_code,
         tree_kill_reliability: match reliability {
             TreeKillReliability::Guaranteed => "guaranteed".to_string(),
             TreeKillReliability::BestEffort => "best_effort".to_string(),
@@ -475,18 +1033,466 @@ pub fn terminate_tree(
     })
 }
 
-/// Outcome of timeout execution.
-#[derive(Debug)]
-pub enum TimeoutOutcome {
-    /// Command completed within timeout.
-    Completed {
-        /// Exit status of the child process.
-        exit_status: ExitStatus,
-    },
+/// Windows [`terminate_tree`]: terminate the Job Object when we spawned the
+/// PID, otherwise fall back to [`terminate_tree_by_descendants`].
+#[cfg(windows)]
+fn terminate_tree_windows(
+    pid: u32,
+    config: TerminateTreeConfig,
+    mut warnings: Vec<Warning>,
+) -> SysprimsResult<TerminateTreeResult> {
+    // If this PID was spawned via spawn_in_group_impl(), we may have a Job Object.
+    // Prefer terminating the Job Object for better tree coverage.
+    if crate::windows::terminate_job_for_pid(pid).is_some() {
+        warnings.push(Warning::new(
+            WarningCode::Info,
+            "Terminated via Job Object (spawn_in_group)",
+        ));
+
+        let wait_ms = if config.skip_grace {
+            config.kill_timeout_ms
+        } else {
+            config.grace_timeout_ms
+        };
+        let job_wait = wait_pid(pid, Duration::from_millis(wait_ms))?;
+        return Ok(TerminateTreeResult {
+            schema_id: TERMINATE_TREE_RESULT_V1,
+            timestamp: current_timestamp(),
+            platform: get_platform(),
+            pid,
+            pgid: None,
+            signal_sent: config.initial_signal(),
+            kill_signal: config.skip_grace.then_some(config.kill_signal),
+            escalated: config.skip_grace,
+            exited: job_wait.exited,
+            timed_out: job_wait.timed_out,
+            exit_code: job_wait.exit_code,
+            tree_kill_reliability: "guaranteed".to_string(),
+            warnings,
+        });
+    }
 
-    /// Command timed out and was killed.
-    TimedOut {
-        /// Signal that was sent to terminate the process.
+    terminate_descendants(pid, config, warnings)
+}
+
+/// Terminate a PID and its descendants by signaling each process individually.
+///
+/// Descendants are enumerated from a single process snapshot (Toolhelp32 on
+/// Windows, `/proc` or libproc on Unix), signaled deepest-first, and then the
+/// root is signaled. Members still running after the grace period receive
+/// `kill_signal`. Use this for trees that have no process group or Job Object,
+/// e.g. externally spawned Windows processes.
+///
+/// Reliability is always `best_effort`: processes spawned or re-parented while
+/// the tree is being torn down can be missed. The caller, its parent, and PID 1
+/// are never signaled even if they appear in the tree.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sysprims_timeout::{terminate_tree_by_descendants, TerminateTreeConfig};
+///
+/// // Replaces: taskkill /T /PID 1234
+/// let result = terminate_tree_by_descendants(1234, TerminateTreeConfig::default()).unwrap();
+/// println!("exited={} warnings={:?}", result.exited, result.warnings);
+/// ```
+pub fn terminate_tree_by_descendants(
+    pid: u32,
+    config: TerminateTreeConfig,
+) -> SysprimsResult<TerminateTreeResult> {
+    validate_tree_pid(pid)?;
+    let warnings = config.validate()?;
+    config.verify_identity(pid)?;
+
+    terminate_descendants(pid, config, warnings)
+}
+
+/// Shared body of [`terminate_tree_by_descendants`]; `warnings` carries any
+/// already-collected warnings (e.g. from config validation).
+fn terminate_descendants(
+    pid: u32,
+    config: TerminateTreeConfig,
+    mut warnings: Vec<Warning>,
+) -> SysprimsResult<TerminateTreeResult> {
+    warnings.push(Warning::new(
+        WarningCode::Degraded,
+        "Tree terminated per-process from a descendants snapshot; processes re-parented or spawned during termination may be missed",
+    ));
+
+    // Capture (pid, start time) for every member so later signals never hit a
+    // PID that was reused after the original process exited.
+    let tree = sysprims_proc::descendants_with_config(whole_tree(pid))?;
+    let mut members: Vec<(u32, Option<u64>)> = tree
+        .levels
+        .iter()
+        .rev()
+        .flat_map(|level| {
+            level
+                .processes
+                .iter()
+                .map(|p| (p.pid, p.start_time_unix_ms))
+        })
+        .collect();
+
+    let mut member_pids: Vec<u32> = members.iter().map(|(p, _)| *p).collect();
+    let skipped = sysprims_proc::filter_protected(&mut member_pids, false);
+    if skipped > 0 {
+        warnings.push(Warning::new(
+            WarningCode::Protected,
+            format!(
+                "Skipped {} protected descendants (self/PID1/parent)",
+                skipped
+            ),
+        ));
+        members.retain(|(p, _)| member_pids.contains(p));
+    }
+
+    let root_exited = if config.skip_grace {
+        false
+    } else {
+        // Step 1: graceful signal, deepest descendants first, then the root.
+        signal_tree_members(&members, config.signal, &mut warnings);
+        match sysprims_signal::kill(pid, config.signal) {
+            Ok(()) | Err(SysprimsError::NotFound { .. }) => {}
+            Err(e) => return Err(e),
+        }
+
+        // Step 2: wait for the root, then check for surviving descendants.
+        let grace_wait = wait_pid(pid, Duration::from_millis(config.grace_timeout_ms))?;
+        members.retain(|&(p, start)| tree_member_alive(p, start));
+        if grace_wait.exited && members.is_empty() {
+            return Ok(TerminateTreeResult {
+                schema_id: TERMINATE_TREE_RESULT_V1,
+                timestamp: current_timestamp(),
+                platform: get_platform(),
+                pid,
+                pgid: None,
+                signal_sent: config.signal,
+                kill_signal: None,
+                escalated: false,
+                exited: true,
+                timed_out: false,
+                exit_code: grace_wait.exit_code,
+                tree_kill_reliability: "best_effort".to_string(),
+                warnings,
+            });
+        }
+        grace_wait.exited
+    };
+
+    // Step 3: escalate survivors. Members are already guarded by start time;
+    // the root is re-checked against the configured identity.
+    signal_tree_members(&members, config.kill_signal, &mut warnings);
+    if !root_exited {
+        match config.escalation_guard(pid) {
+            Some(warning) => warnings.push(warning),
+            None => match sysprims_signal::kill(pid, config.kill_signal) {
+                Ok(()) | Err(SysprimsError::NotFound { .. }) => {}
+                Err(e) => return Err(e),
+            },
+        }
+    }
+
+    let kill_wait = wait_pid(pid, Duration::from_millis(config.kill_timeout_ms))?;
+    members.retain(|&(p, start)| tree_member_alive(p, start));
+    if !members.is_empty() {
+        warnings.push(Warning::new(
+            WarningCode::Other,
+            format!(
+                "{} descendants still running after escalation",
+                members.len()
+            ),
+        ));
+    }
+
+    Ok(TerminateTreeResult {
+        schema_id: TERMINATE_TREE_RESULT_V1,
+        timestamp: current_timestamp(),
+        platform: get_platform(),
+        pid,
+        pgid: None,
+        signal_sent: config.initial_signal(),
+        kill_signal: Some(config.kill_signal),
+        escalated: true,
+        exited: kill_wait.exited,
+        timed_out: kill_wait.timed_out,
+        exit_code: kill_wait.exit_code,
+        tree_kill_reliability: "best_effort".to_string(),
+        warnings,
+    })
+}
+
+fn validate_tree_pid(pid: u32) -> SysprimsResult<()> {
+    if pid == 0 {
+        return Err(SysprimsError::invalid_argument("pid must be > 0"));
+    }
+
+    // Defense in depth: avoid unsafe casts on Unix.
+    // See ADR-0011 (PID Validation Safety).
+    if pid > sysprims_signal::MAX_SAFE_PID {
+        return Err(SysprimsError::invalid_argument(format!(
+            "pid {} exceeds maximum safe value {}",
+            pid,
+            sysprims_signal::MAX_SAFE_PID
+        )));
+    }
+
+    Ok(())
+}
+
+/// Signal each tree member that is still the process we enumerated.
+///
+/// Members that already exited are skipped silently; other failures become
+/// warnings so one stubborn process does not abort the whole teardown.
+fn signal_tree_members(members: &[(u32, Option<u64>)], signal: i32, warnings: &mut Vec<Warning>) {
+    for &(member, start) in members {
+        if !tree_member_alive(member, start) {
+            continue;
+        }
+        match sysprims_signal::kill(member, signal) {
+            Ok(()) | Err(SysprimsError::NotFound { .. }) => {}
+            Err(e) => {
+                let code = match e {
+                    SysprimsError::PermissionDenied { .. } => WarningCode::PermissionDenied,
+                    _ => WarningCode::Other,
+                };
+                warnings.push(Warning::new(
+                    code,
+                    format!("Failed to signal descendant {}: {}", member, e),
+                ));
+            }
+        }
+    }
+}
+
+/// Whether `pid` still refers to the process observed with `start_time_ms`.
+fn tree_member_alive(pid: u32, start_time_ms: Option<u64>) -> bool {
+    match sysprims_proc::get_process(pid) {
+        Ok(info) => {
+            info.state != sysprims_proc::ProcessState::Zombie
+                && (start_time_ms.is_none() || info.start_time_unix_ms == start_time_ms)
+        }
+        Err(_) => false,
+    }
+}
+
+/// Send a signal to a process and wait for it to exit.
+///
+/// Convenience for the common "stop my child cleanly" case: sends `signal`
+/// via [`sysprims_signal::kill`], then calls [`wait_pid`] with `timeout` and
+/// returns its result. No escalation is performed; use [`terminate_tree`] when
+/// the target may ignore the signal.
+///
+/// `exit_code` in the result is only populated when the platform exposes it
+/// (Windows) or when sysprims is the parent of the process (Unix). Children
+/// spawned by the caller through `std::process::Command` are observed as
+/// exited (zombie) but are not reaped here; call `Child::wait()` to collect
+/// their status.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use sysprims_timeout::{kill_and_wait, SIGTERM};
+///
+/// // Replaces: kill -TERM 1234; while kill -0 1234; do sleep 0.1; done
+/// let result = kill_and_wait(1234, SIGTERM, Duration::from_secs(5)).unwrap();
+/// println!("exited={} exit_code={:?}", result.exited, result.exit_code);
+/// ```
+pub fn kill_and_wait(pid: u32, signal: i32, timeout: Duration) -> SysprimsResult<WaitPidResult> {
+    sysprims_signal::kill(pid, signal)?;
+    wait_pid(pid, timeout)
+}
+
+/// Gracefully stop several PIDs, escalating only the ones that ignore it.
+///
+/// Sends `config.signal` to every PID first, waits up to
+/// `config.grace_timeout_ms` for all of them together, then sends
+/// `config.kill_signal` to the survivors and waits up to
+/// `config.kill_timeout_ms`. Total wall time is bounded by one grace period
+/// plus one kill period regardless of how many PIDs are given.
+///
+/// Each PID is signaled directly (no process group or descendant handling);
+/// use [`terminate_tree`] per PID when whole trees must go. Each PID's start
+/// time is captured up front and re-checked before every signal and after
+/// every wait (ADR-0011), so a PID reused by an unrelated process is reported
+/// as exited and never signaled. PIDs that are already gone are reported as
+/// exited. Per-PID signal failures are reported as warnings on that PID's
+/// result rather than aborting the batch.
+///
+/// # Errors
+///
+/// Returns [`SysprimsError::InvalidArgument`] if `pids` is empty, contains an
+/// invalid or duplicate PID, or `config` fails [`TerminateTreeConfig::validate`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sysprims_timeout::{terminate_many, TerminateTreeConfig};
+///
+/// // Replaces: kill -TERM 1234 5678; sleep 10; kill -KILL <survivors>
+/// let results = terminate_many(&[1234, 5678], TerminateTreeConfig::default()).unwrap();
+/// for r in &results {
+///     println!("pid={} exited={} escalated={}", r.pid, r.exited, r.escalated);
+/// }
+/// ```
+pub fn terminate_many(
+    pids: &[u32],
+    config: TerminateTreeConfig,
+) -> SysprimsResult<Vec<TerminateTreeResult>> {
+    if pids.is_empty() {
+        return Err(SysprimsError::invalid_argument("pids must not be empty"));
+    }
+    for (i, &pid) in pids.iter().enumerate() {
+        validate_tree_pid(pid)?;
+        if pids[..i].contains(&pid) {
+            return Err(SysprimsError::invalid_argument(format!(
+                "duplicate pid {pid} in pids"
+            )));
+        }
+    }
+    let config_warnings = config.validate()?;
+
+    let mut results: Vec<TerminateTreeResult> = pids
+        .iter()
+        .map(|&pid| TerminateTreeResult {
+            schema_id: TERMINATE_TREE_RESULT_V1,
+            timestamp: current_timestamp(),
+            platform: get_platform(),
+            pid,
+            pgid: None,
+            signal_sent: config.signal,
+            kill_signal: None,
+            escalated: false,
+            exited: false,
+            timed_out: false,
+            exit_code: None,
+            tree_kill_reliability: "best_effort".to_string(),
+            warnings: config_warnings.clone(),
+        })
+        .collect();
+
+    // Capture each PID's start time so later signals never hit a PID that was
+    // reused after the original process exited.
+    let starts: Vec<Option<u64>> = pids
+        .iter()
+        .map(|&pid| {
+            sysprims_proc::get_process(pid)
+                .ok()
+                .and_then(|info| info.start_time_unix_ms)
+        })
+        .collect();
+
+    let signaled = signal_batch(&mut results, &starts, 0..pids.len(), config.signal);
+    let survivors = wait_batch(
+        &mut results,
+        &starts,
+        signaled,
+        Duration::from_millis(config.grace_timeout_ms),
+    );
+
+    for &i in &survivors {
+        results[i].escalated = true;
+        results[i].kill_signal = Some(config.kill_signal);
+    }
+    let signaled = signal_batch(&mut results, &starts, survivors, config.kill_signal);
+    for i in wait_batch(
+        &mut results,
+        &starts,
+        signaled,
+        Duration::from_millis(config.kill_timeout_ms),
+    ) {
+        results[i].timed_out = true;
+    }
+
+    Ok(results)
+}
+
+/// Send `signal` to each indexed result's PID; returns the indices signaled.
+///
+/// PIDs that no longer exist, or no longer match their captured start time,
+/// are marked exited; other failures become warnings.
+fn signal_batch(
+    results: &mut [TerminateTreeResult],
+    starts: &[Option<u64>],
+    indices: impl IntoIterator<Item = usize>,
+    signal: i32,
+) -> Vec<usize> {
+    let mut signaled = Vec::new();
+    for i in indices {
+        let result = &mut results[i];
+        if !tree_member_alive(result.pid, starts[i]) {
+            result.exited = true;
+            continue;
+        }
+        match sysprims_signal::kill(result.pid, signal) {
+            Ok(()) => signaled.push(i),
+            Err(SysprimsError::NotFound { .. }) => result.exited = true,
+            Err(e) => {
+                let code = match e {
+                    SysprimsError::PermissionDenied { .. } => WarningCode::PermissionDenied,
+                    _ => WarningCode::Other,
+                };
+                result.warnings.push(Warning::new(
+                    code,
+                    format!("Failed to send signal {signal}: {e}"),
+                ));
+            }
+        }
+    }
+    signaled
+}
+
+/// Wait for the indexed PIDs against one shared deadline; returns those still running.
+///
+/// A PID that outlives the wait but no longer matches its captured start time
+/// was reused, so the original process is reported as exited.
+fn wait_batch(
+    results: &mut [TerminateTreeResult],
+    starts: &[Option<u64>],
+    indices: Vec<usize>,
+    timeout: Duration,
+) -> Vec<usize> {
+    let deadline = std::time::Instant::now() + timeout;
+    let mut running = Vec::new();
+    for i in indices {
+        let result = &mut results[i];
+        let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+        match wait_pid(result.pid, remaining) {
+            Ok(wait) if wait.exited => {
+                result.exited = true;
+                result.exit_code = wait.exit_code;
+            }
+            Ok(_) if !tree_member_alive(result.pid, starts[i]) => result.exited = true,
+            Ok(_) => running.push(i),
+            Err(SysprimsError::NotFound { .. }) => result.exited = true,
+            Err(e) => {
+                result.warnings.push(Warning::new(
+                    WarningCode::PartialVisibility,
+                    format!("Failed to confirm exit: {e}"),
+                ));
+                running.push(i);
+            }
+        }
+    }
+    running
+}
+
+/// Outcome of timeout execution.
+#[derive(Debug)]
+pub enum TimeoutOutcome {
+    /// Command completed within timeout.
+    Completed {
+        /// Exit status of the child process.
+        exit_status: ExitStatus,
+
+        /// Non-fatal issues (see [`TimeoutOutcome::warnings`]).
+        warnings: Vec<Warning>,
+    },
+
+    /// Command timed out and was killed.
+    TimedOut {
+        /// Signal that was sent to terminate the process.
         signal_sent: i32,
 
         /// Whether escalation to SIGKILL occurred.
@@ -497,12 +1503,207 @@ pub enum TimeoutOutcome {
 
         /// Whether tree-kill was reliable.
         ///
-        /// `Guaranteed` if process group/Job Object worked.
-        /// `BestEffort` if only the direct child was killed.
+        /// `Guaranteed` if process group/Job Object worked, or if the
+        /// post-kill check found no survivors. `BestEffort` if only the
+        /// direct child was killed and survivors could not be ruled out.
+        tree_kill_reliability: TreeKillReliability,
+
+        /// Descendants of the child still running after the kill (sorted).
+        ///
+        /// The child's descendants are recorded around the first signal
+        /// (right after it in group mode, just before it otherwise) and
+        /// re-checked once the kill completes. Empty when all are gone or
+        /// when the tree could not be enumerated.
+        survivors: Vec<u32>,
+
+        /// Non-fatal issues (see [`TimeoutOutcome::warnings`]).
+        warnings: Vec<Warning>,
+    },
+
+    /// The stop token in [`TimeoutConfig::cancel`] was set and the command
+    /// was killed.
+    Cancelled {
+        /// Signal that was sent to terminate the process.
+        signal_sent: i32,
+
+        /// Whether escalation to SIGKILL occurred.
+        escalated: bool,
+
+        /// Whether tree-kill was reliable.
         tree_kill_reliability: TreeKillReliability,
+
+        /// Descendants still running after the kill (see
+        /// [`TimeoutOutcome::TimedOut`]).
+        survivors: Vec<u32>,
+
+        /// Non-fatal issues (see [`TimeoutOutcome::warnings`]).
+        warnings: Vec<Warning>,
     },
 }
 
+impl TimeoutOutcome {
+    /// Exit code a `timeout`-style frontend should return for this outcome.
+    ///
+    /// Follows GNU `timeout`: a killed command (timed out or cancelled) gives
+    /// [`EXIT_TIMED_OUT`], or with `preserve_status` `128 + signal`, where the
+    /// signal is `SIGKILL` if escalation occurred. A completed command gives
+    /// 0, or with `preserve_status` its own exit code (0 if it has none).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use sysprims_timeout::{run_with_timeout, TimeoutConfig};
+    ///
+    /// let outcome =
+    ///     run_with_timeout("sleep", &["60"], Duration::from_secs(1), TimeoutConfig::default())
+    ///         .unwrap();
+    /// std::process::exit(outcome.exit_code(false)); // 124
+    /// ```
+    pub fn exit_code(&self, preserve_status: bool) -> i32 {
+        match self {
+            TimeoutOutcome::Completed { exit_status, .. } => {
+                if preserve_status {
+                    exit_status.code().unwrap_or(0)
+                } else {
+                    0
+                }
+            }
+            TimeoutOutcome::TimedOut {
+                signal_sent,
+                escalated,
+                ..
+            }
+            | TimeoutOutcome::Cancelled {
+                signal_sent,
+                escalated,
+                ..
+            } => {
+                if !preserve_status {
+                    EXIT_TIMED_OUT
+                } else if *escalated {
+                    128 + SIGKILL
+                } else {
+                    128 + signal_sent
+                }
+            }
+        }
+    }
+
+    /// Non-fatal issues from the run, such as variables left undefined by
+    /// [`TimeoutConfig::expand_env`].
+    pub fn warnings(&self) -> &[Warning] {
+        match self {
+            TimeoutOutcome::Completed { warnings, .. }
+            | TimeoutOutcome::TimedOut { warnings, .. }
+            | TimeoutOutcome::Cancelled { warnings, .. } => warnings,
+        }
+    }
+
+    fn add_warnings(mut self, extra: Vec<Warning>) -> Self {
+        match &mut self {
+            TimeoutOutcome::Completed { warnings, .. }
+            | TimeoutOutcome::TimedOut { warnings, .. }
+            | TimeoutOutcome::Cancelled { warnings, .. } => warnings.extend(extra),
+        }
+        self
+    }
+
+    /// Outcome for a tree killed on timeout, or on cancellation.
+    ///
+    /// `members` are the child's descendants captured before the kill
+    /// ([`capture_tree_members`]); when all of them are gone, reliability is
+    /// upgraded to `Guaranteed`.
+    fn killed(
+        cancelled: bool,
+        signal_sent: i32,
+        escalated: bool,
+        mut tree_kill_reliability: TreeKillReliability,
+        members: Option<Vec<(u32, Option<u64>)>>,
+    ) -> Self {
+        let survivors = match members {
+            Some(members) => {
+                let survivors = surviving_members(members);
+                if survivors.is_empty() {
+                    tree_kill_reliability = TreeKillReliability::Guaranteed;
+                }
+                survivors
+            }
+            None => Vec::new(),
+        };
+        if cancelled {
+            TimeoutOutcome::Cancelled {
+                signal_sent,
+                escalated,
+                tree_kill_reliability,
+                survivors,
+                warnings: Vec::new(),
+            }
+        } else {
+            TimeoutOutcome::TimedOut {
+                signal_sent,
+                escalated,
+                tree_kill_reliability,
+                survivors,
+                warnings: Vec::new(),
+            }
+        }
+    }
+}
+
+/// How long [`surviving_members`] lets killed processes disappear.
+const SURVIVOR_SETTLE: Duration = Duration::from_millis(100);
+
+/// Unfiltered, unbounded descendants walk from `pid`.
+fn whole_tree(pid: u32) -> sysprims_proc::DescendantsConfig {
+    sysprims_proc::DescendantsConfig {
+        root_pid: pid,
+        max_levels: None,
+        filter: None,
+        cpu_mode: sysprims_proc::CpuMode::Lifetime,
+        sample_duration: None,
+        poll: None,
+        deadline: None,
+    }
+}
+
+/// Record `pid`'s descendants as `(pid, start time)` for the survivor check.
+///
+/// Returns `None` if the tree cannot be enumerated, so no survivor check is
+/// made and reliability is left as is.
+pub(crate) fn capture_tree_members(pid: u32) -> Option<Vec<(u32, Option<u64>)>> {
+    let tree = sysprims_proc::descendants_with_config_and_options(
+        whole_tree(pid),
+        sysprims_proc::ProcessOptions::default().without_usernames(),
+    )
+    .ok()?;
+    Some(
+        tree.levels
+            .iter()
+            .flat_map(|level| level.processes.iter())
+            .map(|p| (p.pid, p.start_time_unix_ms))
+            .collect(),
+    )
+}
+
+/// PIDs from `members` still alive after the kill, sorted.
+///
+/// Polls briefly (up to [`SURVIVOR_SETTLE`]) so processes that were
+/// signaled but not yet torn down are not reported.
+fn surviving_members(mut members: Vec<(u32, Option<u64>)>) -> Vec<u32> {
+    let deadline = Instant::now() + SURVIVOR_SETTLE;
+    loop {
+        members.retain(|&(p, start)| tree_member_alive(p, start));
+        if members.is_empty() || Instant::now() >= deadline {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    let mut survivors: Vec<u32> = members.into_iter().map(|(p, _)| p).collect();
+    survivors.sort_unstable();
+    survivors
+}
+
 /// Run a command with timeout.
 ///
 /// Spawns the command and waits for it to complete or timeout. If the command
@@ -517,8 +1718,11 @@ pub enum TimeoutOutcome {
 ///
 /// # Returns
 ///
-/// * `Ok(TimeoutOutcome::Completed { .. })` - Command finished within timeout
+/// * `Ok(TimeoutOutcome::Completed { .. })` - Command finished within timeout;
+///   `exit_status` is the child's real status since the caller is its parent
 /// * `Ok(TimeoutOutcome::TimedOut { .. })` - Command was killed due to timeout
+/// * `Ok(TimeoutOutcome::Cancelled { .. })` - Command was killed because
+///   [`TimeoutConfig::cancel`] was set
 /// * `Err(SysprimsError)` - Failed to spawn or fatal error
 ///
 /// # Examples
@@ -541,6 +1745,17 @@ pub fn run_with_timeout(
     timeout: Duration,
     config: TimeoutConfig,
 ) -> SysprimsResult<TimeoutOutcome> {
+    if config.expand_env {
+        let (command, args, warnings) = expand_command_env(command, args, &config);
+        let arg_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+        let config = TimeoutConfig {
+            expand_env: false,
+            ..config
+        };
+        return run_with_timeout(&command, &arg_refs, timeout, config)
+            .map(|outcome| outcome.add_warnings(warnings));
+    }
+
     #[cfg(unix)]
     return unix::run_with_timeout_impl(command, args, timeout, &config);
 
@@ -548,6 +1763,131 @@ pub fn run_with_timeout(
     return windows::run_with_timeout_impl(command, args, timeout, &config);
 }
 
+/// Expand environment references in a command and its arguments.
+///
+/// Variables resolve against the environment the child will get. Returns a
+/// warning per undefined variable.
+fn expand_command_env(
+    command: &str,
+    args: &[&str],
+    config: &TimeoutConfig,
+) -> (String, Vec<String>, Vec<Warning>) {
+    let lookup = |name: &str| config.child_var(name)?.into_string().ok();
+    let mut undefined = Vec::new();
+    let command = expand_env_refs(command, &lookup, &mut undefined);
+    let args = args
+        .iter()
+        .map(|arg| expand_env_refs(arg, &lookup, &mut undefined))
+        .collect();
+    let warnings = undefined
+        .into_iter()
+        .map(|name| {
+            Warning::new(
+                WarningCode::Config,
+                format!("Environment variable {name} is not set; expanded to empty"),
+            )
+        })
+        .collect();
+    (command, args, warnings)
+}
+
+/// Handle to a child whose timeout is enforced by a background thread.
+///
+/// Returned by [`spawn_with_watchdog`]. Dropping the handle does not disarm
+/// the watchdog: the thread keeps running and still kills the tree when the
+/// timeout elapses.
+#[derive(Debug)]
+pub struct Watchdog {
+    /// PID of the spawned child.
+    pub pid: u32,
+
+    /// Process group of the child, when it leads its own group.
+    ///
+    /// `None` in `Foreground` mode and on Windows (where the Job Object is
+    /// internal to the watchdog).
+    pub pgid: Option<u32>,
+
+    disarmed: Arc<AtomicBool>,
+    thread: JoinHandle<SysprimsResult<TimeoutOutcome>>,
+    warnings: Vec<Warning>,
+}
+
+impl Watchdog {
+    /// Disarm the timeout; the child keeps running.
+    ///
+    /// [`wait`](Self::wait) then returns `Completed` once the child exits on
+    /// its own. Has no effect if the timeout has already fired.
+    pub fn cancel(&self) {
+        self.disarmed.store(true, Ordering::SeqCst);
+    }
+
+    /// Block until the child exits (or is killed) and return the outcome.
+    pub fn wait(self) -> SysprimsResult<TimeoutOutcome> {
+        self.thread
+            .join()
+            .unwrap_or_else(|_| Err(SysprimsError::internal("watchdog thread panicked")))
+            .map(|outcome| outcome.add_warnings(self.warnings))
+    }
+}
+
+/// Spawn a command and enforce `timeout` from a background thread.
+///
+/// Unlike [`run_with_timeout`], this returns as soon as the child is running,
+/// so the caller can interact with it (by PID or process group) while the
+/// watchdog enforces the deadline. Grouping, signals, and escalation follow
+/// `config` exactly as in [`run_with_timeout`].
+///
+/// # Errors
+///
+/// Spawn failures are returned directly; errors while waiting are returned
+/// from [`Watchdog::wait`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use sysprims_timeout::{spawn_with_watchdog, TimeoutConfig};
+///
+/// let watchdog =
+///     spawn_with_watchdog("sleep", &["60"], Duration::from_secs(5), TimeoutConfig::default())
+///         .unwrap();
+/// println!("child pid: {}", watchdog.pid);
+/// let outcome = watchdog.wait().unwrap();
+/// ```
+pub fn spawn_with_watchdog(
+    command: &str,
+    args: &[&str],
+    timeout: Duration,
+    config: TimeoutConfig,
+) -> SysprimsResult<Watchdog> {
+    let (timed, warnings) = if config.expand_env {
+        let (command, args, warnings) = expand_command_env(command, args, &config);
+        let arg_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+        (spawn_timed(&command, &arg_refs, &config)?, warnings)
+    } else {
+        (spawn_timed(command, args, &config)?, Vec::new())
+    };
+    let pid = timed.pid();
+    let pgid = timed.pgid();
+
+    let disarmed = Arc::new(AtomicBool::new(false));
+    let thread = std::thread::Builder::new()
+        .name(format!("sysprims-watchdog-{pid}"))
+        .spawn({
+            let disarmed = Arc::clone(&disarmed);
+            move || supervise(timed, timeout, &config, &disarmed)
+        })
+        .map_err(|e| SysprimsError::internal(format!("failed to start watchdog thread: {e}")))?;
+
+    Ok(Watchdog {
+        pid,
+        pgid,
+        disarmed,
+        thread,
+        warnings,
+    })
+}
+
 /// Run a command with timeout using default configuration.
 ///
 /// Equivalent to `run_with_timeout(command, args, timeout, TimeoutConfig::default())`.
@@ -557,6 +1897,7 @@ pub fn run_with_timeout(
 /// - Kill after: 10 seconds
 /// - Grouping: GroupByDefault
 /// - Preserve status: false
+/// - Expand env: false
 ///
 /// # Examples
 ///
@@ -592,6 +1933,48 @@ mod tests {
         assert_eq!(config.grouping, GroupingMode::GroupByDefault);
     }
 
+    #[test]
+    fn grouping_modes_report_group_creation() {
+        assert!(GroupingMode::GroupByDefault.creates_group());
+        assert!(GroupingMode::NewGroupForeground.creates_group());
+        assert!(!GroupingMode::Foreground.creates_group());
+    }
+
+    #[test]
+    fn outcome_exit_codes_follow_gnu_timeout() {
+        let killed = |escalated| TimeoutOutcome::TimedOut {
+            signal_sent: SIGTERM,
+            escalated,
+            tree_kill_reliability: TreeKillReliability::Guaranteed,
+            survivors: Vec::new(),
+            warnings: Vec::new(),
+        };
+        assert_eq!(killed(false).exit_code(false), EXIT_TIMED_OUT);
+        assert_eq!(killed(false).exit_code(true), 128 + SIGTERM);
+        assert_eq!(killed(true).exit_code(true), 128 + SIGKILL);
+        assert_eq!(killed(true).exit_code(false), EXIT_TIMED_OUT);
+
+        let cancelled = TimeoutOutcome::Cancelled {
+            signal_sent: SIGTERM,
+            escalated: false,
+            tree_kill_reliability: TreeKillReliability::Guaranteed,
+            survivors: Vec::new(),
+            warnings: Vec::new(),
+        };
+        assert_eq!(cancelled.exit_code(false), EXIT_TIMED_OUT);
+
+        #[cfg(unix)]
+        let status = Command::new("sh").args(["-c", "exit 3"]).status().unwrap();
+        #[cfg(windows)]
+        let status = Command::new("cmd").args(["/C", "exit 3"]).status().unwrap();
+        let completed = TimeoutOutcome::Completed {
+            exit_status: status,
+            warnings: Vec::new(),
+        };
+        assert_eq!(completed.exit_code(false), 0);
+        assert_eq!(completed.exit_code(true), 3);
+    }
+
     #[test]
     fn default_config_kill_after_is_10_seconds() {
         let config = TimeoutConfig::default();
@@ -604,6 +1987,352 @@ mod tests {
         assert!(!config.preserve_status);
     }
 
+    #[test]
+    fn spawn_in_group_returns_not_found_for_missing_command() {
+        let err = spawn_in_group(SpawnInGroupConfig {
+            argv: vec!["nonexistent_command_12345".to_string()],
+            cwd: None,
+            env: None,
+            expand_env: false,
+            clear_env: false,
+            env_passthrough: vec![],
+        })
+        .unwrap_err();
+        assert!(matches!(err, SysprimsError::NotFoundCommand { .. }));
+    }
+
+    fn expand(input: &str, undefined: &mut Vec<String>) -> String {
+        let lookup = |name: &str| match name {
+            "HOME" => Some("/home/svc".to_string()),
+            "PORT" => Some("8080".to_string()),
+            _ => None,
+        };
+        expand_env_refs(input, &lookup, undefined)
+    }
+
+    #[test]
+    fn expand_env_refs_handles_plain_and_braced_forms() {
+        let mut undefined = Vec::new();
+        assert_eq!(expand("$HOME/bin", &mut undefined), "/home/svc/bin");
+        assert_eq!(expand("--port=${PORT}x", &mut undefined), "--port=8080x");
+        assert_eq!(expand("no refs", &mut undefined), "no refs");
+        assert!(undefined.is_empty());
+    }
+
+    #[test]
+    fn expand_env_refs_keeps_invalid_references_literal() {
+        let mut undefined = Vec::new();
+        assert_eq!(expand("cost: $5", &mut undefined), "cost: $5");
+        assert_eq!(expand("${unterminated", &mut undefined), "${unterminated");
+        assert_eq!(expand("${}", &mut undefined), "${}");
+        assert_eq!(expand("trailing $", &mut undefined), "trailing $");
+        assert!(undefined.is_empty());
+    }
+
+    #[test]
+    fn expand_env_refs_records_undefined_once() {
+        let mut undefined = Vec::new();
+        assert_eq!(expand("[$MISSING][${MISSING}]", &mut undefined), "[][]");
+        assert_eq!(undefined, vec!["MISSING".to_string()]);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn run_with_timeout_expand_env_warns_on_undefined() {
+        let outcome = run_with_timeout(
+            "sh",
+            &["-c", "exit 0", "$SYSPRIMS_TEST_UNSET_67890"],
+            Duration::from_secs(10),
+            TimeoutConfig {
+                expand_env: true,
+                ..TimeoutConfig::default()
+            },
+        )
+        .unwrap();
+
+        assert!(matches!(outcome, TimeoutOutcome::Completed { .. }));
+        let warnings = outcome.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, WarningCode::Config);
+        assert!(warnings[0].message.contains("SYSPRIMS_TEST_UNSET_67890"));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn run_with_timeout_clear_env_keeps_only_passthrough() {
+        // HOME is dropped and PATH kept; expansion sees the same environment.
+        let outcome = run_with_timeout(
+            "sh",
+            &["-c", "test -z \"$HOME\" && test -n \"$PATH\"", "$HOME"],
+            Duration::from_secs(10),
+            TimeoutConfig {
+                expand_env: true,
+                clear_env: true,
+                env_passthrough: vec!["PATH".to_string()],
+                ..TimeoutConfig::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(outcome.exit_code(false), 0);
+        assert_eq!(outcome.warnings().len(), 1);
+        assert!(outcome.warnings()[0].message.contains("HOME"));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn spawn_in_group_expand_env_warns_on_undefined() {
+        let mut env = std::collections::BTreeMap::new();
+        env.insert("SYSPRIMS_TEST_EXPAND".to_string(), "0".to_string());
+
+        let result = spawn_in_group(SpawnInGroupConfig {
+            argv: vec![
+                "sh".to_string(),
+                "-c".to_string(),
+                "exit ${SYSPRIMS_TEST_EXPAND}$SYSPRIMS_TEST_UNSET_12345".to_string(),
+            ],
+            cwd: None,
+            env: Some(env),
+            expand_env: true,
+            clear_env: false,
+            env_passthrough: vec![],
+        })
+        .unwrap();
+
+        assert_eq!(
+            result.warnings,
+            vec![
+                "Environment variable SYSPRIMS_TEST_UNSET_12345 is not set; expanded to empty"
+                    .to_string()
+            ]
+        );
+        let waited = wait_pid(result.pid, Duration::from_secs(5)).unwrap();
+        assert_eq!(waited.exit_code, Some(0));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn spawn_in_group_clear_env_keeps_only_passthrough_and_overlay() {
+        let mut env = std::collections::BTreeMap::new();
+        env.insert("SYSPRIMS_TEST_OVERLAY".to_string(), "set".to_string());
+
+        // PATH is passed through, HOME is cleared, and the overlay is applied.
+        let result = spawn_in_group(SpawnInGroupConfig {
+            argv: vec![
+                "/bin/sh".to_string(),
+                "-c".to_string(),
+                r#"test -n "$PATH" && test -z "$HOME" && test "$SYSPRIMS_TEST_OVERLAY" = set"#
+                    .to_string(),
+            ],
+            cwd: None,
+            env: Some(env),
+            expand_env: false,
+            clear_env: true,
+            env_passthrough: vec!["PATH".to_string(), "SYSPRIMS_TEST_UNSET_12345".to_string()],
+        })
+        .unwrap();
+
+        let waited = wait_pid(result.pid, Duration::from_secs(5)).unwrap();
+        assert_eq!(waited.exit_code, Some(0));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn spawn_in_group_not_found_notes_child_path() {
+        let err = spawn_in_group(SpawnInGroupConfig {
+            argv: vec!["sysprims-test-no-such-command".to_string()],
+            cwd: None,
+            env: None,
+            expand_env: false,
+            clear_env: true,
+            env_passthrough: vec![],
+        })
+        .unwrap_err();
+        assert!(err.to_string().ends_with("(PATH is not set)"), "{err}");
+    }
+
+    #[test]
+    fn terminate_tree_config_default_is_valid() {
+        let warnings = TerminateTreeConfig::default().validate().unwrap();
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn terminate_tree_config_rejects_out_of_range_signals() {
+        for cfg in [
+            TerminateTreeConfig {
+                signal: 0,
+                ..TerminateTreeConfig::default()
+            },
+            TerminateTreeConfig {
+                kill_signal: max_signal() + 1,
+                ..TerminateTreeConfig::default()
+            },
+        ] {
+            let err = cfg.validate().unwrap_err();
+            assert!(matches!(err, SysprimsError::InvalidArgument { .. }));
+            assert!(err.to_string().contains("out of range"));
+        }
+    }
+
+    #[test]
+    fn terminate_tree_config_rejects_zero_grace_with_escalation() {
+        let cfg = TerminateTreeConfig {
+            grace_timeout_ms: 0,
+            ..TerminateTreeConfig::default()
+        };
+        let err = cfg.validate().unwrap_err();
+        assert!(matches!(err, SysprimsError::InvalidArgument { .. }));
+        assert!(err.to_string().contains("escalates immediately"));
+
+        // Zero grace is fine when there is no escalation to a different signal.
+        let same = TerminateTreeConfig {
+            grace_timeout_ms: 0,
+            kill_signal: SIGTERM,
+            ..TerminateTreeConfig::default()
+        };
+        assert!(same.validate().is_ok());
+
+        // Nor when the grace phase is skipped entirely.
+        let skip = TerminateTreeConfig {
+            grace_timeout_ms: 0,
+            skip_grace: true,
+            ..TerminateTreeConfig::default()
+        };
+        assert!(skip.validate().is_ok());
+    }
+
+    #[test]
+    fn terminate_tree_config_warns_on_long_grace() {
+        let cfg = TerminateTreeConfig {
+            grace_timeout_ms: GRACE_TIMEOUT_WARN_MS + 1,
+            ..TerminateTreeConfig::default()
+        };
+        let warnings = cfg.validate().unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, WarningCode::Config);
+        assert!(warnings[0].message.contains("likely a mistake"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn terminate_tree_rejects_invalid_config_before_signaling() {
+        let mut child = Command::new("sleep").arg("30").spawn().unwrap();
+
+        let cfg = TerminateTreeConfig {
+            grace_timeout_ms: 0,
+            ..TerminateTreeConfig::default()
+        };
+        let err = terminate_tree(child.id(), cfg).unwrap_err();
+        assert!(matches!(err, SysprimsError::InvalidArgument { .. }));
+        assert!(child.try_wait().unwrap().is_none(), "child was signaled");
+
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+
+    #[cfg(unix)]
+    fn spawn_group(script: &str) -> u32 {
+        spawn_in_group(SpawnInGroupConfig {
+            argv: vec!["sh".into(), "-c".into(), script.into()],
+            cwd: None,
+            env: None,
+            expand_env: false,
+            clear_env: false,
+            env_passthrough: vec![],
+        })
+        .unwrap()
+        .pgid
+        .unwrap()
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn wait_group_waits_for_orphaned_members() {
+        let pgid = spawn_group("sleep 0.3 & exit 7");
+        let start = Instant::now();
+        let result = wait_group(pgid, Duration::from_secs(10)).unwrap();
+        assert!(result.exited);
+        assert!(!result.timed_out);
+        assert_eq!(result.leader_exit_code, Some(7));
+        assert!(start.elapsed() >= Duration::from_millis(250));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn wait_group_times_out_and_rejects_pgid_zero() {
+        let pgid = spawn_group("sleep 30");
+        let result = wait_group(pgid, Duration::from_millis(100)).unwrap();
+        assert!(result.timed_out);
+        assert!(!result.exited);
+
+        sysprims_signal::killpg(pgid, SIGKILL).unwrap();
+        // An unbounded timeout must not overflow the deadline computation.
+        let result = wait_group(pgid, Duration::MAX).unwrap();
+        assert!(result.exited);
+        assert_eq!(result.leader_exit_code, None);
+        assert_eq!(
+            result.schema_id,
+            sysprims_core::schema::WAIT_GROUP_RESULT_V1
+        );
+
+        let err = wait_group(0, Duration::from_millis(1)).unwrap_err();
+        assert!(matches!(err, SysprimsError::InvalidArgument { .. }));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn terminate_tree_rejects_identity_mismatch_before_signaling() {
+        let mut child = Command::new("sleep").arg("30").spawn().unwrap();
+        let pid = child.id();
+
+        let cfg = TerminateTreeConfig {
+            require_exe_path: Some("/definitely/not/sleep".to_string()),
+            ..TerminateTreeConfig::default()
+        };
+        let err = terminate_tree(pid, cfg).unwrap_err();
+        assert!(matches!(err, SysprimsError::IdentityMismatch { pid: p, .. } if p == pid));
+        assert!(err.to_string().contains("PID identity mismatch"));
+        assert!(
+            sysprims_proc::get_process(pid).is_ok(),
+            "mismatch must not signal the process"
+        );
+
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn terminate_tree_skips_escalation_when_identity_changes() {
+        // Ignores SIGTERM, then execs into a different program during the
+        // grace period; SIG_IGN survives the exec.
+        let mut child = Command::new("sh")
+            .args(["-c", "trap '' TERM; sleep 0.3; exec sleep 30"])
+            .spawn()
+            .unwrap();
+        let pid = child.id();
+        std::thread::sleep(Duration::from_millis(50));
+        let info = sysprims_proc::get_process(pid).unwrap();
+
+        let cfg = TerminateTreeConfig {
+            grace_timeout_ms: 1_000,
+            kill_timeout_ms: 500,
+            require_exe_path: info.exe_path.clone(),
+            require_start_time_ms: info.start_time_unix_ms,
+            ..TerminateTreeConfig::default()
+        };
+        let result = terminate_tree(pid, cfg).unwrap();
+        assert!(!result.escalated);
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.code == WarningCode::PidReuseGuard && w.message.contains("exe_path")));
+
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+
     #[test]
     fn terminate_tree_rejects_pid_zero() {
         let err = terminate_tree(0, TerminateTreeConfig::default()).unwrap_err();
@@ -643,6 +2372,306 @@ mod tests {
         let _ = child.wait();
     }
 
+    #[test]
+    #[cfg(unix)]
+    fn terminate_tree_reports_exit_code_of_own_child() {
+        let mut child = Command::new("sh")
+            .args(["-c", "trap 'exit 3' TERM; while :; do sleep 0.05; done"])
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("Failed to spawn sh");
+
+        // Let the shell install its trap before signaling.
+        std::thread::sleep(Duration::from_millis(200));
+
+        let result = terminate_tree(
+            child.id(),
+            TerminateTreeConfig {
+                grace_timeout_ms: 5000,
+                kill_timeout_ms: 5000,
+                ..TerminateTreeConfig::default()
+            },
+        )
+        .expect("terminate_tree should succeed");
+
+        assert!(
+            result.exited,
+            "expected child to be exited, got: {result:?}"
+        );
+        assert_eq!(result.exit_code, Some(3), "got: {result:?}");
+
+        let _ = child.wait();
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn terminate_tree_skip_grace_kills_immediately() {
+        let terminators: [fn(u32, TerminateTreeConfig) -> SysprimsResult<TerminateTreeResult>; 2] =
+            [terminate_tree, terminate_tree_by_descendants];
+        for terminate in terminators {
+            // Ignores SIGTERM, so only the kill signal can stop it.
+            let mut child = Command::new("sh")
+                .args(["-c", "trap '' TERM; while :; do sleep 0.05; done"])
+                .stdin(Stdio::null())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .spawn()
+                .expect("Failed to spawn sh");
+            std::thread::sleep(Duration::from_millis(200));
+
+            let start = std::time::Instant::now();
+            let result = terminate(
+                child.id(),
+                TerminateTreeConfig {
+                    grace_timeout_ms: 60_000,
+                    kill_timeout_ms: 5000,
+                    skip_grace: true,
+                    ..TerminateTreeConfig::default()
+                },
+            )
+            .expect("terminate should succeed");
+
+            assert!(
+                start.elapsed() < Duration::from_secs(10),
+                "grace was waited"
+            );
+            assert!(result.exited, "got: {result:?}");
+            assert!(result.escalated);
+            assert_eq!(result.signal_sent, SIGKILL);
+            assert_eq!(result.kill_signal, Some(SIGKILL));
+
+            let _ = child.wait();
+        }
+    }
+
+    #[test]
+    fn terminate_tree_by_descendants_rejects_pid_zero() {
+        let err = terminate_tree_by_descendants(0, TerminateTreeConfig::default()).unwrap_err();
+        assert!(matches!(err, SysprimsError::InvalidArgument { .. }));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn terminate_tree_by_descendants_kills_spawned_tree() {
+        // SAFETY: We spawn this process tree ourselves and control its PIDs.
+        let mut child = Command::new("sh")
+            .args(["-c", "sleep 60 & wait"])
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("Failed to spawn sh process");
+        let pid = child.id();
+
+        // Wait for the background sleep to appear under the shell.
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        let grandchild = loop {
+            let children: Vec<u32> = sysprims_proc::snapshot()
+                .unwrap()
+                .processes
+                .into_iter()
+                .filter(|p| p.ppid == pid)
+                .map(|p| p.pid)
+                .collect();
+            if let Some(&c) = children.first() {
+                break c;
+            }
+            assert!(std::time::Instant::now() < deadline, "sleep never started");
+            std::thread::sleep(Duration::from_millis(20));
+        };
+
+        let result = terminate_tree_by_descendants(
+            pid,
+            TerminateTreeConfig {
+                grace_timeout_ms: 500,
+                kill_timeout_ms: 5000,
+                ..TerminateTreeConfig::default()
+            },
+        )
+        .expect("terminate_tree_by_descendants should succeed");
+
+        assert_eq!(result.tree_kill_reliability, "best_effort");
+        assert!(result.exited, "expected root to exit, got: {result:?}");
+        assert!(!tree_member_alive(grandchild, None));
+
+        let _ = child.wait();
+    }
+
+    #[test]
+    fn kill_and_wait_rejects_pid_zero() {
+        let err = kill_and_wait(0, SIGTERM, Duration::from_millis(10)).unwrap_err();
+        assert!(matches!(err, SysprimsError::InvalidArgument { .. }));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn kill_and_wait_observes_spawned_child_exit() {
+        // SAFETY: We spawn this process ourselves and control its PID.
+        let mut child = Command::new("sleep")
+            .arg("60")
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("Failed to spawn sleep process");
+
+        let pid = child.id();
+        let result = kill_and_wait(pid, SIGTERM, Duration::from_secs(5))
+            .expect("kill_and_wait should succeed");
+
+        assert_eq!(result.pid, pid);
+        assert!(result.exited, "expected child to exit, got: {result:?}");
+        assert!(!result.timed_out);
+
+        let _ = child.wait();
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn terminate_many_rejects_empty_and_duplicate_pids() {
+        let err = terminate_many(&[], TerminateTreeConfig::default()).unwrap_err();
+        assert!(matches!(err, SysprimsError::InvalidArgument { .. }));
+
+        // Duplicates are rejected before anything is signaled.
+        let mut child = Command::new("sleep").arg("30").spawn().unwrap();
+        let pid = child.id();
+        let err = terminate_many(&[pid, pid], TerminateTreeConfig::default()).unwrap_err();
+        assert!(matches!(err, SysprimsError::InvalidArgument { .. }));
+        assert!(err.to_string().contains("duplicate"));
+        assert!(child.try_wait().unwrap().is_none(), "child was signaled");
+
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn signal_batch_skips_pids_whose_start_time_changed() {
+        let mut child = Command::new("sleep").arg("30").spawn().unwrap();
+        let mut results = vec![TerminateTreeResult {
+            schema_id: TERMINATE_TREE_RESULT_V1,
+            timestamp: current_timestamp(),
+            platform: get_platform(),
+            pid: child.id(),
+            pgid: None,
+            signal_sent: SIGTERM,
+            kill_signal: None,
+            escalated: false,
+            exited: false,
+            timed_out: false,
+            exit_code: None,
+            tree_kill_reliability: "best_effort".to_string(),
+            warnings: Vec::new(),
+        }];
+
+        // A start time that cannot match stands in for a reused PID.
+        let signaled = signal_batch(&mut results, &[Some(1)], [0], SIGTERM);
+        assert!(signaled.is_empty());
+        assert!(results[0].exited);
+        assert!(child.try_wait().unwrap().is_none(), "child was signaled");
+
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn terminate_many_escalates_only_survivors() {
+        let mut polite = Command::new("sleep")
+            .arg("60")
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("Failed to spawn sleep process");
+        let mut stubborn = Command::new("sh")
+            .args(["-c", "trap '' TERM; while :; do sleep 1; done"])
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("Failed to spawn sh process");
+        // Give the shell time to install its TERM trap.
+        std::thread::sleep(Duration::from_millis(200));
+
+        let results = terminate_many(
+            &[polite.id(), stubborn.id()],
+            TerminateTreeConfig {
+                grace_timeout_ms: 300,
+                kill_timeout_ms: 5000,
+                ..TerminateTreeConfig::default()
+            },
+        )
+        .expect("terminate_many should succeed");
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].pid, polite.id());
+        assert!(results[0].exited, "got: {:?}", results[0]);
+        assert!(!results[0].escalated);
+        assert_eq!(results[1].pid, stubborn.id());
+        assert!(results[1].exited, "got: {:?}", results[1]);
+        assert!(results[1].escalated);
+        assert_eq!(results[1].kill_signal, Some(SIGKILL));
+
+        let _ = polite.wait();
+        let _ = stubborn.wait();
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn spawn_with_watchdog_returns_while_child_runs() {
+        let start = std::time::Instant::now();
+        let watchdog = spawn_with_watchdog(
+            "sleep",
+            &["60"],
+            Duration::from_millis(300),
+            TimeoutConfig::default(),
+        )
+        .expect("spawn should succeed");
+        assert!(start.elapsed() < Duration::from_millis(300));
+        assert_eq!(watchdog.pgid, Some(watchdog.pid));
+        assert!(sysprims_proc::get_process(watchdog.pid).is_ok());
+
+        let outcome = watchdog.wait().expect("wait should succeed");
+        assert!(
+            matches!(outcome, TimeoutOutcome::TimedOut { .. }),
+            "got: {outcome:?}"
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn spawn_with_watchdog_cancel_lets_child_finish() {
+        let watchdog = spawn_with_watchdog(
+            "sh",
+            &["-c", "sleep 0.5; exit 3"],
+            Duration::from_millis(100),
+            TimeoutConfig::default(),
+        )
+        .expect("spawn should succeed");
+        watchdog.cancel();
+
+        match watchdog.wait().expect("wait should succeed") {
+            TimeoutOutcome::Completed { exit_status, .. } => {
+                assert_eq!(exit_status.code(), Some(3))
+            }
+            other => panic!("expected Completed, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn spawn_with_watchdog_returns_not_found_for_missing_command() {
+        let result = spawn_with_watchdog(
+            "nonexistent_command_12345",
+            &[],
+            Duration::from_secs(1),
+            TimeoutConfig::default(),
+        );
+        assert!(matches!(result, Err(SysprimsError::NotFoundCommand { .. })));
+    }
+
     #[test]
     #[cfg(windows)]
     fn terminate_tree_kills_spawned_child() {
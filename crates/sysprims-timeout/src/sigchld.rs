@@ -0,0 +1,201 @@
+//! Process-wide SIGCHLD notification via the self-pipe trick.
+//!
+//! The signal handler only writes one byte to a non-blocking pipe (the only
+//! async-signal-safe thing it can usefully do). A listener thread drains the
+//! pipe and bumps a generation counter under a mutex, waking every waiter, so
+//! any number of threads can block on "some child changed state" at once.
+//!
+//! `signalfd` is deliberately not used on Linux: it requires SIGCHLD to be
+//! blocked in every thread of the process, which a library cannot enforce.
+//!
+//! The handler is installed lazily on first use and never removed. Any handler
+//! present at that time is called after ours, so an application's own SIGCHLD
+//! handling keeps working.
+
+use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::{Condvar, Mutex, OnceLock};
+use std::time::Duration;
+
+use libc::{c_int, c_void, siginfo_t};
+
+/// Write end of the self-pipe, read by the signal handler (-1 until installed).
+static WRITE_FD: AtomicI32 = AtomicI32::new(-1);
+
+/// Disposition replaced by our handler, chained after it.
+static PREVIOUS: OnceLock<libc::sigaction> = OnceLock::new();
+
+static NOTIFIER: OnceLock<Option<Notifier>> = OnceLock::new();
+
+/// Wakes waiters whenever SIGCHLD is delivered to the process.
+pub(crate) struct Notifier {
+    generation: Mutex<u64>,
+    changed: Condvar,
+}
+
+impl Notifier {
+    /// Number of SIGCHLD deliveries observed so far.
+    ///
+    /// Read this before checking child state, then pass it to [`Self::wait_past`]
+    /// so a signal arriving in between is not missed.
+    pub(crate) fn generation(&self) -> u64 {
+        *self.generation.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Block until a SIGCHLD newer than `seen` arrives or `timeout` elapses.
+    pub(crate) fn wait_past(&self, seen: u64, timeout: Duration) {
+        let guard = self.generation.lock().unwrap_or_else(|e| e.into_inner());
+        let _ = self
+            .changed
+            .wait_timeout_while(guard, timeout, |generation| *generation == seen);
+    }
+
+    fn bump(&self) {
+        let mut generation = self.generation.lock().unwrap_or_else(|e| e.into_inner());
+        *generation = generation.wrapping_add(1);
+        self.changed.notify_all();
+    }
+}
+
+/// The process-wide notifier, installing the SIGCHLD handler on first call.
+///
+/// Returns `None` when SIGCHLD is ignored (`SIG_IGN`: the kernel reaps
+/// children itself, and installing a handler would change that) or when the
+/// pipe, listener thread or handler could not be set up.
+pub(crate) fn notifier() -> Option<&'static Notifier> {
+    NOTIFIER.get_or_init(install).as_ref()
+}
+
+fn install() -> Option<Notifier> {
+    // SAFETY: querying the current disposition with a null new action only
+    // writes to `previous`.
+    let previous = unsafe {
+        let mut previous: libc::sigaction = std::mem::zeroed();
+        if libc::sigaction(libc::SIGCHLD, std::ptr::null(), &mut previous) != 0 {
+            return None;
+        }
+        previous
+    };
+    if previous.sa_sigaction == libc::SIG_IGN {
+        return None;
+    }
+
+    let (read_fd, write_fd) = self_pipe()?;
+
+    let spawned = std::thread::Builder::new()
+        .name("sysprims-sigchld".to_string())
+        .spawn(move || listen(read_fd));
+    if spawned.is_err() {
+        // SAFETY: both descriptors were created above and are not shared yet.
+        unsafe {
+            libc::close(read_fd);
+            libc::close(write_fd);
+        }
+        return None;
+    }
+
+    let _ = PREVIOUS.set(previous);
+    WRITE_FD.store(write_fd, Ordering::SeqCst);
+
+    // SAFETY: `on_sigchld` only performs async-signal-safe operations; the
+    // flags keep the previous handler's SA_NOCLDSTOP/SA_NOCLDWAIT semantics.
+    let installed = unsafe {
+        let mut action: libc::sigaction = std::mem::zeroed();
+        action.sa_sigaction = on_sigchld as *const () as libc::sighandler_t;
+        action.sa_flags = libc::SA_SIGINFO
+            | libc::SA_RESTART
+            | (previous.sa_flags & (libc::SA_NOCLDSTOP | libc::SA_NOCLDWAIT));
+        libc::sigemptyset(&mut action.sa_mask);
+        libc::sigaction(libc::SIGCHLD, &action, std::ptr::null_mut()) == 0
+    };
+    if !installed {
+        // The listener stays parked on a pipe nobody writes to; harmless.
+        WRITE_FD.store(-1, Ordering::SeqCst);
+        return None;
+    }
+
+    Some(Notifier {
+        generation: Mutex::new(0),
+        changed: Condvar::new(),
+    })
+}
+
+/// Create the self-pipe: close-on-exec both ends, non-blocking write end.
+fn self_pipe() -> Option<(c_int, c_int)> {
+    let mut fds = [0 as c_int; 2];
+    // SAFETY: `fds` is a valid two-element buffer for pipe(2); fcntl only
+    // touches the descriptors it returned.
+    unsafe {
+        if libc::pipe(fds.as_mut_ptr()) != 0 {
+            return None;
+        }
+        for fd in fds {
+            libc::fcntl(fd, libc::F_SETFD, libc::FD_CLOEXEC);
+        }
+        let flags = libc::fcntl(fds[1], libc::F_GETFL);
+        libc::fcntl(fds[1], libc::F_SETFL, flags | libc::O_NONBLOCK);
+    }
+    Some((fds[0], fds[1]))
+}
+
+/// Listener thread body: drain the pipe and wake waiters once per read.
+fn listen(read_fd: c_int) {
+    let mut buf = [0u8; 64];
+    loop {
+        // SAFETY: `buf` is valid for `buf.len()` bytes.
+        let n = unsafe { libc::read(read_fd, buf.as_mut_ptr().cast(), buf.len()) };
+        if n > 0 {
+            if let Some(Some(notifier)) = NOTIFIER.get() {
+                notifier.bump();
+            }
+        } else if n == 0
+            || std::io::Error::last_os_error().kind() != std::io::ErrorKind::Interrupted
+        {
+            return;
+        }
+    }
+}
+
+extern "C" fn on_sigchld(signal: c_int, info: *mut siginfo_t, context: *mut c_void) {
+    // SAFETY: write(2) is async-signal-safe. A full pipe (EAGAIN) already has
+    // a wakeup pending, so the result is ignored. errno is restored so the
+    // interrupted code does not observe our failure.
+    unsafe {
+        let errno = errno_location();
+        let saved = *errno;
+        let fd = WRITE_FD.load(Ordering::Relaxed);
+        if fd >= 0 {
+            let byte = 1u8;
+            libc::write(fd, (&byte as *const u8).cast(), 1);
+        }
+        *errno = saved;
+    }
+
+    let Some(previous) = PREVIOUS.get() else {
+        return;
+    };
+    let handler = previous.sa_sigaction;
+    if handler == libc::SIG_DFL || handler == libc::SIG_IGN {
+        return;
+    }
+    // SAFETY: the previous disposition was a real handler; SA_SIGINFO tells
+    // which of the two C signatures it was registered with.
+    unsafe {
+        if previous.sa_flags & libc::SA_SIGINFO != 0 {
+            let f: extern "C" fn(c_int, *mut siginfo_t, *mut c_void) = std::mem::transmute(handler);
+            f(signal, info, context);
+        } else {
+            let f: extern "C" fn(c_int) = std::mem::transmute(handler);
+            f(signal);
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+unsafe fn errno_location() -> *mut c_int {
+    libc::__errno_location()
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+unsafe fn errno_location() -> *mut c_int {
+    libc::__error()
+}
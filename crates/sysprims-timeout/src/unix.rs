@@ -5,15 +5,16 @@
 
 use std::os::unix::process::CommandExt;
 use std::process::{Child, Command};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
 
 use libc::{killpg, SIGKILL};
-use sysprims_core::{SysprimsError, SysprimsResult};
+use sysprims_core::{SysprimsError, SysprimsResult, Warning, WarningCode};
 
 use crate::{GroupingMode, TimeoutConfig, TimeoutOutcome, TreeKillReliability};
-use crate::{SpawnInGroupConfig, SpawnInGroupResult};
+use crate::{SpawnInGroupConfig, SpawnInGroupResult, WaitGroupResult};
 use sysprims_core::get_platform;
-use sysprims_core::schema::SPAWN_IN_GROUP_RESULT_V1;
+use sysprims_core::schema::{SPAWN_IN_GROUP_RESULT_V1, WAIT_GROUP_RESULT_V1};
 
 /// Polling interval for checking if child has exited.
 const POLL_INTERVAL: Duration = Duration::from_millis(10);
@@ -37,7 +38,10 @@ pub fn spawn_in_group_impl(config: SpawnInGroupConfig) -> SysprimsResult<SpawnIn
         }
     }
 
-    if let Some(env) = config.env {
+    if config.clear_env {
+        cmd.env_clear();
+    }
+    if let Some(env) = &config.env {
         for (k, v) in env {
             cmd.env(k, v);
         }
@@ -54,13 +58,7 @@ pub fn spawn_in_group_impl(config: SpawnInGroupConfig) -> SysprimsResult<SpawnIn
     }
 
     let child = cmd.spawn().map_err(|e| {
-        if e.kind() == std::io::ErrorKind::NotFound {
-            SysprimsError::not_found_command(command)
-        } else if e.kind() == std::io::ErrorKind::PermissionDenied {
-            SysprimsError::permission_denied_command(command)
-        } else {
-            SysprimsError::spawn_failed(command, e.to_string())
-        }
+        SysprimsError::from_spawn_error_in(command, &e, config.child_path().as_deref())
     })?;
 
     let pid = child.id();
@@ -76,42 +74,187 @@ pub fn spawn_in_group_impl(config: SpawnInGroupConfig) -> SysprimsResult<SpawnIn
     })
 }
 
+/// Poll interval once SIGCHLD can no longer report progress (see [`wait_group_impl`]).
+const GROUP_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+pub fn wait_group_impl(pgid: u32, timeout: Duration) -> SysprimsResult<WaitGroupResult> {
+    // An unrepresentable deadline (e.g. Duration::MAX) means wait forever.
+    let deadline = Instant::now().checked_add(timeout);
+    let notifier = crate::sigchld::notifier();
+    let mut result = WaitGroupResult {
+        schema_id: WAIT_GROUP_RESULT_V1,
+        pgid,
+        exited: false,
+        timed_out: false,
+        leader_exit_code: None,
+        warnings: Vec::new(),
+    };
+    if notifier.is_none() {
+        result.warnings.push(Warning::new(
+            WarningCode::Degraded,
+            "SIGCHLD notification unavailable; polling the group instead",
+        ));
+    }
+
+    // Whether SIGCHLD still tells us something: only while the leader is our
+    // running child. Grandchildren are reparented, so their exits never reach us.
+    let mut leader_is_child = true;
+    loop {
+        let seen = notifier.map(|n| n.generation());
+
+        if leader_is_child {
+            match reap_leader(pgid) {
+                LeaderState::Running => {}
+                LeaderState::Reaped(code) => {
+                    result.leader_exit_code = code;
+                    leader_is_child = false;
+                }
+                LeaderState::NotChild => leader_is_child = false,
+            }
+        }
+
+        if !group_alive(pgid) {
+            result.exited = true;
+            return Ok(result);
+        }
+
+        let remaining = match deadline {
+            Some(deadline) => {
+                let now = Instant::now();
+                if now >= deadline {
+                    result.timed_out = true;
+                    return Ok(result);
+                }
+                deadline - now
+            }
+            None => Duration::MAX,
+        };
+
+        match (notifier, seen) {
+            (Some(notifier), Some(seen)) if leader_is_child => notifier.wait_past(seen, remaining),
+            _ => std::thread::sleep(remaining.min(GROUP_POLL_INTERVAL)),
+        }
+    }
+}
+
+enum LeaderState {
+    Running,
+    /// Reaped; exit code when it exited normally (`None` if killed by a signal).
+    Reaped(Option<i32>),
+    NotChild,
+}
+
+fn reap_leader(pgid: u32) -> LeaderState {
+    let mut status: libc::c_int = 0;
+    // SAFETY: waitpid with WNOHANG on a validated positive PID; `status` is a
+    // valid out pointer.
+    let rc = unsafe { libc::waitpid(pgid as libc::pid_t, &mut status, libc::WNOHANG) };
+    if rc > 0 {
+        let code = libc::WIFEXITED(status).then(|| libc::WEXITSTATUS(status));
+        LeaderState::Reaped(code)
+    } else if rc == 0 || std::io::Error::last_os_error().raw_os_error() == Some(libc::EINTR) {
+        LeaderState::Running
+    } else {
+        LeaderState::NotChild
+    }
+}
+
+/// Whether any process (including zombies) is still in group `pgid`.
+fn group_alive(pgid: u32) -> bool {
+    // SAFETY: signal 0 only checks existence and permission.
+    let rc = unsafe { libc::kill(-(pgid as libc::pid_t), 0) };
+    rc == 0 || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
 pub fn run_with_timeout_impl(
     command: &str,
     args: &[&str],
     timeout: Duration,
     config: &TimeoutConfig,
 ) -> SysprimsResult<TimeoutOutcome> {
+    let timed = spawn_timed(command, args, config)?;
+    supervise(timed, timeout, config, &AtomicBool::new(false))
+}
+
+/// A spawned child plus what [`supervise`] needs to kill its tree.
+pub(crate) struct TimedChild {
+    child: Child,
+    use_process_group: bool,
+    /// Set while the child holds the terminal; reclaims it when dropped.
+    terminal: Option<TerminalGuard>,
+}
+
+impl TimedChild {
+    pub(crate) fn pid(&self) -> u32 {
+        self.child.id()
+    }
+
+    /// Process group ID, when the child leads its own group.
+    pub(crate) fn pgid(&self) -> Option<u32> {
+        self.use_process_group.then(|| self.child.id())
+    }
+}
+
+/// Spawn `command` according to `config.grouping`.
+pub(crate) fn spawn_timed(
+    command: &str,
+    args: &[&str],
+    config: &TimeoutConfig,
+) -> SysprimsResult<TimedChild> {
     let mut cmd = Command::new(command);
     cmd.args(args);
+    config.apply_env(&mut cmd);
 
-    // Set up process group if GroupByDefault
-    let use_process_group = config.grouping == GroupingMode::GroupByDefault;
+    // Set up process group unless running in Foreground mode
+    let use_process_group = config.grouping.creates_group();
+    let take_terminal = config.grouping == GroupingMode::NewGroupForeground && owns_terminal();
 
     if use_process_group {
         // SAFETY: setpgid(0, 0) creates a new process group with the child's
         // PID as the PGID. This is safe and standard practice for job control.
+        // tcsetpgrp/signal are async-signal-safe.
         unsafe {
-            cmd.pre_exec(|| {
+            cmd.pre_exec(move || {
                 if libc::setpgid(0, 0) != 0 {
                     return Err(std::io::Error::last_os_error());
                 }
+                if take_terminal {
+                    give_terminal_to(libc::getpgrp());
+                }
                 Ok(())
             });
         }
     }
 
     // Spawn the child process
-    let mut child = cmd.spawn().map_err(|e| {
-        if e.kind() == std::io::ErrorKind::NotFound {
-            SysprimsError::not_found_command(command)
-        } else if e.kind() == std::io::ErrorKind::PermissionDenied {
-            SysprimsError::permission_denied_command(command)
-        } else {
-            SysprimsError::spawn_failed(command, e.to_string())
-        }
+    let child = cmd.spawn().map_err(|e| {
+        SysprimsError::from_spawn_error_in(command, &e, config.child_path().as_deref())
     })?;
 
+    Ok(TimedChild {
+        child,
+        use_process_group,
+        terminal: take_terminal.then_some(TerminalGuard),
+    })
+}
+
+/// Wait for `timed` to exit, killing its tree once `timeout` elapses or
+/// `config.cancel` is set.
+///
+/// While `disarmed` is set the timeout is not enforced; cancellation still is.
+pub(crate) fn supervise(
+    timed: TimedChild,
+    timeout: Duration,
+    config: &TimeoutConfig,
+    disarmed: &AtomicBool,
+) -> SysprimsResult<TimeoutOutcome> {
+    let TimedChild {
+        mut child,
+        use_process_group,
+        // Held until return so every exit path, including wait errors, hands
+        // the terminal back.
+        terminal: _terminal,
+    } = timed;
     let child_pid = child.id() as i32;
     let start = Instant::now();
 
@@ -122,13 +265,14 @@ pub fn run_with_timeout_impl(
                 // Child exited within timeout
                 return Ok(TimeoutOutcome::Completed {
                     exit_status: status,
+                    warnings: Vec::new(),
                 });
             }
             Ok(None) => {
-                // Still running - check timeout
-                if start.elapsed() >= timeout {
-                    // Timeout! Kill the tree
-                    return kill_tree(child_pid, &mut child, config, use_process_group);
+                // Still running - check cancellation and timeout
+                let cancelled = is_cancelled(config);
+                if cancelled || (!disarmed.load(Ordering::SeqCst) && start.elapsed() >= timeout) {
+                    return kill_tree(child_pid, &mut child, config, use_process_group, cancelled);
                 }
                 std::thread::sleep(POLL_INTERVAL);
             }
@@ -142,6 +286,57 @@ pub fn run_with_timeout_impl(
     }
 }
 
+/// Whether the stop token in `config.cancel` has been set.
+fn is_cancelled(config: &TimeoutConfig) -> bool {
+    config
+        .cancel
+        .as_ref()
+        .is_some_and(|cancel| cancel.load(Ordering::SeqCst))
+}
+
+/// Whether the caller's process group is the terminal's foreground group.
+///
+/// We only hand the terminal to the child (and take it back afterwards) when
+/// we hold it ourselves; a backgrounded caller must not steal the terminal.
+fn owns_terminal() -> bool {
+    // SAFETY: isatty/tcgetpgrp/getpgrp only query state for stdin and self.
+    unsafe {
+        libc::isatty(libc::STDIN_FILENO) == 1
+            && libc::tcgetpgrp(libc::STDIN_FILENO) == libc::getpgrp()
+    }
+}
+
+/// Make `pgid` the foreground process group of the controlling terminal.
+///
+/// Best-effort: does nothing when stdin is not a TTY. SIGTTOU is ignored for
+/// the duration of the call because a background group calling `tcsetpgrp()`
+/// would otherwise be stopped.
+///
+/// Must remain async-signal-safe (it runs in `pre_exec`).
+fn give_terminal_to(pgid: libc::pid_t) {
+    // SAFETY: isatty/signal/tcsetpgrp are async-signal-safe and operate only
+    // on stdin and the calling process's signal disposition.
+    unsafe {
+        if libc::isatty(libc::STDIN_FILENO) != 1 {
+            return;
+        }
+        let prev = libc::signal(libc::SIGTTOU, libc::SIG_IGN);
+        let _ = libc::tcsetpgrp(libc::STDIN_FILENO, pgid);
+        libc::signal(libc::SIGTTOU, prev);
+    }
+}
+
+/// Restores the caller's process group as the terminal foreground group
+/// when dropped.
+struct TerminalGuard;
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        // SAFETY: getpgrp has no preconditions.
+        give_terminal_to(unsafe { libc::getpgrp() });
+    }
+}
+
 /// Kill the process tree and wait for exit.
 ///
 /// If using process group, sends signal to entire group via `killpg()`.
@@ -156,6 +351,7 @@ fn kill_tree(
     child: &mut Child,
     config: &TimeoutConfig,
     use_process_group: bool,
+    cancelled: bool,
 ) -> SysprimsResult<TimeoutOutcome> {
     let reliability = if use_process_group {
         TreeKillReliability::Guaranteed
@@ -164,17 +360,24 @@ fn kill_tree(
     };
 
     // Send initial signal
-    if use_process_group {
+    let members = if use_process_group {
         // Child is process group leader, so pid == pgid
         // SAFETY: killpg is safe with valid pgid and signal
         unsafe {
             killpg(pid, config.signal);
         }
+        // Snapshot after the signal so the scan never delays delivery. The
+        // survivor check targets descendants that left the group, and the
+        // group signal does not reach those.
+        crate::capture_tree_members(pid as u32)
     } else {
-        // Foreground mode: signal direct child only
+        // Foreground mode: signal direct child only. Snapshot first, while
+        // its descendants are still parented to it.
+        let members = crate::capture_tree_members(pid as u32);
         // Use sysprims_signal for consistency
         let _ = sysprims_signal::kill(pid as u32, config.signal);
-    }
+        members
+    };
 
     // Wait for kill_after duration for graceful exit
     let escalation_deadline = Instant::now() + config.kill_after;
@@ -185,11 +388,13 @@ fn kill_tree(
             leader_exited = true;
             // For non-group mode, we can return early since we only care about the direct child
             if !use_process_group {
-                return Ok(TimeoutOutcome::TimedOut {
-                    signal_sent: config.signal,
-                    escalated: false,
-                    tree_kill_reliability: reliability,
-                });
+                return Ok(TimeoutOutcome::killed(
+                    cancelled,
+                    config.signal,
+                    false,
+                    reliability,
+                    members,
+                ));
             }
             // For group mode, continue waiting - other group members may still be alive
         }
@@ -213,11 +418,13 @@ fn kill_tree(
     // Reap the zombie (if not already reaped)
     let _ = child.wait();
 
-    Ok(TimeoutOutcome::TimedOut {
-        signal_sent: config.signal,
+    Ok(TimeoutOutcome::killed(
+        cancelled,
+        config.signal,
         escalated,
-        tree_kill_reliability: reliability,
-    })
+        reliability,
+        members,
+    ))
 }
 
 #[cfg(test)]
@@ -253,6 +460,38 @@ mod tests {
         assert!(matches!(result, TimeoutOutcome::TimedOut { .. }));
     }
 
+    #[test]
+    fn cancel_token_kills_before_timeout() {
+        let cancel = std::sync::Arc::new(AtomicBool::new(false));
+        let config = TimeoutConfig {
+            kill_after: Duration::from_millis(100),
+            cancel: Some(cancel.clone()),
+            ..Default::default()
+        };
+        let canceller = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(100));
+            cancel.store(true, Ordering::SeqCst);
+        });
+
+        let start = Instant::now();
+        let result =
+            run_with_timeout_impl("sleep", &["60"], Duration::from_secs(30), &config).unwrap();
+        canceller.join().unwrap();
+
+        assert!(start.elapsed() < Duration::from_secs(10));
+        if let TimeoutOutcome::Cancelled {
+            signal_sent,
+            tree_kill_reliability,
+            ..
+        } = result
+        {
+            assert_eq!(signal_sent, config.signal);
+            assert_eq!(tree_kill_reliability, TreeKillReliability::Guaranteed);
+        } else {
+            panic!("Expected cancellation, got: {result:?}");
+        }
+    }
+
     #[test]
     fn timeout_returns_not_found_for_missing_command() {
         let result = run_with_timeout_impl(
@@ -273,15 +512,100 @@ mod tests {
             ..Default::default()
         };
 
+        // Only the shell is signaled; its background sleep escapes.
+        let result = run_with_timeout_impl(
+            "sh",
+            &["-c", "sleep 60 & wait"],
+            Duration::from_millis(200),
+            &config,
+        )
+        .unwrap();
+
+        if let TimeoutOutcome::TimedOut {
+            tree_kill_reliability,
+            survivors,
+            ..
+        } = result
+        {
+            for &pid in &survivors {
+                let _ = sysprims_signal::force_kill(pid);
+            }
+            assert_eq!(tree_kill_reliability, TreeKillReliability::BestEffort);
+            assert_eq!(survivors.len(), 1, "survivors: {survivors:?}");
+        } else {
+            panic!("Expected timeout");
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn group_mode_reports_descendants_that_left_the_group() {
+        let config = TimeoutConfig {
+            kill_after: Duration::from_millis(100),
+            ..Default::default()
+        };
+
+        // The shell ignores SIGTERM, so it is still the escaped sleep's
+        // parent when the tree is recorded after the group signal.
+        let result = run_with_timeout_impl(
+            "sh",
+            &["-c", "trap '' TERM; setsid sleep 60 & wait"],
+            Duration::from_millis(300),
+            &config,
+        )
+        .unwrap();
+
+        if let TimeoutOutcome::TimedOut { survivors, .. } = result {
+            for &pid in &survivors {
+                let _ = sysprims_signal::force_kill(pid);
+            }
+            assert_eq!(survivors.len(), 1, "survivors: {survivors:?}");
+        } else {
+            panic!("Expected timeout");
+        }
+    }
+
+    #[test]
+    fn foreground_mode_without_survivors_is_guaranteed() {
+        let config = TimeoutConfig {
+            grouping: GroupingMode::Foreground,
+            kill_after: Duration::from_millis(100),
+            ..Default::default()
+        };
+
         let result =
             run_with_timeout_impl("sleep", &["60"], Duration::from_millis(100), &config).unwrap();
 
         if let TimeoutOutcome::TimedOut {
             tree_kill_reliability,
+            survivors,
             ..
         } = result
         {
-            assert_eq!(tree_kill_reliability, TreeKillReliability::BestEffort);
+            assert_eq!(tree_kill_reliability, TreeKillReliability::Guaranteed);
+            assert!(survivors.is_empty());
+        } else {
+            panic!("Expected timeout");
+        }
+    }
+
+    #[test]
+    fn new_group_foreground_mode_uses_process_group() {
+        let config = TimeoutConfig {
+            grouping: GroupingMode::NewGroupForeground,
+            kill_after: Duration::from_millis(100),
+            ..Default::default()
+        };
+
+        let result =
+            run_with_timeout_impl("sleep", &["60"], Duration::from_millis(100), &config).unwrap();
+
+        if let TimeoutOutcome::TimedOut {
+            tree_kill_reliability,
+            ..
+        } = result
+        {
+            assert_eq!(tree_kill_reliability, TreeKillReliability::Guaranteed);
         } else {
             panic!("Expected timeout");
         }
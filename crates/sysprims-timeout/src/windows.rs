@@ -6,6 +6,7 @@
 use std::os::windows::io::AsRawHandle;
 use std::process::{Child, Command};
 use std::ptr;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
 use std::{collections::HashMap, sync::Mutex};
 
@@ -23,8 +24,7 @@ use windows_sys::Win32::System::Threading::{
 use sysprims_core::{SysprimsError, SysprimsResult};
 
 use crate::{
-    GroupingMode, SpawnInGroupConfig, SpawnInGroupResult, TimeoutConfig, TimeoutOutcome,
-    TreeKillReliability,
+    SpawnInGroupConfig, SpawnInGroupResult, TimeoutConfig, TimeoutOutcome, TreeKillReliability,
 };
 use sysprims_core::get_platform;
 use sysprims_core::schema::SPAWN_IN_GROUP_RESULT_V1;
@@ -87,7 +87,37 @@ pub fn run_with_timeout_impl(
     timeout: Duration,
     config: &TimeoutConfig,
 ) -> SysprimsResult<TimeoutOutcome> {
-    let use_job_object = config.grouping == GroupingMode::GroupByDefault;
+    let timed = spawn_timed(command, args, config)?;
+    supervise(timed, timeout, config, &AtomicBool::new(false))
+}
+
+/// A spawned child plus what [`supervise`] needs to kill its tree.
+pub(crate) struct TimedChild {
+    child: Child,
+    job_handle: Option<HANDLE>,
+    reliability: TreeKillReliability,
+}
+
+impl TimedChild {
+    pub(crate) fn pid(&self) -> u32 {
+        self.child.id()
+    }
+
+    /// Windows has no process groups; the Job Object is internal.
+    pub(crate) fn pgid(&self) -> Option<u32> {
+        None
+    }
+}
+
+/// Spawn `command`, assigning it to a Job Object unless running in the foreground.
+pub(crate) fn spawn_timed(
+    command: &str,
+    args: &[&str],
+    config: &TimeoutConfig,
+) -> SysprimsResult<TimedChild> {
+    // NewGroupForeground has no terminal semantics on Windows; it falls back
+    // to the same Job Object handling as GroupByDefault.
+    let use_job_object = config.grouping.creates_group();
     let mut reliability = TreeKillReliability::Guaranteed;
 
     // Create Job Object if GroupByDefault
@@ -106,18 +136,15 @@ pub fn run_with_timeout_impl(
     };
 
     // Spawn the child process
-    let mut child = Command::new(command).args(args).spawn().map_err(|e| {
+    let mut cmd = Command::new(command);
+    cmd.args(args);
+    config.apply_env(&mut cmd);
+    let child = cmd.spawn().map_err(|e| {
         // Clean up job handle on error
         if let Some(job) = job_handle {
             unsafe { CloseHandle(job) };
         }
-        if e.kind() == std::io::ErrorKind::NotFound {
-            SysprimsError::not_found_command(command)
-        } else if e.kind() == std::io::ErrorKind::PermissionDenied {
-            SysprimsError::permission_denied_command(command)
-        } else {
-            SysprimsError::spawn_failed(command, e.to_string())
-        }
+        SysprimsError::from_spawn_error_in(command, &e, config.child_path().as_deref())
     })?;
 
     // Assign process to Job Object if available
@@ -132,6 +159,28 @@ pub fn run_with_timeout_impl(
         }
     }
 
+    Ok(TimedChild {
+        child,
+        job_handle,
+        reliability,
+    })
+}
+
+/// Wait for `timed` to exit, killing its tree once `timeout` elapses or
+/// `config.cancel` is set.
+///
+/// While `disarmed` is set the timeout is not enforced; cancellation still is.
+pub(crate) fn supervise(
+    timed: TimedChild,
+    timeout: Duration,
+    config: &TimeoutConfig,
+    disarmed: &AtomicBool,
+) -> SysprimsResult<TimeoutOutcome> {
+    let TimedChild {
+        mut child,
+        job_handle,
+        reliability,
+    } = timed;
     let start = Instant::now();
 
     // Wait loop with timeout
@@ -144,13 +193,17 @@ pub fn run_with_timeout_impl(
                 }
                 return Ok(TimeoutOutcome::Completed {
                     exit_status: status,
+                    warnings: Vec::new(),
                 });
             }
             Ok(None) => {
-                // Still running - check timeout
-                if start.elapsed() >= timeout {
-                    // Timeout! Kill the tree
-                    return kill_tree(&mut child, job_handle, config, reliability);
+                // Still running - check cancellation and timeout
+                let cancelled = config
+                    .cancel
+                    .as_ref()
+                    .is_some_and(|cancel| cancel.load(Ordering::SeqCst));
+                if cancelled || (!disarmed.load(Ordering::SeqCst) && start.elapsed() >= timeout) {
+                    return kill_tree(&mut child, job_handle, config, reliability, cancelled);
                 }
                 std::thread::sleep(POLL_INTERVAL);
             }
@@ -218,7 +271,10 @@ pub fn spawn_in_group_impl(config: SpawnInGroupConfig) -> SysprimsResult<SpawnIn
         }
     }
 
-    if let Some(env) = config.env {
+    if config.clear_env {
+        cmd.env_clear();
+    }
+    if let Some(env) = &config.env {
         for (k, v) in env {
             cmd.env(k, v);
         }
@@ -240,13 +296,7 @@ pub fn spawn_in_group_impl(config: SpawnInGroupConfig) -> SysprimsResult<SpawnIn
         if let Some(job) = job_handle {
             unsafe { CloseHandle(job) };
         }
-        if e.kind() == std::io::ErrorKind::NotFound {
-            SysprimsError::not_found_command(command)
-        } else if e.kind() == std::io::ErrorKind::PermissionDenied {
-            SysprimsError::permission_denied_command(command)
-        } else {
-            SysprimsError::spawn_failed(command, e.to_string())
-        }
+        SysprimsError::from_spawn_error_in(command, &e, config.child_path().as_deref())
     })?;
 
     let pid = child.id();
@@ -287,7 +337,10 @@ fn kill_tree(
     job_handle: Option<HANDLE>,
     config: &TimeoutConfig,
     reliability: TreeKillReliability,
+    cancelled: bool,
 ) -> SysprimsResult<TimeoutOutcome> {
+    let members = crate::capture_tree_members(child.id());
+
     if let Some(job) = job_handle {
         // Terminate all processes in the job
         // Exit code 1 is arbitrary; use sysprims-timeout CLI for nuanced codes
@@ -303,11 +356,14 @@ fn kill_tree(
     // Reap the child
     let _ = child.wait();
 
-    Ok(TimeoutOutcome::TimedOut {
-        signal_sent: config.signal,
-        escalated: false, // Windows doesn't have signal escalation
-        tree_kill_reliability: reliability,
-    })
+    // Windows doesn't have signal escalation
+    Ok(TimeoutOutcome::killed(
+        cancelled,
+        config.signal,
+        false,
+        reliability,
+        members,
+    ))
 }
 
 #[cfg(test)]
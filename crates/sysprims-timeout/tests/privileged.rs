@@ -186,6 +186,9 @@ mod privileged {
             TimeoutOutcome::Completed { .. } => {
                 panic!("Expected timeout, but command completed");
             }
+            TimeoutOutcome::Cancelled { .. } => {
+                panic!("Expected timeout, but command was cancelled");
+            }
         }
 
         // Wait for cleanup
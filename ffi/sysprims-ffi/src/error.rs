@@ -4,6 +4,7 @@
 //! in thread-local storage. Callers retrieve error details via:
 //! - `sysprims_last_error_code()` - Get error code
 //! - `sysprims_last_error()` - Get error message (must free with `sysprims_free_string`)
+//! - `sysprims_last_error_json()` - Get code, kind, message and context as JSON
 //! - `sysprims_clear_error()` - Clear error state
 
 use std::cell::RefCell;
@@ -101,6 +102,10 @@ impl From<&SysprimsError> for SysprimsErrorCode {
 struct ErrorState {
     code: SysprimsErrorCode,
     message: Option<String>,
+    /// Serialized `SysprimsError`, rendered when the error is set.
+    json: Option<String>,
+    /// Output schema mismatches from a successful call (`SYSPRIMS_VALIDATE_SCHEMA=1`).
+    schema_mismatches: Vec<String>,
 }
 
 impl Default for ErrorState {
@@ -108,10 +113,15 @@ impl Default for ErrorState {
         Self {
             code: SysprimsErrorCode::Ok,
             message: None,
+            json: None,
+            schema_mismatches: Vec::new(),
         }
     }
 }
 
+/// JSON reported by `sysprims_last_error_json()` when there is no error.
+const NO_ERROR_JSON: &str = r#"{"code":0,"kind":"ok","message":"","context":{}}"#;
+
 thread_local! {
     static LAST_ERROR: RefCell<ErrorState> = RefCell::new(ErrorState::default());
 }
@@ -122,15 +132,38 @@ pub(crate) fn set_error(err: &SysprimsError) {
         let mut state = state.borrow_mut();
         state.code = SysprimsErrorCode::from(err);
         state.message = Some(err.to_string());
+        // Fall back to the context-free shape so callers always get JSON.
+        state.json = Some(serde_json::to_string(err).unwrap_or_else(|_| {
+            serde_json::json!({
+                "code": err.error_code(),
+                "kind": err.kind(),
+                "message": err.to_string(),
+                "context": {},
+            })
+            .to_string()
+        }));
     });
 }
 
+/// Record output schema mismatches without failing the call.
+///
+/// They are reported by `sysprims_last_error_json()` under
+/// `context.schema_mismatches` until the error state is next cleared.
+pub(crate) fn record_schema_mismatches(mismatches: Vec<String>) {
+    if mismatches.is_empty() {
+        return;
+    }
+    LAST_ERROR.with(|state| state.borrow_mut().schema_mismatches.extend(mismatches));
+}
+
 /// Clear the thread-local error state.
 pub(crate) fn clear_error_state() {
     LAST_ERROR.with(|state| {
         let mut state = state.borrow_mut();
         state.code = SysprimsErrorCode::Ok;
         state.message = None;
+        state.json = None;
+        state.schema_mismatches.clear();
     });
 }
 
@@ -167,24 +200,71 @@ pub extern "C" fn sysprims_last_error_code() -> SysprimsErrorCode {
 /// Error state is thread-local. Each thread has its own error state.
 #[no_mangle]
 pub extern "C" fn sysprims_last_error() -> *mut c_char {
+    LAST_ERROR.with(|state| to_owned_c_string(state.borrow().message.as_deref().unwrap_or("")))
+}
+
+/// Get the last error as a single JSON object.
+///
+/// Returns an owned string (must be freed with `sysprims_free_string()`) of
+/// the form:
+///
+/// ```json
+/// {"code": 4, "kind": "permission_denied",
+///  "message": "Permission denied for 'signal' on PID 1",
+///  "context": {"pid": 1, "operation": "signal", "needs_elevation": true}}
+/// ```
+///
+/// `code` matches `sysprims_last_error_code()` and `message` matches
+/// `sysprims_last_error()`, so one call replaces both. `kind` names the error
+/// variant and `context` holds its typed fields (empty when there are none).
+///
+/// After a successful operation (or after calling `sysprims_clear_error()`),
+/// this returns `{"code":0,"kind":"ok","message":"","context":{}}`. With
+/// `SYSPRIMS_VALIDATE_SCHEMA=1`, a successful call whose output did not match
+/// its schema lists the mismatches in `context.schema_mismatches`.
+///
+/// # Safety
+///
+/// The returned pointer must be freed with `sysprims_free_string()`.
+/// The caller owns the returned string.
+///
+/// # Thread Safety
+///
+/// Error state is thread-local. Each thread has its own error state.
+#[no_mangle]
+pub extern "C" fn sysprims_last_error_json() -> *mut c_char {
     LAST_ERROR.with(|state| {
         let state = state.borrow();
-        let msg = state.message.as_deref().unwrap_or("");
-
-        // CString::new can fail if the string contains null bytes.
-        // In that case, return a sanitized version.
-        match CString::new(msg) {
-            Ok(c_str) => c_str.into_raw(),
-            Err(_) => {
-                let sanitized = msg.replace('\0', "?");
-                CString::new(sanitized)
-                    .map(|s| s.into_raw())
-                    .unwrap_or(std::ptr::null_mut())
+        match &state.json {
+            Some(json) => to_owned_c_string(json),
+            None if state.schema_mismatches.is_empty() => to_owned_c_string(NO_ERROR_JSON),
+            None => {
+                let json = serde_json::json!({
+                    "code": 0,
+                    "kind": "ok",
+                    "message": "",
+                    "context": { "schema_mismatches": state.schema_mismatches },
+                });
+                to_owned_c_string(&json.to_string())
             }
         }
     })
 }
 
+fn to_owned_c_string(msg: &str) -> *mut c_char {
+    // CString::new can fail if the string contains null bytes.
+    // In that case, return a sanitized version.
+    match CString::new(msg) {
+        Ok(c_str) => c_str.into_raw(),
+        Err(_) => {
+            let sanitized = msg.replace('\0', "?");
+            CString::new(sanitized)
+                .map(|s| s.into_raw())
+                .unwrap_or(std::ptr::null_mut())
+        }
+    }
+}
+
 /// Clear the error state for the current thread.
 ///
 /// After calling this function, `sysprims_last_error_code()` will return
@@ -252,6 +332,56 @@ mod tests {
         unsafe { crate::sysprims_free_string(msg_ptr) };
     }
 
+    fn last_error_json() -> serde_json::Value {
+        let ptr = sysprims_last_error_json();
+        assert!(!ptr.is_null());
+        let text = unsafe { CStr::from_ptr(ptr).to_str().unwrap().to_owned() };
+        unsafe { crate::sysprims_free_string(ptr) };
+        serde_json::from_str(&text).unwrap()
+    }
+
+    #[test]
+    fn test_last_error_json() {
+        clear_error_state();
+        let ok = last_error_json();
+        assert_eq!(ok["code"], 0);
+        assert_eq!(ok["kind"], "ok");
+        assert_eq!(ok["message"], "");
+
+        set_error(&SysprimsError::permission_denied(42, "signal"));
+        let err = last_error_json();
+        assert_eq!(err["code"], SysprimsErrorCode::PermissionDenied as i32);
+        assert_eq!(err["kind"], "permission_denied");
+        assert_eq!(err["message"], "Permission denied for 'signal' on PID 42");
+        assert_eq!(err["context"]["pid"], 42);
+        assert_eq!(err["context"]["operation"], "signal");
+        assert_eq!(err["context"]["needs_elevation"], true);
+
+        set_error(&SysprimsError::invalid_argument("bad"));
+        let err = last_error_json();
+        assert_eq!(err["kind"], "invalid_argument");
+        assert_eq!(err["context"], serde_json::json!({}));
+
+        sysprims_clear_error();
+        assert_eq!(last_error_json()["code"], 0);
+    }
+
+    #[test]
+    fn test_schema_mismatches_reported_without_error() {
+        clear_error_state();
+        record_schema_mismatches(vec!["$.pid: expected integer".to_string()]);
+        assert_eq!(sysprims_last_error_code(), SysprimsErrorCode::Ok);
+        let ok = last_error_json();
+        assert_eq!(ok["code"], 0);
+        assert_eq!(
+            ok["context"]["schema_mismatches"],
+            serde_json::json!(["$.pid: expected integer"])
+        );
+
+        clear_error_state();
+        assert_eq!(last_error_json()["context"], serde_json::json!({}));
+    }
+
     #[test]
     fn test_error_code_mapping() {
         let test_cases = [
@@ -259,6 +389,10 @@ mod tests {
                 SysprimsError::invalid_argument(""),
                 SysprimsErrorCode::InvalidArgument,
             ),
+            (
+                SysprimsError::identity_mismatch(1, ""),
+                SysprimsErrorCode::InvalidArgument,
+            ),
             (SysprimsError::Timeout, SysprimsErrorCode::Timeout),
             (
                 SysprimsError::permission_denied(1, "op"),
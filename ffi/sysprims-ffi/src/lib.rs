@@ -13,6 +13,7 @@
 //! available via:
 //! - `sysprims_last_error_code()` - Get error code
 //! - `sysprims_last_error()` - Get error message
+//! - `sysprims_last_error_json()` - Get code, kind, message and context as JSON
 //! - `sysprims_clear_error()` - Clear error state
 //!
 //! Error state is thread-local.
@@ -22,10 +23,12 @@
 //! Check `sysprims_abi_version()` for ABI compatibility. The ABI version
 //! increments when breaking changes are made to the FFI interface.
 
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
 
-use sysprims_core::get_platform;
+use sysprims_core::{capabilities, get_platform, parse_duration, schema_check, SysprimsError};
+
+use crate::error::{clear_error_state, record_schema_mismatches, set_error};
 
 // Modules
 mod error;
@@ -39,16 +42,20 @@ mod timeout;
 pub use error::SysprimsErrorCode;
 
 // Re-export FFI functions from submodules
-pub use error::{sysprims_clear_error, sysprims_last_error, sysprims_last_error_code};
+pub use error::{
+    sysprims_clear_error, sysprims_last_error, sysprims_last_error_code, sysprims_last_error_json,
+};
 pub use proc::{
     sysprims_proc_descendants, sysprims_proc_descendants_ex, sysprims_proc_get,
-    sysprims_proc_get_ex, sysprims_proc_kill_descendants, sysprims_proc_kill_descendants_ex,
-    sysprims_proc_list, sysprims_proc_list_ex, sysprims_proc_list_fds,
-    sysprims_proc_listening_ports, sysprims_proc_wait_pid,
+    sysprims_proc_get_ex, sysprims_proc_get_self, sysprims_proc_kill_descendants,
+    sysprims_proc_kill_descendants_ex, sysprims_proc_list, sysprims_proc_list_ex,
+    sysprims_proc_list_fds, sysprims_proc_listening_ports, sysprims_proc_resolve_kill_targets,
+    sysprims_proc_wait_pid,
 };
 pub use session::{sysprims_self_getpgid, sysprims_self_getsid};
 pub use signal::{
-    sysprims_force_kill, sysprims_signal_send, sysprims_signal_send_group, sysprims_terminate,
+    sysprims_force_kill, sysprims_signal_resolve, sysprims_signal_send,
+    sysprims_signal_send_by_name, sysprims_signal_send_group, sysprims_terminate,
 };
 pub use spawn::sysprims_spawn_in_group;
 pub use timeout::{
@@ -140,6 +147,105 @@ pub extern "C" fn sysprims_get_platform() -> *mut c_char {
     c_platform.into_raw()
 }
 
+/// Returns the current platform's capabilities as a JSON C string.
+///
+/// The JSON matches `capabilities.schema.json`, e.g.:
+///
+/// ```json
+/// {
+///   "schema_id": "https://schemas.3leaps.dev/sysprims/core/v1.1.0/capabilities.schema.json",
+///   "platform": "linux",
+///   "supports_killpg": true,
+///   "supports_fd_enumeration": true,
+///   "port_attribution": "full",
+///   "supports_setsid": true
+/// }
+/// ```
+///
+/// # Safety
+///
+/// The returned pointer must be freed with `sysprims_free_string()`.
+/// Do not use `free()` or any other deallocator.
+#[no_mangle]
+pub extern "C" fn sysprims_capabilities_json() -> *mut c_char {
+    let json = serde_json::to_string(&capabilities())
+        .expect("capabilities should always serialize to JSON");
+    CString::new(json).unwrap().into_raw()
+}
+
+/// Serialize a result for return across the FFI boundary.
+///
+/// With `SYSPRIMS_VALIDATE_SCHEMA` set, the value is first checked against its
+/// embedded schema: mismatches are recorded in the error state (see
+/// `sysprims_last_error_json()`), or fail the call when set to `strict`.
+pub(crate) fn to_json<T: serde::Serialize + ?Sized>(value: &T) -> Result<String, String> {
+    let mismatches = schema_check::check_output(value).map_err(|e| e.to_string())?;
+    record_schema_mismatches(mismatches);
+    serde_json::to_string(value).map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Duration Parsing
+// ============================================================================
+
+/// Parse a human-friendly duration string into milliseconds.
+///
+/// Accepts the same syntax as the CLI: `"500ms"`, `"5s"`, `"2m"`, `"1h"`, or
+/// a bare number of seconds (`"5"`, `"0.25"`). Negative values are rejected.
+/// Sub-millisecond precision is truncated; values beyond `u64::MAX` ms saturate.
+///
+/// # Safety
+///
+/// - `s` must be a valid null-terminated UTF-8 C string.
+/// - `ms_out` must be a valid pointer to a `u64`.
+///
+/// # Example (C)
+///
+/// ```c
+/// uint64_t ms = 0;
+/// if (sysprims_parse_duration_ms("1.5s", &ms) == SYSPRIMS_OK) {
+///     // ms == 1500
+/// }
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn sysprims_parse_duration_ms(
+    s: *const c_char,
+    ms_out: *mut u64,
+) -> SysprimsErrorCode {
+    clear_error_state();
+
+    if s.is_null() {
+        let err = SysprimsError::invalid_argument("s cannot be null");
+        set_error(&err);
+        return SysprimsErrorCode::InvalidArgument;
+    }
+    if ms_out.is_null() {
+        let err = SysprimsError::invalid_argument("ms_out cannot be null");
+        set_error(&err);
+        return SysprimsErrorCode::InvalidArgument;
+    }
+
+    let input = match CStr::from_ptr(s).to_str() {
+        Ok(input) => input,
+        Err(_) => {
+            let err = SysprimsError::invalid_argument("s is not valid UTF-8");
+            set_error(&err);
+            return SysprimsErrorCode::InvalidArgument;
+        }
+    };
+
+    match parse_duration(input) {
+        Ok(duration) => {
+            *ms_out = u64::try_from(duration.as_millis()).unwrap_or(u64::MAX);
+            SysprimsErrorCode::Ok
+        }
+        Err(e) => {
+            set_error(&e);
+            SysprimsErrorCode::from(&e)
+        }
+    }
+}
+
 // ============================================================================
 // Memory Management
 // ============================================================================
@@ -226,6 +332,36 @@ mod tests {
         unsafe { sysprims_free_string(platform_ptr) };
     }
 
+    #[test]
+    fn test_capabilities_json_is_valid() {
+        let ptr = sysprims_capabilities_json();
+        assert!(!ptr.is_null());
+
+        // SAFETY: We just created this pointer and know it's valid
+        let json = unsafe { CStr::from_ptr(ptr).to_str().unwrap().to_string() };
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["schema_id"], sysprims_core::schema::CAPABILITIES_V1);
+        assert_eq!(value["platform"], env::consts::OS);
+        assert!(value["port_attribution"].is_string());
+
+        // SAFETY: Free the string we allocated above
+        unsafe { sysprims_free_string(ptr) };
+    }
+
+    #[test]
+    fn test_parse_duration_ms() {
+        let mut ms = 0u64;
+        let code = unsafe { sysprims_parse_duration_ms(c"1.5s".as_ptr(), &mut ms) };
+        assert_eq!(code, SysprimsErrorCode::Ok);
+        assert_eq!(ms, 1500);
+
+        let code = unsafe { sysprims_parse_duration_ms(c"-5s".as_ptr(), &mut ms) };
+        assert_eq!(code, SysprimsErrorCode::InvalidArgument);
+
+        let code = unsafe { sysprims_parse_duration_ms(std::ptr::null(), &mut ms) };
+        assert_eq!(code, SysprimsErrorCode::InvalidArgument);
+    }
+
     #[test]
     fn test_free_null_is_safe() {
         // Should not panic or crash
@@ -10,8 +10,8 @@ use std::time::Duration;
 use crate::error::{clear_error_state, set_error, SysprimsErrorCode};
 use sysprims_core::SysprimsError;
 use sysprims_proc::{
-    descendants_with_config_and_options, CpuMode, DescendantsConfig, FdFilter, PortFilter,
-    ProcessFilter, ProcessOptions,
+    descendants_with_config_and_options, CpuMode, DescendantsConfig, FdFilter, PollConfig,
+    PortFilter, ProcessFilter, ProcessOptions,
 };
 
 #[derive(Debug, Default, serde::Deserialize)]
@@ -19,6 +19,16 @@ use sysprims_proc::{
 struct ProcessOptionsWire {
     include_env: bool,
     include_threads: bool,
+    include_cgroup: bool,
+    include_socket_count: bool,
+    include_net: bool,
+    include_limits: bool,
+    include_signal_masks: bool,
+    include_exe_stat: bool,
+    include_identity: bool,
+    include_cmdline_string: bool,
+    resolve_usernames: Option<bool>,
+    preserve_raw_bytes: bool,
 }
 
 #[derive(Debug, Clone, Copy, Default, serde::Deserialize)]
@@ -36,6 +46,10 @@ struct DescendantsConfigWire {
     filter: ProcessFilter,
     cpu_mode: CpuModeWire,
     sample_duration_ms: Option<u64>,
+    poll_interval_ms: Option<u64>,
+    poll_duration_ms: Option<u64>,
+    deadline_ms: Option<u64>,
+    include_root: bool,
 }
 
 #[derive(Debug, Default)]
@@ -43,6 +57,9 @@ struct ParsedDescendantsConfig {
     filter: Option<ProcessFilter>,
     cpu_mode: CpuMode,
     sample_duration: Option<Duration>,
+    poll: Option<PollConfig>,
+    deadline: Option<Duration>,
+    include_root: bool,
 }
 
 unsafe fn parse_process_options(
@@ -66,12 +83,24 @@ unsafe fn parse_process_options(
     Ok(ProcessOptions {
         include_env: wire.include_env,
         include_threads: wire.include_threads,
+        include_cgroup: wire.include_cgroup,
+        include_socket_count: wire.include_socket_count,
+        include_net: wire.include_net,
+        include_limits: wire.include_limits,
+        include_signal_masks: wire.include_signal_masks,
+        include_exe_stat: wire.include_exe_stat,
+        include_identity: wire.include_identity,
+        include_cmdline_string: wire.include_cmdline_string,
+        resolve_usernames: wire.resolve_usernames.unwrap_or(true),
+        preserve_raw_bytes: wire.preserve_raw_bytes,
+        ..ProcessOptions::default()
     })
 }
 
 fn process_filter_has_criteria(filter: &ProcessFilter) -> bool {
     filter.name_contains.is_some()
         || filter.name_equals.is_some()
+        || filter.name_glob.is_some()
         || filter.user_equals.is_some()
         || filter.pid_in.is_some()
         || filter.ppid.is_some()
@@ -79,6 +108,10 @@ fn process_filter_has_criteria(filter: &ProcessFilter) -> bool {
         || filter.cpu_above.is_some()
         || filter.memory_above_kb.is_some()
         || filter.running_for_at_least_secs.is_some()
+        || filter.age_bucket_in.is_some()
+        || filter.exclude_kernel_threads
+        || filter.cgroup_contains.is_some()
+        || filter.exe_deleted_only
 }
 
 fn wire_cpu_mode_to_proc(mode: CpuModeWire) -> CpuMode {
@@ -112,13 +145,48 @@ unsafe fn parse_descendants_config(
         None
     };
 
+    let poll = match (wire.poll_interval_ms, wire.poll_duration_ms) {
+        (None, None) => None,
+        (interval_ms, Some(duration_ms)) => Some(PollConfig {
+            interval: Duration::from_millis(interval_ms.unwrap_or(50)),
+            duration: Duration::from_millis(duration_ms),
+        }),
+        (Some(_), None) => {
+            return Err(SysprimsError::invalid_argument(
+                "poll_interval_ms requires poll_duration_ms",
+            ))
+        }
+    };
+
     Ok(ParsedDescendantsConfig {
         filter,
         cpu_mode: wire_cpu_mode_to_proc(wire.cpu_mode),
         sample_duration: wire.sample_duration_ms.map(Duration::from_millis),
+        poll,
+        deadline: wire.deadline_ms.map(Duration::from_millis),
+        include_root: wire.include_root,
     })
 }
 
+unsafe fn parse_process_filter(filter_json: *const c_char) -> Result<ProcessFilter, SysprimsError> {
+    if filter_json.is_null() {
+        return Ok(ProcessFilter::default());
+    }
+
+    let filter_str = CStr::from_ptr(filter_json)
+        .to_str()
+        .map_err(|_| SysprimsError::invalid_argument("filter_json is not valid UTF-8"))?;
+
+    if filter_str.is_empty() || filter_str == "{}" {
+        return Ok(ProcessFilter::default());
+    }
+
+    let filter: ProcessFilter = serde_json::from_str(filter_str)
+        .map_err(|e| SysprimsError::invalid_argument(format!("invalid filter JSON: {}", e)))?;
+    filter.validate()?;
+    Ok(filter)
+}
+
 /// List open file descriptors for a PID, optionally filtered.
 ///
 /// Returns a JSON object matching `fd-snapshot.schema.json`.
@@ -195,7 +263,7 @@ pub unsafe extern "C" fn sysprims_proc_list_fds(
         }
     };
 
-    let json = match serde_json::to_string(&snapshot) {
+    let json = match crate::to_json(&snapshot) {
         Ok(j) => j,
         Err(e) => {
             let err = SysprimsError::internal(format!("failed to serialize fd snapshot: {}", e));
@@ -292,7 +360,7 @@ pub unsafe extern "C" fn sysprims_proc_listening_ports(
         }
     };
 
-    let json = match serde_json::to_string(&snapshot) {
+    let json = match crate::to_json(&snapshot) {
         Ok(j) => j,
         Err(e) => {
             let err = SysprimsError::internal(format!("failed to serialize port bindings: {}", e));
@@ -374,6 +442,9 @@ pub unsafe extern "C" fn sysprims_proc_list(
 /// {"include_env": true, "include_threads": true}
 /// ```
 ///
+/// `filter_json` may also carry `deadline_ms`, bounding the whole traversal;
+/// when it expires the partial result has `truncated: true`.
+///
 /// # Safety
 ///
 /// * `result_json_out` must be a valid pointer to a `char*`
@@ -401,39 +472,94 @@ pub unsafe extern "C" fn sysprims_proc_list_ex(
         }
     };
 
-    let filter = if filter_json.is_null() {
-        ProcessFilter::default()
-    } else {
-        let filter_str = match CStr::from_ptr(filter_json).to_str() {
-            Ok(s) => s,
-            Err(_) => {
-                let err = SysprimsError::invalid_argument("filter_json is not valid UTF-8");
-                set_error(&err);
-                return SysprimsErrorCode::InvalidArgument;
-            }
-        };
+    let filter = match parse_process_filter(filter_json) {
+        Ok(f) => f,
+        Err(e) => {
+            set_error(&e);
+            return SysprimsErrorCode::from(&e);
+        }
+    };
 
-        if filter_str.is_empty() || filter_str == "{}" {
-            ProcessFilter::default()
-        } else {
-            match serde_json::from_str::<ProcessFilter>(filter_str) {
-                Ok(f) => f,
-                Err(e) => {
-                    let err =
-                        SysprimsError::invalid_argument(format!("invalid filter JSON: {}", e));
-                    set_error(&err);
-                    return SysprimsErrorCode::InvalidArgument;
-                }
-            }
+    let snapshot = match sysprims_proc::snapshot_filtered_with_options(&filter, options) {
+        Ok(s) => s,
+        Err(e) => {
+            set_error(&e);
+            return SysprimsErrorCode::from(&e);
         }
     };
 
-    if let Err(e) = filter.validate() {
-        set_error(&e);
-        return SysprimsErrorCode::from(&e);
+    let json = match crate::to_json(&snapshot) {
+        Ok(j) => j,
+        Err(e) => {
+            let err = SysprimsError::internal(format!("failed to serialize snapshot: {}", e));
+            set_error(&err);
+            return SysprimsErrorCode::Internal;
+        }
+    };
+
+    let c_json = match CString::new(json) {
+        Ok(c) => c,
+        Err(e) => {
+            let err = SysprimsError::internal(format!("JSON contains null byte: {}", e));
+            set_error(&err);
+            return SysprimsErrorCode::Internal;
+        }
+    };
+
+    *result_json_out = c_json.into_raw();
+    SysprimsErrorCode::Ok
+}
+
+/// Resolve which PIDs a filter-selected kill would signal, without signaling.
+///
+/// Applies `filter_json` (same format as [`sysprims_proc_list`]) and then the
+/// PID safety checks that kill workflows enforce (self, PID 1, parent).
+/// Bindings can show this as an authoritative preview before sending signals
+/// to `targets`.
+///
+/// # Result JSON Format
+///
+/// ```json
+/// {
+///   "schema_id": "https://schemas.3leaps.dev/sysprims/signal/v1.1.0/kill-targets.schema.json",
+///   "targets": [1234, 5678],
+///   "skipped_safety_pids": [1]
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `SYSPRIMS_OK` on success (result written to `result_json_out`)
+/// * `SYSPRIMS_ERR_INVALID_ARGUMENT` if filter JSON is invalid
+/// * `SYSPRIMS_ERR_SYSTEM` on system error
+///
+/// # Safety
+///
+/// * `result_json_out` must be a valid pointer to a `char*`
+/// * `filter_json` must be NULL or a valid UTF-8 C string
+/// * The result string must be freed with `sysprims_free_string()`
+#[no_mangle]
+pub unsafe extern "C" fn sysprims_proc_resolve_kill_targets(
+    filter_json: *const c_char,
+    result_json_out: *mut *mut c_char,
+) -> SysprimsErrorCode {
+    clear_error_state();
+
+    if result_json_out.is_null() {
+        let err = SysprimsError::invalid_argument("result_json_out cannot be null");
+        set_error(&err);
+        return SysprimsErrorCode::InvalidArgument;
     }
 
-    let snapshot = match sysprims_proc::snapshot_filtered_with_options(&filter, options) {
+    let filter = match parse_process_filter(filter_json) {
+        Ok(f) => f,
+        Err(e) => {
+            set_error(&e);
+            return SysprimsErrorCode::from(&e);
+        }
+    };
+
+    let snapshot = match sysprims_proc::snapshot_filtered(&filter) {
         Ok(s) => s,
         Err(e) => {
             set_error(&e);
@@ -441,10 +567,25 @@ pub unsafe extern "C" fn sysprims_proc_list_ex(
         }
     };
 
-    let json = match serde_json::to_string(&snapshot) {
+    let mut targets: Vec<u32> = snapshot.processes.iter().map(|p| p.pid).collect();
+    targets.sort_unstable();
+    targets.dedup();
+
+    // Safety: same exclusions as kill-descendants (self, PID 1, parent).
+    let protected = sysprims_proc::protected_pids();
+    let (skipped_safety_pids, targets): (Vec<u32>, Vec<u32>) =
+        targets.into_iter().partition(|pid| protected.contains(pid));
+
+    let result = KillTargetsJson {
+        schema_id: sysprims_core::schema::KILL_TARGETS_V1,
+        targets,
+        skipped_safety_pids,
+    };
+
+    let json = match crate::to_json(&result) {
         Ok(j) => j,
         Err(e) => {
-            let err = SysprimsError::internal(format!("failed to serialize snapshot: {}", e));
+            let err = SysprimsError::internal(format!("failed to serialize kill targets: {}", e));
             set_error(&err);
             return SysprimsErrorCode::Internal;
         }
@@ -562,7 +703,7 @@ pub unsafe extern "C" fn sysprims_proc_get_ex(
         }
     };
 
-    let json = match serde_json::to_string(&info) {
+    let json = match crate::to_json(&info) {
         Ok(j) => j,
         Err(e) => {
             let err = SysprimsError::internal(format!("failed to serialize process info: {}", e));
@@ -584,6 +725,21 @@ pub unsafe extern "C" fn sysprims_proc_get_ex(
     SysprimsErrorCode::Ok
 }
 
+/// Get process info for the calling process.
+///
+/// Equivalent to `sysprims_proc_get(getpid(), result_json_out)`.
+///
+/// # Safety
+///
+/// * `result_json_out` must be a valid pointer to a `char*`
+/// * The result string must be freed with `sysprims_free_string()`
+#[no_mangle]
+pub unsafe extern "C" fn sysprims_proc_get_self(
+    result_json_out: *mut *mut c_char,
+) -> SysprimsErrorCode {
+    sysprims_proc_get_ex(sysprims_core::self_pid(), std::ptr::null(), result_json_out)
+}
+
 /// Wait for a PID to exit, up to a timeout.
 ///
 /// Returns a JSON object matching `wait-pid-result.schema.json`.
@@ -620,7 +776,7 @@ pub unsafe extern "C" fn sysprims_proc_wait_pid(
         }
     };
 
-    let json = match serde_json::to_string(&result) {
+    let json = match crate::to_json(&result) {
         Ok(j) => j,
         Err(e) => {
             let err = SysprimsError::internal(format!("failed to serialize wait result: {}", e));
@@ -728,12 +884,20 @@ pub unsafe extern "C" fn sysprims_proc_descendants_ex(
         }
     };
 
+    if parsed.include_root {
+        let err = SysprimsError::invalid_argument("include_root only applies to kill_descendants");
+        set_error(&err);
+        return SysprimsErrorCode::InvalidArgument;
+    }
+
     let config = DescendantsConfig {
         root_pid,
         max_levels: Some(max_levels),
         filter: parsed.filter,
         cpu_mode: parsed.cpu_mode,
         sample_duration: parsed.sample_duration,
+        poll: parsed.poll,
+        deadline: parsed.deadline,
     };
 
     let result = match descendants_with_config_and_options(config, options) {
@@ -744,7 +908,7 @@ pub unsafe extern "C" fn sysprims_proc_descendants_ex(
         }
     };
 
-    let json = match serde_json::to_string(&result) {
+    let json = match crate::to_json(&result) {
         Ok(j) => j,
         Err(e) => {
             let err =
@@ -772,6 +936,7 @@ pub unsafe extern "C" fn sysprims_proc_descendants_ex(
 /// Traverses the process tree from `root_pid`, collects descendant PIDs, and
 /// sends the specified signal to each. Safety rules are enforced in this layer:
 /// the root PID, self, PID 1, and parent are excluded from the kill list.
+/// Set `"include_root": true` in `filter_json` to signal the root as well.
 ///
 /// Returns a JSON object with `schema_id`, `signal_sent`, `succeeded`, `failed`,
 /// and `skipped_safety` fields.
@@ -787,7 +952,7 @@ pub unsafe extern "C" fn sysprims_proc_descendants_ex(
 /// # Safety Rules (enforced here, not in bindings)
 ///
 /// The following PIDs are always excluded from the kill list:
-/// - The root PID itself (descendants-only)
+/// - The root PID itself (descendants-only, unless `include_root` is set)
 /// - The calling process (self)
 /// - PID 1 (init/launchd)
 /// - The calling process's parent
@@ -815,6 +980,15 @@ pub unsafe extern "C" fn sysprims_proc_kill_descendants(
 /// {"cpu_mode": "lifetime|monitor", "sample_duration_ms": 3000}
 /// ```
 ///
+/// `poll_duration_ms` (and optional `poll_interval_ms`, default 50) enable
+/// repeated sampling; descendants that exited during the window are never signaled.
+///
+/// `deadline_ms` bounds the traversal; only descendants found before it expires
+/// are signaled.
+///
+/// `include_root: true` adds `root_pid` to the kill list regardless of filters
+/// (still subject to the self/PID 1/parent exclusions).
+///
 /// # Safety
 ///
 /// * `result_json_out` must be a valid pointer to a `char*`
@@ -843,6 +1017,7 @@ pub unsafe extern "C" fn sysprims_proc_kill_descendants_ex(
             return SysprimsErrorCode::from(&e);
         }
     };
+    let include_root = parsed.include_root;
 
     let config = DescendantsConfig {
         root_pid,
@@ -850,6 +1025,8 @@ pub unsafe extern "C" fn sysprims_proc_kill_descendants_ex(
         filter: parsed.filter,
         cpu_mode: parsed.cpu_mode,
         sample_duration: parsed.sample_duration,
+        poll: parsed.poll,
+        deadline: parsed.deadline,
     };
 
     // Traverse descendants before sending any signal.
@@ -870,19 +1047,23 @@ pub unsafe extern "C" fn sysprims_proc_kill_descendants_ex(
     target_pids.sort_unstable();
     target_pids.dedup();
 
-    // Safety: exclude root PID (descendants-only).
-    target_pids.retain(|&pid| pid != root_pid);
-
-    // Safety: exclude self, PID 1, parent.
-    let self_pid = std::process::id();
-    let parent_pid = sysprims_proc::get_process(self_pid).ok().map(|p| p.ppid);
+    // Safety: never signal PIDs only seen while polling; they have exited and
+    // the PID may already belong to an unrelated process.
+    if let Some(transient) = desc_result.transient_pids.as_ref() {
+        target_pids.retain(|pid| !transient.contains(pid));
+    }
 
-    let before = target_pids.len();
-    target_pids.retain(|&pid| pid != self_pid && pid != 1);
-    if let Some(ppid) = parent_pid {
-        target_pids.retain(|&pid| pid != ppid);
+    // Safety: exclude root PID unless explicitly requested.
+    if include_root {
+        if !target_pids.contains(&root_pid) {
+            target_pids.push(root_pid);
+        }
+    } else {
+        target_pids.retain(|&pid| pid != root_pid);
     }
-    let skipped_safety = before.saturating_sub(target_pids.len());
+
+    // Safety: exclude self, PID 1, parent.
+    let skipped_safety = sysprims_proc::filter_protected(&mut target_pids, false);
 
     // Build result.
     let (succeeded, failed) = if target_pids.is_empty() {
@@ -916,7 +1097,7 @@ pub unsafe extern "C" fn sysprims_proc_kill_descendants_ex(
         skipped_safety,
     };
 
-    let json = match serde_json::to_string(&result) {
+    let json = match crate::to_json(&result) {
         Ok(j) => j,
         Err(e) => {
             let err = SysprimsError::internal(format!(
@@ -951,6 +1132,10 @@ struct KillDescendantsFailure {
 /// JSON-serializable result for kill-descendants.
 #[derive(serde::Serialize)]
 struct KillDescendantsResultJson {
+    #[serde(
+        flatten,
+        serialize_with = "sysprims_core::schema::serialize_with_version"
+    )]
     schema_id: &'static str,
     signal_sent: i32,
     root_pid: u32,
@@ -959,6 +1144,18 @@ struct KillDescendantsResultJson {
     skipped_safety: usize,
 }
 
+/// JSON-serializable result for kill target resolution.
+#[derive(serde::Serialize)]
+struct KillTargetsJson {
+    #[serde(
+        flatten,
+        serialize_with = "sysprims_core::schema::serialize_with_version"
+    )]
+    schema_id: &'static str,
+    targets: Vec<u32>,
+    skipped_safety_pids: Vec<u32>,
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -1009,6 +1206,35 @@ mod tests {
         assert!(result.is_null());
     }
 
+    #[test]
+    fn test_proc_resolve_kill_targets_skips_self() {
+        let pid = std::process::id();
+        let filter = CString::new(format!(r#"{{"pid_in": [{pid}]}}"#)).unwrap();
+        let mut result: *mut c_char = std::ptr::null_mut();
+
+        let code = unsafe { sysprims_proc_resolve_kill_targets(filter.as_ptr(), &mut result) };
+
+        assert_eq!(code, SysprimsErrorCode::Ok);
+        let json = unsafe { CStr::from_ptr(result).to_str().unwrap() };
+        let value: serde_json::Value = serde_json::from_str(json).unwrap();
+        assert_eq!(value["schema_id"], sysprims_core::schema::KILL_TARGETS_V1);
+        assert_eq!(value["targets"], serde_json::json!([]));
+        assert_eq!(value["skipped_safety_pids"], serde_json::json!([pid]));
+
+        unsafe { sysprims_free_string(result) };
+    }
+
+    #[test]
+    fn test_proc_resolve_kill_targets_invalid_filter() {
+        let filter = CString::new(r#"{"unknown_field": true}"#).unwrap();
+        let mut result: *mut c_char = std::ptr::null_mut();
+
+        let code = unsafe { sysprims_proc_resolve_kill_targets(filter.as_ptr(), &mut result) };
+
+        assert_eq!(code, SysprimsErrorCode::InvalidArgument);
+        assert!(result.is_null());
+    }
+
     #[test]
     fn test_proc_list_fds_self() {
         let pid = std::process::id();
@@ -1128,6 +1354,20 @@ mod tests {
         unsafe { sysprims_free_string(result) };
     }
 
+    #[test]
+    fn test_proc_get_self_fn() {
+        let mut result: *mut c_char = std::ptr::null_mut();
+
+        let code = unsafe { sysprims_proc_get_self(&mut result) };
+
+        assert_eq!(code, SysprimsErrorCode::Ok);
+        // SAFETY: We just allocated this
+        let json = unsafe { CStr::from_ptr(result).to_str().unwrap() };
+        assert!(json.contains(&format!("\"pid\":{}", std::process::id())));
+
+        unsafe { sysprims_free_string(result) };
+    }
+
     #[test]
     fn test_proc_get_invalid_pid() {
         let mut result: *mut c_char = std::ptr::null_mut();
@@ -1202,6 +1442,48 @@ mod tests {
         unsafe { sysprims_free_string(result) };
     }
 
+    #[test]
+    fn test_proc_descendants_poll_config_reports_transient_pids() {
+        let pid = std::process::id();
+        let config = CString::new(r#"{"poll_interval_ms":10,"poll_duration_ms":20}"#).unwrap();
+        let mut result: *mut c_char = std::ptr::null_mut();
+
+        let code =
+            unsafe { sysprims_proc_descendants(pid, u32::MAX, config.as_ptr(), &mut result) };
+
+        assert_eq!(code, SysprimsErrorCode::Ok);
+        let json = unsafe { CStr::from_ptr(result).to_str().unwrap() };
+        assert!(json.contains("\"transient_pids\""));
+
+        unsafe { sysprims_free_string(result) };
+    }
+
+    #[test]
+    fn test_proc_descendants_zero_deadline_rejected() {
+        let pid = std::process::id();
+        let config = CString::new(r#"{"deadline_ms":0}"#).unwrap();
+        let mut result: *mut c_char = std::ptr::null_mut();
+
+        let code =
+            unsafe { sysprims_proc_descendants(pid, u32::MAX, config.as_ptr(), &mut result) };
+
+        assert_eq!(code, SysprimsErrorCode::InvalidArgument);
+        assert!(result.is_null());
+    }
+
+    #[test]
+    fn test_proc_descendants_poll_interval_without_duration_rejected() {
+        let pid = std::process::id();
+        let config = CString::new(r#"{"poll_interval_ms":10}"#).unwrap();
+        let mut result: *mut c_char = std::ptr::null_mut();
+
+        let code =
+            unsafe { sysprims_proc_descendants(pid, u32::MAX, config.as_ptr(), &mut result) };
+
+        assert_eq!(code, SysprimsErrorCode::InvalidArgument);
+        assert!(result.is_null());
+    }
+
     // ========================================================================
     // Descendants FFI tests
     // ========================================================================
@@ -1364,6 +1646,44 @@ mod tests {
         unsafe { sysprims_free_string(result) };
     }
 
+    #[test]
+    fn test_proc_kill_descendants_include_root_still_protects_self() {
+        // Root is self: include_root adds it, but the self/PID 1/parent policy
+        // must still keep it off the kill list.
+        let pid = std::process::id();
+        let config = CString::new(r#"{"include_root":true}"#).unwrap();
+        let mut result: *mut c_char = std::ptr::null_mut();
+
+        let code = unsafe {
+            sysprims_proc_kill_descendants(pid, u32::MAX, 0, config.as_ptr(), &mut result)
+        };
+
+        assert_eq!(code, SysprimsErrorCode::Ok);
+        let json = unsafe { CStr::from_ptr(result).to_str().unwrap() };
+        let value: serde_json::Value = serde_json::from_str(json).unwrap();
+        assert!(value["skipped_safety"].as_u64().unwrap() >= 1);
+        assert!(!value["succeeded"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|p| p.as_u64() == Some(pid as u64)));
+
+        unsafe { sysprims_free_string(result) };
+    }
+
+    #[test]
+    fn test_proc_descendants_include_root_rejected() {
+        let pid = std::process::id();
+        let config = CString::new(r#"{"include_root":true}"#).unwrap();
+        let mut result: *mut c_char = std::ptr::null_mut();
+
+        let code =
+            unsafe { sysprims_proc_descendants(pid, u32::MAX, config.as_ptr(), &mut result) };
+
+        assert_eq!(code, SysprimsErrorCode::InvalidArgument);
+        assert!(result.is_null());
+    }
+
     #[test]
     fn test_proc_kill_descendants_invalid_filter() {
         let pid = std::process::id();
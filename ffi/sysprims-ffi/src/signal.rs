@@ -2,7 +2,11 @@
 //!
 //! Thin wrappers around `sysprims_signal` functions for C-ABI export.
 
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
 use crate::error::{clear_error_state, set_error, SysprimsErrorCode};
+use sysprims_core::SysprimsError;
 
 /// Send a signal to a process.
 ///
@@ -46,6 +50,65 @@ pub extern "C" fn sysprims_signal_send(pid: u32, signal: i32) -> SysprimsErrorCo
     }
 }
 
+/// Send a signal to a process, resolving the signal by name.
+///
+/// Accepts the same forms as the CLI: `"TERM"`, `"SIGTERM"`, `"term"`, or a
+/// number such as `"15"`. Glob patterns are not expanded; resolve them first
+/// with `sysprims_signal_resolve` if needed.
+///
+/// # Arguments
+///
+/// * `pid` - Process ID (must be > 0 and <= i32::MAX)
+/// * `name` - Null-terminated UTF-8 signal name or number
+///
+/// # Returns
+///
+/// * `SYSPRIMS_OK` on success
+/// * `SYSPRIMS_ERR_INVALID_ARGUMENT` if `name` is null or unknown, or pid is invalid
+/// * Other error codes as for `sysprims_signal_send`
+///
+/// # Safety
+///
+/// * `name` must be a valid null-terminated C string
+///
+/// # Example (C)
+///
+/// ```c
+/// SysprimsErrorCode err = sysprims_signal_send_by_name(pid, "TERM");
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn sysprims_signal_send_by_name(
+    pid: u32,
+    name: *const c_char,
+) -> SysprimsErrorCode {
+    clear_error_state();
+
+    if name.is_null() {
+        let err = SysprimsError::invalid_argument("name cannot be null");
+        set_error(&err);
+        return SysprimsErrorCode::InvalidArgument;
+    }
+
+    let name_str = match CStr::from_ptr(name).to_str() {
+        Ok(s) => s,
+        Err(_) => {
+            let err = SysprimsError::invalid_argument("name is not valid UTF-8");
+            set_error(&err);
+            return SysprimsErrorCode::InvalidArgument;
+        }
+    };
+
+    let result =
+        sysprims_signal::resolve_signal(name_str).and_then(|sig| sysprims_signal::kill(pid, sig));
+    match result {
+        Ok(()) => SysprimsErrorCode::Ok,
+        Err(e) => {
+            set_error(&e);
+            SysprimsErrorCode::from(&e)
+        }
+    }
+}
+
 /// Send a signal to a process group.
 ///
 /// On Unix, this calls `killpg(pgid, signal)`.
@@ -139,6 +202,70 @@ pub extern "C" fn sysprims_force_kill(pid: u32) -> SysprimsErrorCode {
     }
 }
 
+/// Resolve a signal string to its number.
+///
+/// Accepts numbers (`"15"`), names with or without the `SIG` prefix in any
+/// case (`"SIGTERM"`, `"TERM"`, `"term"`), and glob patterns that match exactly
+/// one signal (`"*usr1"`).
+///
+/// # Arguments
+///
+/// * `signal` - Null-terminated UTF-8 signal string
+/// * `signal_out` - Receives the resolved signal number
+///
+/// # Returns
+///
+/// * `SYSPRIMS_OK` on success
+/// * `SYSPRIMS_ERR_INVALID_ARGUMENT` for null pointers, unknown names, or
+///   patterns matching zero or several signals
+///
+/// # Safety
+///
+/// * `signal` must be a valid null-terminated C string
+/// * `signal_out` must be a valid pointer to an `i32`
+///
+/// # Example (C)
+///
+/// ```c
+/// int32_t sig = 0;
+/// if (sysprims_signal_resolve("term", &sig) == SYSPRIMS_OK) {
+///     sysprims_signal_send(pid, sig);
+/// }
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn sysprims_signal_resolve(
+    signal: *const c_char,
+    signal_out: *mut i32,
+) -> SysprimsErrorCode {
+    clear_error_state();
+
+    if signal.is_null() || signal_out.is_null() {
+        let err = SysprimsError::invalid_argument("signal and signal_out cannot be null");
+        set_error(&err);
+        return SysprimsErrorCode::InvalidArgument;
+    }
+
+    let signal_str = match CStr::from_ptr(signal).to_str() {
+        Ok(s) => s,
+        Err(_) => {
+            let err = SysprimsError::invalid_argument("signal is not valid UTF-8");
+            set_error(&err);
+            return SysprimsErrorCode::InvalidArgument;
+        }
+    };
+
+    match sysprims_signal::resolve_signal_or_pattern(signal_str) {
+        Ok(target) => {
+            *signal_out = target.number();
+            SysprimsErrorCode::Ok
+        }
+        Err(e) => {
+            set_error(&e);
+            SysprimsErrorCode::from(&e)
+        }
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -189,6 +316,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_signal_send_by_name_rejects_unknown_name() {
+        let pid = std::process::id();
+        let code = unsafe { sysprims_signal_send_by_name(pid, c"NOTASIGNAL".as_ptr()) };
+        assert_eq!(code, SysprimsErrorCode::InvalidArgument);
+
+        let code = unsafe { sysprims_signal_send_by_name(pid, std::ptr::null()) };
+        assert_eq!(code, SysprimsErrorCode::InvalidArgument);
+    }
+
+    #[test]
+    fn test_signal_send_by_name_accepts_name_and_number() {
+        // Signal 0 only checks existence, so it is safe to send to ourselves.
+        let pid = std::process::id();
+        let code = unsafe { sysprims_signal_send_by_name(pid, c"0".as_ptr()) };
+        assert_eq!(code, SysprimsErrorCode::Ok);
+
+        let code = unsafe { sysprims_signal_send_by_name(0, c"TERM".as_ptr()) };
+        assert_eq!(code, SysprimsErrorCode::InvalidArgument);
+    }
+
+    #[test]
+    fn test_signal_resolve() {
+        let mut sig = 0;
+        let code = unsafe { sysprims_signal_resolve(c"term".as_ptr(), &mut sig) };
+        assert_eq!(code, SysprimsErrorCode::Ok);
+        assert_eq!(sig, sysprims_signal::SIGTERM);
+
+        let code = unsafe { sysprims_signal_resolve(c"NOPE".as_ptr(), &mut sig) };
+        assert_eq!(code, SysprimsErrorCode::InvalidArgument);
+
+        let code = unsafe { sysprims_signal_resolve(std::ptr::null(), &mut sig) };
+        assert_eq!(code, SysprimsErrorCode::InvalidArgument);
+    }
+
     #[test]
     #[cfg(windows)]
     fn test_signal_send_group_not_supported_on_windows() {
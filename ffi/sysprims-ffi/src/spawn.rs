@@ -7,7 +7,7 @@ use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
 
 use crate::error::{clear_error_state, set_error, SysprimsErrorCode};
-use sysprims_core::schema::SPAWN_IN_GROUP_CONFIG_V1;
+use sysprims_core::schema::{accepts_schema_id, SPAWN_IN_GROUP_CONFIG_V1};
 use sysprims_core::SysprimsError;
 use sysprims_timeout::{spawn_in_group, SpawnInGroupConfig};
 
@@ -68,6 +68,12 @@ pub unsafe extern "C" fn sysprims_spawn_in_group(
         cwd: Option<String>,
         #[serde(default)]
         env: Option<std::collections::BTreeMap<String, String>>,
+        #[serde(default)]
+        expand_env: bool,
+        #[serde(default)]
+        clear_env: bool,
+        #[serde(default)]
+        env_passthrough: Vec<String>,
     }
 
     let wire = match serde_json::from_str::<WireConfig>(cfg_str) {
@@ -79,7 +85,7 @@ pub unsafe extern "C" fn sysprims_spawn_in_group(
         }
     };
 
-    if wire.schema_id != SPAWN_IN_GROUP_CONFIG_V1 {
+    if !accepts_schema_id(SPAWN_IN_GROUP_CONFIG_V1, &wire.schema_id) {
         let err = SysprimsError::invalid_argument(format!(
             "invalid schema_id (expected {})",
             SPAWN_IN_GROUP_CONFIG_V1
@@ -92,6 +98,9 @@ pub unsafe extern "C" fn sysprims_spawn_in_group(
         argv: wire.argv,
         cwd: wire.cwd,
         env: wire.env,
+        expand_env: wire.expand_env,
+        clear_env: wire.clear_env,
+        env_passthrough: wire.env_passthrough,
     };
 
     let result = match spawn_in_group(cfg) {
@@ -102,7 +111,7 @@ pub unsafe extern "C" fn sysprims_spawn_in_group(
         }
     };
 
-    let json = match serde_json::to_string(&result) {
+    let json = match crate::to_json(&result) {
         Ok(j) => j,
         Err(e) => {
             let err = SysprimsError::internal(format!("failed to serialize spawn result: {}", e));
@@ -7,8 +7,8 @@ use std::os::raw::c_char;
 use std::time::Duration;
 
 use serde::Serialize;
-use sysprims_core::schema::{TERMINATE_TREE_CONFIG_V1, TIMEOUT_RESULT_V1};
-use sysprims_core::SysprimsError;
+use sysprims_core::schema::{accepts_schema_id, TERMINATE_TREE_CONFIG_V1, TIMEOUT_RESULT_V1};
+use sysprims_core::{SysprimsError, Warning};
 use sysprims_timeout::{
     terminate_tree, GroupingMode, TerminateTreeConfig, TimeoutConfig, TimeoutOutcome,
     TreeKillReliability,
@@ -30,6 +30,12 @@ struct SysprimsTerminateTreeConfig {
     signal: Option<i32>,
     #[serde(default)]
     kill_signal: Option<i32>,
+    #[serde(default)]
+    require_start_time_ms: Option<u64>,
+    #[serde(default)]
+    require_exe_path: Option<String>,
+    #[serde(default)]
+    skip_grace: bool,
 }
 
 fn default_config_schema_id() -> String {
@@ -51,6 +57,9 @@ impl From<SysprimsTerminateTreeConfig> for TerminateTreeConfig {
         if let Some(v) = value.kill_signal {
             cfg.kill_signal = v;
         }
+        cfg.require_start_time_ms = value.require_start_time_ms;
+        cfg.require_exe_path = value.require_exe_path;
+        cfg.skip_grace = value.skip_grace;
         cfg
     }
 }
@@ -67,6 +76,9 @@ pub enum SysprimsGroupingMode {
     GroupByDefault = 0,
     /// Run in foreground. Only kills direct child on timeout.
     Foreground = 1,
+    /// Create new process group but keep it in the terminal foreground.
+    /// Kill entire tree on timeout. On Windows, same as GroupByDefault.
+    NewGroupForeground = 2,
 }
 
 // C-friendly constants (see `ffi/sysprims-ffi/src/error.rs` for rationale).
@@ -74,12 +86,16 @@ pub enum SysprimsGroupingMode {
 pub const SYSPRIMS_GROUP_BY_DEFAULT: SysprimsGroupingMode = SysprimsGroupingMode::GroupByDefault;
 #[allow(dead_code)] // exported for cbindgen-generated C header
 pub const SYSPRIMS_FOREGROUND: SysprimsGroupingMode = SysprimsGroupingMode::Foreground;
+#[allow(dead_code)] // exported for cbindgen-generated C header
+pub const SYSPRIMS_NEW_GROUP_FOREGROUND: SysprimsGroupingMode =
+    SysprimsGroupingMode::NewGroupForeground;
 
 impl From<SysprimsGroupingMode> for GroupingMode {
     fn from(mode: SysprimsGroupingMode) -> Self {
         match mode {
             SysprimsGroupingMode::GroupByDefault => GroupingMode::GroupByDefault,
             SysprimsGroupingMode::Foreground => GroupingMode::Foreground,
+            SysprimsGroupingMode::NewGroupForeground => GroupingMode::NewGroupForeground,
         }
     }
 }
@@ -114,6 +130,11 @@ pub struct SysprimsTimeoutConfig {
 
     /// Whether to preserve the child's exit code.
     pub preserve_status: bool,
+
+    /// Expand `$VAR` / `${VAR}` in `command` and `args` against the inherited
+    /// environment. Undefined variables expand to empty and are reported in
+    /// the result's `warnings`.
+    pub expand_env: bool,
 }
 
 /// Result of timeout execution.
@@ -122,6 +143,12 @@ pub struct SysprimsTimeoutConfig {
 #[derive(Debug, Serialize)]
 struct SysprimsTimeoutResult {
     /// Schema ID for this output.
+    ///
+    /// Serialized together with `schema_version`, its major version.
+    #[serde(
+        flatten,
+        serialize_with = "sysprims_core::schema::serialize_with_version"
+    )]
     pub schema_id: &'static str,
     /// Whether the command completed or timed out.
     pub status: String,
@@ -141,23 +168,37 @@ struct SysprimsTimeoutResult {
     /// Tree-kill reliability: "guaranteed" or "best_effort".
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tree_kill_reliability: Option<String>,
+
+    /// Descendants still running after the kill (None if completed).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub survivors: Option<Vec<u32>>,
+
+    /// Non-fatal issues, such as variables left undefined by `expand_env`.
+    pub warnings: Vec<Warning>,
 }
 
 impl From<TimeoutOutcome> for SysprimsTimeoutResult {
     fn from(outcome: TimeoutOutcome) -> Self {
         match outcome {
-            TimeoutOutcome::Completed { exit_status } => SysprimsTimeoutResult {
+            TimeoutOutcome::Completed {
+                exit_status,
+                warnings,
+            } => SysprimsTimeoutResult {
                 schema_id: TIMEOUT_RESULT_V1,
                 status: "completed".to_string(),
                 exit_code: exit_status.code(),
                 signal_sent: None,
                 escalated: None,
                 tree_kill_reliability: None,
+                survivors: None,
+                warnings,
             },
             TimeoutOutcome::TimedOut {
                 signal_sent,
                 escalated,
                 tree_kill_reliability,
+                survivors,
+                warnings,
             } => SysprimsTimeoutResult {
                 schema_id: TIMEOUT_RESULT_V1,
                 status: "timed_out".to_string(),
@@ -168,6 +209,27 @@ impl From<TimeoutOutcome> for SysprimsTimeoutResult {
                     TreeKillReliability::Guaranteed => "guaranteed".to_string(),
                     TreeKillReliability::BestEffort => "best_effort".to_string(),
                 }),
+                survivors: Some(survivors),
+                warnings,
+            },
+            TimeoutOutcome::Cancelled {
+                signal_sent,
+                escalated,
+                tree_kill_reliability,
+                survivors,
+                warnings,
+            } => SysprimsTimeoutResult {
+                schema_id: TIMEOUT_RESULT_V1,
+                status: "cancelled".to_string(),
+                exit_code: None,
+                signal_sent: Some(signal_sent),
+                escalated: Some(escalated),
+                tree_kill_reliability: Some(match tree_kill_reliability {
+                    TreeKillReliability::Guaranteed => "guaranteed".to_string(),
+                    TreeKillReliability::BestEffort => "best_effort".to_string(),
+                }),
+                survivors: Some(survivors),
+                warnings,
             },
         }
     }
@@ -229,6 +291,7 @@ impl From<TimeoutOutcome> for SysprimsTimeoutResult {
 ///     .signal = 15,  // SIGTERM
 ///     .grouping = SYSPRIMS_GROUP_BY_DEFAULT,
 ///     .preserve_status = false,
+///     .expand_env = false,
 /// };
 ///
 /// char* result = NULL;
@@ -323,6 +386,10 @@ pub unsafe extern "C" fn sysprims_timeout_run(
         kill_after: Duration::from_millis(cfg.kill_after_ms),
         grouping: GroupingMode::from(cfg.grouping),
         preserve_status: cfg.preserve_status,
+        expand_env: cfg.expand_env,
+        clear_env: false,
+        env_passthrough: Vec::new(),
+        cancel: None,
     };
 
     let timeout = Duration::from_millis(cfg.timeout_ms);
@@ -341,7 +408,7 @@ pub unsafe extern "C" fn sysprims_timeout_run(
     let result = SysprimsTimeoutResult::from(outcome);
 
     // Serialize to JSON
-    let json = match serde_json::to_string(&result) {
+    let json = match crate::to_json(&result) {
         Ok(j) => j,
         Err(e) => {
             let err = SysprimsError::internal(format!("failed to serialize result: {}", e));
@@ -418,7 +485,7 @@ pub unsafe extern "C" fn sysprims_terminate_tree(
                 }
             };
 
-            if parsed.schema_id != TERMINATE_TREE_CONFIG_V1 {
+            if !accepts_schema_id(TERMINATE_TREE_CONFIG_V1, &parsed.schema_id) {
                 let err = SysprimsError::invalid_argument(format!(
                     "invalid schema_id (expected {})",
                     TERMINATE_TREE_CONFIG_V1
@@ -439,7 +506,7 @@ pub unsafe extern "C" fn sysprims_terminate_tree(
         }
     };
 
-    let json = match serde_json::to_string(&result) {
+    let json = match crate::to_json(&result) {
         Ok(j) => j,
         Err(e) => {
             let err =
@@ -483,6 +550,7 @@ mod tests {
             signal: 15, // SIGTERM
             grouping: SysprimsGroupingMode::GroupByDefault,
             preserve_status: false,
+            expand_env: false,
         }
     }
 
@@ -515,6 +583,7 @@ mod tests {
             signal: 15,
             grouping: SysprimsGroupingMode::GroupByDefault,
             preserve_status: false,
+            expand_env: false,
         };
 
         let mut result: *mut c_char = ptr::null_mut();
@@ -547,6 +616,7 @@ mod tests {
             signal: 15,
             grouping: SysprimsGroupingMode::GroupByDefault,
             preserve_status: false,
+            expand_env: false,
         };
 
         let mut result: *mut c_char = ptr::null_mut();
@@ -587,6 +657,7 @@ mod tests {
             signal: 15,
             grouping: SysprimsGroupingMode::GroupByDefault,
             preserve_status: false,
+            expand_env: false,
         };
 
         let mut result: *mut c_char = ptr::null_mut();
@@ -605,6 +676,44 @@ mod tests {
             TIMEOUT_RESULT_V1,
             json
         );
+        // The result validates against the embedded timeout-result schema.
+        let value: serde_json::Value = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            sysprims_core::schema_check::validate_value(&value),
+            Ok(()),
+            "JSON: {json}"
+        );
+
+        unsafe { sysprims_free_string(result) };
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_timeout_expand_env_reports_undefined() {
+        let cmd = CString::new("sh").unwrap();
+        let args_raw = [
+            CString::new("-c").unwrap(),
+            CString::new("exit 0").unwrap(),
+            CString::new("$SYSPRIMS_FFI_TEST_UNDEFINED_VAR").unwrap(),
+        ];
+        let args_ptrs: Vec<*const c_char> = args_raw.iter().map(|s| s.as_ptr()).collect();
+        let mut config = make_config(&cmd, 10000);
+        config.args = args_ptrs.as_ptr();
+        config.args_len = args_ptrs.len();
+        config.expand_env = true;
+
+        let mut result: *mut c_char = ptr::null_mut();
+        let code = unsafe { sysprims_timeout_run(&config, &mut result) };
+        assert_eq!(code, SysprimsErrorCode::Ok);
+
+        let json = unsafe { CStr::from_ptr(result).to_str().unwrap() };
+        let value: serde_json::Value = serde_json::from_str(json).unwrap();
+        let warnings = value["warnings"].as_array().unwrap();
+        assert_eq!(warnings.len(), 1, "JSON: {json}");
+        assert!(warnings[0]["message"]
+            .as_str()
+            .unwrap()
+            .contains("SYSPRIMS_FFI_TEST_UNDEFINED_VAR"));
 
         unsafe { sysprims_free_string(result) };
     }
@@ -617,6 +726,40 @@ mod tests {
         assert!(result.is_null());
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_terminate_tree_enforces_required_identity() {
+        let mut child = std::process::Command::new("sleep")
+            .arg("60")
+            .spawn()
+            .expect("Failed to spawn sleep");
+
+        // Configs written against the older v1.0.0 schema are still accepted.
+        let cfg = CString::new(format!(
+            r#"{{"schema_id":"{}","require_exe_path":"/definitely/not/sleep"}}"#,
+            TERMINATE_TREE_CONFIG_V1.replace("/v1.1.0/", "/v1.0.0/")
+        ))
+        .unwrap();
+        let mut result: *mut c_char = ptr::null_mut();
+        let code = unsafe { sysprims_terminate_tree(child.id(), cfg.as_ptr(), &mut result) };
+        assert_eq!(code, SysprimsErrorCode::InvalidArgument);
+        assert!(result.is_null());
+
+        let err_ptr = crate::error::sysprims_last_error_json();
+        let err: serde_json::Value = serde_json::from_str(
+            unsafe { std::ffi::CStr::from_ptr(err_ptr) }
+                .to_str()
+                .unwrap(),
+        )
+        .unwrap();
+        unsafe { crate::sysprims_free_string(err_ptr) };
+        assert_eq!(err["kind"], "identity_mismatch");
+        assert_eq!(err["context"]["pid"], child.id());
+
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+
     #[test]
     fn test_terminate_tree_kills_spawned_child() {
         #[cfg(unix)]
@@ -684,5 +827,9 @@ mod tests {
             GroupingMode::from(SysprimsGroupingMode::Foreground),
             GroupingMode::Foreground
         );
+        assert_eq!(
+            GroupingMode::from(SysprimsGroupingMode::NewGroupForeground),
+            GroupingMode::NewGroupForeground
+        );
     }
 }